@@ -0,0 +1,70 @@
+import unittest
+
+from metis.testing import ComponentTestCase
+from metis.access import AccessControl
+
+
+class AccessControlTestCase(ComponentTestCase):
+    PORT = 39948
+
+    @classmethod
+    def setUpClass(cls):
+        super().setUpClass()
+        # alice is the fliper, bob is the setter, charlie is the admin
+        cls.contract = AccessControl.deploy(
+            cls.substrate, cls.alice, False,
+            cls.alice.ss58_address, cls.bob.ss58_address, cls.charlie.ss58_address,
+        )
+
+    def test_init_roles(self):
+        self.assertTrue(self.contract.has_role(AccessControl.ROLE_ID_FLIPER, self.alice.ss58_address))
+        self.assertTrue(self.contract.has_role(AccessControl.ROLE_ID_SETTER, self.bob.ss58_address))
+        self.assertTrue(self.contract.has_role(AccessControl.ROLE_ID_ADMIN, self.charlie.ss58_address))
+        self.assertFalse(self.contract.has_role(AccessControl.ROLE_ID_FLIPER, self.dave.ss58_address))
+
+        self.assertEqual(
+            self.contract.get_role_admin(AccessControl.ROLE_ID_FLIPER), AccessControl.ROLE_ID_ADMIN)
+
+    def test_role_checks(self):
+        self.assertFailed(self.contract.flip(self.bob))
+        self.assertFailed(self.contract.set(self.alice, True))
+
+        value = self.contract.get()
+        self.assertSuccess(self.contract.flip(self.alice))
+        self.assertEqual(self.contract.get(), not value)
+
+        self.assertSuccess(self.contract.set(self.bob, value))
+        self.assertEqual(self.contract.get(), value)
+
+    def test_grant_and_revoke(self):
+        # only the admin of the role can grant it
+        res = self.contract.grant_role(self.alice, AccessControl.ROLE_ID_FLIPER, self.dave.ss58_address)
+        self.assertFailed(res)
+
+        res = self.contract.grant_role(self.charlie, AccessControl.ROLE_ID_FLIPER, self.dave.ss58_address)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'RoleGranted')
+        self.assertTrue(self.contract.has_role(AccessControl.ROLE_ID_FLIPER, self.dave.ss58_address))
+        self.assertSuccess(self.contract.flip(self.dave))
+
+        res = self.contract.revoke_role(self.charlie, AccessControl.ROLE_ID_FLIPER, self.dave.ss58_address)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'RoleRevoked')
+        self.assertFalse(self.contract.has_role(AccessControl.ROLE_ID_FLIPER, self.dave.ss58_address))
+        self.assertFailed(self.contract.flip(self.dave))
+
+    def test_renounce(self):
+        res = self.contract.grant_role(self.charlie, AccessControl.ROLE_ID_FLIPER, self.bob.ss58_address)
+        self.assertSuccess(res)
+
+        # can only renounce the roles for self
+        res = self.contract.renounce_role(self.alice, AccessControl.ROLE_ID_FLIPER, self.bob.ss58_address)
+        self.assertFailed(res)
+
+        res = self.contract.renounce_role(self.bob, AccessControl.ROLE_ID_FLIPER, self.bob.ss58_address)
+        self.assertSuccess(res)
+        self.assertFalse(self.contract.has_role(AccessControl.ROLE_ID_FLIPER, self.bob.ss58_address))
+
+
+if __name__ == '__main__':
+    unittest.main()
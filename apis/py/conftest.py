@@ -0,0 +1,5 @@
+import os
+import sys
+
+# make the `metis` package importable for the tests under `apis/py`
+sys.path.insert(0, os.path.dirname(os.path.abspath(__file__)))
@@ -0,0 +1,96 @@
+import time
+import unittest
+
+from metis.testing import ComponentTestCase
+from metis.governance import TimelockController, TimelockControllerReceiver
+
+# in milliseconds
+MIN_DELAY = 6000
+
+SALT = 0x01
+
+
+class TimelockControllerTestCase(ComponentTestCase):
+    PORT = 39950
+
+    @classmethod
+    def setUpClass(cls):
+        super().setUpClass()
+        # alice and bob are the proposers, charlie and dave are the executors
+        cls.timelock = TimelockController.deploy(
+            cls.substrate, cls.alice, MIN_DELAY,
+            [cls.alice.ss58_address, cls.bob.ss58_address],
+            [cls.charlie.ss58_address, cls.dave.ss58_address],
+        )
+
+        # the target of the operations, which accepts the calls from timelock
+        cls.receiver = TimelockControllerReceiver.deploy(cls.substrate, cls.alice)
+        assert cls.receiver.add_accept_caller(cls.alice, cls.timelock.address).is_success
+        assert cls.receiver.set_receive_status(cls.alice, True).is_success
+
+    def operation(self, salt):
+        # an operation which calls the receiver without value
+        return (self.receiver.address, 0, b'', None, salt)
+
+    def test_init(self):
+        self.assertEqual(self.timelock.get_min_delay(), MIN_DELAY)
+
+        self.assertTrue(self.timelock.has_role(TimelockController.TIMELOCK_ADMIN_ROLE, self.alice.ss58_address))
+        self.assertTrue(self.timelock.has_role(TimelockController.PROPOSER_ROLE, self.alice.ss58_address))
+        self.assertTrue(self.timelock.has_role(TimelockController.PROPOSER_ROLE, self.bob.ss58_address))
+        self.assertTrue(self.timelock.has_role(TimelockController.EXECUTOR_ROLE, self.charlie.ss58_address))
+        self.assertTrue(self.timelock.has_role(TimelockController.EXECUTOR_ROLE, self.dave.ss58_address))
+        self.assertFalse(self.timelock.has_role(TimelockController.PROPOSER_ROLE, self.charlie.ss58_address))
+
+    def test_schedule_and_cancel(self):
+        operation = self.operation(SALT)
+        id = self.timelock.hash_operation(*operation)
+        self.assertFalse(self.timelock.is_operation(id))
+
+        # only the proposers can schedule
+        self.assertFailed(self.timelock.schedule(self.charlie, *operation, MIN_DELAY))
+        # the delay should not be less than the min delay
+        self.assertFailed(self.timelock.schedule(self.alice, *operation, MIN_DELAY - 1))
+
+        res = self.timelock.schedule(self.alice, *operation, MIN_DELAY)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'CallScheduled')
+        self.assertTrue(self.timelock.is_operation(id))
+        self.assertTrue(self.timelock.is_operation_pending(id))
+        self.assertFalse(self.timelock.is_operation_done(id))
+
+        # can not schedule twice
+        self.assertFailed(self.timelock.schedule(self.bob, *operation, MIN_DELAY))
+
+        # only the proposers can cancel
+        self.assertFailed(self.timelock.cancel(self.charlie, id))
+
+        res = self.timelock.cancel(self.bob, id)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Cancelled')
+        self.assertFalse(self.timelock.is_operation(id))
+
+    def test_execute(self):
+        operation = self.operation(0x02)
+        id = self.timelock.hash_operation(*operation)
+
+        self.assertSuccess(self.timelock.schedule(self.alice, *operation, MIN_DELAY))
+
+        # not ready yet
+        self.assertFalse(self.timelock.is_operation_ready(id))
+        self.assertFailed(self.timelock.execute(self.charlie, *operation))
+
+        time.sleep(MIN_DELAY / 1000 + 6)
+        self.assertTrue(self.timelock.is_operation_ready(id))
+
+        # only the executors can execute
+        self.assertFailed(self.timelock.execute(self.alice, *operation))
+
+        res = self.timelock.execute(self.charlie, *operation)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'CallExecuted')
+        self.assertTrue(self.timelock.is_operation_done(id))
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,14 @@
+"""Python api for metis components.
+
+The wrappers are driven by the metadata of the example contracts in
+`example/`, which are compiled by `npx redspot compile` into
+`example/artifacts`, and call the contracts by the `patractinterface`.
+"""
+
+from .contract import (
+    ARTIFACTS_PATH,
+    DEFAULT_GAS_LIMIT,
+    Component,
+    artifact_files,
+    to_bytes32,
+)
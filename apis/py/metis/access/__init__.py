@@ -0,0 +1,2 @@
+from .access_control import AccessControl
+from .ownable import Ownable
@@ -0,0 +1,40 @@
+from ..contract import Component, to_bytes32
+
+
+class AccessControlMessages(Component):
+    """The messages of `metis_access_control`, the `role` is a `[u8; 32]`."""
+
+    def has_role(self, role, account):
+        return self.read('has_role', to_bytes32(role), account)
+
+    def get_role_admin(self, role):
+        return self.read('get_role_admin', to_bytes32(role))
+
+    def grant_role(self, signer, role, account, **kwargs):
+        return self.exec(signer, 'grant_role', to_bytes32(role), account, **kwargs)
+
+    def revoke_role(self, signer, role, account, **kwargs):
+        return self.exec(signer, 'revoke_role', to_bytes32(role), account, **kwargs)
+
+    def renounce_role(self, signer, role, account, **kwargs):
+        return self.exec(signer, 'renounce_role', to_bytes32(role), account, **kwargs)
+
+
+class AccessControl(AccessControlMessages):
+    """The api of the `access_control` example, which has a flag can be
+    flipped by the `ROLE_ID_FLIPER` and be set by the `ROLE_ID_SETTER`."""
+
+    ARTIFACT = 'access_control'
+
+    ROLE_ID_FLIPER = to_bytes32(0x01)
+    ROLE_ID_SETTER = to_bytes32(0x02)
+    ROLE_ID_ADMIN = to_bytes32(0x03)
+
+    def get(self):
+        return self.read('get')
+
+    def flip(self, signer, **kwargs):
+        return self.exec(signer, 'flip', **kwargs)
+
+    def set(self, signer, value, **kwargs):
+        return self.exec(signer, 'set', value, **kwargs)
@@ -0,0 +1,15 @@
+from ..contract import Component
+
+
+class Ownable(Component):
+    """The messages of `metis_ownable`, mixed into the apis of the example
+    contracts which import it."""
+
+    def owner(self):
+        return self.read('owner')
+
+    def renounce_ownership(self, signer, **kwargs):
+        return self.exec(signer, 'renounce_ownership', **kwargs)
+
+    def transfer_ownership(self, signer, new_owner, **kwargs):
+        return self.exec(signer, 'transfer_ownership', new_owner, **kwargs)
@@ -0,0 +1,94 @@
+import os
+import json
+import tempfile
+
+from patractinterface.contract import ContractFactory
+
+# the artifacts compiled by `npx redspot compile` in `example`
+ARTIFACTS_PATH = os.environ.get(
+    'METIS_ARTIFACTS',
+    os.path.join(os.path.dirname(__file__), '../../../example/artifacts'),
+)
+
+DEFAULT_GAS_LIMIT = 200000000000
+DEFAULT_DEPLOY_GAS_LIMIT = 1000000000000
+DEFAULT_ENDOWMENT = 10 ** 15
+
+
+def artifact_files(name, path=None):
+    """Returns the wasm and the metadata file of the contract `name`.
+
+    Redspot puts the metadata into `<name>.json` and the wasm into the
+    `source.wasm` of `<name>.contract`, the wasm is extracted into a temp file
+    if there is no `<name>.wasm`.
+    """
+    path = path or ARTIFACTS_PATH
+    metadata_file = os.path.join(path, f'{name}.json')
+    code_file = os.path.join(path, f'{name}.wasm')
+
+    if not os.path.exists(metadata_file):
+        raise FileNotFoundError(
+            f'no metadata of {name} in {path}, run `npx redspot compile` in example at first')
+
+    if not os.path.exists(code_file):
+        with open(os.path.join(path, f'{name}.contract')) as f:
+            wasm = json.load(f)['source']['wasm']
+
+        code_file = os.path.join(tempfile.gettempdir(), f'metis_{name}.wasm')
+        with open(code_file, 'wb') as f:
+            f.write(bytes.fromhex(wasm[2:] if wasm.startswith('0x') else wasm))
+
+    return code_file, metadata_file
+
+
+def to_bytes32(value):
+    """Returns the `[u8; 32]` args like `TokenId` and `RoleId` as the hex
+    string, from a byte repeated, such as `to_bytes32(0x0a)`, or the bytes."""
+    if isinstance(value, int):
+        value = bytes([value] * 32)
+    if isinstance(value, (bytes, bytearray)):
+        assert len(value) == 32, 'bytes32 should be 32 bytes'
+        return '0x' + value.hex()
+    return value
+
+
+class Component:
+    """The base of the component apis.
+
+    The messages of a contract are called by the `ContractAPI` of the
+    `patractinterface`, which reads the messages not mutates and executes the
+    others, so the wrappers only give the names and the order of the args.
+    """
+
+    # the artifact name of the example contract, such as `erc20_basic`
+    ARTIFACT = None
+
+    def __init__(self, api, caller):
+        self.api = api
+        # the default caller of the reads
+        self.caller = caller
+
+    @classmethod
+    def deploy(cls, substrate, signer, *args, artifacts=None, constructor='new',
+               endowment=DEFAULT_ENDOWMENT, gas_limit=DEFAULT_DEPLOY_GAS_LIMIT):
+        """Deploy the example contract of the component by the `constructor`."""
+        code_file, metadata_file = artifact_files(cls.ARTIFACT, artifacts)
+        factory = ContractFactory.create_from_file(
+            substrate=substrate,
+            code_file=code_file,
+            metadata_file=metadata_file,
+        )
+
+        api = getattr(factory, constructor)(signer, *args, endowment=endowment, gas_limit=gas_limit)
+        return cls(api, signer)
+
+    @property
+    def address(self):
+        """The ss58 address of the contract."""
+        return self.api.contract_address
+
+    def read(self, method, *args, caller=None):
+        return getattr(self.api, method)(caller or self.caller, *args)
+
+    def exec(self, signer, method, *args, gas_limit=DEFAULT_GAS_LIMIT, **kwargs):
+        return getattr(self.api, method)(signer, *args, gas_limit=gas_limit, **kwargs)
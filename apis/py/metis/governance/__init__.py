@@ -0,0 +1 @@
+from .timelock_controller import TimelockController, TimelockControllerReceiver
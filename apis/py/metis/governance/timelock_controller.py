@@ -0,0 +1,72 @@
+from ..contract import Component, to_bytes32
+from ..access.access_control import AccessControlMessages
+
+
+class TimelockController(AccessControlMessages):
+    """The api of `metis_timelock_controller`, by the `timelock_controller`
+    example, the `delay` is in milliseconds."""
+
+    ARTIFACT = 'timelock_controller'
+
+    # the `metis_lang::hash!` of the role names
+    TIMELOCK_ADMIN_ROLE = '0xb057ce3cb9051cf2581899ed54b0d6803c0e38e8338025ab3d1c21b4b4f6f8e3'
+    PROPOSER_ROLE = '0xec2f41cc3cff7469794f1f07ae29218d852ff065e75c2d5265d76286e2bf8e3f'
+    EXECUTOR_ROLE = '0x5b5d255344597471a6dec86e87d2617958ba78e21e675de9ed51aeec2e525345'
+
+    def is_operation(self, id):
+        return self.read('is_operation', to_bytes32(id))
+
+    def is_operation_pending(self, id):
+        return self.read('is_operation_pending', to_bytes32(id))
+
+    def is_operation_ready(self, id):
+        return self.read('is_operation_ready', to_bytes32(id))
+
+    def is_operation_done(self, id):
+        return self.read('is_operation_done', to_bytes32(id))
+
+    def get_timestamp(self, id):
+        return self.read('get_timestamp', to_bytes32(id))
+
+    def get_min_delay(self):
+        return self.read('get_min_delay')
+
+    def hash_operation(self, target, value, data, predecessor, salt):
+        return self.read(
+            'hash_operation', target, value, data, _predecessor(predecessor), to_bytes32(salt))
+
+    def schedule(self, signer, target, value, data, predecessor, salt, delay, **kwargs):
+        return self.exec(
+            signer, 'schedule', target, value, data,
+            _predecessor(predecessor), to_bytes32(salt), delay, **kwargs)
+
+    def cancel(self, signer, id, **kwargs):
+        return self.exec(signer, 'cancel', to_bytes32(id), **kwargs)
+
+    def execute(self, signer, target, value, data, predecessor, salt, **kwargs):
+        return self.exec(
+            signer, 'execute', target, value, data,
+            _predecessor(predecessor), to_bytes32(salt), **kwargs)
+
+
+class TimelockControllerReceiver(Component):
+    """The api of the `timelock_controller_receiver` example, the target of
+    the operations, which accepts the calls from the callers added by owner."""
+
+    ARTIFACT = 'timelock_controller_receiver'
+
+    def get_ownership(self):
+        return self.read('get_ownership')
+
+    def set_receive_status(self, signer, is_receive, **kwargs):
+        return self.exec(signer, 'set_receive_status', is_receive, **kwargs)
+
+    def add_accept_caller(self, signer, contract, **kwargs):
+        return self.exec(signer, 'add_accept_caller', contract, **kwargs)
+
+    def del_accept_caller(self, signer, contract, **kwargs):
+        return self.exec(signer, 'del_accept_caller', contract, **kwargs)
+
+
+def _predecessor(predecessor):
+    return None if predecessor is None else to_bytes32(predecessor)
@@ -0,0 +1 @@
+from .pausable import Pausable
@@ -0,0 +1,15 @@
+from ..contract import Component
+
+
+class Pausable(Component):
+    """The messages of `metis_pausable`, mixed into the apis of the example
+    contracts which import it, the `pause` and `unpause` are for the owner."""
+
+    def paused(self):
+        return self.read('paused')
+
+    def pause(self, signer, **kwargs):
+        return self.exec(signer, 'pause', **kwargs)
+
+    def unpause(self, signer, **kwargs):
+        return self.exec(signer, 'unpause', **kwargs)
@@ -0,0 +1,46 @@
+import unittest
+import logging
+
+from substrateinterface import SubstrateInterface, Keypair
+from patractinterface.unittest.env import SubstrateTestEnv
+
+
+class ComponentTestCase(unittest.TestCase):
+    """The base of the component tests, which launch a local europa node for
+    each test class, and deploy the example contracts to it.
+    """
+
+    # each test class should use its own port, so they can run together
+    PORT = 39944
+
+    @classmethod
+    def setUpClass(cls):
+        logging.info(f'start europa for {cls.__name__}')
+
+        cls.env = SubstrateTestEnv.create_europa(port=cls.PORT)
+        cls.env.start_node()
+        cls.substrate = SubstrateInterface(
+            url=cls.env.url(),
+            type_registry_preset=cls.env.typ(),
+            type_registry=cls.env.types(),
+        )
+
+        cls.alice = Keypair.create_from_uri('//Alice')
+        cls.bob = Keypair.create_from_uri('//Bob')
+        cls.charlie = Keypair.create_from_uri('//Charlie')
+        cls.dave = Keypair.create_from_uri('//Dave')
+
+    @classmethod
+    def tearDownClass(cls):
+        cls.env.stop_node()
+
+    def assertSuccess(self, res):
+        self.assertTrue(res.is_success, f'call should be success: {res.error_message}')
+
+    def assertFailed(self, res):
+        self.assertFalse(res.is_success, 'call should be failed')
+
+    def assertEvent(self, res, name):
+        """Assert the `name` event is emitted in the call."""
+        names = [event.name for event in getattr(res, 'contract_events', [])]
+        self.assertIn(name, names, f'{name} should be emitted, but {names}')
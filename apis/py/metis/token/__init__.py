@@ -0,0 +1,4 @@
+from .erc20 import Erc20, Erc20Pausable
+from .erc721 import Erc721
+from .erc1155 import Erc1155
+from .erc777 import Erc777
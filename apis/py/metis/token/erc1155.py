@@ -0,0 +1,34 @@
+from ..contract import Component, to_bytes32
+
+
+class Erc1155(Component):
+    """The api of `metis_erc1155`, by the `erc1155` example."""
+
+    ARTIFACT = 'erc1155'
+
+    def url(self, token_id):
+        return self.read('url', to_bytes32(token_id))
+
+    def balance_of(self, owner, token_id):
+        return self.read('balance_of', owner, to_bytes32(token_id))
+
+    def balance_of_batch(self, accounts, token_ids):
+        return self.read('balance_of_batch', accounts, [to_bytes32(id) for id in token_ids])
+
+    def is_approved_for_all(self, owner, operator):
+        return self.read('is_approved_for_all', owner, operator)
+
+    def set_approval_for_all(self, signer, operator, approved, **kwargs):
+        return self.exec(signer, 'set_approval_for_all', operator, approved, **kwargs)
+
+    def safe_transfer_from(self, signer, from_, to, token_id, amount, data=b'', **kwargs):
+        return self.exec(
+            signer, 'safe_transfer_from', from_, to, to_bytes32(token_id), amount, data, **kwargs)
+
+    # the test messages of the example
+
+    def mint(self, signer, to, token_id, amount, data=b'', **kwargs):
+        return self.exec(signer, 'mint_test', to, to_bytes32(token_id), amount, data, **kwargs)
+
+    def burn(self, signer, account, token_id, amount, **kwargs):
+        return self.exec(signer, 'burn_test', account, to_bytes32(token_id), amount, **kwargs)
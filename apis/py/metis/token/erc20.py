@@ -0,0 +1,43 @@
+from ..contract import Component
+from ..access.ownable import Ownable
+from ..security.pausable import Pausable
+
+
+class Erc20(Component):
+    """The api of `metis_erc20`, by the `erc20_basic` example."""
+
+    ARTIFACT = 'erc20_basic'
+
+    def name(self):
+        return self.read('name')
+
+    def symbol(self):
+        return self.read('symbol')
+
+    def decimals(self):
+        return self.read('decimals')
+
+    def total_supply(self):
+        return self.read('total_supply')
+
+    def balance_of(self, owner):
+        return self.read('balance_of', owner)
+
+    def allowance(self, owner, spender):
+        return self.read('allowance', owner, spender)
+
+    def transfer(self, signer, to, value, **kwargs):
+        return self.exec(signer, 'transfer', to, value, **kwargs)
+
+    def approve(self, signer, spender, value, **kwargs):
+        return self.exec(signer, 'approve', spender, value, **kwargs)
+
+    def transfer_from(self, signer, from_, to, value, **kwargs):
+        return self.exec(signer, 'transfer_from', from_, to, value, **kwargs)
+
+
+class Erc20Pausable(Erc20, Ownable, Pausable):
+    """The api of the erc20 with `metis_ownable` and `metis_pausable`, by the
+    `erc20_pausable` example, in which the owner can pause the transfers."""
+
+    ARTIFACT = 'erc20_pausable'
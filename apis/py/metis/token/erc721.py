@@ -0,0 +1,56 @@
+from ..contract import Component, to_bytes32
+
+
+class Erc721(Component):
+    """The api of `metis_erc721`, by the `erc721` example.
+
+    The `token_id` is a `[u8; 32]`, which can be given as a byte repeated,
+    such as `0x0a`, or the 32 bytes.
+    """
+
+    ARTIFACT = 'erc721'
+
+    def name(self):
+        return self.read('name')
+
+    def symbol(self):
+        return self.read('symbol')
+
+    def token_url(self, token_id):
+        return self.read('token_url', to_bytes32(token_id))
+
+    def balance_of(self, owner):
+        return self.read('balance_of', owner)
+
+    def owner_of(self, token_id):
+        return self.read('owner_of', to_bytes32(token_id))
+
+    def get_approved(self, token_id):
+        return self.read('get_approved', to_bytes32(token_id))
+
+    def is_approved_for_all(self, owner, operator):
+        return self.read('is_approved_for_all', owner, operator)
+
+    def approve(self, signer, to, token_id, **kwargs):
+        return self.exec(signer, 'approve', to, to_bytes32(token_id), **kwargs)
+
+    def set_approval_for_all(self, signer, operator, approved, **kwargs):
+        return self.exec(signer, 'set_approval_for_all', operator, approved, **kwargs)
+
+    def transfer_from(self, signer, from_, to, token_id, **kwargs):
+        return self.exec(signer, 'transfer_from', from_, to, to_bytes32(token_id), **kwargs)
+
+    def safe_transfer_from(self, signer, from_, to, token_id, **kwargs):
+        return self.exec(signer, 'safe_transfer_from', from_, to, to_bytes32(token_id), **kwargs)
+
+    def safe_transfer_from_with_data(self, signer, from_, to, token_id, data, **kwargs):
+        return self.exec(
+            signer, 'safe_transfer_from_with_data', from_, to, to_bytes32(token_id), data, **kwargs)
+
+    # the test messages of the example
+
+    def mint(self, signer, to, token_id, **kwargs):
+        return self.exec(signer, 'mint', to, to_bytes32(token_id), **kwargs)
+
+    def burn(self, signer, token_id, **kwargs):
+        return self.exec(signer, 'burn', to_bytes32(token_id), **kwargs)
@@ -0,0 +1,67 @@
+from ..contract import Component
+
+
+class Erc777(Component):
+    """The api of `metis_erc777`, by the `erc777` example."""
+
+    ARTIFACT = 'erc777'
+
+    def name(self):
+        return self.read('name')
+
+    def symbol(self):
+        return self.read('symbol')
+
+    def decimals(self):
+        return self.read('decimals')
+
+    def granularity(self):
+        return self.read('granularity')
+
+    def total_supply(self):
+        return self.read('total_supply')
+
+    def balance_of(self, owner):
+        return self.read('balance_of', owner)
+
+    def allowance(self, owner, spender):
+        return self.read('allowance', owner, spender)
+
+    def default_operators(self):
+        return self.read('default_operators')
+
+    def is_operator_for(self, operator, token_holder):
+        return self.read('is_operator_for', operator, token_holder)
+
+    def send(self, signer, recipient, amount, data=b'', **kwargs):
+        return self.exec(signer, 'send', recipient, amount, data, **kwargs)
+
+    def transfer(self, signer, to, value, **kwargs):
+        return self.exec(signer, 'transfer', to, value, **kwargs)
+
+    def burn(self, signer, amount, data=b'', **kwargs):
+        return self.exec(signer, 'burn', amount, data, **kwargs)
+
+    def authorize_operator(self, signer, operator, **kwargs):
+        return self.exec(signer, 'authorize_operator', operator, **kwargs)
+
+    def revoke_operator(self, signer, operator, **kwargs):
+        return self.exec(signer, 'revoke_operator', operator, **kwargs)
+
+    def operator_send(self, signer, sender, recipient, amount, data=b'', operator_data=b'', **kwargs):
+        return self.exec(
+            signer, 'operator_send', sender, recipient, amount, data, operator_data, **kwargs)
+
+    def operator_burn(self, signer, account, amount, data=b'', operator_data=b'', **kwargs):
+        return self.exec(signer, 'operator_burn', account, amount, data, operator_data, **kwargs)
+
+    def approve(self, signer, spender, value, **kwargs):
+        return self.exec(signer, 'approve', spender, value, **kwargs)
+
+    def transfer_from(self, signer, from_, to, value, **kwargs):
+        return self.exec(signer, 'transfer_from', from_, to, value, **kwargs)
+
+    # the test messages of the example
+
+    def mint(self, signer, to, value, **kwargs):
+        return self.exec(signer, 'mint', to, value, **kwargs)
@@ -0,0 +1 @@
+from .escrow import Escrow
@@ -0,0 +1,17 @@
+from ..contract import Component
+
+
+class Escrow(Component):
+    """The api of `metis_escrow`, by the `escrow` example, only the owner,
+    which is the deployer, can deposit and withdraw."""
+
+    ARTIFACT = 'escrow'
+
+    def deposits_of(self, payee):
+        return self.read('deposits_of', payee)
+
+    def deposit(self, signer, payee, value, **kwargs):
+        return self.exec(signer, 'deposit', payee, value=value, **kwargs)
+
+    def withdraw(self, signer, payee, **kwargs):
+        return self.exec(signer, 'withdraw', payee, **kwargs)
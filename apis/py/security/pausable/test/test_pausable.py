@@ -0,0 +1,51 @@
+import unittest
+
+from metis.testing import ComponentTestCase
+from metis.token import Erc20Pausable
+
+INITIAL_SUPPLY = 1000000
+
+
+class PausableTestCase(ComponentTestCase):
+    PORT = 39949
+
+    @classmethod
+    def setUpClass(cls):
+        super().setUpClass()
+        cls.erc20 = Erc20Pausable.deploy(cls.substrate, cls.alice, INITIAL_SUPPLY)
+
+    def test_init(self):
+        self.assertFalse(self.erc20.paused())
+        self.assertEqual(self.erc20.owner(), self.alice.ss58_address)
+
+    def test_pause_and_unpause(self):
+        # only the owner can pause
+        self.assertFailed(self.erc20.pause(self.bob))
+
+        res = self.erc20.pause(self.alice)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Paused')
+        self.assertTrue(self.erc20.paused())
+
+        # can not pause twice
+        self.assertFailed(self.erc20.pause(self.alice))
+
+        # the transfers are paused
+        self.assertFailed(self.erc20.transfer(self.alice, self.bob.ss58_address, 100))
+
+        self.assertFailed(self.erc20.unpause(self.bob))
+
+        res = self.erc20.unpause(self.alice)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Unpaused')
+        self.assertFalse(self.erc20.paused())
+
+        self.assertSuccess(self.erc20.transfer(self.alice, self.bob.ss58_address, 100))
+        self.assertEqual(self.erc20.balance_of(self.bob.ss58_address), 100)
+
+    def test_unpause_not_paused_should_failed(self):
+        self.assertFailed(self.erc20.unpause(self.alice))
+
+
+if __name__ == '__main__':
+    unittest.main()
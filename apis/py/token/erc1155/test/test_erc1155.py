@@ -0,0 +1,72 @@
+import unittest
+
+from metis.testing import ComponentTestCase
+from metis.token import Erc1155
+
+URL = 'https://token-cdn-domain/{id}.json'
+
+FIRST_TOKEN_ID = 0x01
+SECOND_TOKEN_ID = 0x02
+
+
+class ERC1155TestCase(ComponentTestCase):
+    PORT = 39946
+
+    @classmethod
+    def setUpClass(cls):
+        super().setUpClass()
+        cls.erc1155 = Erc1155.deploy(cls.substrate, cls.alice, URL)
+
+        assert cls.erc1155.mint(cls.alice, cls.alice.ss58_address, FIRST_TOKEN_ID, 1000).is_success
+        assert cls.erc1155.mint(cls.alice, cls.alice.ss58_address, SECOND_TOKEN_ID, 2000).is_success
+
+    def test_url(self):
+        self.assertEqual(self.erc1155.url(FIRST_TOKEN_ID), URL)
+
+    def test_balance_of_batch(self):
+        balances = self.erc1155.balance_of_batch(
+            [self.alice.ss58_address, self.alice.ss58_address, self.dave.ss58_address],
+            [FIRST_TOKEN_ID, SECOND_TOKEN_ID, FIRST_TOKEN_ID],
+        )
+        self.assertEqual(balances[2], 0)
+        self.assertTrue(balances[0] > 0 and balances[1] > 0)
+
+    def test_safe_transfer_from(self):
+        res = self.erc1155.safe_transfer_from(
+            self.alice, self.alice.ss58_address, self.bob.ss58_address, FIRST_TOKEN_ID, 100)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'TransferSingle')
+        self.assertEqual(self.erc1155.balance_of(self.bob.ss58_address, FIRST_TOKEN_ID), 100)
+
+    def test_operator_transfer(self):
+        res = self.erc1155.set_approval_for_all(self.alice, self.charlie.ss58_address, True)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'ApprovalForAll')
+        self.assertTrue(self.erc1155.is_approved_for_all(self.alice.ss58_address, self.charlie.ss58_address))
+
+        res = self.erc1155.safe_transfer_from(
+            self.charlie, self.alice.ss58_address, self.charlie.ss58_address, SECOND_TOKEN_ID, 200)
+        self.assertSuccess(res)
+        self.assertEqual(self.erc1155.balance_of(self.charlie.ss58_address, SECOND_TOKEN_ID), 200)
+
+    def test_transfer_by_not_approved_should_failed(self):
+        res = self.erc1155.safe_transfer_from(
+            self.dave, self.alice.ss58_address, self.dave.ss58_address, FIRST_TOKEN_ID, 1)
+        self.assertFailed(res)
+
+    def test_transfer_exceeds_balance_should_failed(self):
+        res = self.erc1155.safe_transfer_from(
+            self.dave, self.dave.ss58_address, self.alice.ss58_address, FIRST_TOKEN_ID, 1)
+        self.assertFailed(res)
+
+    def test_burn(self):
+        res = self.erc1155.mint(self.alice, self.dave.ss58_address, SECOND_TOKEN_ID, 50)
+        self.assertSuccess(res)
+
+        res = self.erc1155.burn(self.alice, self.dave.ss58_address, SECOND_TOKEN_ID, 20)
+        self.assertSuccess(res)
+        self.assertEqual(self.erc1155.balance_of(self.dave.ss58_address, SECOND_TOKEN_ID), 30)
+
+
+if __name__ == '__main__':
+    unittest.main()
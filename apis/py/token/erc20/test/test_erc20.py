@@ -1,88 +1,53 @@
-import os
 import unittest
-import logging
 
-from substrateinterface import SubstrateInterface, ContractMetadata, Keypair
-from substrateinterface.utils.ss58 import ss58_encode
-from patractinterface.contract import ContractAPI, ContractFactory
-from patractinterface.unittest.env import SubstrateTestEnv
+from metis.testing import ComponentTestCase
+from metis.token import Erc20
 
-example_path = "../../../../../examples/erc20-ownable/contracts/target/ink/"
+INITIAL_SUPPLY = 1000000 * (10 ** 15)
 
-default_gas_limit = 200000000000
 
-class ERC20TestCase(unittest.TestCase):
-    @classmethod
-    def tearDown(cls):
-        cls.env.stop_node()
+class ERC20TestCase(ComponentTestCase):
+    PORT = 39944
 
     @classmethod
     def setUpClass(cls):
-        logging.info("init deplay erc20")
-
-        cls.env = SubstrateTestEnv.create_europa(port=39944)
-        cls.env.start_node()
-        cls.substrate=SubstrateInterface(url=cls.env.url(), type_registry_preset=cls.env.typ(), type_registry=cls.env.types())
-
-        cls.contract_metadata = ContractMetadata.create_from_file(
-            metadata_file=os.path.join(os.path.dirname(__file__), example_path, 'metadata.json'),
-            substrate=cls.substrate
-        )
-
-        cls.factory = ContractFactory.create_from_file(
-            substrate=cls.substrate, 
-            code_file=os.path.join(os.path.dirname(__file__), example_path, 'erc20ownable.wasm'),
-            metadata_file=os.path.join(os.path.dirname(__file__), example_path, 'metadata.json')
-        )
-
-        cls.alice = Keypair.create_from_uri('//Alice')
-        cls.bob = Keypair.create_from_uri('//Bob')
-
-        cls.api = cls.factory.new(cls.alice, 1000000 * (10 ** 15), endowment=10**15, gas_limit=1000000000000)
-
-    def transfer(self):
-        supply = self.api.total_supply(self.alice)
-        self.assertEqual(supply, 1000000 * (10 ** 15))
-
-        res = self.api.transfer(self.alice, self.bob.ss58_address, 10000, gas_limit=default_gas_limit)
-        self.assertTrue(res.is_success)
-        self.check_balance_of(self.bob.ss58_address, 10000)
-
-    def transfer_from(self):
-        res = self.api.transfer_from(self.alice,
-            self.alice.ss58_address, 
-            self.bob.ss58_address, 
-            10000, gas_limit=default_gas_limit)
-        self.assertTrue(res.is_success)
-
-    def approve(self):
-        res = self.api.approve(self.alice, self.bob.ss58_address, 10000, gas_limit=default_gas_limit)
-        self.assertTrue(res.is_success)
-        allowance = self.api.allowance(self.alice, self.alice.ss58_address, self.bob.ss58_address)
-        self.assertEqual(allowance, 10000)
-
-    def check_balance_of(self, acc, value):
-        res = self.api.balance_of(self.alice, acc)
-        self.assertEqual(res, value)
-
-    def metadata_info(self):
-        name = self.api.name(self.alice)
-        self.assertEqual(name, 'MetisTestToken')
-
-        symbol = self.api.symbol(self.alice)
-        self.assertEqual(symbol, 'MET')
-
-        decimals = self.api.decimals(self.alice)
-        self.assertEqual(decimals, 18)
-
-        logging.info(f'metadata_info {name} {symbol} {decimals}')
+        super().setUpClass()
+        cls.erc20 = Erc20.deploy(cls.substrate, cls.alice, INITIAL_SUPPLY)
 
+    def test_metadata(self):
+        self.assertEqual(self.erc20.name(), 'MetisTestToken')
+        self.assertEqual(self.erc20.symbol(), 'MET')
+        self.assertEqual(self.erc20.decimals(), 18)
 
     def test_exec_and_read(self):
-        self.transfer()
-        self.approve()
-        self.transfer_from()
-        self.metadata_info()
+        self.assertEqual(self.erc20.total_supply(), INITIAL_SUPPLY)
+
+        res = self.erc20.transfer(self.alice, self.bob.ss58_address, 10000)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Transfer')
+        self.assertEqual(self.erc20.balance_of(self.bob.ss58_address), 10000)
+
+        res = self.erc20.approve(self.bob, self.charlie.ss58_address, 3000)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Approval')
+        self.assertEqual(self.erc20.allowance(self.bob.ss58_address, self.charlie.ss58_address), 3000)
+
+        res = self.erc20.transfer_from(
+            self.charlie, self.bob.ss58_address, self.dave.ss58_address, 1000)
+        self.assertSuccess(res)
+        self.assertEqual(self.erc20.balance_of(self.dave.ss58_address), 1000)
+        self.assertEqual(self.erc20.balance_of(self.bob.ss58_address), 9000)
+        self.assertEqual(self.erc20.allowance(self.bob.ss58_address, self.charlie.ss58_address), 2000)
+
+    def test_transfer_exceeds_balance_should_failed(self):
+        res = self.erc20.transfer(self.dave, self.alice.ss58_address, INITIAL_SUPPLY)
+        self.assertFailed(res)
+
+    def test_transfer_from_exceeds_allowance_should_failed(self):
+        res = self.erc20.transfer_from(
+            self.dave, self.alice.ss58_address, self.dave.ss58_address, 1)
+        self.assertFailed(res)
+
 
 if __name__ == '__main__':
     unittest.main()
@@ -0,0 +1,74 @@
+import unittest
+
+from metis.testing import ComponentTestCase
+from metis.token import Erc721
+
+FIRST_TOKEN_ID = 0x0a
+SECOND_TOKEN_ID = 0x0b
+NON_EXISTENT_TOKEN_ID = 0x0c
+
+
+class ERC721TestCase(ComponentTestCase):
+    PORT = 39945
+
+    @classmethod
+    def setUpClass(cls):
+        super().setUpClass()
+        cls.erc721 = Erc721.deploy(cls.substrate, cls.alice, 'Non Fungible Token', 'NFT')
+
+        assert cls.erc721.mint(cls.alice, cls.alice.ss58_address, FIRST_TOKEN_ID).is_success
+        assert cls.erc721.mint(cls.alice, cls.alice.ss58_address, SECOND_TOKEN_ID).is_success
+
+    def test_metadata(self):
+        self.assertEqual(self.erc721.name(), 'Non Fungible Token')
+        self.assertEqual(self.erc721.symbol(), 'NFT')
+
+    def test_mint_existent_token_should_failed(self):
+        res = self.erc721.mint(self.alice, self.bob.ss58_address, FIRST_TOKEN_ID)
+        self.assertFailed(res)
+
+    def test_approve_and_transfer(self):
+        self.assertEqual(self.erc721.owner_of(SECOND_TOKEN_ID), self.alice.ss58_address)
+
+        res = self.erc721.approve(self.alice, self.bob.ss58_address, SECOND_TOKEN_ID)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Approval')
+        self.assertEqual(self.erc721.get_approved(SECOND_TOKEN_ID), self.bob.ss58_address)
+
+        res = self.erc721.transfer_from(
+            self.bob, self.alice.ss58_address, self.charlie.ss58_address, SECOND_TOKEN_ID)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Transfer')
+        self.assertEqual(self.erc721.owner_of(SECOND_TOKEN_ID), self.charlie.ss58_address)
+        self.assertEqual(self.erc721.balance_of(self.charlie.ss58_address), 1)
+
+        # the approval is cleared by the transfer
+        self.assertIsNone(self.erc721.get_approved(SECOND_TOKEN_ID))
+
+    def test_approval_for_all(self):
+        res = self.erc721.set_approval_for_all(self.alice, self.dave.ss58_address, True)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'ApprovalForAll')
+        self.assertTrue(self.erc721.is_approved_for_all(self.alice.ss58_address, self.dave.ss58_address))
+
+        res = self.erc721.set_approval_for_all(self.alice, self.dave.ss58_address, False)
+        self.assertSuccess(res)
+        self.assertFalse(self.erc721.is_approved_for_all(self.alice.ss58_address, self.dave.ss58_address))
+
+    def test_transfer_by_not_approved_should_failed(self):
+        res = self.erc721.transfer_from(
+            self.bob, self.alice.ss58_address, self.bob.ss58_address, FIRST_TOKEN_ID)
+        self.assertFailed(res)
+
+    def test_burn(self):
+        res = self.erc721.mint(self.alice, self.bob.ss58_address, NON_EXISTENT_TOKEN_ID)
+        self.assertSuccess(res)
+        self.assertEqual(self.erc721.balance_of(self.bob.ss58_address), 1)
+
+        res = self.erc721.burn(self.bob, NON_EXISTENT_TOKEN_ID)
+        self.assertSuccess(res)
+        self.assertEqual(self.erc721.balance_of(self.bob.ss58_address), 0)
+
+
+if __name__ == '__main__':
+    unittest.main()
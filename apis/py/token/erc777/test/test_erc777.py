@@ -0,0 +1,82 @@
+import unittest
+
+from metis.testing import ComponentTestCase
+from metis.token import Erc777
+
+INITIAL_SUPPLY = 10000000000
+
+
+class ERC777TestCase(ComponentTestCase):
+    PORT = 39947
+
+    @classmethod
+    def setUpClass(cls):
+        super().setUpClass()
+        cls.erc777 = Erc777.deploy(cls.substrate, cls.alice, 'Test', 'TST', 18, INITIAL_SUPPLY)
+
+    def test_metadata(self):
+        self.assertEqual(self.erc777.name(), 'Test')
+        self.assertEqual(self.erc777.symbol(), 'TST')
+        self.assertEqual(self.erc777.decimals(), 18)
+        self.assertEqual(self.erc777.granularity(), 1)
+        self.assertEqual(self.erc777.default_operators(), [])
+
+    def test_send(self):
+        res = self.erc777.send(self.alice, self.bob.ss58_address, 1000)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Sent')
+        self.assertEqual(self.erc777.balance_of(self.bob.ss58_address), 1000)
+
+        res = self.erc777.transfer(self.bob, self.charlie.ss58_address, 400)
+        self.assertSuccess(res)
+        self.assertEqual(self.erc777.balance_of(self.charlie.ss58_address), 400)
+
+    def test_send_exceeds_balance_should_failed(self):
+        res = self.erc777.send(self.dave, self.alice.ss58_address, 1)
+        self.assertFailed(res)
+
+    def test_operator(self):
+        res = self.erc777.mint(self.alice, self.dave.ss58_address, 500)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Minted')
+
+        # not operator yet
+        res = self.erc777.operator_send(
+            self.charlie, self.dave.ss58_address, self.charlie.ss58_address, 100)
+        self.assertFailed(res)
+
+        res = self.erc777.authorize_operator(self.dave, self.charlie.ss58_address)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'AuthorizedOperator')
+        self.assertTrue(self.erc777.is_operator_for(self.charlie.ss58_address, self.dave.ss58_address))
+
+        res = self.erc777.operator_burn(self.charlie, self.dave.ss58_address, 100)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Burned')
+        self.assertEqual(self.erc777.balance_of(self.dave.ss58_address), 400)
+
+        res = self.erc777.revoke_operator(self.dave, self.charlie.ss58_address)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'RevokedOperator')
+        self.assertFalse(self.erc777.is_operator_for(self.charlie.ss58_address, self.dave.ss58_address))
+
+    def test_burn(self):
+        supply = self.erc777.total_supply()
+
+        res = self.erc777.burn(self.alice, 100)
+        self.assertSuccess(res)
+        self.assertEqual(self.erc777.total_supply(), supply - 100)
+
+    def test_approve_and_transfer_from(self):
+        res = self.erc777.approve(self.alice, self.bob.ss58_address, 300)
+        self.assertSuccess(res)
+        self.assertEqual(self.erc777.allowance(self.alice.ss58_address, self.bob.ss58_address), 300)
+
+        res = self.erc777.transfer_from(
+            self.bob, self.alice.ss58_address, self.bob.ss58_address, 200)
+        self.assertSuccess(res)
+        self.assertEqual(self.erc777.allowance(self.alice.ss58_address, self.bob.ss58_address), 100)
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,42 @@
+import unittest
+
+from metis.testing import ComponentTestCase
+from metis.utils import Escrow
+
+
+class EscrowTestCase(ComponentTestCase):
+    PORT = 39951
+
+    @classmethod
+    def setUpClass(cls):
+        super().setUpClass()
+        cls.escrow = Escrow.deploy(cls.substrate, cls.alice)
+
+    def test_deposit_and_withdraw(self):
+        self.assertEqual(self.escrow.deposits_of(self.bob.ss58_address), 0)
+
+        res = self.escrow.deposit(self.alice, self.bob.ss58_address, 1000)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Deposited')
+        self.assertEqual(self.escrow.deposits_of(self.bob.ss58_address), 1000)
+
+        res = self.escrow.withdraw(self.alice, self.bob.ss58_address)
+        self.assertSuccess(res)
+        self.assertEvent(res, 'Withdrawn')
+        self.assertEqual(self.escrow.deposits_of(self.bob.ss58_address), 0)
+
+    def test_only_owner_should_deposit(self):
+        res = self.escrow.deposit(self.bob, self.charlie.ss58_address, 1000)
+        self.assertFailed(res)
+        self.assertEqual(self.escrow.deposits_of(self.charlie.ss58_address), 0)
+
+    def test_only_owner_should_withdraw(self):
+        self.assertSuccess(self.escrow.deposit(self.alice, self.dave.ss58_address, 500))
+
+        res = self.escrow.withdraw(self.dave, self.dave.ss58_address)
+        self.assertFailed(res)
+        self.assertEqual(self.escrow.deposits_of(self.dave.ss58_address), 500)
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -2,10 +2,10 @@
 
 use ink_lang as ink;
 
-#[ink::contract]
+#[ink::contract(env = patra_env::PatraEnvironment)]
 mod groth16 {
-    // use curve::result::Result;
-    use ink_prelude::{string::String, vec::Vec};
+    use ink_prelude::{format, string::String, vec::Vec};
+    use metis_groth16::{verifier, Curve, VerifyingKey};
 
     /// Groth16 exports
     #[ink(storage)]
@@ -13,6 +13,9 @@ mod groth16 {
         value: bool,
     }
 
+    /// The curve ops are called by the `CurveExtension` of the `PatraEnvironment`.
+    impl metis_groth16::ChainExtension for Groth16 {}
+
     impl Groth16 {
         #[ink(constructor)]
         pub fn default() -> Self {
@@ -21,7 +24,9 @@ mod groth16 {
 
         #[ink(message)]
         pub fn debug(&self, parcel: Vec<u8>) -> Result<Vec<u8>, String> {
-            curve::call(0x2a, &parcel).map_err(|e| e.debug())
+            <Self as metis_groth16::ChainExtension>::extension()
+                .debug(parcel)
+                .map_err(|e| format!("{:?}", e))
         }
 
         #[ink(message)]
@@ -32,7 +37,7 @@ mod groth16 {
             proof: Vec<u8>,
             public_inputs: Vec<Vec<u8>>,
         ) -> Result<bool, String> {
-            curve::verify(0x00, vk_gamma_abc, vk, proof, public_inputs).map_err(|e| e.debug())
+            Self::verify(Curve::Bls12_377, vk_gamma_abc, vk, proof, public_inputs)
         }
 
         #[ink(message)]
@@ -43,7 +48,7 @@ mod groth16 {
             proof: Vec<u8>,
             public_inputs: Vec<Vec<u8>>,
         ) -> Result<bool, String> {
-            curve::verify(0x10, vk_gamma_abc, vk, proof, public_inputs).map_err(|e| e.debug())
+            Self::verify(Curve::Bls12_381, vk_gamma_abc, vk, proof, public_inputs)
         }
 
         #[ink(message)]
@@ -54,7 +59,7 @@ mod groth16 {
             proof: Vec<u8>,
             public_inputs: Vec<Vec<u8>>,
         ) -> Result<bool, String> {
-            curve::verify(0x20, vk_gamma_abc, vk, proof, public_inputs).map_err(|e| e.debug())
+            Self::verify(Curve::Bn254, vk_gamma_abc, vk, proof, public_inputs)
         }
 
         #[ink(message)]
@@ -65,7 +70,111 @@ mod groth16 {
             proof: Vec<u8>,
             public_inputs: Vec<Vec<u8>>,
         ) -> Result<bool, String> {
-            curve::verify(0x30, vk_gamma_abc, vk, proof, public_inputs).map_err(|e| e.debug())
+            Self::verify(Curve::Bw6_761, vk_gamma_abc, vk, proof, public_inputs)
+        }
+
+        fn verify(
+            curve: Curve,
+            vk_gamma_abc: Vec<Vec<u8>>,
+            vk: Vec<u8>,
+            proof: Vec<u8>,
+            public_inputs: Vec<Vec<u8>>,
+        ) -> Result<bool, String> {
+            let vk = VerifyingKey {
+                curve,
+                vk,
+                gamma_abc: vk_gamma_abc,
+            };
+
+            verifier::verify::<Self>(&vk, &proof, &public_inputs).map_err(|e| format!("{:?}", e))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+        use metis_groth16::{
+            mock,
+            Curve,
+        };
+
+        /// Returns the args of the verify messages from the fixture.
+        fn args(curve: Curve) -> (Vec<Vec<u8>>, Vec<u8>, Vec<u8>, Vec<Vec<u8>>) {
+            let fixture = mock::fixture(curve);
+            (
+                fixture.vk.gamma_abc,
+                fixture.vk.vk,
+                fixture.proof,
+                fixture.public_inputs,
+            )
+        }
+
+        #[ink::test]
+        fn debug_works() {
+            mock::register_curve_extension();
+
+            let groth16 = Groth16::default();
+            assert_eq!(groth16.debug(vec![1, 2, 3]), Ok(vec![1, 2, 3]));
+        }
+
+        #[ink::test]
+        fn bls12_377_verify_works() {
+            mock::register_curve_extension();
+            let (vk_gamma_abc, vk, proof, public_inputs) = args(Curve::Bls12_377);
+
+            let groth16 = Groth16::default();
+            assert_eq!(
+                groth16.bls12_377_verify(vk_gamma_abc, vk, proof, public_inputs),
+                Ok(true)
+            );
+        }
+
+        #[ink::test]
+        fn bls12_381_verify_works() {
+            mock::register_curve_extension();
+            let (vk_gamma_abc, vk, proof, public_inputs) = args(Curve::Bls12_381);
+
+            let groth16 = Groth16::default();
+            assert_eq!(
+                groth16.bls12_381_verify(vk_gamma_abc, vk, proof, public_inputs),
+                Ok(true)
+            );
+        }
+
+        #[ink::test]
+        fn bn254_verify_works() {
+            mock::register_curve_extension();
+            let (vk_gamma_abc, vk, proof, mut public_inputs) = args(Curve::Bn254);
+
+            let groth16 = Groth16::default();
+            assert_eq!(
+                groth16.bn254_verify(
+                    vk_gamma_abc.clone(),
+                    vk.clone(),
+                    proof.clone(),
+                    public_inputs.clone()
+                ),
+                Ok(true)
+            );
+
+            public_inputs[0][0] ^= 0x01;
+            assert_eq!(
+                groth16.bn254_verify(vk_gamma_abc, vk, proof, public_inputs),
+                Ok(false)
+            );
+        }
+
+        #[ink::test]
+        fn bw6_761_works() {
+            mock::register_curve_extension();
+            let (vk_gamma_abc, vk, proof, public_inputs) = args(Curve::Bw6_761);
+
+            let groth16 = Groth16::default();
+            assert_eq!(
+                groth16.bw6_761(vk_gamma_abc, vk, proof, public_inputs),
+                Ok(true)
+            );
         }
     }
 }
@@ -1,6 +1,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use ink_env::Environment;
+use ink_lang as ink;
+use ink_prelude::vec::Vec;
+use scale::{
+    Decode,
+    Encode,
+};
 
 pub enum PatraEnvironment {}
 
@@ -14,6 +20,112 @@ impl Environment for PatraEnvironment {
     type Timestamp = <ink_env::DefaultEnvironment as Environment>::Timestamp;
     type BlockNumber = u32;
 
-    type ChainExtension = ();
+    type ChainExtension = CurveExtension;
     type RentFraction = <ink_env::DefaultEnvironment as Environment>::RentFraction;
 }
+
+/// A G1 point serialized (uncompressed) by the arkworks.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct G1(pub Vec<u8>);
+
+/// A G2 point serialized (uncompressed) by the arkworks.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct G2(pub Vec<u8>);
+
+/// A scalar of the curve serialized by the arkworks.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Scalar(pub Vec<u8>);
+
+/// The curve ops of the Patract node, the func id is the id of the curve
+/// (`0x00` bls12_377, `0x10` bls12_381, `0x20` alt_bn128, `0x30` bw6_761)
+/// plus the op (`0x00` add, `0x01` mul, `0x02` pairing), the args and the
+/// return value are SCALE encoded:
+///
+/// - add: the sum of two G1 points.
+/// - mul: the product of a G1 point and a scalar.
+/// - pairing: whether the product of the pairings of the (G1, G2) pairs is
+///   one.
+/// - debug (`0x2a`): returns the input, to check the node calls the ops.
+#[ink::chain_extension]
+pub trait CurveExtension {
+    type ErrorCode = CurveError;
+
+    #[ink(extension = 0x00)]
+    fn bls12_377_add(a: G1, b: G1) -> Result<G1, CurveError>;
+
+    #[ink(extension = 0x01)]
+    fn bls12_377_mul(point: G1, scalar: Scalar) -> Result<G1, CurveError>;
+
+    #[ink(extension = 0x02)]
+    fn bls12_377_pairing(pairs: Vec<(G1, G2)>) -> Result<bool, CurveError>;
+
+    #[ink(extension = 0x10)]
+    fn bls12_381_add(a: G1, b: G1) -> Result<G1, CurveError>;
+
+    #[ink(extension = 0x11)]
+    fn bls12_381_mul(point: G1, scalar: Scalar) -> Result<G1, CurveError>;
+
+    #[ink(extension = 0x12)]
+    fn bls12_381_pairing(pairs: Vec<(G1, G2)>) -> Result<bool, CurveError>;
+
+    #[ink(extension = 0x20)]
+    fn alt_bn128_add(a: G1, b: G1) -> Result<G1, CurveError>;
+
+    #[ink(extension = 0x21)]
+    fn alt_bn128_mul(point: G1, scalar: Scalar) -> Result<G1, CurveError>;
+
+    #[ink(extension = 0x22)]
+    fn alt_bn128_pairing(pairs: Vec<(G1, G2)>) -> Result<bool, CurveError>;
+
+    #[ink(extension = 0x30)]
+    fn bw6_761_add(a: G1, b: G1) -> Result<G1, CurveError>;
+
+    #[ink(extension = 0x31)]
+    fn bw6_761_mul(point: G1, scalar: Scalar) -> Result<G1, CurveError>;
+
+    #[ink(extension = 0x32)]
+    fn bw6_761_pairing(pairs: Vec<(G1, G2)>) -> Result<bool, CurveError>;
+
+    #[ink(extension = 0x2a)]
+    fn debug(input: Vec<u8>) -> Result<Vec<u8>, CurveError>;
+}
+
+/// The error codes returned by the curve ops.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CurveError {
+    /// The points or the scalars can not be deserialized, such as the points
+    /// are not on the curve.
+    InvalidInput = 1,
+    /// The output of the op can not be decoded.
+    InvalidOutput = 2,
+    /// The op failed with an unknown status code.
+    Unknown = 255,
+}
+
+impl CurveError {
+    /// Returns the status code of the error.
+    pub fn status_code(&self) -> u32 {
+        *self as u32
+    }
+}
+
+impl ink_env::chain_extension::FromStatusCode for CurveError {
+    fn from_status_code(status_code: u32) -> Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            1 => Err(Self::InvalidInput),
+            2 => Err(Self::InvalidOutput),
+            _ => Err(Self::Unknown),
+        }
+    }
+}
+
+impl From<scale::Error> for CurveError {
+    fn from(_: scale::Error) -> Self {
+        Self::InvalidOutput
+    }
+}
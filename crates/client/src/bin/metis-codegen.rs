@@ -0,0 +1,39 @@
+//! Generate the rust code of a contract from its `metadata.json`.
+//!
+//! ```bash
+//! metis-codegen path/to/metadata.json [--module erc20] [--storage Erc20] > erc20.rs
+//! ```
+
+use metis_client::codegen::{
+    generate_from_file,
+    Options,
+};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let mut path = None;
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--module" => options.module = args.next(),
+            "--storage" => options.storage_ident = args.next(),
+            _ => path = Some(arg),
+        }
+    }
+
+    let path = path.unwrap_or_else(|| {
+        eprintln!(
+            "usage: metis-codegen <metadata.json> [--module <name>] [--storage <ident>]"
+        );
+        std::process::exit(1)
+    });
+
+    match generate_from_file(&path, &options) {
+        Ok(code) => print!("{}", code),
+        Err(err) => {
+            eprintln!("metis-codegen: {}", err);
+            std::process::exit(1)
+        }
+    }
+}
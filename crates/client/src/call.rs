@@ -0,0 +1,90 @@
+//! The calls of the messages and the constructors.
+
+use crate::{
+    Error,
+    Result,
+};
+use core::marker::PhantomData;
+use scale::{
+    Decode,
+    DecodeAll,
+    Encode,
+};
+
+/// The selector of a message or a constructor.
+pub type Selector = [u8; 4];
+
+/// Parse the selector in the metadata, such as `"0x84a15da1"`.
+pub fn parse_selector(selector: &str) -> Result<Selector> {
+    let bytes = hex::decode(selector.trim_start_matches("0x")).map_err(|err| {
+        Error::Metadata(format!("invalid selector {}: {}", selector, err))
+    })?;
+
+    let mut res = [0_u8; 4];
+    if bytes.len() != res.len() {
+        return Err(Error::Metadata(format!("invalid selector {}", selector)))
+    }
+    res.copy_from_slice(&bytes);
+
+    Ok(res)
+}
+
+/// A call of a message or a constructor, `R` is the return type.
+///
+/// The input data of the call is the selector followed by the SCALE encoded
+/// args in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Call<R> {
+    selector: Selector,
+    args: Vec<u8>,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<R> Call<R> {
+    /// Build the call by the `args`, which should be a tuple of the args in
+    /// order, such as `(to, value)`, `(owner,)` or `()`.
+    pub fn new<Args: Encode>(selector: Selector, args: Args) -> Self {
+        Self::from_encoded(selector, args.encode())
+    }
+
+    /// Build the call by the args already encoded.
+    pub fn from_encoded(selector: Selector, args: Vec<u8>) -> Self {
+        Self {
+            selector,
+            args,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the selector of the call.
+    pub fn selector(&self) -> Selector {
+        self.selector
+    }
+
+    /// Returns the encoded args of the call.
+    pub fn args(&self) -> &[u8] {
+        &self.args
+    }
+
+    /// Returns the input data of the call.
+    pub fn data(&self) -> Vec<u8> {
+        let mut data = self.selector.to_vec();
+        data.extend_from_slice(&self.args);
+        data
+    }
+
+    /// Returns the input data of the call as the hex string with `0x`.
+    pub fn data_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.data()))
+    }
+}
+
+impl<R> Call<R>
+where
+    R: Decode,
+{
+    /// Decode the output of the call, which should be exactly a `R`.
+    pub fn decode_output(&self, output: &[u8]) -> Result<R> {
+        Ok(<R as DecodeAll>::decode_all(output)?)
+    }
+}
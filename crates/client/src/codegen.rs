@@ -0,0 +1,340 @@
+//! Generate the typed call builders and the event types from the metadata.
+//!
+//! For a contract, a module is generated with:
+//!
+//! - `types`: the custom types used by the messages and the events, such as
+//!   the `Error` of the components.
+//! - `constructors` and `messages`: a fn for each one, which takes the typed
+//!   args and returns the `Call` with the return type.
+//! - `events`: a struct for each event which impl the `ContractEvent`, with
+//!   a `topic_<field>` fn for each topic field to filter the events, and the
+//!   `Event` enum to decode any event of the contract.
+//!
+//! The generated code uses the `metis_client` and derives the SCALE codec,
+//! so the crate should depend on both `metis_client` and the
+//! `parity-scale-codec` (with the `derive` feature).
+//!
+//! ```ignore
+//! // build.rs
+//! let metadata = ContractMetadata::from_file("erc20/metadata.json")?;
+//! let code = codegen::generate(&metadata, &Options::default())?;
+//! std::fs::write(out_dir.join("erc20.rs"), code)?;
+//!
+//! // lib.rs
+//! include!(concat!(env!("OUT_DIR"), "/erc20.rs"));
+//!
+//! let call = erc20::messages::transfer(to, 100);
+//! let data = call.data();
+//! ```
+
+use crate::{
+    metadata::{
+        ArgSpec,
+        ContractMetadata,
+    },
+    registry::{
+        docs,
+        ident,
+    },
+    Result,
+};
+use convert_case::{
+    Case,
+    Casing,
+};
+use std::path::Path;
+
+/// The options of the codegen.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// The name of the generated module, the contract name by default.
+    pub module: Option<String>,
+    /// The name of the storage struct of the contract, which is the prefix of
+    /// the event signatures, the contract name in camel case by default.
+    pub storage_ident: Option<String>,
+}
+
+/// Generate the code of the contract from the `metadata.json` file.
+pub fn generate_from_file<P: AsRef<Path>>(path: P, options: &Options) -> Result<String> {
+    generate(&ContractMetadata::from_file(path)?, options)
+}
+
+/// Generate the code of the contract.
+pub fn generate(metadata: &ContractMetadata, options: &Options) -> Result<String> {
+    let module = options
+        .module
+        .clone()
+        .unwrap_or_else(|| metadata.name.to_case(Case::Snake));
+    let storage_ident = options
+        .storage_ident
+        .clone()
+        .unwrap_or_else(|| metadata.name.to_case(Case::Pascal));
+
+    let mut code = String::new();
+    code.push_str("// Generated by `metis_client::codegen`, do not edit.\n\n");
+    code.push_str(&docs(&metadata.spec.docs, ""));
+    code.push_str("#[allow(dead_code, unused_imports, clippy::all)]\n");
+    code.push_str(&format!("pub mod {} {{\n", ident(&module)));
+    code.push_str(&indent(&generate_types(metadata)?, 1));
+    code.push('\n');
+    code.push_str(&indent(&generate_constructors(metadata)?, 1));
+    code.push('\n');
+    code.push_str(&indent(&generate_messages(metadata)?, 1));
+    code.push('\n');
+    code.push_str(&indent(&generate_events(metadata, &storage_ident)?, 1));
+    code.push_str("}\n");
+
+    Ok(code)
+}
+
+fn generate_types(metadata: &ContractMetadata) -> Result<String> {
+    let spec = &metadata.spec;
+    let roots = spec
+        .constructors
+        .iter()
+        .flat_map(|constructor| constructor.args.iter().map(|arg| arg.ty.id))
+        .chain(spec.messages.iter().flat_map(|message| {
+            message
+                .args
+                .iter()
+                .map(|arg| arg.ty.id)
+                .chain(message.return_type.iter().map(|ty| ty.id))
+        }))
+        .chain(
+            spec.events
+                .iter()
+                .flat_map(|event| event.args.iter().map(|arg| arg.ty.id)),
+        )
+        .collect::<Vec<_>>();
+
+    let mut code = "pub mod types {\n".to_string();
+    for id in metadata.registry.custom_types(roots)? {
+        code.push_str(&indent(&metadata.registry.declare(id)?, 1));
+        code.push('\n');
+    }
+    code.push_str("}\n");
+
+    Ok(code)
+}
+
+fn generate_constructors(metadata: &ContractMetadata) -> Result<String> {
+    let mut code = "pub mod constructors {\n    use super::types::*;\n\n".to_string();
+    for constructor in metadata.spec.constructors.iter() {
+        code.push_str(&indent(
+            &generate_call(
+                &constructor.name,
+                &constructor.docs,
+                constructor.selector()?,
+                &constructor.args,
+                "()".into(),
+                metadata,
+            )?,
+            1,
+        ));
+        code.push('\n');
+    }
+    code.push_str("}\n");
+
+    Ok(code)
+}
+
+fn generate_messages(metadata: &ContractMetadata) -> Result<String> {
+    let mut code = "pub mod messages {\n    use super::types::*;\n\n".to_string();
+    for message in metadata.spec.messages.iter() {
+        let output = match &message.return_type {
+            Some(ty) => metadata.registry.rust_type(ty.id)?,
+            None => "()".into(),
+        };
+
+        code.push_str(&indent(
+            &generate_call(
+                &message.name,
+                &message.docs,
+                message.selector()?,
+                &message.args,
+                output,
+                metadata,
+            )?,
+            1,
+        ));
+        code.push('\n');
+    }
+    code.push_str("}\n");
+
+    Ok(code)
+}
+
+/// Generate the fn of a message or a constructor, the name of a trait
+/// message is joined by `_`, such as `erc20_transfer`.
+fn generate_call(
+    name: &[String],
+    doc_lines: &[String],
+    selector: [u8; 4],
+    args: &[ArgSpec],
+    output: String,
+    metadata: &ContractMetadata,
+) -> Result<String> {
+    let fn_name = name
+        .iter()
+        .map(|segment| segment.to_case(Case::Snake))
+        .collect::<Vec<_>>()
+        .join("_");
+
+    let params = args
+        .iter()
+        .map(|arg| -> Result<String> {
+            Ok(format!(
+                "{}: {}",
+                ident(&arg.name),
+                metadata.registry.rust_type(arg.ty.id)?
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let arg_names = args.iter().map(|arg| ident(&arg.name)).collect::<Vec<_>>();
+    let tuple = match arg_names.len() {
+        1 => format!("({},)", arg_names[0]),
+        _ => format!("({})", arg_names.join(", ")),
+    };
+
+    let mut code = docs(doc_lines, "");
+    code.push_str(&format!(
+        "pub fn {}({}) -> ::metis_client::Call<{}> {{\n",
+        ident(&fn_name),
+        params.join(", "),
+        output
+    ));
+    code.push_str(&format!(
+        "    ::metis_client::Call::new({}, {})\n",
+        selector_literal(selector),
+        tuple
+    ));
+    code.push_str("}\n");
+
+    Ok(code)
+}
+
+fn generate_events(metadata: &ContractMetadata, storage_ident: &str) -> Result<String> {
+    let registry = &metadata.registry;
+    let mut code = "pub mod events {\n    use super::types::*;\n\n".to_string();
+
+    for (index, event) in metadata.spec.events.iter().enumerate() {
+        let signature = format!("{}::{}", storage_ident, event.name);
+
+        let mut event_code = docs(&event.docs, "");
+        event_code.push_str(
+            "#[derive(Debug, Clone, PartialEq, Eq, ::metis_client::scale::Encode, ::metis_client::scale::Decode)]\n",
+        );
+        event_code.push_str(&format!("pub struct {} {{\n", event.name));
+        for arg in event.args.iter() {
+            event_code.push_str(&docs(&arg.docs, "    "));
+            event_code.push_str(&format!(
+                "    pub {}: {},\n",
+                ident(&arg.name),
+                registry.rust_type(arg.ty.id)?
+            ));
+        }
+        event_code.push_str("}\n\n");
+
+        event_code.push_str(&format!(
+            "impl ::metis_client::ContractEvent for {} {{\n",
+            event.name
+        ));
+        event_code.push_str(&format!("    const INDEX: u8 = {};\n", index));
+        event_code.push_str(&format!(
+            "    const SIGNATURE: &'static [u8] = b\"{}\";\n\n",
+            signature
+        ));
+        event_code.push_str(
+            "    fn topics(&self) -> ::std::vec::Vec<::metis_client::types::Hash> {\n",
+        );
+        event_code.push_str("        vec![\n");
+        event_code.push_str(
+            "            <Self as ::metis_client::ContractEvent>::signature_topic(),\n",
+        );
+        for arg in event.args.iter().filter(|arg| arg.indexed) {
+            event_code.push_str(&format!(
+                "            Self::topic_{}(&self.{}),\n",
+                arg.name.to_case(Case::Snake),
+                ident(&arg.name)
+            ));
+        }
+        event_code.push_str("        ]\n    }\n}\n\n");
+
+        event_code.push_str(&format!("impl {} {{\n", event.name));
+        for arg in event.args.iter().filter(|arg| arg.indexed) {
+            event_code.push_str(&format!(
+                "    /// The topic of the `{}` field, to filter the events by it.\n",
+                arg.name
+            ));
+            event_code.push_str(&format!(
+                "    pub fn topic_{}(value: &{}) -> ::metis_client::types::Hash {{\n",
+                arg.name.to_case(Case::Snake),
+                registry.rust_type(arg.ty.id)?
+            ));
+            event_code.push_str(&format!(
+                "        ::metis_client::event::field_topic(b\"{}::{}\", value)\n",
+                signature, arg.name
+            ));
+            event_code.push_str("    }\n");
+        }
+        event_code.push_str("}\n");
+
+        code.push_str(&indent(&event_code, 1));
+        code.push('\n');
+    }
+
+    if metadata.spec.events.is_empty() {
+        code.push_str("}\n");
+        return Ok(code)
+    }
+
+    let mut enum_code = "/// All the events of the contract.\n".to_string();
+    enum_code.push_str(
+        "#[derive(Debug, Clone, PartialEq, Eq, ::metis_client::scale::Encode, ::metis_client::scale::Decode)]\n",
+    );
+    enum_code.push_str("pub enum Event {\n");
+    for (index, event) in metadata.spec.events.iter().enumerate() {
+        enum_code.push_str(&format!("    #[codec(index = {})]\n", index));
+        enum_code.push_str(&format!("    {}({}),\n", event.name, event.name));
+    }
+    enum_code.push_str("}\n\n");
+    enum_code.push_str("impl Event {\n");
+    enum_code.push_str("    /// Decode the event data emitted by the contract.\n");
+    enum_code.push_str(
+        "    pub fn decode(data: &[u8]) -> ::core::result::Result<Self, ::metis_client::scale::Error> {\n",
+    );
+    enum_code.push_str(
+        "        <Self as ::metis_client::scale::DecodeAll>::decode_all(data)\n",
+    );
+    enum_code.push_str("    }\n}\n");
+
+    code.push_str(&indent(&enum_code, 1));
+    code.push_str("}\n");
+
+    Ok(code)
+}
+
+fn selector_literal(selector: [u8; 4]) -> String {
+    format!(
+        "[{}]",
+        selector
+            .iter()
+            .map(|byte| format!("0x{:02x}", byte))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Indent the non-empty lines of the `code` by `level` * 4 spaces.
+fn indent(code: &str, level: usize) -> String {
+    let prefix = " ".repeat(level * 4);
+    code.lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("{}{}\n", prefix, line)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,81 @@
+//! The topics and the decoding of the events.
+//!
+//! An event of a contract is emitted as the encoded event enum of the
+//! contract, the first byte is the index of the event in the metadata, and
+//! the topics are:
+//!
+//! - the signature topic, from `<Storage>::<Event>`, such as `Erc20::Transfer`.
+//! - a topic for each `#[ink(topic)]` field, from the field path, such as
+//!   `Erc20::Transfer::from`, and the value of the field.
+
+use crate::types::Hash;
+use scale::{
+    Decode,
+    DecodeAll,
+};
+
+/// The topics are calculated by `metis_lang::events`, same as the codegen of
+/// the contracts.
+pub use metis_lang::events::{
+    encoded_into_hash,
+    field_topic,
+    signature_topic,
+    PrefixedValue,
+};
+
+/// An event type of a contract, impl by the generated code.
+pub trait ContractEvent: Decode {
+    /// The index of the event in the metadata.
+    const INDEX: u8;
+    /// The signature of the event, such as `b"Erc20::Transfer"`.
+    const SIGNATURE: &'static [u8];
+
+    /// Returns the signature topic of the event.
+    fn signature_topic() -> Hash {
+        signature_topic(Self::SIGNATURE)
+    }
+
+    /// Returns the topics of the event, same as the emitted ones.
+    fn topics(&self) -> Vec<Hash>;
+}
+
+/// A raw event emitted by a contract, such as the `ContractEmitted` event of
+/// the `pallet-contracts`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct EventRecord {
+    /// The encoded event enum of the contract.
+    pub data: Vec<u8>,
+    /// The topics of the event, may be empty if the node does not give.
+    pub topics: Vec<Hash>,
+}
+
+impl EventRecord {
+    pub fn new(data: Vec<u8>, topics: Vec<Hash>) -> Self {
+        Self { data, topics }
+    }
+
+    /// Returns the index of the event.
+    pub fn index(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    /// Returns whether the record is a `T`, by the index and the signature
+    /// topic (if the record has the topics).
+    pub fn is<T: ContractEvent>(&self) -> bool {
+        self.index() == Some(T::INDEX)
+            && self
+                .topics
+                .first()
+                .map(|topic| *topic == T::signature_topic())
+                .unwrap_or(true)
+    }
+
+    /// Decode the record as a `T`, returns `None` if it is not a `T`.
+    pub fn decode<T: ContractEvent>(&self) -> Option<T> {
+        if !self.is::<T>() {
+            return None
+        }
+
+        <T as DecodeAll>::decode_all(&self.data[1..]).ok()
+    }
+}
@@ -0,0 +1,103 @@
+//! Client sdk for the metis contracts, from the `metadata.json` generated by
+//! the `cargo contract`.
+//!
+//! - `metadata`: parse the metadata, find the constructors, messages and
+//!   events by the name.
+//! - `call`: the typed `Call` with the selector and the SCALE encoded args,
+//!   which is the `input_data` of a contract call, and decodes the return.
+//! - `event`: compute the topics of the events same as the ink! codegen, and
+//!   decode the raw event data into the event types.
+//! - `codegen`: generate the typed call builders and the event types of a
+//!   contract from its metadata, by `metis-codegen metadata.json` or in the
+//!   `build.rs`.
+//! - `rpc`: with the `rpc` feature, dry run the calls by the `contracts_call`
+//!   of a node, such as a local europa.
+//!
+//! All but the `rpc` work offline, so the call data can be encoded without a
+//! node, and signed and submitted by any substrate client.
+
+pub mod call;
+pub mod codegen;
+pub mod event;
+pub mod metadata;
+mod registry;
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+pub use call::{
+    Call,
+    Selector,
+};
+pub use event::{
+    ContractEvent,
+    EventRecord,
+};
+pub use metadata::ContractMetadata;
+pub use registry::TypeRegistry;
+
+/// Re-export for the generated code.
+pub use scale;
+
+/// The types of the default environment used by the metis contracts.
+pub mod types {
+    pub use ink_env::{
+        AccountId,
+        Hash,
+    };
+
+    pub type Balance = u128;
+    pub type BlockNumber = u32;
+    pub type Timestamp = u64;
+}
+
+/// The Errors from client
+#[derive(Debug)]
+pub enum Error {
+    /// The metadata can not be parsed.
+    Metadata(String),
+    /// No constructor, message, event or type by the name or the id.
+    NotFound(String),
+    /// The type in the metadata is not supported by the codegen.
+    UnsupportedType(String),
+    /// The SCALE encoded data can not be decoded.
+    Decode(scale::Error),
+    /// The call to the node failed.
+    Rpc(String),
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Metadata(msg) => write!(f, "invalid metadata: {}", msg),
+            Error::NotFound(name) => write!(f, "not found: {}", name),
+            Error::UnsupportedType(ty) => write!(f, "unsupported type: {}", ty),
+            Error::Decode(err) => write!(f, "decode failed: {}", err),
+            Error::Rpc(msg) => write!(f, "rpc failed: {}", msg),
+            Error::Io(err) => write!(f, "io failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<scale::Error> for Error {
+    fn from(err: scale::Error) -> Self {
+        Error::Decode(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Metadata(err.to_string())
+    }
+}
+
+/// The Result of client
+pub type Result<T> = core::result::Result<T, Error>;
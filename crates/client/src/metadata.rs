@@ -0,0 +1,239 @@
+//! The metadata of a contract, as the `metadata.json` generated by the
+//! `cargo contract`, only the spec and the types are parsed.
+//!
+//! Both the versioned layout (`{"V1": {"spec": .., "types": ..}}`) and the
+//! plain one are supported, and the type registry of the `scale-info` both
+//! with and without the type ids.
+
+use crate::{
+    call::{
+        parse_selector,
+        Call,
+        Selector,
+    },
+    registry::TypeRegistry,
+    Error,
+    Result,
+};
+use scale::Encode;
+use serde::{
+    Deserialize,
+    Deserializer,
+};
+use serde_json::Value;
+use std::path::Path;
+
+/// The metadata of a contract.
+#[derive(Debug, Clone)]
+pub struct ContractMetadata {
+    /// The name of the contract crate.
+    pub name: String,
+    pub spec: Spec,
+    pub registry: TypeRegistry,
+}
+
+/// The spec of the constructors, the messages and the events.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Spec {
+    pub constructors: Vec<ConstructorSpec>,
+    pub messages: Vec<MessageSpec>,
+    #[serde(default)]
+    pub events: Vec<EventSpec>,
+    #[serde(default)]
+    pub docs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConstructorSpec {
+    #[serde(alias = "label", deserialize_with = "deserialize_name")]
+    pub name: Vec<String>,
+    pub selector: String,
+    #[serde(default)]
+    pub args: Vec<ArgSpec>,
+    #[serde(default)]
+    pub docs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessageSpec {
+    #[serde(alias = "label", deserialize_with = "deserialize_name")]
+    pub name: Vec<String>,
+    pub selector: String,
+    #[serde(default)]
+    pub args: Vec<ArgSpec>,
+    #[serde(default)]
+    pub mutates: bool,
+    #[serde(default)]
+    pub payable: bool,
+    #[serde(rename = "returnType", default)]
+    pub return_type: Option<TypeSpec>,
+    #[serde(default)]
+    pub docs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventSpec {
+    #[serde(alias = "label")]
+    pub name: String,
+    #[serde(default)]
+    pub args: Vec<EventArgSpec>,
+    #[serde(default)]
+    pub docs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArgSpec {
+    #[serde(alias = "label")]
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: TypeSpec,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventArgSpec {
+    #[serde(alias = "label")]
+    pub name: String,
+    #[serde(default)]
+    pub indexed: bool,
+    #[serde(rename = "type")]
+    pub ty: TypeSpec,
+    #[serde(default)]
+    pub docs: Vec<String>,
+}
+
+/// The type of an arg, the `id` is the id in the type registry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TypeSpec {
+    #[serde(rename = "displayName", default)]
+    pub display_name: Vec<String>,
+    #[serde(rename = "type")]
+    pub id: u32,
+}
+
+/// The name is a path for the trait messages, such as `["Erc20", "transfer"]`.
+fn deserialize_name<'de, D>(
+    deserializer: D,
+) -> core::result::Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Name {
+        Path(Vec<String>),
+        Label(String),
+    }
+
+    Ok(match Name::deserialize(deserializer)? {
+        Name::Path(path) => path,
+        Name::Label(label) => label.split("::").map(String::from).collect(),
+    })
+}
+
+impl ConstructorSpec {
+    /// The name joined by `::`.
+    pub fn label(&self) -> String {
+        self.name.join("::")
+    }
+
+    pub fn selector(&self) -> Result<Selector> {
+        parse_selector(&self.selector)
+    }
+}
+
+impl MessageSpec {
+    /// The name joined by `::`.
+    pub fn label(&self) -> String {
+        self.name.join("::")
+    }
+
+    pub fn selector(&self) -> Result<Selector> {
+        parse_selector(&self.selector)
+    }
+}
+
+impl ContractMetadata {
+    /// Parse the metadata from the json.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(json)?;
+
+        let name = value
+            .get("contract")
+            .and_then(|contract| contract.get("name"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+
+        let project = ["V3", "V2", "V1", "V0"]
+            .iter()
+            .find_map(|version| value.get(version))
+            .unwrap_or(&value);
+
+        let spec = project
+            .get("spec")
+            .ok_or_else(|| Error::Metadata("no spec".into()))?;
+        let types = project
+            .get("types")
+            .ok_or_else(|| Error::Metadata("no types".into()))?;
+
+        Ok(Self {
+            name,
+            spec: Spec::deserialize(spec)?,
+            registry: TypeRegistry::from_json(types)?,
+        })
+    }
+
+    /// Parse the metadata from the `metadata.json` file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Returns the constructor by the name, such as `new`.
+    pub fn constructor(&self, name: &str) -> Result<&ConstructorSpec> {
+        self.spec
+            .constructors
+            .iter()
+            .find(|constructor| constructor.label() == name)
+            .ok_or_else(|| Error::NotFound(format!("constructor {}", name)))
+    }
+
+    /// Returns the message by the name, such as `transfer` or
+    /// `Erc20::transfer` for the trait messages.
+    pub fn message(&self, name: &str) -> Result<&MessageSpec> {
+        self.spec
+            .messages
+            .iter()
+            .find(|message| message.label() == name)
+            .ok_or_else(|| Error::NotFound(format!("message {}", name)))
+    }
+
+    /// Returns the event and its index by the name, such as `Transfer`.
+    pub fn event(&self, name: &str) -> Result<(u8, &EventSpec)> {
+        self.spec
+            .events
+            .iter()
+            .enumerate()
+            .find(|(_, event)| event.name == name)
+            .map(|(index, event)| (index as u8, event))
+            .ok_or_else(|| Error::NotFound(format!("event {}", name)))
+    }
+
+    /// Build the call of the message `name` with the `args`, which should be
+    /// a tuple of the args in order, `R` is the return type.
+    pub fn message_call<Args: Encode, R>(
+        &self,
+        name: &str,
+        args: Args,
+    ) -> Result<Call<R>> {
+        Ok(Call::new(self.message(name)?.selector()?, args))
+    }
+
+    /// Build the call of the constructor `name` with the `args`.
+    pub fn constructor_call<Args: Encode>(
+        &self,
+        name: &str,
+        args: Args,
+    ) -> Result<Call<()>> {
+        Ok(Call::new(self.constructor(name)?.selector()?, args))
+    }
+}
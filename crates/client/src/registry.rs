@@ -0,0 +1,405 @@
+//! The type registry of the metadata (`scale-info`), resolves the type ids
+//! into the rust types for the codegen.
+
+use crate::{
+    Error,
+    Result,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+/// A type in the registry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Type {
+    #[serde(default)]
+    pub path: Vec<String>,
+    #[serde(default)]
+    pub params: Vec<TypeParam>,
+    pub def: TypeDef,
+    #[serde(default)]
+    pub docs: Vec<String>,
+}
+
+/// A generic param of a type, with the name since the `scale-info` 1.0.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum TypeParam {
+    Named {
+        name: String,
+        #[serde(rename = "type")]
+        ty: Option<u32>,
+    },
+    Id(u32),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TypeDef {
+    Composite {
+        #[serde(default)]
+        fields: Vec<Field>,
+    },
+    Variant {
+        #[serde(default)]
+        variants: Vec<Variant>,
+    },
+    Sequence {
+        #[serde(rename = "type")]
+        ty: u32,
+    },
+    Array {
+        len: u32,
+        #[serde(rename = "type")]
+        ty: u32,
+    },
+    Tuple(Vec<u32>),
+    Primitive(String),
+    Compact {
+        #[serde(rename = "type")]
+        ty: u32,
+    },
+    BitSequence(Value),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Field {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: u32,
+    #[serde(rename = "typeName", default)]
+    pub type_name: Option<String>,
+    #[serde(default)]
+    pub docs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Variant {
+    pub name: String,
+    #[serde(default)]
+    pub fields: Vec<Field>,
+    #[serde(default)]
+    pub index: Option<u8>,
+    #[serde(default)]
+    pub discriminant: Option<u64>,
+    #[serde(default)]
+    pub docs: Vec<String>,
+}
+
+/// An entry of the registry, with the id since the `scale-info` 1.0, else
+/// the id is the position from 1.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum Entry {
+    WithId {
+        id: u32,
+        #[serde(rename = "type")]
+        ty: Type,
+    },
+    Plain(Type),
+}
+
+/// The type registry of the metadata.
+#[derive(Debug, Clone, Default)]
+pub struct TypeRegistry {
+    types: BTreeMap<u32, Type>,
+    /// The names of the types which should be declared by the codegen.
+    names: BTreeMap<u32, String>,
+}
+
+impl TypeRegistry {
+    /// Parse the registry from the `types` of the metadata.
+    pub fn from_json(types: &Value) -> Result<Self> {
+        let entries = Vec::<Entry>::deserialize(types)?;
+
+        let types = entries
+            .into_iter()
+            .enumerate()
+            .map(|(pos, entry)| {
+                match entry {
+                    Entry::WithId { id, ty } => (id, ty),
+                    Entry::Plain(ty) => (pos as u32 + 1, ty),
+                }
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut names = BTreeMap::new();
+        let mut used = BTreeSet::new();
+        for (id, ty) in types.iter() {
+            if !is_custom(ty) {
+                continue
+            }
+
+            let base = ty
+                .path
+                .last()
+                .cloned()
+                .unwrap_or_else(|| format!("Type{}", id));
+            let name = if used.contains(&base) {
+                format!("{}{}", base, id)
+            } else {
+                base
+            };
+
+            used.insert(name.clone());
+            names.insert(*id, name);
+        }
+
+        Ok(Self { types, names })
+    }
+
+    /// Returns the type by the id.
+    pub fn get(&self, id: u32) -> Result<&Type> {
+        self.types
+            .get(&id)
+            .ok_or_else(|| Error::NotFound(format!("type {}", id)))
+    }
+
+    /// Returns the rust type of the id, the custom types are referred by
+    /// their names, which are declared by `declare`.
+    pub fn rust_type(&self, id: u32) -> Result<String> {
+        let ty = self.get(id)?;
+
+        if let Some(name) = self.names.get(&id) {
+            return Ok(name.clone())
+        }
+
+        match &ty.def {
+            TypeDef::Primitive(primitive) => primitive_type(primitive),
+            TypeDef::Sequence { ty } => {
+                Ok(format!("::std::vec::Vec<{}>", self.rust_type(*ty)?))
+            }
+            TypeDef::Array { len, ty } => {
+                Ok(format!("[{}; {}]", self.rust_type(*ty)?, len))
+            }
+            TypeDef::Tuple(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| self.rust_type(*field))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(match fields.len() {
+                    1 => format!("({},)", fields[0]),
+                    _ => format!("({})", fields.join(", ")),
+                })
+            }
+            TypeDef::Compact { ty } => {
+                Ok(format!(
+                    "::metis_client::scale::Compact<{}>",
+                    self.rust_type(*ty)?
+                ))
+            }
+            TypeDef::BitSequence(_) => {
+                Err(Error::UnsupportedType(format!("bit sequence {}", id)))
+            }
+            TypeDef::Composite { .. } | TypeDef::Variant { .. } => {
+                self.known_type(id, ty)
+            }
+        }
+    }
+
+    /// The types known by the client, such as the `Option` and `AccountId`.
+    fn known_type(&self, id: u32, ty: &Type) -> Result<String> {
+        let params = self.params(ty)?;
+        let name = ty.path.last().map(String::as_str).unwrap_or_default();
+
+        match (name, params.as_slice()) {
+            ("AccountId", []) => Ok("::metis_client::types::AccountId".into()),
+            ("Hash", []) => Ok("::metis_client::types::Hash".into()),
+            ("Option", [t]) => Ok(format!("::core::option::Option<{}>", t)),
+            ("Result", [t, e]) => Ok(format!("::core::result::Result<{}, {}>", t, e)),
+            ("BTreeMap", [k, v]) => {
+                Ok(format!("::std::collections::BTreeMap<{}, {}>", k, v))
+            }
+            ("BTreeSet", [t]) => Ok(format!("::std::collections::BTreeSet<{}>", t)),
+            _ => {
+                Err(Error::UnsupportedType(format!(
+                    "{} ({})",
+                    ty.path.join("::"),
+                    id
+                )))
+            }
+        }
+    }
+
+    /// Returns the rust types of the generic params of the type.
+    fn params(&self, ty: &Type) -> Result<Vec<String>> {
+        ty.params
+            .iter()
+            .filter_map(|param| {
+                match param {
+                    TypeParam::Named { ty, .. } => *ty,
+                    TypeParam::Id(id) => Some(*id),
+                }
+            })
+            .map(|id| self.rust_type(id))
+            .collect()
+    }
+
+    /// Returns the ids of the custom types used by the `roots`, including the
+    /// ones used by the fields of the custom types.
+    pub fn custom_types(&self, roots: impl IntoIterator<Item = u32>) -> Result<Vec<u32>> {
+        let mut visited = BTreeSet::new();
+        let mut pending = roots.into_iter().collect::<Vec<_>>();
+
+        while let Some(id) = pending.pop() {
+            if !visited.insert(id) {
+                continue
+            }
+
+            let ty = self.get(id)?;
+            pending.extend(ty.params.iter().filter_map(|param| {
+                match param {
+                    TypeParam::Named { ty, .. } => *ty,
+                    TypeParam::Id(id) => Some(*id),
+                }
+            }));
+            match &ty.def {
+                TypeDef::Composite { fields } => {
+                    pending.extend(fields.iter().map(|f| f.ty))
+                }
+                TypeDef::Variant { variants } => {
+                    pending.extend(
+                        variants.iter().flat_map(|v| v.fields.iter().map(|f| f.ty)),
+                    )
+                }
+                TypeDef::Sequence { ty }
+                | TypeDef::Array { ty, .. }
+                | TypeDef::Compact { ty } => pending.push(*ty),
+                TypeDef::Tuple(fields) => pending.extend(fields.iter().copied()),
+                TypeDef::Primitive(_) | TypeDef::BitSequence(_) => {}
+            }
+        }
+
+        Ok(visited
+            .into_iter()
+            .filter(|id| self.names.contains_key(id))
+            .collect())
+    }
+
+    /// Returns the declaration of the custom type `id`.
+    pub fn declare(&self, id: u32) -> Result<String> {
+        let ty = self.get(id)?;
+        let name = self
+            .names
+            .get(&id)
+            .ok_or_else(|| Error::NotFound(format!("custom type {}", id)))?;
+
+        let mut code = docs(&ty.docs, "");
+        code.push_str(
+            "#[derive(Debug, Clone, PartialEq, Eq, ::metis_client::scale::Encode, ::metis_client::scale::Decode)]\n",
+        );
+
+        match &ty.def {
+            TypeDef::Composite { fields } => {
+                code.push_str(&format!(
+                    "pub struct {}{}\n",
+                    name,
+                    self.fields(fields, "    ", true)?
+                ));
+            }
+            TypeDef::Variant { variants } => {
+                code.push_str(&format!("pub enum {} {{\n", name));
+                for variant in variants.iter() {
+                    code.push_str(&docs(&variant.docs, "    "));
+                    if let Some(index) =
+                        variant.index.or(variant.discriminant.map(|d| d as u8))
+                    {
+                        code.push_str(&format!("    #[codec(index = {})]\n", index));
+                    }
+                    let fields = self.fields(&variant.fields, "        ", false)?;
+                    let fields = fields.trim_end_matches(';');
+                    code.push_str(&format!("    {}{},\n", variant.name, fields));
+                }
+                code.push_str("}\n");
+            }
+            _ => unreachable!("only composite and variant are custom; qed"),
+        }
+
+        Ok(code)
+    }
+
+    /// Returns the fields of a struct or a variant, such as `{ a: u8 }`,
+    /// `(u8);` or `;`.
+    fn fields(&self, fields: &[Field], indent: &str, public: bool) -> Result<String> {
+        let vis = if public { "pub " } else { "" };
+
+        if fields.is_empty() {
+            return Ok(";".into())
+        }
+
+        if fields.iter().all(|field| field.name.is_some()) {
+            let mut code = " {\n".to_string();
+            for field in fields.iter() {
+                code.push_str(&docs(&field.docs, indent));
+                code.push_str(&format!(
+                    "{}{}{}: {},\n",
+                    indent,
+                    vis,
+                    ident(field.name.as_deref().unwrap_or_default()),
+                    self.rust_type(field.ty)?
+                ));
+            }
+            code.push_str(&indent[4..]);
+            code.push('}');
+            return Ok(code)
+        }
+
+        let fields = fields
+            .iter()
+            .map(|field| -> Result<String> {
+                Ok(format!("{}{}", vis, self.rust_type(field.ty)?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(format!("({});", fields.join(", ")))
+    }
+}
+
+/// Whether the type should be declared by the codegen.
+fn is_custom(ty: &Type) -> bool {
+    let is_adt = matches!(ty.def, TypeDef::Composite { .. } | TypeDef::Variant { .. });
+    let known = matches!(
+        ty.path.last().map(String::as_str),
+        Some("AccountId") | Some("Hash") | Some("BTreeMap") | Some("BTreeSet")
+    ) || ty.path == ["Option"]
+        || ty.path == ["Result"];
+
+    is_adt && !known
+}
+
+fn primitive_type(primitive: &str) -> Result<String> {
+    match primitive {
+        "bool" | "char" | "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16"
+        | "i32" | "i64" | "i128" => Ok(primitive.into()),
+        "str" => Ok("::std::string::String".into()),
+        _ => Err(Error::UnsupportedType(primitive.into())),
+    }
+}
+
+/// Returns the identifier, escaped if it is a keyword.
+pub(crate) fn ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false",
+        "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+        "pub", "ref", "return", "static", "struct", "trait", "true", "type", "unsafe",
+        "use", "where", "while", "async", "await", "dyn", "abstract", "become", "box",
+        "do", "final", "macro", "override", "priv", "typeof", "unsized", "virtual",
+        "yield", "try",
+    ];
+
+    if KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Returns the doc comments with the `indent`.
+pub(crate) fn docs(docs: &[String], indent: &str) -> String {
+    docs.iter()
+        .map(|line| format!("{}///{}\n", indent, line))
+        .collect()
+}
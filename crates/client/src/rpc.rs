@@ -0,0 +1,140 @@
+//! Dry run the calls by the `contracts_call` rpc of a node, such as a local
+//! europa, for the queries and to check a call before submitting it.
+//!
+//! The calls which change the state should be submitted as the extrinsics,
+//! signed by a substrate client, with the `Call::data` as the input data.
+
+use crate::{
+    types::Balance,
+    Call,
+    Error,
+    Result,
+};
+use scale::Decode;
+use serde_json::{
+    json,
+    Value,
+};
+
+/// The flag of the output set if the contract reverted.
+const FLAG_REVERT: u64 = 1;
+
+/// The gas limit used by the calls if not set.
+pub const DEFAULT_GAS_LIMIT: u64 = 200_000_000_000;
+
+/// The output of a dry run call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallOutput {
+    /// The flags returned by the contract, `1` if reverted.
+    pub flags: u64,
+    /// The encoded return value.
+    pub data: Vec<u8>,
+    /// The gas consumed by the call.
+    pub gas_consumed: u64,
+}
+
+impl CallOutput {
+    /// Returns whether the contract reverted.
+    pub fn is_revert(&self) -> bool {
+        self.flags & FLAG_REVERT != 0
+    }
+}
+
+/// A client of the `contracts` rpc of a node.
+#[derive(Debug, Clone)]
+pub struct Client {
+    url: String,
+}
+
+impl Client {
+    /// The `url` of the http rpc, such as `http://127.0.0.1:9933`.
+    pub fn new<S: Into<String>>(url: S) -> Self {
+        Self { url: url.into() }
+    }
+
+    /// Dry run the `call` from `origin` to the contract `dest`, both are the
+    /// SS58 addresses, and decode the return value.
+    pub fn call<R: Decode>(
+        &self,
+        origin: &str,
+        dest: &str,
+        value: Balance,
+        call: &Call<R>,
+    ) -> Result<R> {
+        let output =
+            self.call_raw(origin, dest, value, DEFAULT_GAS_LIMIT, call.data())?;
+        if output.is_revert() {
+            return Err(Error::Rpc(format!(
+                "contract reverted: 0x{}",
+                hex::encode(&output.data)
+            )))
+        }
+
+        call.decode_output(&output.data)
+    }
+
+    /// Dry run the call with the `input_data`.
+    pub fn call_raw(
+        &self,
+        origin: &str,
+        dest: &str,
+        value: Balance,
+        gas_limit: u64,
+        input_data: Vec<u8>,
+    ) -> Result<CallOutput> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "contracts_call",
+            "params": [{
+                "origin": origin,
+                "dest": dest,
+                "value": format!("0x{:x}", value),
+                "gasLimit": gas_limit,
+                "inputData": format!("0x{}", hex::encode(input_data)),
+            }],
+        });
+
+        let response: Value = ureq::post(&self.url)
+            .send_json(request)
+            .map_err(|err| Error::Rpc(err.to_string()))?
+            .into_json()?;
+
+        if let Some(err) = response.get("error") {
+            return Err(Error::Rpc(err.to_string()))
+        }
+
+        let result = response
+            .get("result")
+            .ok_or_else(|| Error::Rpc("no result".into()))?;
+        parse_call_result(result)
+    }
+}
+
+/// Parse the result of the `contracts_call`, both the `{"result": {"Ok": ..}}`
+/// and the older `{"Success": ..}` layouts.
+fn parse_call_result(result: &Value) -> Result<CallOutput> {
+    let (output, gas_consumed) = match result.get("result") {
+        Some(res) => (res.get("Ok"), result.get("gasConsumed")),
+        None => {
+            let success = result.get("Success");
+            (
+                success,
+                success.and_then(|success| success.get("gasConsumed")),
+            )
+        }
+    };
+
+    let output = output.ok_or_else(|| Error::Rpc(format!("call failed: {}", result)))?;
+    let data = output
+        .get("data")
+        .and_then(Value::as_str)
+        .ok_or_else(|| Error::Rpc("no data".into()))?;
+
+    Ok(CallOutput {
+        flags: output.get("flags").and_then(Value::as_u64).unwrap_or(0),
+        data: hex::decode(data.trim_start_matches("0x"))
+            .map_err(|err| Error::Rpc(err.to_string()))?,
+        gas_consumed: gas_consumed.and_then(Value::as_u64).unwrap_or(0),
+    })
+}
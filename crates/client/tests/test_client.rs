@@ -0,0 +1,287 @@
+use metis_client::{
+    call::parse_selector,
+    codegen,
+    event::{
+        field_topic,
+        signature_topic,
+    },
+    types::{
+        AccountId,
+        Balance,
+        Hash,
+    },
+    Call,
+    ContractEvent,
+    ContractMetadata,
+    Error,
+    EventRecord,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+const METADATA: &str = include_str!("fixtures/erc20.json");
+
+fn metadata() -> ContractMetadata {
+    ContractMetadata::from_json(METADATA).expect("metadata should be parsed")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+struct Transfer {
+    from: Option<AccountId>,
+    to: Option<AccountId>,
+    value: Balance,
+}
+
+impl ContractEvent for Transfer {
+    const INDEX: u8 = 0;
+    const SIGNATURE: &'static [u8] = b"Erc20::Transfer";
+
+    fn topics(&self) -> Vec<Hash> {
+        vec![
+            Self::signature_topic(),
+            field_topic(b"Erc20::Transfer::from", &self.from),
+            field_topic(b"Erc20::Transfer::to", &self.to),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+struct Approval {
+    owner: AccountId,
+    spender: AccountId,
+    value: Balance,
+}
+
+impl ContractEvent for Approval {
+    const INDEX: u8 = 1;
+    const SIGNATURE: &'static [u8] = b"Erc20::Approval";
+
+    fn topics(&self) -> Vec<Hash> {
+        vec![
+            Self::signature_topic(),
+            field_topic(b"Erc20::Approval::owner", &self.owner),
+            field_topic(b"Erc20::Approval::spender", &self.spender),
+        ]
+    }
+}
+
+fn account(byte: u8) -> AccountId {
+    AccountId::from([byte; 32])
+}
+
+#[test]
+fn parse_metadata_should_ok() {
+    let metadata = metadata();
+
+    assert_eq!(metadata.name, "erc20");
+    assert_eq!(metadata.spec.constructors.len(), 1);
+    assert_eq!(metadata.spec.messages.len(), 4);
+    assert_eq!(metadata.spec.events.len(), 2);
+
+    let transfer = metadata.message("transfer").expect("transfer should exist");
+    assert!(transfer.mutates);
+    assert_eq!(transfer.args.len(), 2);
+    assert_eq!(transfer.selector().unwrap(), [0x84, 0xa1, 0x5d, 0xa1]);
+
+    let (index, approval) = metadata.event("Approval").expect("approval should exist");
+    assert_eq!(index, 1);
+    assert_eq!(approval.args.len(), 3);
+    assert!(approval.args[0].indexed);
+    assert!(!approval.args[2].indexed);
+}
+
+#[test]
+fn parse_metadata_not_found_should_err() {
+    let metadata = metadata();
+
+    assert!(matches!(metadata.message("mint"), Err(Error::NotFound(_))));
+    assert!(matches!(
+        metadata.constructor("default"),
+        Err(Error::NotFound(_))
+    ));
+    assert!(matches!(metadata.event("Burn"), Err(Error::NotFound(_))));
+}
+
+#[test]
+fn parse_invalid_metadata_should_err() {
+    assert!(matches!(
+        ContractMetadata::from_json("{}"),
+        Err(Error::Metadata(_))
+    ));
+    assert!(matches!(
+        ContractMetadata::from_json("not a json"),
+        Err(Error::Metadata(_))
+    ));
+}
+
+#[test]
+fn parse_selector_should_ok() {
+    assert_eq!(
+        parse_selector("0x9bae9d5e").unwrap(),
+        [0x9b, 0xae, 0x9d, 0x5e]
+    );
+    assert_eq!(
+        parse_selector("0f755a56").unwrap(),
+        [0x0f, 0x75, 0x5a, 0x56]
+    );
+
+    assert!(parse_selector("0x9bae9d").is_err());
+    assert!(parse_selector("0xzzzzzzzz").is_err());
+}
+
+#[test]
+fn call_data_should_ok() {
+    let to = account(0x02);
+    let call = Call::<Result<(), u8>>::new([0x84, 0xa1, 0x5d, 0xa1], (to, 100_u128));
+
+    let mut expected = vec![0x84, 0xa1, 0x5d, 0xa1];
+    expected.extend(to.encode());
+    expected.extend(100_u128.encode());
+
+    assert_eq!(call.selector(), [0x84, 0xa1, 0x5d, 0xa1]);
+    assert_eq!(call.args(), &expected[4..]);
+    assert_eq!(call.data(), expected);
+    assert_eq!(call.data_hex(), format!("0x{}", hex::encode(&expected)));
+}
+
+#[test]
+fn message_call_from_metadata_should_ok() {
+    let metadata = metadata();
+    let owner = account(0x01);
+
+    let call = metadata
+        .message_call::<_, Balance>("balance_of", (owner,))
+        .expect("balance_of should exist");
+    assert_eq!(call.data()[..4], [0x0f, 0x75, 0x5a, 0x56]);
+    assert_eq!(call.args(), owner.encode().as_slice());
+
+    let call = metadata
+        .constructor_call("new", (1000_u128, String::from("Metis")))
+        .expect("new should exist");
+    assert_eq!(call.selector(), [0x9b, 0xae, 0x9d, 0x5e]);
+    assert_eq!(
+        call.args(),
+        (1000_u128, String::from("Metis")).encode().as_slice()
+    );
+}
+
+#[test]
+fn decode_output_should_ok() {
+    let call = Call::<Balance>::new([0xdb, 0x63, 0x75, 0xa8], ());
+
+    assert_eq!(call.decode_output(&1000_u128.encode()).unwrap(), 1000);
+    assert!(matches!(
+        call.decode_output(&[0x01, 0x02]),
+        Err(Error::Decode(_))
+    ));
+
+    // the output should be decoded exactly.
+    let mut output = 1000_u128.encode();
+    output.push(0x00);
+    assert!(call.decode_output(&output).is_err());
+}
+
+#[test]
+fn event_topics_should_ok() {
+    let event = Transfer {
+        from: None,
+        to: Some(account(0x02)),
+        value: 100,
+    };
+
+    let topics = event.topics();
+    assert_eq!(topics.len(), 3);
+    assert_eq!(topics[0], signature_topic(b"Erc20::Transfer"));
+    assert_eq!(topics[0], Transfer::signature_topic());
+    assert_ne!(topics[0], Approval::signature_topic());
+
+    // `None` is short enough to be used as it is.
+    let mut expected = Hash::default();
+    let encoded = (&b"Erc20::Transfer::from"[..], None::<AccountId>).encode();
+    expected.as_mut()[..encoded.len()].copy_from_slice(&encoded);
+    assert_eq!(topics[1], expected);
+
+    assert_ne!(
+        topics[2],
+        field_topic(b"Erc20::Transfer::to", &Some(account(0x03)))
+    );
+}
+
+#[test]
+fn decode_event_should_ok() {
+    let event = Approval {
+        owner: account(0x01),
+        spender: account(0x02),
+        value: 100,
+    };
+
+    let mut data = vec![Approval::INDEX];
+    data.extend(event.encode());
+    let record = EventRecord::new(data.clone(), event.topics());
+
+    assert_eq!(record.index(), Some(1));
+    assert!(record.is::<Approval>());
+    assert!(!record.is::<Transfer>());
+    assert_eq!(record.decode::<Approval>(), Some(event.clone()));
+    assert_eq!(record.decode::<Transfer>(), None);
+
+    // the record without topics is checked by the index only.
+    let record = EventRecord::new(data, Vec::new());
+    assert_eq!(record.decode::<Approval>(), Some(event));
+}
+
+#[test]
+fn decode_event_with_wrong_topic_should_none() {
+    let event = Approval {
+        owner: account(0x01),
+        spender: account(0x02),
+        value: 100,
+    };
+
+    let mut data = vec![Approval::INDEX];
+    data.extend(event.encode());
+    let record = EventRecord::new(data, vec![Transfer::signature_topic()]);
+
+    assert!(!record.is::<Approval>());
+    assert_eq!(record.decode::<Approval>(), None);
+}
+
+#[test]
+fn codegen_should_ok() {
+    let code =
+        codegen::generate(&metadata(), &Default::default()).expect("codegen should ok");
+
+    assert!(code.contains("pub mod erc20 {"));
+    assert!(code.contains("pub enum Error {"));
+    assert!(code.contains("InsufficientAllowance"));
+    assert!(code.contains(
+        "pub fn new(initial_supply: u128, name: ::std::string::String) -> ::metis_client::Call<()>"
+    ));
+    assert!(code.contains(
+        "pub fn balance_of(owner: ::metis_client::types::AccountId) -> ::metis_client::Call<u128>"
+    ));
+    assert!(code.contains("-> ::metis_client::Call<::core::result::Result<(), Error>>"));
+    assert!(
+        code.contains("::metis_client::Call::new([0x84, 0xa1, 0x5d, 0xa1], (to, value))")
+    );
+    assert!(code.contains("pub struct Transfer {"));
+    assert!(code.contains("const SIGNATURE: &'static [u8] = b\"Erc20::Transfer\";"));
+    assert!(code.contains(
+        "::metis_client::event::field_topic(b\"Erc20::Approval::owner\", value)"
+    ));
+    assert!(code.contains("pub enum Event {"));
+}
+
+#[test]
+fn codegen_with_options_should_ok() {
+    let options = codegen::Options {
+        module: Some("token".into()),
+        storage_ident: Some("Token".into()),
+    };
+    let code = codegen::generate(&metadata(), &options).expect("codegen should ok");
+
+    assert!(code.contains("pub mod token {"));
+    assert!(code.contains("const SIGNATURE: &'static [u8] = b\"Token::Transfer\";"));
+}
@@ -0,0 +1,520 @@
+//! Extension of `Access Control` Component that allows specifying special rules to manage
+//! the `DEFAULT_ADMIN_ROLE` holder, which is a sensitive role with special permissions
+//! over other roles that may potentially have privileged rights in the system.
+//!
+//! If a specific role doesn't have an admin role assigned, the holder of the
+//! `DEFAULT_ADMIN_ROLE` will have the ability to grant it and revoke it.
+//!
+//! This component implements the following risk mitigations on top of `Access Control`:
+//!
+//! - Only one account holds the `DEFAULT_ADMIN_ROLE` since deployment until it's
+//!   potentially renounced.
+//! - Enforces a 2-step process to transfer the `DEFAULT_ADMIN_ROLE` to another account.
+//! - Enforces a configurable delay between the two steps, with the ability to cancel
+//!   before the transfer is accepted.
+//! - The delay can be changed by scheduling, see `change_default_admin_delay`.
+//! - It is not possible to use another role to manage the `DEFAULT_ADMIN_ROLE`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod module;
+
+pub use access_control::{
+    Error,
+    Result,
+    RoleId,
+    DEFAULT_ADMIN_ROLE,
+};
+use metis_access_control as access_control;
+
+use metis_lang::{
+    arithmetic::Saturating,
+    Env,
+    EnvAccess,
+    Storage,
+};
+
+pub use module::Data;
+
+/// The `EventEmit` impl the event emit api for component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emitted when a `DEFAULT_ADMIN_ROLE` transfer is started, setting `new_admin`
+    /// as the next address to become the `DEFAULT_ADMIN_ROLE` holder, which will
+    /// have to accept the transfer after `accept_schedule` passes.
+    fn emit_event_default_admin_transfer_scheduled(
+        &mut self,
+        new_admin: Option<E::AccountId>,
+        accept_schedule: E::Timestamp,
+    );
+
+    /// Emitted when a `pending_default_admin` is reset if it was never accepted,
+    /// regardless of its schedule.
+    fn emit_event_default_admin_transfer_canceled(&mut self);
+
+    /// Emitted when a `default_admin_delay` change is started, setting `new_delay`
+    /// as the next delay to be applied between default admin transfer after
+    /// `effect_schedule` has passed.
+    fn emit_event_default_admin_delay_change_scheduled(
+        &mut self,
+        new_delay: E::Timestamp,
+        effect_schedule: E::Timestamp,
+    );
+
+    /// Emitted when a `pending_default_admin_delay` is reset if its schedule didn't pass.
+    fn emit_event_default_admin_delay_change_canceled(&mut self);
+}
+
+/// Extension of {AccessControl} that enforces a single, delay-gated `DEFAULT_ADMIN_ROLE`.
+pub trait Impl<E>: access_control::Impl<E> + EventEmit<E> + Storage<E, Data<E>>
+where
+    E: Env,
+{
+    /// Initialize the component, `initial_default_admin` will be the only one
+    /// account which has the `DEFAULT_ADMIN_ROLE`.
+    fn init(&mut self, initial_default_admin: E::AccountId) {
+        assert!(
+            initial_default_admin != E::AccountId::default(),
+            "AccessControlDefaultAdminRules: invalid default admin"
+        );
+
+        access_control::Impl::_set_role_admin(
+            self,
+            DEFAULT_ADMIN_ROLE,
+            DEFAULT_ADMIN_ROLE,
+        );
+
+        Impl::_setup_role(self, DEFAULT_ADMIN_ROLE, initial_default_admin);
+    }
+
+    /// Returns the address of the current `DEFAULT_ADMIN_ROLE` holder.
+    fn default_admin(&self) -> Option<E::AccountId> {
+        Storage::<E, Data<E>>::get(self).default_admin().clone()
+    }
+
+    /// Returns a tuple of a `new_admin` and an accept schedule.
+    ///
+    /// After the `schedule` passes, the `new_admin` will be able to accept the
+    /// `default_admin` role by calling `accept_default_admin_transfer`, completing
+    /// the role transfer.
+    ///
+    /// A zero value only in `schedule` indicates no pending admin transfer.
+    fn pending_default_admin(&self) -> (Option<E::AccountId>, E::Timestamp) {
+        Storage::<E, Data<E>>::get(self).pending_default_admin()
+    }
+
+    /// Returns the delay required to schedule the acceptance of a `DEFAULT_ADMIN_ROLE`
+    /// transfer started.
+    ///
+    /// This delay will be added to the current timestamp when calling
+    /// `begin_default_admin_transfer` to set the acceptance schedule.
+    ///
+    /// NOTE: If a delay change has been scheduled, it will take effect as soon as the
+    /// schedule passes, making this function returns the new delay.
+    fn default_admin_delay(&self) -> E::Timestamp {
+        let (pending_delay, schedule) = Storage::<E, Data<E>>::get(self).pending_delay();
+
+        if _is_schedule_set::<E>(&schedule) && _has_schedule_passed::<E, Self>(&schedule)
+        {
+            pending_delay
+        } else {
+            Storage::<E, Data<E>>::get(self).current_delay()
+        }
+    }
+
+    /// Returns a tuple of `new_delay` and an effect schedule.
+    ///
+    /// After the `schedule` passes, the `new_delay` will get into effect immediately
+    /// for every new `DEFAULT_ADMIN_ROLE` transfer started with
+    /// `begin_default_admin_transfer`.
+    ///
+    /// A zero value only in `schedule` indicates no pending delay change.
+    fn pending_default_admin_delay(&self) -> (E::Timestamp, E::Timestamp) {
+        let (pending_delay, schedule) = Storage::<E, Data<E>>::get(self).pending_delay();
+
+        if _is_schedule_set::<E>(&schedule) && !_has_schedule_passed::<E, Self>(&schedule)
+        {
+            (pending_delay, schedule)
+        } else {
+            (E::Timestamp::from(0_u8), E::Timestamp::from(0_u8))
+        }
+    }
+
+    /// Maximum time for a `default_admin_delay` to be increased.
+    ///
+    /// This wait is the protection against setting a very high delay which will
+    /// make the `DEFAULT_ADMIN_ROLE` locked. Default to 5 days in milliseconds,
+    /// can be overridden in contracts.
+    fn default_admin_delay_increase_wait(&self) -> E::Timestamp {
+        E::Timestamp::from(432_000_000_u32)
+    }
+
+    /// Returns `true` if `account` has been granted `role`.
+    fn has_role(&self, role: RoleId, account: E::AccountId) -> bool {
+        access_control::Impl::has_role(self, role, account)
+    }
+
+    /// @dev Returns the admin role that controls `role`. See {grantRole} and
+    /// {revokeRole}.
+    ///
+    /// To change a role's admin, use {_setRoleAdmin}.
+    fn get_role_admin(&self, role: RoleId) -> Option<RoleId> {
+        access_control::Impl::get_role_admin(self, role)
+    }
+
+    /// Panic if `account` is not granted role
+    fn ensure_role(&self, role: RoleId, account: E::AccountId) {
+        access_control::Impl::ensure_role(self, role, account)
+    }
+
+    /// Panic if caller is not granted role
+    fn ensure_caller_role(&self, role: RoleId) {
+        access_control::Impl::ensure_caller_role(self, role)
+    }
+
+    /// Panic error if `account` is missing the admin role of the `role`.
+    fn ensure_admin_role(&self, role: RoleId, account: E::AccountId) {
+        access_control::Impl::ensure_admin_role(self, role, account)
+    }
+
+    /// Return error if `account` is missing `role`.
+    fn check_role(&self, role: RoleId, account: E::AccountId) -> Result<()> {
+        access_control::Impl::check_role(self, role, account)
+    }
+
+    /// Return error if `account` is missing the admin role of the `role`.
+    fn check_admin_role(&self, role: RoleId, account: E::AccountId) -> Result<()> {
+        access_control::Impl::check_admin_role(self, role, account)
+    }
+
+    /// @dev Grants `role` to `account`.
+    ///
+    /// Requirements:
+    ///
+    /// - `role` can not be the `DEFAULT_ADMIN_ROLE`, which should use
+    /// `begin_default_admin_transfer`.
+    /// - the caller must have ``role``'s admin role.
+    fn grant_role(&mut self, role: RoleId, account: E::AccountId) {
+        assert!(
+            role != DEFAULT_ADMIN_ROLE,
+            "AccessControlDefaultAdminRules: can't directly grant default admin role"
+        );
+
+        access_control::Impl::grant_role(self, role, account)
+    }
+
+    /// @dev Revokes `role` from `account`.
+    ///
+    /// Requirements:
+    ///
+    /// - `role` can not be the `DEFAULT_ADMIN_ROLE`.
+    /// - the caller must have ``role``'s admin role.
+    fn revoke_role(&mut self, role: RoleId, account: E::AccountId) {
+        assert!(
+            role != DEFAULT_ADMIN_ROLE,
+            "AccessControlDefaultAdminRules: can't directly revoke default admin role"
+        );
+
+        access_control::Impl::revoke_role(self, role, account)
+    }
+
+    /// @dev Revokes `role` from the calling account.
+    ///
+    /// For the `DEFAULT_ADMIN_ROLE`, it only allows renouncing in two steps by
+    /// first calling `begin_default_admin_transfer` to the `None` address, so
+    /// it's required that the `pending_default_admin` schedule has also passed
+    /// when calling this function.
+    ///
+    /// After its execution, it will not be possible to call the functions
+    /// which need the `DEFAULT_ADMIN_ROLE`.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be `account`.
+    fn renounce_role(&mut self, role: RoleId, account: E::AccountId) {
+        if role == DEFAULT_ADMIN_ROLE && Some(account.clone()) == self.default_admin() {
+            let (new_default_admin, schedule) = self.pending_default_admin();
+
+            assert!(
+                new_default_admin.is_none()
+                    && _is_schedule_set::<E>(&schedule)
+                    && _has_schedule_passed::<E, Self>(&schedule),
+                "AccessControlDefaultAdminRules: only can renounce in two delayed steps"
+            );
+
+            Storage::<E, Data<E>>::get_mut(self)
+                .set_pending_default_admin(None, E::Timestamp::from(0_u8));
+            Storage::<E, Data<E>>::get_mut(self).set_default_admin(None);
+        }
+
+        access_control::Impl::renounce_role(self, role, account)
+    }
+
+    /// @dev Grants `role` to `account`.
+    ///
+    /// For the `DEFAULT_ADMIN_ROLE`, it only allows granting if there isn't
+    /// already a `default_admin` or if the role has been previously renounced.
+    ///
+    /// NOTE: Exposing this function through another mechanism may make the
+    /// `DEFAULT_ADMIN_ROLE` assignable again. Make sure to guarantee this is
+    /// the expected behavior in your implementation.
+    fn _setup_role(&mut self, role: RoleId, account: E::AccountId) {
+        if role == DEFAULT_ADMIN_ROLE {
+            assert!(
+                self.default_admin().is_none(),
+                "AccessControlDefaultAdminRules: default admin already granted"
+            );
+
+            Storage::<E, Data<E>>::get_mut(self).set_default_admin(Some(account.clone()));
+        }
+
+        access_control::Impl::_setup_role(self, role, account)
+    }
+
+    /// Sets `admin_role` as ``role``'s admin role.
+    ///
+    /// Requirements:
+    ///
+    /// - `role` can not be the `DEFAULT_ADMIN_ROLE`.
+    fn _set_role_admin(&mut self, role: RoleId, admin_role: RoleId) {
+        assert!(
+            role != DEFAULT_ADMIN_ROLE,
+            "AccessControlDefaultAdminRules: can't violate default admin rules"
+        );
+
+        access_control::Impl::_set_role_admin(self, role, admin_role)
+    }
+
+    /// Starts a `DEFAULT_ADMIN_ROLE` transfer by setting a `pending_default_admin`
+    /// scheduled for acceptance after the current timestamp plus a `default_admin_delay`.
+    ///
+    /// Requirements:
+    ///
+    /// - Only can be called by the current `default_admin`.
+    ///
+    /// Emits a `DefaultAdminTransferScheduled` event.
+    fn begin_default_admin_transfer(&mut self, new_admin: Option<E::AccountId>) {
+        access_control::Impl::ensure_caller_role(self, DEFAULT_ADMIN_ROLE);
+
+        self._begin_default_admin_transfer(new_admin)
+    }
+
+    /// Internal function of `begin_default_admin_transfer` without access restriction.
+    fn _begin_default_admin_transfer(&mut self, new_admin: Option<E::AccountId>) {
+        let new_schedule =
+            Self::block_timestamp().saturating_add(self.default_admin_delay());
+
+        self._set_pending_default_admin(new_admin.clone(), new_schedule);
+
+        self.emit_event_default_admin_transfer_scheduled(new_admin, new_schedule);
+    }
+
+    /// Cancels a `DEFAULT_ADMIN_ROLE` transfer previously started with
+    /// `begin_default_admin_transfer`.
+    ///
+    /// A `pending_default_admin` not yet accepted can also be cancelled with
+    /// this function.
+    ///
+    /// Requirements:
+    ///
+    /// - Only can be called by the current `default_admin`.
+    ///
+    /// May emit a `DefaultAdminTransferCanceled` event.
+    fn cancel_default_admin_transfer(&mut self) {
+        access_control::Impl::ensure_caller_role(self, DEFAULT_ADMIN_ROLE);
+
+        self._cancel_default_admin_transfer()
+    }
+
+    /// Internal function of `cancel_default_admin_transfer` without access restriction.
+    fn _cancel_default_admin_transfer(&mut self) {
+        self._set_pending_default_admin(None, E::Timestamp::from(0_u8));
+    }
+
+    /// Completes a `DEFAULT_ADMIN_ROLE` transfer previously started with
+    /// `begin_default_admin_transfer`.
+    ///
+    /// After calling the function:
+    ///
+    /// - `DEFAULT_ADMIN_ROLE` should be granted to the caller.
+    /// - `DEFAULT_ADMIN_ROLE` should be revoked from the previous holder.
+    /// - `pending_default_admin` should be reset to zero values.
+    ///
+    /// Requirements:
+    ///
+    /// - Only can be called by the `pending_default_admin`'s `new_admin`.
+    /// - The `pending_default_admin`'s `accept_schedule` should've passed.
+    fn accept_default_admin_transfer(&mut self) {
+        let (new_default_admin, _) = self.pending_default_admin();
+
+        assert!(
+            new_default_admin == Some(self._msg_sender()),
+            "AccessControlDefaultAdminRules: pending admin must accept"
+        );
+
+        self._accept_default_admin_transfer()
+    }
+
+    /// Internal function of `accept_default_admin_transfer` without access restriction.
+    fn _accept_default_admin_transfer(&mut self) {
+        let (new_default_admin, schedule) = self.pending_default_admin();
+
+        assert!(
+            _is_schedule_set::<E>(&schedule)
+                && _has_schedule_passed::<E, Self>(&schedule),
+            "AccessControlDefaultAdminRules: transfer delay not passed"
+        );
+
+        let new_default_admin =
+            new_default_admin.expect("AccessControlDefaultAdminRules: no pending admin");
+        let caller = self._msg_sender();
+
+        if let Some(old_default_admin) = self.default_admin() {
+            Storage::<E, access_control::Data<E>>::get_mut(self)
+                .revoke_role(DEFAULT_ADMIN_ROLE, old_default_admin.clone())
+                .expect("no has role");
+            self.emit_event_role_revoked(
+                DEFAULT_ADMIN_ROLE,
+                old_default_admin,
+                caller.clone(),
+            );
+        }
+
+        Storage::<E, access_control::Data<E>>::get_mut(self)
+            .grant_role(DEFAULT_ADMIN_ROLE, new_default_admin.clone())
+            .expect("has role");
+        self.emit_event_role_granted(
+            DEFAULT_ADMIN_ROLE,
+            new_default_admin.clone(),
+            caller,
+        );
+
+        Storage::<E, Data<E>>::get_mut(self).set_default_admin(Some(new_default_admin));
+        Storage::<E, Data<E>>::get_mut(self)
+            .set_pending_default_admin(None, E::Timestamp::from(0_u8));
+    }
+
+    /// Initiates a `default_admin_delay` update by setting a `pending_default_admin_delay`
+    /// scheduled for getting into effect after the current timestamp plus a
+    /// `default_admin_delay`.
+    ///
+    /// This function guarantees that any call to `begin_default_admin_transfer`
+    /// done between the timestamp this method is called and the
+    /// `pending_default_admin_delay` effect schedule will use the current
+    /// `default_admin_delay` set before calling.
+    ///
+    /// Requirements:
+    ///
+    /// - Only can be called by the current `default_admin`.
+    ///
+    /// Emits a `DefaultAdminDelayChangeScheduled` event and may emit a
+    /// `DefaultAdminDelayChangeCanceled` event.
+    fn change_default_admin_delay(&mut self, new_delay: E::Timestamp) {
+        access_control::Impl::ensure_caller_role(self, DEFAULT_ADMIN_ROLE);
+
+        self._change_default_admin_delay(new_delay)
+    }
+
+    /// Internal function of `change_default_admin_delay` without access restriction.
+    fn _change_default_admin_delay(&mut self, new_delay: E::Timestamp) {
+        let new_schedule =
+            Self::block_timestamp().saturating_add(self._delay_change_wait(new_delay));
+
+        self._set_pending_delay(new_delay, new_schedule);
+
+        self.emit_event_default_admin_delay_change_scheduled(new_delay, new_schedule);
+    }
+
+    /// Cancels a scheduled `default_admin_delay` change.
+    ///
+    /// Requirements:
+    ///
+    /// - Only can be called by the current `default_admin`.
+    ///
+    /// May emit a `DefaultAdminDelayChangeCanceled` event.
+    fn rollback_default_admin_delay(&mut self) {
+        access_control::Impl::ensure_caller_role(self, DEFAULT_ADMIN_ROLE);
+
+        self._rollback_default_admin_delay()
+    }
+
+    /// Internal function of `rollback_default_admin_delay` without access restriction.
+    fn _rollback_default_admin_delay(&mut self) {
+        self._set_pending_delay(E::Timestamp::from(0_u8), E::Timestamp::from(0_u8));
+    }
+
+    /// Returns the amount of time to wait after a `new_delay` will become the
+    /// new `default_admin_delay`.
+    ///
+    /// If the `new_delay` is greater than the current delay, the wait is capped
+    /// by `default_admin_delay_increase_wait`, if not, the wait is the difference
+    /// between the current delay and the `new_delay`, so that a `DEFAULT_ADMIN_ROLE`
+    /// transfer scheduled before the change is still gated by the previous delay.
+    fn _delay_change_wait(&self, new_delay: E::Timestamp) -> E::Timestamp {
+        let current_delay = self.default_admin_delay();
+
+        if new_delay > current_delay {
+            let increase_wait = self.default_admin_delay_increase_wait();
+            if new_delay < increase_wait {
+                new_delay
+            } else {
+                increase_wait
+            }
+        } else {
+            current_delay - new_delay
+        }
+    }
+
+    /// Setter of the tuple for pending admin and its schedule.
+    ///
+    /// May emit a `DefaultAdminTransferCanceled` event.
+    fn _set_pending_default_admin(
+        &mut self,
+        new_admin: Option<E::AccountId>,
+        new_schedule: E::Timestamp,
+    ) {
+        let (_, old_schedule) = self.pending_default_admin();
+
+        Storage::<E, Data<E>>::get_mut(self)
+            .set_pending_default_admin(new_admin, new_schedule);
+
+        // An `old_schedule` set means a pending admin never accepted.
+        if _is_schedule_set::<E>(&old_schedule) {
+            self.emit_event_default_admin_transfer_canceled();
+        }
+    }
+
+    /// Setter of the tuple for pending delay and its schedule.
+    ///
+    /// May emit a `DefaultAdminDelayChangeCanceled` event.
+    fn _set_pending_delay(
+        &mut self,
+        new_delay: E::Timestamp,
+        new_schedule: E::Timestamp,
+    ) {
+        let (old_delay, old_schedule) = Storage::<E, Data<E>>::get(self).pending_delay();
+
+        if _is_schedule_set::<E>(&old_schedule) {
+            if _has_schedule_passed::<E, Self>(&old_schedule) {
+                // Materialize a virtual delay
+                Storage::<E, Data<E>>::get_mut(self).set_current_delay(old_delay);
+            } else {
+                // Emit for consistency since the pending delay is not effected
+                self.emit_event_default_admin_delay_change_canceled();
+            }
+        }
+
+        Storage::<E, Data<E>>::get_mut(self).set_pending_delay(new_delay, new_schedule);
+    }
+}
+
+/// Defines if an `schedule` is considered set. For consistency purposes.
+fn _is_schedule_set<E: Env>(schedule: &E::Timestamp) -> bool {
+    *schedule != E::Timestamp::from(0_u8)
+}
+
+/// Defines if an `schedule` is considered passed. For consistency purposes.
+fn _has_schedule_passed<E: Env, T: EnvAccess<E> + ?Sized>(
+    schedule: &E::Timestamp,
+) -> bool {
+    *schedule < T::block_timestamp()
+}
@@ -0,0 +1,107 @@
+pub use metis_lang::Env;
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The Data of access control default admin rules component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E>
+where
+    E: Env,
+{
+    /// The account which is the current default admin
+    pub current_default_admin: Lazy<Option<E::AccountId>>,
+
+    /// The pending default admin and the schedule it can accept the role
+    pub pending_default_admin: Lazy<(Option<E::AccountId>, E::Timestamp)>,
+
+    /// The current delay for the default admin transfer
+    pub current_delay: Lazy<E::Timestamp>,
+
+    /// The pending delay and the schedule it will take effect
+    pub pending_delay: Lazy<(E::Timestamp, E::Timestamp)>,
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    /// Sets the `initial_delay` for the default admin transfer.
+    pub fn new(initial_delay: E::Timestamp) -> Self {
+        let mut instance = Self::default();
+
+        instance.current_delay = Lazy::new(initial_delay);
+
+        instance
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            current_default_admin: Lazy::new(None),
+            pending_default_admin: Lazy::new((None, E::Timestamp::from(0_u8))),
+            current_delay: Lazy::new(E::Timestamp::from(0_u8)),
+            pending_delay: Lazy::new((
+                E::Timestamp::from(0_u8),
+                E::Timestamp::from(0_u8),
+            )),
+        }
+    }
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    /// Get the current default admin
+    pub fn default_admin(&self) -> &Option<E::AccountId> {
+        &self.current_default_admin
+    }
+
+    /// Set the current default admin
+    pub fn set_default_admin(&mut self, admin: Option<E::AccountId>) {
+        Lazy::set(&mut self.current_default_admin, admin);
+    }
+
+    /// Get the pending default admin with its accept schedule
+    pub fn pending_default_admin(&self) -> (Option<E::AccountId>, E::Timestamp) {
+        (*self.pending_default_admin).clone()
+    }
+
+    /// Set the pending default admin with its accept schedule
+    pub fn set_pending_default_admin(
+        &mut self,
+        admin: Option<E::AccountId>,
+        schedule: E::Timestamp,
+    ) {
+        Lazy::set(&mut self.pending_default_admin, (admin, schedule));
+    }
+
+    /// Get the current delay, without the pending delay
+    pub fn current_delay(&self) -> E::Timestamp {
+        *self.current_delay
+    }
+
+    /// Set the current delay
+    pub fn set_current_delay(&mut self, delay: E::Timestamp) {
+        Lazy::set(&mut self.current_delay, delay);
+    }
+
+    /// Get the pending delay with its effect schedule
+    pub fn pending_delay(&self) -> (E::Timestamp, E::Timestamp) {
+        *self.pending_delay
+    }
+
+    /// Set the pending delay with its effect schedule
+    pub fn set_pending_delay(&mut self, delay: E::Timestamp, schedule: E::Timestamp) {
+        Lazy::set(&mut self.pending_delay, (delay, schedule));
+    }
+}
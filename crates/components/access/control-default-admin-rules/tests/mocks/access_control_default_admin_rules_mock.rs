@@ -0,0 +1,218 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod mock {
+    pub const ROLE_ID_FLIPER: RoleId = RoleId::new([0x01; 32]);
+
+    pub use access_control_default_admin_rules::{
+        Error,
+        Result,
+        RoleId,
+        DEFAULT_ADMIN_ROLE,
+    };
+    use metis_access_control as access_control;
+    use metis_access_control_default_admin_rules as access_control_default_admin_rules;
+    use metis_lang::{
+        import,
+        metis,
+    };
+
+    #[ink(storage)]
+    #[import(access_control, access_control_default_admin_rules)]
+    pub struct AccessControl {
+        access_control: access_control::Data<AccessControl>,
+        access_control_default_admin_rules:
+            access_control_default_admin_rules::Data<AccessControl>,
+
+        value: bool,
+    }
+
+    impl access_control_default_admin_rules::Impl<AccessControl> for AccessControl {}
+
+    /// Emitted when `new_admin_role` is set as ``role``'s admin role, replacing `previous_admin_role`
+    #[ink(event)]
+    #[metis(access_control)]
+    pub struct RoleAdminChanged {
+        #[ink(topic)]
+        pub role: RoleId,
+        #[ink(topic)]
+        pub previous_admin_role: Option<RoleId>,
+        #[ink(topic)]
+        pub new_admin_role: RoleId,
+    }
+
+    /// Emitted when `account` is granted `role`.
+    ///
+    /// `sender` is the account that originated the contract call, an admin role
+    /// bearer except when using {_setup_role}.
+    #[ink(event)]
+    #[metis(access_control)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        pub role: RoleId,
+        #[ink(topic)]
+        pub account: AccountId,
+        #[ink(topic)]
+        pub sender: AccountId,
+    }
+
+    /// Emitted when `account` is revoked `role`.
+    ///
+    /// `sender` is the account that originated the contract call:
+    ///   - if using `revoke_role`, it is the admin role bearer
+    ///   - if using `renounce_role`, it is the role bearer (i.e. `account`)
+    #[ink(event)]
+    #[metis(access_control)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        pub role: RoleId,
+        #[ink(topic)]
+        pub account: AccountId,
+        #[ink(topic)]
+        pub sender: AccountId,
+    }
+
+    /// Emitted when a `DEFAULT_ADMIN_ROLE` transfer is started.
+    #[ink(event)]
+    #[metis(access_control_default_admin_rules)]
+    pub struct DefaultAdminTransferScheduled {
+        #[ink(topic)]
+        pub new_admin: Option<AccountId>,
+        pub accept_schedule: Timestamp,
+    }
+
+    /// Emitted when a `pending_default_admin` is reset if it was never accepted.
+    #[ink(event)]
+    #[metis(access_control_default_admin_rules)]
+    pub struct DefaultAdminTransferCanceled {}
+
+    /// Emitted when a `default_admin_delay` change is started.
+    #[ink(event)]
+    #[metis(access_control_default_admin_rules)]
+    pub struct DefaultAdminDelayChangeScheduled {
+        pub new_delay: Timestamp,
+        pub effect_schedule: Timestamp,
+    }
+
+    /// Emitted when a `pending_default_admin_delay` is reset if its schedule didn't pass.
+    #[ink(event)]
+    #[metis(access_control_default_admin_rules)]
+    pub struct DefaultAdminDelayChangeCanceled {}
+
+    // impl
+    impl AccessControl {
+        #[ink(constructor)]
+        pub fn new(initial_delay: Timestamp, initial_default_admin: AccountId) -> Self {
+            let mut instance = Self {
+                access_control: access_control::Data::new(),
+                access_control_default_admin_rules:
+                    access_control_default_admin_rules::Data::new(initial_delay),
+
+                value: false,
+            };
+
+            access_control_default_admin_rules::Impl::init(
+                &mut instance,
+                initial_default_admin,
+            );
+            access_control_default_admin_rules::Impl::_set_role_admin(
+                &mut instance,
+                ROLE_ID_FLIPER,
+                DEFAULT_ADMIN_ROLE,
+            );
+
+            instance
+        }
+
+        // flip the state of contract, need flipper role
+        #[ink(message)]
+        pub fn flip(&mut self) {
+            access_control_default_admin_rules::Impl::ensure_caller_role(
+                self,
+                ROLE_ID_FLIPER,
+            );
+
+            self.value = !self.value;
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> bool {
+            self.value
+        }
+
+        /// Returns `true` if `account` has been granted `role`.
+        #[ink(message)]
+        pub fn has_role(&self, role: RoleId, account: AccountId) -> bool {
+            access_control_default_admin_rules::Impl::has_role(self, role, account)
+        }
+
+        #[ink(message)]
+        pub fn get_role_admin(&self, role: RoleId) -> Option<RoleId> {
+            access_control_default_admin_rules::Impl::get_role_admin(self, role)
+        }
+
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: RoleId, account: AccountId) {
+            access_control_default_admin_rules::Impl::grant_role(self, role, account)
+        }
+
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: RoleId, account: AccountId) {
+            access_control_default_admin_rules::Impl::revoke_role(self, role, account)
+        }
+
+        #[ink(message)]
+        pub fn renounce_role(&mut self, role: RoleId, account: AccountId) {
+            access_control_default_admin_rules::Impl::renounce_role(self, role, account)
+        }
+
+        #[ink(message)]
+        pub fn default_admin(&self) -> Option<AccountId> {
+            access_control_default_admin_rules::Impl::default_admin(self)
+        }
+
+        #[ink(message)]
+        pub fn pending_default_admin(&self) -> (Option<AccountId>, Timestamp) {
+            access_control_default_admin_rules::Impl::pending_default_admin(self)
+        }
+
+        #[ink(message)]
+        pub fn default_admin_delay(&self) -> Timestamp {
+            access_control_default_admin_rules::Impl::default_admin_delay(self)
+        }
+
+        #[ink(message)]
+        pub fn pending_default_admin_delay(&self) -> (Timestamp, Timestamp) {
+            access_control_default_admin_rules::Impl::pending_default_admin_delay(self)
+        }
+
+        #[ink(message)]
+        pub fn begin_default_admin_transfer(&mut self, new_admin: Option<AccountId>) {
+            access_control_default_admin_rules::Impl::begin_default_admin_transfer(
+                self, new_admin,
+            )
+        }
+
+        #[ink(message)]
+        pub fn cancel_default_admin_transfer(&mut self) {
+            access_control_default_admin_rules::Impl::cancel_default_admin_transfer(self)
+        }
+
+        #[ink(message)]
+        pub fn accept_default_admin_transfer(&mut self) {
+            access_control_default_admin_rules::Impl::accept_default_admin_transfer(self)
+        }
+
+        #[ink(message)]
+        pub fn change_default_admin_delay(&mut self, new_delay: Timestamp) {
+            access_control_default_admin_rules::Impl::change_default_admin_delay(
+                self, new_delay,
+            )
+        }
+
+        #[ink(message)]
+        pub fn rollback_default_admin_delay(&mut self) {
+            access_control_default_admin_rules::Impl::rollback_default_admin_delay(self)
+        }
+    }
+}
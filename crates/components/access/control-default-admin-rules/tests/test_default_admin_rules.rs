@@ -0,0 +1,340 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod access_control_default_admin_rules_mock;
+}
+
+mod access_control_default_admin_rules_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use mock::{
+        AccessControl,
+        DEFAULT_ADMIN_ROLE,
+        ROLE_ID_FLIPER,
+    };
+
+    use ink_lang as ink;
+    use metis_test::*;
+    use mocks::access_control_default_admin_rules_mock::mock;
+
+    const DELAY: Timestamp = 10;
+
+    /// The default constructor does its job.
+    #[ink::test]
+    fn new_works() {
+        let accounts = default_accounts();
+
+        let ac = AccessControl::new(DELAY, accounts.alice);
+
+        assert_eq!(
+            ac.default_admin(),
+            Some(accounts.alice),
+            "default admin should be init"
+        );
+        assert_eq!(
+            ac.has_role(DEFAULT_ADMIN_ROLE, accounts.alice),
+            true,
+            "default admin should have default admin role"
+        );
+        assert_eq!(
+            ac.get_role_admin(DEFAULT_ADMIN_ROLE),
+            Some(DEFAULT_ADMIN_ROLE),
+            "default admin role should be admin of itself"
+        );
+        assert_eq!(ac.default_admin_delay(), DELAY, "delay should be init");
+        assert_eq!(
+            ac.pending_default_admin(),
+            (None, 0),
+            "no pending default admin by default"
+        );
+        assert_eq!(
+            ac.pending_default_admin_delay(),
+            (0, 0),
+            "no pending delay by default"
+        );
+    }
+
+    #[ink::test]
+    fn default_admin_can_grant_other_roles() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        ac.grant_role(ROLE_ID_FLIPER, accounts.bob);
+        assert_eq!(
+            ac.has_role(ROLE_ID_FLIPER, accounts.bob),
+            true,
+            "bob should be granted by default admin"
+        );
+
+        next_call_by(&accounts.bob);
+        ac.flip();
+        assert_eq!(ac.get(), true, "bob should can flip");
+    }
+
+    #[ink::test]
+    #[should_panic(
+        expected = "AccessControlDefaultAdminRules: can't directly grant default admin role"
+    )]
+    fn grant_default_admin_role_should_panic() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        ac.grant_role(DEFAULT_ADMIN_ROLE, accounts.bob);
+    }
+
+    #[ink::test]
+    #[should_panic(
+        expected = "AccessControlDefaultAdminRules: can't directly revoke default admin role"
+    )]
+    fn revoke_default_admin_role_should_panic() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        ac.revoke_role(DEFAULT_ADMIN_ROLE, accounts.alice);
+    }
+
+    #[ink::test]
+    fn transfer_default_admin_should_ok() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        let schedule = block_timestamp() + DELAY;
+        ac.begin_default_admin_transfer(Some(accounts.bob));
+        assert_eq!(
+            ac.pending_default_admin(),
+            (Some(accounts.bob), schedule),
+            "pending default admin should be scheduled"
+        );
+
+        advance_after(schedule);
+
+        next_call_by(&accounts.bob);
+        ac.accept_default_admin_transfer();
+
+        assert_eq!(
+            ac.default_admin(),
+            Some(accounts.bob),
+            "default admin should be transferred"
+        );
+        assert_eq!(
+            ac.has_role(DEFAULT_ADMIN_ROLE, accounts.bob),
+            true,
+            "new default admin should have role"
+        );
+        assert_eq!(
+            ac.has_role(DEFAULT_ADMIN_ROLE, accounts.alice),
+            false,
+            "old default admin should lose role"
+        );
+        assert_eq!(
+            ac.pending_default_admin(),
+            (None, 0),
+            "pending default admin should be reset"
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(
+        expected = "AccessControlDefaultAdminRules: transfer delay not passed"
+    )]
+    fn accept_before_delay_should_panic() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        ac.begin_default_admin_transfer(Some(accounts.bob));
+
+        next_call_by(&accounts.bob);
+        ac.accept_default_admin_transfer();
+    }
+
+    #[ink::test]
+    #[should_panic(
+        expected = "AccessControlDefaultAdminRules: transfer delay not passed"
+    )]
+    fn accept_with_max_delay_should_panic() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(Timestamp::MAX, accounts.alice);
+
+        advance_after(block_timestamp());
+        ac.begin_default_admin_transfer(Some(accounts.bob));
+
+        // the schedule saturates instead of wrapping to the past
+        assert_eq!(
+            ac.pending_default_admin(),
+            (Some(accounts.bob), Timestamp::MAX)
+        );
+
+        advance_after(block_timestamp());
+
+        next_call_by(&accounts.bob);
+        ac.accept_default_admin_transfer();
+    }
+
+    #[ink::test]
+    #[should_panic(
+        expected = "AccessControlDefaultAdminRules: pending admin must accept"
+    )]
+    fn accept_by_others_should_panic() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        let schedule = block_timestamp() + DELAY;
+        ac.begin_default_admin_transfer(Some(accounts.bob));
+
+        advance_after(schedule);
+
+        next_call_by(&accounts.charlie);
+        ac.accept_default_admin_transfer();
+    }
+
+    #[ink::test]
+    #[should_panic(
+        expected = "AccessControlDefaultAdminRules: pending admin must accept"
+    )]
+    fn cancel_default_admin_transfer_should_panic() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        let schedule = block_timestamp() + DELAY;
+        ac.begin_default_admin_transfer(Some(accounts.bob));
+        ac.cancel_default_admin_transfer();
+
+        assert_eq!(
+            ac.pending_default_admin(),
+            (None, 0),
+            "pending default admin should be canceled"
+        );
+
+        advance_after(schedule);
+
+        next_call_by(&accounts.bob);
+        ac.accept_default_admin_transfer();
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "role missing")]
+    fn begin_transfer_by_others_should_panic() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        next_call_by(&accounts.bob);
+        ac.begin_default_admin_transfer(Some(accounts.bob));
+    }
+
+    #[ink::test]
+    fn change_default_admin_delay_should_ok() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        // increase the delay will wait the new delay
+        let new_delay = DELAY * 2;
+        let schedule = block_timestamp() + new_delay;
+        ac.change_default_admin_delay(new_delay);
+
+        assert_eq!(
+            ac.pending_default_admin_delay(),
+            (new_delay, schedule),
+            "pending delay should be scheduled"
+        );
+        assert_eq!(
+            ac.default_admin_delay(),
+            DELAY,
+            "delay should not change before schedule"
+        );
+
+        advance_after(schedule);
+
+        assert_eq!(
+            ac.default_admin_delay(),
+            new_delay,
+            "delay should change after schedule"
+        );
+        assert_eq!(
+            ac.pending_default_admin_delay(),
+            (0, 0),
+            "pending delay should be effected"
+        );
+
+        // decrease the delay will wait the difference
+        let schedule = block_timestamp() + (new_delay - DELAY);
+        ac.change_default_admin_delay(DELAY);
+
+        assert_eq!(
+            ac.pending_default_admin_delay(),
+            (DELAY, schedule),
+            "pending delay should be scheduled by difference"
+        );
+    }
+
+    #[ink::test]
+    fn rollback_default_admin_delay_should_ok() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        let schedule = block_timestamp() + DELAY * 2;
+        ac.change_default_admin_delay(DELAY * 2);
+        ac.rollback_default_admin_delay();
+
+        assert_eq!(
+            ac.pending_default_admin_delay(),
+            (0, 0),
+            "pending delay should be rollback"
+        );
+
+        advance_after(schedule);
+
+        assert_eq!(
+            ac.default_admin_delay(),
+            DELAY,
+            "delay should not be changed"
+        );
+    }
+
+    #[ink::test]
+    fn renounce_default_admin_should_ok() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        let schedule = block_timestamp() + DELAY;
+        ac.begin_default_admin_transfer(None);
+
+        advance_after(schedule);
+
+        ac.renounce_role(DEFAULT_ADMIN_ROLE, accounts.alice);
+
+        assert_eq!(
+            ac.default_admin(),
+            None,
+            "default admin should be renounced"
+        );
+        assert_eq!(
+            ac.has_role(DEFAULT_ADMIN_ROLE, accounts.alice),
+            false,
+            "default admin role should be renounced"
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(
+        expected = "AccessControlDefaultAdminRules: only can renounce in two delayed steps"
+    )]
+    fn renounce_default_admin_directly_should_panic() {
+        let accounts = default_accounts();
+
+        let mut ac = AccessControl::new(DELAY, accounts.alice);
+
+        ac.renounce_role(DEFAULT_ADMIN_ROLE, accounts.alice);
+    }
+}
@@ -8,24 +8,19 @@ pub use access_control::{
 use metis_access_control as access_control;
 
 use metis_lang::{
+    storage::EnumerableSets,
     Env,
     Storage,
 };
 
 #[cfg(not(feature = "ink-as-dependency"))]
-use ::ink_storage::{
-    collections::{
-        hashmap::Entry,
-        HashMap as StorageHashMap,
-    },
-    traits::SpreadLayout,
-};
+use ::ink_storage::traits::SpreadLayout;
 
 /// The Data of ERC20 component
 #[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
 #[derive(Debug, SpreadLayout)]
 pub struct Data<E: Env> {
-    role_members: StorageHashMap<RoleId, Vec<E::AccountId>>,
+    role_members: EnumerableSets<RoleId, E::AccountId>,
 }
 
 impl<E: Env> Data<E> {
@@ -42,33 +37,18 @@ where
 {
     fn default() -> Self {
         Self {
-            role_members: StorageHashMap::default(),
+            role_members: EnumerableSets::default(),
         }
     }
 }
 
 impl<E: Env> Data<E> {
     fn add_member(&mut self, role: &RoleId, member: &E::AccountId) {
-        self.role_members
-            .entry(role.clone())
-            .or_insert(Vec::default())
-            .push(member.clone());
+        self.role_members.add(role, member.clone());
     }
 
     fn remove_member(&mut self, role: &RoleId, member: &E::AccountId) {
-        let mut occupied = match self.role_members.entry(role.clone()) {
-            Entry::Vacant(_) => panic!("no found role by id"),
-            Entry::Occupied(occupied) => occupied,
-        };
-
-        let members = occupied.get_mut();
-
-        for i in (0..members.len()).rev() {
-            if members[i] == *member {
-                members.swap_remove(i);
-                return // the vec will have just one member
-            }
-        }
+        self.role_members.remove(role, member);
     }
 }
 
@@ -88,19 +68,17 @@ where
     /// https://forum.openzeppelin.com/t/iterating-over-elements-on-enumerableset-in-openzeppelin-contracts/2296[forum post]
     /// for more information.
     fn get_role_member(&self, role: &RoleId, index: usize) -> E::AccountId {
-        match Storage::<E, Data<E>>::get(self).role_members.get(role) {
-            None => panic!("no found role by id"),
-            Some(members) => members[index].clone(), // will panic when out of index
-        }
+        Storage::<E, Data<E>>::get(self)
+            .role_members
+            .at(role, index as u32)
+            .expect("AccessControlEnumerable: role member index out of bounds")
+            .clone()
     }
 
     /// Returns the number of accounts that have `role`. Can be used
     /// together with {getRoleMember} to enumerate all bearers of a role.
     fn get_role_member_count(&self, role: &RoleId) -> usize {
-        match Storage::<E, Data<E>>::get(self).role_members.get(role) {
-            None => panic!("no found role by id"),
-            Some(members) => members.len(),
-        }
+        Storage::<E, Data<E>>::get(self).role_members.len(role) as usize
     }
 
     /// Returns `true` if `account` has been granted `role`.
@@ -157,8 +135,6 @@ where
     ///
     /// - the caller must have ``role``'s admin role.
     fn grant_role(&mut self, role: RoleId, account: E::AccountId) {
-        // if has a role, the add will revert by panic
-        // grant_role will call the _setup_role but not add
         Storage::<E, Data<E>>::get_mut(self).add_member(&role, &account);
 
         access_control::Impl::grant_role(self, role, account)
@@ -210,7 +186,6 @@ where
     /// system imposed by {AccessControl}.
     /// ====
     fn _setup_role(&mut self, role: RoleId, account: E::AccountId) {
-        // if has a role, the add will revert by panic
         Storage::<E, Data<E>>::get_mut(self).add_member(&role, &account);
 
         access_control::Impl::_setup_role(self, role, account)
@@ -37,6 +37,7 @@ mod module;
 mod types;
 
 use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     Storage,
@@ -48,6 +49,7 @@ pub use types::{
     Error,
     Result,
     RoleId,
+    DEFAULT_ADMIN_ROLE,
 };
 
 /// The `EventEmit` impl the event emit api for component.
@@ -86,7 +88,7 @@ pub trait EventEmit<E: Env>: EnvAccess<E> {
 }
 
 /// The `Impl` define component impl funcs
-pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Context<E> {
     /// Returns `true` if `account` has been granted `role`.
     fn has_role(&self, role: RoleId, account: E::AccountId) -> bool {
         self.get().has_role(role, account)
@@ -107,7 +109,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
 
     /// Panic if caller is not granted role
     fn ensure_caller_role(&self, role: RoleId) {
-        self.ensure_role(role, Self::caller());
+        self.ensure_role(role, self._msg_sender());
     }
 
     /// Panic error if `account` is missing the admin role of the `role`.
@@ -145,7 +147,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     /// - the caller must have ``role``'s admin role.
     fn grant_role(&mut self, role: RoleId, account: E::AccountId) {
         // check the admin role
-        self.ensure_admin_role(role, Self::caller());
+        self.ensure_admin_role(role, self._msg_sender());
 
         self._setup_role(role, account);
     }
@@ -158,7 +160,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// - the caller must have ``role``'s admin role.
     fn revoke_role(&mut self, role: RoleId, account: E::AccountId) {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
 
         // check the admin role
         self.ensure_admin_role(role, caller.clone());
@@ -185,7 +187,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// - the caller must be `account`.
     fn renounce_role(&mut self, role: RoleId, account: E::AccountId) {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
 
         // check the caller is account
         assert!(caller == account, "account not caller");
@@ -214,7 +216,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     /// system imposed by {AccessControl}.
     /// ====
     fn _setup_role(&mut self, role: RoleId, account: E::AccountId) {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
 
         // if has role, so return error
         self.get_mut()
@@ -242,4 +244,4 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     }
 }
 
-impl<E: Env, T: Storage<E, Data<E>> + EventEmit<E>> Impl<E> for T {}
+impl<E: Env, T: Storage<E, Data<E>> + EventEmit<E> + Context<E>> Impl<E> for T {}
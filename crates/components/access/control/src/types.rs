@@ -54,3 +54,6 @@ impl RoleId {
         Self(data)
     }
 }
+
+/// The `DEFAULT_ADMIN_ROLE`, by all zero bytes like `bytes32(0)`.
+pub const DEFAULT_ADMIN_ROLE: RoleId = RoleId::new([0x00; 32]);
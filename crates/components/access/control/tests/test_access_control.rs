@@ -4,8 +4,6 @@ mod mocks {
     pub mod access_control_mock;
 }
 
-mod utils;
-
 pub use access_control_tests::{
     assert_role_admin_changed_event,
     assert_role_granted_event,
@@ -26,8 +24,12 @@ mod access_control_tests {
     use ink::ContractEnv;
 
     use ink_lang as ink;
+    use metis_test::{
+        assert_emitted_event_len,
+        encoded_into_hash,
+        PrefixedValue,
+    };
     use mocks::access_control_mock::access_control_mock;
-    use utils::*;
 
     type AccountId =
         <<AccessControl as ContractEnv>::Env as ink_env::Environment>::AccountId;
@@ -14,6 +14,7 @@
 mod module;
 
 use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     Storage,
@@ -32,10 +33,11 @@ pub trait EventEmit<E: Env>: EnvAccess<E> {
 }
 
 /// The `Impl` define ownable component impl funcs
-pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Context<E> {
     /// init Initializes the contract setting the deployer as the initial owner.
     fn init(&mut self) {
-        self.get_mut().set_ownership(&Some(Self::caller()));
+        let caller = self._msg_sender();
+        self.get_mut().set_ownership(&Some(caller));
     }
 
     /// Leaves the contract without owner. It will not be possible to call
@@ -77,7 +79,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
 
     /// Panic if caller is not an owner
     fn ensure_caller_is_owner(&self) {
-        self.ensure_owner(&Self::caller());
+        self.ensure_owner(&self._msg_sender());
     }
 
     /// Panic the contract owner is not renounced,
@@ -86,4 +88,4 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     }
 }
 
-impl<E: Env, T: Storage<E, Data<E>> + EventEmit<E>> Impl<E> for T {}
+impl<E: Env, T: Storage<E, Data<E>> + EventEmit<E> + Context<E>> Impl<E> for T {}
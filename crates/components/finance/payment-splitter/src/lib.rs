@@ -0,0 +1,269 @@
+//! Payment Splitter
+//! This component allows to split native balance payments among a group of
+//! accounts. The sender does not need to be aware that the balance will be
+//! split in this way, since it is handled transparently by the contract.
+//!
+//! The split can be in equal parts or in any other arbitrary proportion. The
+//! way this is specified is by assigning each account to a number of shares.
+//! Of all the balance that this contract receives, each account will then be
+//! able to claim an amount proportional to the percentage of total shares they
+//! were assigned.
+//!
+//! `PaymentSplitter` follows a _pull payment_ model. This means that payments
+//! are not automatically forwarded to the accounts but kept in this contract,
+//! and the actual transfer is triggered as a separate step by calling the
+//! `release` function.
+//!
+//! NOTE: This component also supports ERC20 tokens by their account id, the
+//! token balances are got and transferred by cross-contract calls.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod module;
+
+use core::convert::TryFrom;
+use ink_lang::{
+    ForwardCall,
+    ForwardCallMut,
+};
+use ink_prelude::vec::Vec;
+use metis_lang::arithmetic::Saturating;
+pub use metis_lang::{
+    Context,
+    Env,
+    EnvAccess,
+    FromAccountId,
+    Storage,
+};
+use num_traits::CheckedMul;
+
+pub use module::Data;
+
+use metis_erc20_stub::Erc20Stub;
+
+/// The `EventEmit` impl the event emit api for payment splitter component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emitted when a new payee `account` added with its `shares`.
+    fn emit_event_payee_added(&mut self, account: E::AccountId, shares: E::Balance);
+
+    /// Emitted when `amount` native balance released to `to`.
+    fn emit_event_payment_released(&mut self, to: E::AccountId, amount: E::Balance);
+
+    /// Emitted when `amount` of erc20 `token` released to `to`.
+    fn emit_event_token_payment_released(
+        &mut self,
+        token: E::AccountId,
+        to: E::AccountId,
+        amount: E::Balance,
+    );
+
+    /// Emitted when the contract received `amount` native balance from `from`.
+    fn emit_event_payment_received(&mut self, from: E::AccountId, amount: E::Balance);
+}
+
+/// The `Impl` define payment splitter component impl funcs
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Context<E> {
+    /// @dev Creates an instance of `PaymentSplitter` where each account in
+    /// `payees` is assigned the number of shares at the matching position in
+    /// the `shares` array.
+    ///
+    /// All accounts in `payees` must be non-zero. Both arrays must have the
+    /// same non-zero length, and there must be no duplicates in `payees`.
+    fn init(&mut self, payees: Vec<E::AccountId>, shares: Vec<E::Balance>) {
+        assert!(
+            payees.len() == shares.len(),
+            "PaymentSplitter: payees and shares length mismatch"
+        );
+        assert!(payees.len() > 0, "PaymentSplitter: no payees");
+
+        for (payee, share) in payees.into_iter().zip(shares.into_iter()) {
+            self._add_payee(payee, share);
+        }
+    }
+
+    /// @dev The received native balance will be logged with `PaymentReceived`
+    /// events. The contract should call this in a payable message.
+    fn receive(&mut self) {
+        self.emit_event_payment_received(self._msg_sender(), Self::transferred_balance());
+    }
+
+    /// @dev Getter for the total shares held by payees.
+    fn total_shares(&self) -> E::Balance {
+        Storage::<E, Data<E>>::get(self).total_shares()
+    }
+
+    /// @dev Getter for the total amount of native balance already released.
+    fn total_released(&self) -> E::Balance {
+        Storage::<E, Data<E>>::get(self).total_released()
+    }
+
+    /// @dev Getter for the total amount of `token` already released.
+    fn erc20_total_released(&self, token: &E::AccountId) -> E::Balance {
+        Storage::<E, Data<E>>::get(self).erc20_total_released(token)
+    }
+
+    /// @dev Getter for the amount of shares held by an account.
+    fn shares(&self, account: &E::AccountId) -> E::Balance {
+        Storage::<E, Data<E>>::get(self).shares(account)
+    }
+
+    /// @dev Getter for the amount of native balance already released to a payee.
+    fn released(&self, account: &E::AccountId) -> E::Balance {
+        Storage::<E, Data<E>>::get(self).released(account)
+    }
+
+    /// @dev Getter for the amount of `token` already released to a payee.
+    fn erc20_released(&self, token: &E::AccountId, account: &E::AccountId) -> E::Balance {
+        Storage::<E, Data<E>>::get(self).erc20_released(token, account)
+    }
+
+    /// @dev Getter for the address of the payee number `index`.
+    fn payee(&self, index: u32) -> Option<E::AccountId> {
+        Storage::<E, Data<E>>::get(self).payees.get(index).cloned()
+    }
+
+    /// @dev Getter for the amount of payee's releasable native balance, the
+    /// minimum balance of the contract is not counted as received.
+    fn releasable(&self, account: &E::AccountId) -> E::Balance {
+        let total_received = Self::balance().saturating_sub(Self::minimum_balance())
+            + self.total_released();
+
+        self._pending_payment(account, total_received, self.released(account))
+    }
+
+    /// @dev Getter for the amount of payee's releasable `token` tokens.
+    fn erc20_releasable(
+        &self,
+        token: &E::AccountId,
+        account: &E::AccountId,
+    ) -> E::Balance {
+        let total_received = _erc20_balance_of::<E>(token, &Self::account_id())
+            + self.erc20_total_released(token);
+
+        self._pending_payment(
+            account,
+            total_received,
+            self.erc20_released(token, account),
+        )
+    }
+
+    /// @dev Triggers a transfer to `account` of the amount of native balance
+    /// they are owed, according to their percentage of the total shares and
+    /// their previous withdrawals.
+    fn release(&mut self, account: E::AccountId) {
+        assert!(
+            self.shares(&account) > E::Balance::from(0_u8),
+            "PaymentSplitter: account has no shares"
+        );
+
+        let payment = self.releasable(&account);
+        assert!(
+            payment != E::Balance::from(0_u8),
+            "PaymentSplitter: account is not due payment"
+        );
+
+        Storage::<E, Data<E>>::get_mut(self).add_released(&account, payment);
+
+        let res = Self::transfer(account.clone(), payment);
+        assert!(res.is_ok(), "PaymentSplitter: transfer to payee error");
+
+        self.emit_event_payment_released(account, payment);
+    }
+
+    /// @dev Triggers a transfer to `account` of the amount of `token` tokens
+    /// they are owed, according to their percentage of the total shares and
+    /// their previous withdrawals. `token` must be the account id of an ERC20
+    /// contract.
+    fn erc20_release(&mut self, token: E::AccountId, account: E::AccountId) {
+        assert!(
+            self.shares(&account) > E::Balance::from(0_u8),
+            "PaymentSplitter: account has no shares"
+        );
+
+        let payment = self.erc20_releasable(&token, &account);
+        assert!(
+            payment != E::Balance::from(0_u8),
+            "PaymentSplitter: account is not due payment"
+        );
+
+        Storage::<E, Data<E>>::get_mut(self)
+            .add_erc20_released(&token, &account, payment);
+
+        let mut erc20 = <Erc20Stub as FromAccountId<E>>::from_account_id(token.clone());
+        let res = erc20
+            .call_mut()
+            .transfer(account.clone().into(), payment.into())
+            .fire();
+        assert!(
+            matches!(res, Ok(Ok(()))),
+            "PaymentSplitter: erc20 transfer to payee error"
+        );
+
+        self.emit_event_token_payment_released(token, account, payment);
+    }
+
+    /// @dev internal logic for computing the pending payment of an `account`
+    /// given the token historical balances and already released amounts.
+    fn _pending_payment(
+        &self,
+        account: &E::AccountId,
+        total_received: E::Balance,
+        already_released: E::Balance,
+    ) -> E::Balance {
+        let total_shares = self.total_shares();
+        if total_shares == E::Balance::from(0_u8) {
+            return E::Balance::from(0_u8)
+        }
+
+        // `total_received * shares / total_shares` without overflow of the
+        // multiplication, as the shares is not more than the total shares.
+        let shares = self.shares(account);
+        let remainder = (total_received % total_shares)
+            .checked_mul(&shares)
+            .expect("PaymentSplitter: payment overflow");
+        let due = total_received / total_shares * shares + remainder / total_shares;
+
+        if due > already_released {
+            due - already_released
+        } else {
+            E::Balance::from(0_u8)
+        }
+    }
+
+    /// @dev Add a new payee to the contract.
+    /// @param account The address of the payee to add.
+    /// @param shares The number of shares owned by the payee.
+    fn _add_payee(&mut self, account: E::AccountId, shares: E::Balance) {
+        assert!(
+            account != E::AccountId::default(),
+            "PaymentSplitter: account is the zero address"
+        );
+        assert!(
+            shares > E::Balance::from(0_u8),
+            "PaymentSplitter: shares are 0"
+        );
+        assert!(
+            self.shares(&account) == E::Balance::from(0_u8),
+            "PaymentSplitter: account already has shares"
+        );
+
+        Storage::<E, Data<E>>::get_mut(self).add_payee(&account, shares);
+
+        self.emit_event_payee_added(account, shares);
+    }
+}
+
+impl<E: Env, T: Storage<E, Data<E>> + EventEmit<E> + Context<E>> Impl<E> for T {}
+
+/// Get the erc20 `token` balance of `account` by cross-contract call.
+fn _erc20_balance_of<E: Env>(token: &E::AccountId, account: &E::AccountId) -> E::Balance {
+    let erc20 = <Erc20Stub as FromAccountId<E>>::from_account_id(token.clone());
+    let balance = erc20
+        .call()
+        .balance_of(account.clone().into())
+        .fire()
+        .expect("PaymentSplitter: get erc20 balance error");
+
+    E::Balance::try_from(balance)
+        .unwrap_or_else(|_| panic!("PaymentSplitter: erc20 balance overflow"))
+}
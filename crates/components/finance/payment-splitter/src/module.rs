@@ -0,0 +1,145 @@
+pub use metis_lang::Env;
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    collections::{
+        HashMap as StorageHashMap,
+        Vec as StorageVec,
+    },
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The Data of payment splitter component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E>
+where
+    E: Env,
+{
+    /// The sum of the shares of all payees
+    pub total_shares: Lazy<E::Balance>,
+    /// The total amount of native balance already released
+    pub total_released: Lazy<E::Balance>,
+    /// Mapping from payee to the number of shares held
+    pub shares: StorageHashMap<E::AccountId, E::Balance>,
+    /// Mapping from payee to the amount of native balance already released
+    pub released: StorageHashMap<E::AccountId, E::Balance>,
+    /// All the payees
+    pub payees: StorageVec<E::AccountId>,
+    /// Mapping from erc20 token to the total amount already released
+    pub erc20_total_released: StorageHashMap<E::AccountId, E::Balance>,
+    /// Mapping from (erc20 token, payee) to the amount already released
+    pub erc20_released: StorageHashMap<(E::AccountId, E::AccountId), E::Balance>,
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            total_shares: Lazy::new(E::Balance::from(0_u8)),
+            total_released: Lazy::new(E::Balance::from(0_u8)),
+            shares: StorageHashMap::new(),
+            released: StorageHashMap::new(),
+            payees: StorageVec::new(),
+            erc20_total_released: StorageHashMap::new(),
+            erc20_released: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    /// Get the sum of the shares of all payees
+    pub fn total_shares(&self) -> E::Balance {
+        *self.total_shares
+    }
+
+    /// Get the total amount of native balance already released
+    pub fn total_released(&self) -> E::Balance {
+        *self.total_released
+    }
+
+    /// Get the shares of the `account`
+    pub fn shares(&self, account: &E::AccountId) -> E::Balance {
+        self.shares
+            .get(account)
+            .copied()
+            .unwrap_or(E::Balance::from(0_u8))
+    }
+
+    /// Get the amount of native balance already released to the `account`
+    pub fn released(&self, account: &E::AccountId) -> E::Balance {
+        self.released
+            .get(account)
+            .copied()
+            .unwrap_or(E::Balance::from(0_u8))
+    }
+
+    /// Get the total amount of `token` already released
+    pub fn erc20_total_released(&self, token: &E::AccountId) -> E::Balance {
+        self.erc20_total_released
+            .get(token)
+            .copied()
+            .unwrap_or(E::Balance::from(0_u8))
+    }
+
+    /// Get the amount of `token` already released to the `account`
+    pub fn erc20_released(
+        &self,
+        token: &E::AccountId,
+        account: &E::AccountId,
+    ) -> E::Balance {
+        self.erc20_released
+            .get(&(token.clone(), account.clone()))
+            .copied()
+            .unwrap_or(E::Balance::from(0_u8))
+    }
+
+    /// Add a new payee with its shares
+    pub fn add_payee(&mut self, account: &E::AccountId, shares: E::Balance) {
+        self.payees.push(account.clone());
+        self.shares.insert(account.clone(), shares);
+
+        let total_shares = self.total_shares() + shares;
+        Lazy::set(&mut self.total_shares, total_shares);
+    }
+
+    /// Add the `amount` of native balance released to the `account`
+    pub fn add_released(&mut self, account: &E::AccountId, amount: E::Balance) {
+        let released = self.released(account) + amount;
+        self.released.insert(account.clone(), released);
+
+        let total_released = self.total_released() + amount;
+        Lazy::set(&mut self.total_released, total_released);
+    }
+
+    /// Add the `amount` of `token` released to the `account`
+    pub fn add_erc20_released(
+        &mut self,
+        token: &E::AccountId,
+        account: &E::AccountId,
+        amount: E::Balance,
+    ) {
+        let released = self.erc20_released(token, account) + amount;
+        self.erc20_released
+            .insert((token.clone(), account.clone()), released);
+
+        let total_released = self.erc20_total_released(token) + amount;
+        self.erc20_total_released
+            .insert(token.clone(), total_released);
+    }
+}
@@ -0,0 +1,518 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod mock {
+    use ink_prelude::vec::Vec;
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_payment_splitter as payment_splitter;
+
+    #[ink(storage)]
+    #[import(payment_splitter)]
+    pub struct PaymentSplitter {
+        payment_splitter: payment_splitter::Data<PaymentSplitter>,
+    }
+
+    /// Emitted when a new payee added
+    #[ink(event)]
+    #[metis(payment_splitter)]
+    pub struct PayeeAdded {
+        #[ink(topic)]
+        pub account: AccountId,
+        pub shares: Balance,
+    }
+
+    /// Emitted when native balance released to payee
+    #[ink(event)]
+    #[metis(payment_splitter)]
+    pub struct PaymentReleased {
+        #[ink(topic)]
+        pub to: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Emitted when erc20 token released to payee
+    #[ink(event)]
+    #[metis(payment_splitter)]
+    pub struct TokenPaymentReleased {
+        #[ink(topic)]
+        pub token: AccountId,
+        #[ink(topic)]
+        pub to: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Emitted when the contract received native balance
+    #[ink(event)]
+    #[metis(payment_splitter)]
+    pub struct PaymentReceived {
+        #[ink(topic)]
+        pub from: AccountId,
+        pub amount: Balance,
+    }
+
+    // impl
+    impl PaymentSplitter {
+        #[ink(constructor)]
+        pub fn new(payees: Vec<AccountId>, shares: Vec<Balance>) -> Self {
+            let mut instance = Self {
+                payment_splitter: payment_splitter::Data::new(),
+            };
+
+            payment_splitter::Impl::init(&mut instance, payees, shares);
+
+            instance
+        }
+
+        #[ink(message, payable)]
+        pub fn receive(&mut self) {
+            payment_splitter::Impl::receive(self)
+        }
+
+        #[ink(message)]
+        pub fn total_shares(&self) -> Balance {
+            payment_splitter::Impl::total_shares(self)
+        }
+
+        #[ink(message)]
+        pub fn total_released(&self) -> Balance {
+            payment_splitter::Impl::total_released(self)
+        }
+
+        #[ink(message)]
+        pub fn shares(&self, account: AccountId) -> Balance {
+            payment_splitter::Impl::shares(self, &account)
+        }
+
+        #[ink(message)]
+        pub fn released(&self, account: AccountId) -> Balance {
+            payment_splitter::Impl::released(self, &account)
+        }
+
+        #[ink(message)]
+        pub fn payee(&self, index: u32) -> Option<AccountId> {
+            payment_splitter::Impl::payee(self, index)
+        }
+
+        #[ink(message)]
+        pub fn releasable(&self, account: AccountId) -> Balance {
+            payment_splitter::Impl::releasable(self, &account)
+        }
+
+        #[ink(message)]
+        pub fn release(&mut self, account: AccountId) {
+            payment_splitter::Impl::release(self, account)
+        }
+
+        #[ink(message)]
+        pub fn erc20_total_released(&self, token: AccountId) -> Balance {
+            payment_splitter::Impl::erc20_total_released(self, &token)
+        }
+
+        #[ink(message)]
+        pub fn erc20_released(&self, token: AccountId, account: AccountId) -> Balance {
+            payment_splitter::Impl::erc20_released(self, &token, &account)
+        }
+
+        #[ink(message)]
+        pub fn erc20_releasable(&self, token: AccountId, account: AccountId) -> Balance {
+            payment_splitter::Impl::erc20_releasable(self, &token, &account)
+        }
+
+        #[ink(message)]
+        pub fn erc20_release(&mut self, token: AccountId, account: AccountId) {
+            payment_splitter::Impl::erc20_release(self, token, account)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+        use ink_prelude::{
+            string::String,
+            vec,
+        };
+        use metis_erc20_stub::{
+            Erc20 as Token,
+            Erc20Stub,
+            Error,
+            Result,
+        };
+        use metis_test::{
+            contract_id,
+            decode_event,
+            default_accounts,
+            get_balance,
+            get_last_emitted_event,
+            set_balance,
+            set_caller,
+            set_caller_with_value,
+        };
+        use std::{
+            cell::RefCell,
+            collections::HashMap,
+            rc::Rc,
+        };
+
+        /// A erc20 contract which only keeps the balances.
+        #[derive(Default)]
+        struct MockToken {
+            balances: HashMap<AccountId, Balance>,
+        }
+
+        impl Token for MockToken {
+            fn name(&self) -> String {
+                String::from("MockToken")
+            }
+
+            fn symbol(&self) -> String {
+                String::from("MT")
+            }
+
+            fn decimals(&self) -> u8 {
+                18
+            }
+
+            fn total_supply(&self) -> Balance {
+                self.balances.values().sum()
+            }
+
+            fn balance_of(&self, account: AccountId) -> Balance {
+                self.balances.get(&account).cloned().unwrap_or_default()
+            }
+
+            fn allowance(&self, _owner: AccountId, _spender: AccountId) -> Balance {
+                0
+            }
+
+            fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+                let from = ink_env::caller::<ink_env::DefaultEnvironment>()
+                    .expect("Cannot get caller");
+                let from_balance = self.balance_of(from);
+                if from_balance < value {
+                    return Err(Error::InsufficientBalance)
+                }
+
+                self.balances.insert(from, from_balance - value);
+                *self.balances.entry(to).or_default() += value;
+
+                Ok(())
+            }
+
+            fn approve(&mut self, _spender: AccountId, _amount: Balance) -> Result<()> {
+                Ok(())
+            }
+
+            fn transfer_from(
+                &mut self,
+                _from: AccountId,
+                _to: AccountId,
+                _amount: Balance,
+            ) -> Result<()> {
+                Err(Error::InsufficientAllowance)
+            }
+        }
+
+        /// Mock the erc20 token at eve, the splitter holds `amount` tokens.
+        fn create_token(amount: Balance) -> (AccountId, Rc<RefCell<MockToken>>) {
+            let accounts = default_accounts();
+
+            let token = Rc::new(RefCell::new(MockToken::default()));
+            Erc20Stub::mock(accounts.eve, token.clone());
+            token.borrow_mut().balances.insert(contract_id(), amount);
+
+            (accounts.eve, token)
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+
+            let splitter = create_contract(0);
+
+            assert_eq!(splitter.total_shares(), 100, "total shares should be init");
+            assert_eq!(
+                splitter.shares(accounts.bob),
+                20,
+                "bob shares should be init"
+            );
+            assert_eq!(
+                splitter.shares(accounts.charlie),
+                80,
+                "charlie shares should be init"
+            );
+            assert_eq!(splitter.payee(0), Some(accounts.bob), "payee 0 is bob");
+            assert_eq!(
+                splitter.payee(1),
+                Some(accounts.charlie),
+                "payee 1 is charlie"
+            );
+            assert_eq!(splitter.payee(2), None, "no payee 2");
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "PaymentSplitter: payees and shares length mismatch")]
+        fn new_with_mismatch_should_panic() {
+            let accounts = default_accounts();
+
+            PaymentSplitter::new(vec![accounts.bob, accounts.charlie], vec![20]);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "PaymentSplitter: account already has shares")]
+        fn new_with_duplicate_payee_should_panic() {
+            let accounts = default_accounts();
+
+            PaymentSplitter::new(vec![accounts.bob, accounts.bob], vec![20, 80]);
+        }
+
+        #[ink::test]
+        fn release_ok() {
+            let accounts = default_accounts();
+
+            let mut splitter = create_contract(1000);
+
+            assert_eq!(
+                splitter.releasable(accounts.bob),
+                200,
+                "bob releasable should by shares"
+            );
+            assert_eq!(
+                splitter.releasable(accounts.charlie),
+                800,
+                "charlie releasable should by shares"
+            );
+
+            set_balance(accounts.bob, 0);
+            splitter.release(accounts.bob);
+
+            assert_eq!(get_balance(accounts.bob), 200, "bob should be released");
+            assert_eq!(splitter.released(accounts.bob), 200, "bob released");
+            assert_eq!(splitter.total_released(), 200, "total released");
+            assert_eq!(
+                splitter.releasable(accounts.bob),
+                0,
+                "bob nothing releasable"
+            );
+            assert_eq!(
+                splitter.releasable(accounts.charlie),
+                800,
+                "charlie releasable should not changed"
+            );
+
+            // more payment received
+            set_balance(contract_id(), get_balance(contract_id()) + 1000);
+
+            assert_eq!(
+                splitter.releasable(accounts.bob),
+                200,
+                "bob releasable should by new payment"
+            );
+            assert_eq!(
+                splitter.releasable(accounts.charlie),
+                1600,
+                "charlie releasable should by all payment"
+            );
+
+            set_balance(accounts.charlie, 0);
+            splitter.release(accounts.charlie);
+
+            assert_eq!(
+                get_balance(accounts.charlie),
+                1600,
+                "charlie should be released"
+            );
+            assert_eq!(splitter.total_released(), 1800, "total released");
+        }
+
+        #[ink::test]
+        fn release_all_should_keep_minimum_balance() {
+            let accounts = default_accounts();
+
+            let mut splitter = create_contract(1000);
+
+            set_balance(accounts.bob, 0);
+            set_balance(accounts.charlie, 0);
+            splitter.release(accounts.bob);
+            splitter.release(accounts.charlie);
+
+            assert_eq!(get_balance(accounts.bob), 200, "bob should be released");
+            assert_eq!(
+                get_balance(accounts.charlie),
+                800,
+                "charlie should be released"
+            );
+            assert_eq!(
+                get_balance(contract_id()),
+                minimum_balance(),
+                "splitter should keep the minimum balance"
+            );
+        }
+
+        #[ink::test]
+        fn releasable_of_large_balance_should_not_overflow() {
+            let accounts = default_accounts();
+
+            let received = Balance::MAX / 2;
+            let splitter = create_contract(received);
+
+            assert_eq!(
+                splitter.releasable(accounts.bob),
+                received / 100 * 20 + received % 100 * 20 / 100,
+                "bob releasable should by shares"
+            );
+            assert_eq!(
+                splitter.releasable(accounts.charlie),
+                received / 100 * 80 + received % 100 * 80 / 100,
+                "charlie releasable should by shares"
+            );
+        }
+
+        #[ink::test]
+        fn receive_should_emit_payment_received() {
+            let accounts = default_accounts();
+
+            let mut splitter = create_contract(0);
+
+            set_caller_with_value(accounts.django, 100);
+            splitter.receive();
+
+            let PaymentReceived { from, amount } =
+                decode_event(&get_last_emitted_event())
+                    .expect("should be a PaymentReceived");
+            assert_eq!(from, accounts.django, "received from the caller");
+            assert_eq!(amount, 100, "received the transferred value");
+        }
+
+        #[ink::test]
+        fn erc20_release_ok() {
+            let accounts = default_accounts();
+
+            let mut splitter = create_contract(0);
+            let (token, mock) = create_token(1000);
+
+            assert_eq!(
+                splitter.erc20_releasable(token, accounts.bob),
+                200,
+                "bob releasable should by shares"
+            );
+            assert_eq!(
+                splitter.erc20_releasable(token, accounts.charlie),
+                800,
+                "charlie releasable should by shares"
+            );
+
+            splitter.erc20_release(token, accounts.bob);
+
+            assert_eq!(
+                mock.borrow().balance_of(accounts.bob),
+                200,
+                "bob should be released"
+            );
+            assert_eq!(
+                splitter.erc20_released(token, accounts.bob),
+                200,
+                "bob released"
+            );
+            assert_eq!(splitter.erc20_total_released(token), 200, "total released");
+            assert_eq!(
+                splitter.erc20_releasable(token, accounts.bob),
+                0,
+                "bob nothing releasable"
+            );
+            assert_eq!(
+                splitter.erc20_releasable(token, accounts.charlie),
+                800,
+                "charlie releasable should not changed"
+            );
+
+            let TokenPaymentReleased {
+                token: released_token,
+                to,
+                amount,
+            } = decode_event(&get_last_emitted_event())
+                .expect("should be a TokenPaymentReleased");
+            assert_eq!(released_token, token, "released token");
+            assert_eq!(to, accounts.bob, "released to bob");
+            assert_eq!(amount, 200, "released amount");
+
+            // more tokens received
+            *mock.borrow_mut().balances.entry(contract_id()).or_default() += 1000;
+
+            assert_eq!(
+                splitter.erc20_releasable(token, accounts.bob),
+                200,
+                "bob releasable should by new payment"
+            );
+            assert_eq!(
+                splitter.erc20_releasable(token, accounts.charlie),
+                1600,
+                "charlie releasable should by all payment"
+            );
+
+            splitter.erc20_release(token, accounts.charlie);
+
+            assert_eq!(
+                mock.borrow().balance_of(accounts.charlie),
+                1600,
+                "charlie should be released"
+            );
+            assert_eq!(splitter.erc20_total_released(token), 1800, "total released");
+            assert_eq!(
+                mock.borrow().balance_of(contract_id()),
+                200,
+                "bob payment kept in splitter"
+            );
+            assert_eq!(splitter.total_released(), 0, "native not released");
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "PaymentSplitter: account is not due payment")]
+        fn erc20_release_without_tokens_should_panic() {
+            let accounts = default_accounts();
+
+            let mut splitter = create_contract(1000);
+            let (token, _) = create_token(0);
+
+            splitter.erc20_release(token, accounts.bob);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "PaymentSplitter: account has no shares")]
+        fn release_to_no_shares_should_panic() {
+            let accounts = default_accounts();
+
+            let mut splitter = create_contract(1000);
+
+            splitter.release(accounts.django);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "PaymentSplitter: account is not due payment")]
+        fn release_twice_should_panic() {
+            let accounts = default_accounts();
+
+            let mut splitter = create_contract(1000);
+
+            splitter.release(accounts.bob);
+            splitter.release(accounts.bob);
+        }
+
+        /// Create the splitter which has received `initial_balance`, the
+        /// contract keeps the minimum balance besides the received.
+        fn create_contract(initial_balance: Balance) -> PaymentSplitter {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+            set_balance(contract_id(), minimum_balance() + initial_balance);
+            PaymentSplitter::new(vec![accounts.bob, accounts.charlie], vec![20, 80])
+        }
+
+        fn minimum_balance() -> Balance {
+            ink_env::minimum_balance::<ink_env::DefaultEnvironment>()
+                .expect("Cannot get minimum balance")
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Pull Payment
+//! Simple implementation of a
+//! [pull-payment](https://consensys.github.io/smart-contract-best-practices/recommendations/#favor-pull-over-push-for-external-calls)
+//! strategy, where the paying contract doesn't interact directly with the
+//! receiver account, which must withdraw its payments itself.
+//!
+//! Pull-payments are often considered the best practice when it comes to sending
+//! native balance, security-wise. It prevents recipients from blocking execution,
+//! and eliminates reentrancy concerns.
+//!
+//! To use, the contract should import the `Escrow` component, and use
+//! `_async_transfer` instead of `transfer` to send funds.
+//! The funds are held by the `Escrow` component until the payee withdraw them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use metis_escrow as escrow;
+use metis_lang::Env;
+
+pub use metis_escrow::{
+    Data,
+    EventEmit,
+};
+
+/// The `Impl` define pull payment component impl funcs
+pub trait Impl<E: Env>: escrow::Impl<E> {
+    /// @dev Withdraw accumulated payments, forwarding all gas to the recipient.
+    ///
+    /// Note that _any_ account can call this function, not just the `payee`.
+    /// This means that contracts unaware of the `PullPayment` protocol can still
+    /// receive funds this way, by having a separate account call
+    /// `withdraw_payments`.
+    ///
+    /// WARNING: Forwarding all gas opens the door to reentrancy vulnerabilities.
+    /// Make sure you trust the recipient, or are either following the
+    /// checks-effects-interactions pattern or using {ReentrancyGuard}.
+    ///
+    /// @param payee Whose payments will be withdrawn.
+    fn withdraw_payments(&mut self, payee: E::AccountId) {
+        escrow::Impl::_withdraw(self, payee)
+    }
+
+    /// @dev Returns the payments owed to an address.
+    /// @param dest The creditor's address.
+    fn payments(&self, dest: &E::AccountId) -> E::Balance {
+        escrow::Impl::deposits_of(self, dest)
+    }
+
+    /// @dev Called by the payer to store the sent amount as credit to be pulled.
+    /// Funds sent in this way are stored in the `Escrow` component, so there
+    /// is no danger of them being spent before withdrawal.
+    ///
+    /// @param dest The destination address of the funds.
+    /// @param amount The amount to transfer.
+    fn _async_transfer(&mut self, dest: E::AccountId, amount: E::Balance) {
+        escrow::Impl::_deposit(self, dest, amount)
+    }
+}
+
+impl<E: Env, T: escrow::Impl<E>> Impl<E> for T {}
@@ -0,0 +1,182 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod mock {
+    use metis_escrow as escrow;
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_ownable as ownable;
+    use metis_pull_payment as pull_payment;
+
+    #[ink(storage)]
+    #[import(ownable, escrow)]
+    pub struct PullPayment {
+        ownable: ownable::Data<PullPayment>,
+        escrow: escrow::Data<PullPayment>,
+    }
+
+    /// Event emitted when payee deposit amount
+    #[ink(event)]
+    #[metis(escrow)]
+    pub struct Deposited {
+        #[ink(topic)]
+        pub payee: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Event emitted when payee withdraw
+    #[ink(event)]
+    #[metis(escrow)]
+    pub struct Withdrawn {
+        #[ink(topic)]
+        pub payee: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Event emitted when Owner AccountId Transferred
+    #[ink(event)]
+    #[metis(ownable)]
+    pub struct OwnershipTransferred {
+        /// previous owner account id
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
+        /// new owner account id
+        #[ink(topic)]
+        new_owner: Option<AccountId>,
+    }
+
+    // impl
+    impl PullPayment {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self {
+                ownable: ownable::Data::new(),
+                escrow: escrow::Data::new(),
+            };
+
+            ownable::Impl::init(&mut instance);
+
+            instance
+        }
+
+        /// Pay the `amount` to `dest` by pull payment, only owner
+        #[ink(message)]
+        pub fn call_transfer(&mut self, dest: AccountId, amount: Balance) {
+            ownable::Impl::ensure_caller_is_owner(self);
+
+            pull_payment::Impl::_async_transfer(self, dest, amount)
+        }
+
+        #[ink(message)]
+        pub fn payments(&self, dest: AccountId) -> Balance {
+            pull_payment::Impl::payments(self, &dest)
+        }
+
+        #[ink(message)]
+        pub fn withdraw_payments(&mut self, payee: AccountId) {
+            pull_payment::Impl::withdraw_payments(self, payee)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_env::{
+            call,
+            test,
+        };
+        use ink_lang as ink;
+
+        #[ink::test]
+        fn async_transfer_ok() {
+            let accounts = default_accounts();
+
+            let mut pull_payment = create_contract(1000);
+
+            assert_eq!(pull_payment.payments(accounts.bob), 0, "payments init");
+
+            pull_payment.call_transfer(accounts.bob, 100);
+            pull_payment.call_transfer(accounts.bob, 200);
+
+            assert_eq!(
+                pull_payment.payments(accounts.bob),
+                300,
+                "payments should be recorded"
+            );
+            assert_eq!(
+                get_balance(contract_id()),
+                1000,
+                "funds should be held by contract"
+            );
+        }
+
+        #[ink::test]
+        fn withdraw_payments_by_anyone_ok() {
+            let accounts = default_accounts();
+
+            let mut pull_payment = create_contract(1000);
+
+            pull_payment.call_transfer(accounts.bob, 300);
+
+            set_balance(accounts.bob, 0);
+            set_sender(accounts.charlie, 0);
+            pull_payment.withdraw_payments(accounts.bob);
+
+            assert_eq!(pull_payment.payments(accounts.bob), 0, "payments withdrawn");
+            assert_eq!(get_balance(accounts.bob), 300, "payee should receive");
+            assert_eq!(get_balance(contract_id()), 700, "contract balance reduced");
+        }
+
+        #[ink::test]
+        #[should_panic]
+        fn async_transfer_by_others_should_panic() {
+            let accounts = default_accounts();
+
+            let mut pull_payment = create_contract(1000);
+
+            set_sender(accounts.bob, 0);
+            pull_payment.call_transfer(accounts.bob, 300);
+        }
+
+        fn create_contract(initial_balance: Balance) -> PullPayment {
+            let accounts = default_accounts();
+            set_sender(accounts.alice, 0);
+            set_balance(contract_id(), initial_balance);
+            PullPayment::new()
+        }
+
+        fn contract_id() -> AccountId {
+            test::get_current_contract_account_id::<ink_env::DefaultEnvironment>()
+                .expect("Cannot get contract id")
+        }
+
+        fn set_sender(sender: AccountId, endowment: Balance) {
+            let callee = ink_env::account_id::<ink_env::DefaultEnvironment>()
+                .unwrap_or([0x0; 32].into());
+            test::push_execution_context::<Environment>(
+                sender,
+                callee,
+                1000000,
+                endowment,
+                test::CallData::new(call::Selector::new([0x00; 4])), // dummy
+            );
+        }
+
+        fn default_accounts() -> test::DefaultAccounts<ink_env::DefaultEnvironment> {
+            test::default_accounts::<ink_env::DefaultEnvironment>()
+                .expect("Off-chain environment should have been initialized already")
+        }
+
+        fn set_balance(account_id: AccountId, balance: Balance) {
+            test::set_account_balance::<ink_env::DefaultEnvironment>(account_id, balance)
+                .expect("Cannot set account balance");
+        }
+
+        fn get_balance(account_id: AccountId) -> Balance {
+            test::get_account_balance::<ink_env::DefaultEnvironment>(account_id)
+                .expect("Cannot set account balance")
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Vesting Wallet
+//! This component handles the vesting of native balance and ERC20 tokens for a
+//! given beneficiary. Custody of multiple tokens can be given to this contract,
+//! which will release the token to the beneficiary following a given vesting
+//! schedule.
+//!
+//! The vesting schedule is linear from the `start` during the `duration`, with
+//! an optional `cliff` since the `start`, nothing can be released before the
+//! cliff ends. Any token transferred to this contract will follow the vesting
+//! schedule as if they were locked from the beginning. Consequently, if the
+//! vesting has already started, any amount of tokens sent to this contract
+//! will (at least partly) be immediately releasable.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod module;
+
+use core::convert::{
+    TryFrom,
+    TryInto,
+};
+use ink_lang::{
+    ForwardCall,
+    ForwardCallMut,
+};
+use metis_lang::arithmetic::Saturating;
+pub use metis_lang::{
+    Env,
+    EnvAccess,
+    FromAccountId,
+    Storage,
+};
+
+pub use module::Data;
+
+use metis_erc20_stub::Erc20Stub;
+
+/// The `EventEmit` impl the event emit api for vesting component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emitted when `amount` native balance released to the beneficiary.
+    fn emit_event_native_released(&mut self, amount: E::Balance);
+
+    /// Emitted when `amount` of erc20 `token` released to the beneficiary.
+    fn emit_event_token_released(&mut self, token: E::AccountId, amount: E::Balance);
+}
+
+/// The `Impl` define vesting component impl funcs
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
+    /// @dev Set the beneficiary, start timestamp, vesting duration and the
+    /// optional cliff duration of the vesting wallet.
+    fn init(
+        &mut self,
+        beneficiary: E::AccountId,
+        start: E::Timestamp,
+        duration: E::Timestamp,
+        cliff: Option<E::Timestamp>,
+    ) {
+        assert!(
+            beneficiary != E::AccountId::default(),
+            "VestingWallet: beneficiary is zero address"
+        );
+
+        if let Some(cliff) = cliff {
+            assert!(
+                cliff <= duration,
+                "VestingWallet: cliff is longer than duration"
+            );
+        }
+
+        Storage::<E, Data<E>>::get_mut(self).set_schedule(
+            beneficiary,
+            start,
+            duration,
+            cliff,
+        );
+    }
+
+    /// @dev Getter for the beneficiary address.
+    fn beneficiary(&self) -> E::AccountId {
+        Storage::<E, Data<E>>::get(self).beneficiary().clone()
+    }
+
+    /// @dev Getter for the start timestamp.
+    fn start(&self) -> E::Timestamp {
+        Storage::<E, Data<E>>::get(self).start()
+    }
+
+    /// @dev Getter for the vesting duration.
+    fn duration(&self) -> E::Timestamp {
+        Storage::<E, Data<E>>::get(self).duration()
+    }
+
+    /// @dev Getter for the cliff duration since the start.
+    fn cliff(&self) -> Option<E::Timestamp> {
+        Storage::<E, Data<E>>::get(self).cliff()
+    }
+
+    /// @dev Amount of native balance already released
+    fn released(&self) -> E::Balance {
+        Storage::<E, Data<E>>::get(self).released()
+    }
+
+    /// @dev Amount of `token` already released
+    fn erc20_released(&self, token: &E::AccountId) -> E::Balance {
+        Storage::<E, Data<E>>::get(self).erc20_released(token)
+    }
+
+    /// @dev Getter for the amount of releasable native balance.
+    fn releasable(&self) -> E::Balance {
+        self.vested_amount(Self::block_timestamp()) - self.released()
+    }
+
+    /// @dev Getter for the amount of releasable `token` tokens.
+    fn erc20_releasable(&self, token: &E::AccountId) -> E::Balance {
+        self.erc20_vested_amount(token, Self::block_timestamp())
+            - self.erc20_released(token)
+    }
+
+    /// @dev Release the native balance that have already vested.
+    ///
+    /// Emits a `NativeReleased` event.
+    fn release(&mut self) {
+        let amount = self.releasable();
+
+        Storage::<E, Data<E>>::get_mut(self).add_released(amount);
+
+        self.emit_event_native_released(amount);
+
+        let res = Self::transfer(self.beneficiary(), amount);
+        assert!(res.is_ok(), "VestingWallet: transfer to beneficiary error");
+    }
+
+    /// @dev Release the `token` tokens that have already vested.
+    ///
+    /// Emits a `TokenReleased` event.
+    fn erc20_release(&mut self, token: E::AccountId) {
+        let amount = self.erc20_releasable(&token);
+
+        Storage::<E, Data<E>>::get_mut(self).add_erc20_released(&token, amount);
+
+        self.emit_event_token_released(token.clone(), amount);
+
+        let mut erc20 = <Erc20Stub as FromAccountId<E>>::from_account_id(token);
+        let res = erc20
+            .call_mut()
+            .transfer(self.beneficiary().into(), amount.into())
+            .fire();
+        assert!(
+            matches!(res, Ok(Ok(()))),
+            "VestingWallet: erc20 transfer to beneficiary error"
+        );
+    }
+
+    /// @dev Calculates the amount of native balance that has already vested,
+    /// the minimum balance of the contract is not counted as allocated.
+    fn vested_amount(&self, timestamp: E::Timestamp) -> E::Balance {
+        let total_allocation =
+            Self::balance().saturating_sub(Self::minimum_balance()) + self.released();
+
+        self._vesting_schedule(total_allocation, timestamp)
+    }
+
+    /// @dev Calculates the amount of `token` tokens that has already vested.
+    fn erc20_vested_amount(
+        &self,
+        token: &E::AccountId,
+        timestamp: E::Timestamp,
+    ) -> E::Balance {
+        let total_allocation = _erc20_balance_of::<E>(token, &Self::account_id())
+            + self.erc20_released(token);
+
+        self._vesting_schedule(total_allocation, timestamp)
+    }
+
+    /// @dev Virtual implementation of the vesting formula. This returns the
+    /// amount vested, as a function of time, for an asset given its total
+    /// historical allocation.
+    fn _vesting_schedule(
+        &self,
+        total_allocation: E::Balance,
+        timestamp: E::Timestamp,
+    ) -> E::Balance {
+        let start = self.start();
+        let duration = self.duration();
+        let cliff = self.cliff().unwrap_or(E::Timestamp::from(0_u8));
+
+        if timestamp < start.saturating_add(cliff) {
+            E::Balance::from(0_u8)
+        } else if timestamp >= start.saturating_add(duration) {
+            total_allocation
+        } else {
+            let total: u128 = total_allocation.into();
+            let elapsed = _timestamp_to_u128::<E>(timestamp - start);
+            let duration = _timestamp_to_u128::<E>(duration);
+
+            // `total * elapsed / duration` without the overflow of the
+            // product, `elapsed` is less than `duration` here.
+            let vested =
+                total / duration * elapsed + total % duration * elapsed / duration;
+
+            E::Balance::try_from(vested)
+                .unwrap_or_else(|_| panic!("VestingWallet: vested amount overflow"))
+        }
+    }
+}
+
+impl<E: Env, T: Storage<E, Data<E>> + EventEmit<E>> Impl<E> for T {}
+
+fn _timestamp_to_u128<E: Env>(timestamp: E::Timestamp) -> u128 {
+    timestamp
+        .try_into()
+        .unwrap_or_else(|_| panic!("VestingWallet: timestamp overflow"))
+}
+
+/// Get the erc20 `token` balance of `account` by cross-contract call.
+fn _erc20_balance_of<E: Env>(token: &E::AccountId, account: &E::AccountId) -> E::Balance {
+    let erc20 = <Erc20Stub as FromAccountId<E>>::from_account_id(token.clone());
+    let balance = erc20
+        .call()
+        .balance_of(account.clone().into())
+        .fire()
+        .expect("VestingWallet: get erc20 balance error");
+
+    E::Balance::try_from(balance)
+        .unwrap_or_else(|_| panic!("VestingWallet: erc20 balance overflow"))
+}
@@ -0,0 +1,118 @@
+pub use metis_lang::Env;
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    collections::HashMap as StorageHashMap,
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The Data of vesting component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E>
+where
+    E: Env,
+{
+    /// The beneficiary of the vested funds
+    pub beneficiary: Lazy<E::AccountId>,
+    /// The start timestamp of the vesting
+    pub start: Lazy<E::Timestamp>,
+    /// The duration of the vesting
+    pub duration: Lazy<E::Timestamp>,
+    /// The cliff duration since the start, nothing vested before the cliff
+    pub cliff: Lazy<Option<E::Timestamp>>,
+    /// The amount of native balance already released
+    pub released: Lazy<E::Balance>,
+    /// Mapping from erc20 token to the amount already released
+    pub erc20_released: StorageHashMap<E::AccountId, E::Balance>,
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            beneficiary: Lazy::new(E::AccountId::default()),
+            start: Lazy::new(E::Timestamp::from(0_u8)),
+            duration: Lazy::new(E::Timestamp::from(0_u8)),
+            cliff: Lazy::new(None),
+            released: Lazy::new(E::Balance::from(0_u8)),
+            erc20_released: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    /// Set the vesting schedule
+    pub fn set_schedule(
+        &mut self,
+        beneficiary: E::AccountId,
+        start: E::Timestamp,
+        duration: E::Timestamp,
+        cliff: Option<E::Timestamp>,
+    ) {
+        Lazy::set(&mut self.beneficiary, beneficiary);
+        Lazy::set(&mut self.start, start);
+        Lazy::set(&mut self.duration, duration);
+        Lazy::set(&mut self.cliff, cliff);
+    }
+
+    /// Get the beneficiary of the vested funds
+    pub fn beneficiary(&self) -> &E::AccountId {
+        &self.beneficiary
+    }
+
+    /// Get the start timestamp of the vesting
+    pub fn start(&self) -> E::Timestamp {
+        *self.start
+    }
+
+    /// Get the duration of the vesting
+    pub fn duration(&self) -> E::Timestamp {
+        *self.duration
+    }
+
+    /// Get the cliff duration of the vesting
+    pub fn cliff(&self) -> Option<E::Timestamp> {
+        *self.cliff
+    }
+
+    /// Get the amount of native balance already released
+    pub fn released(&self) -> E::Balance {
+        *self.released
+    }
+
+    /// Get the amount of `token` already released
+    pub fn erc20_released(&self, token: &E::AccountId) -> E::Balance {
+        self.erc20_released
+            .get(token)
+            .copied()
+            .unwrap_or(E::Balance::from(0_u8))
+    }
+
+    /// Add the `amount` of native balance released
+    pub fn add_released(&mut self, amount: E::Balance) {
+        let released = self.released() + amount;
+        Lazy::set(&mut self.released, released);
+    }
+
+    /// Add the `amount` of `token` released
+    pub fn add_erc20_released(&mut self, token: &E::AccountId, amount: E::Balance) {
+        let released = self.erc20_released(token) + amount;
+        self.erc20_released.insert(token.clone(), released);
+    }
+}
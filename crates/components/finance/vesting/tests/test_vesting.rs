@@ -0,0 +1,470 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod mock {
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_vesting as vesting;
+
+    #[ink(storage)]
+    #[import(vesting)]
+    pub struct VestingWallet {
+        vesting: vesting::Data<VestingWallet>,
+    }
+
+    /// Emitted when native balance released
+    #[ink(event)]
+    #[metis(vesting)]
+    pub struct NativeReleased {
+        pub amount: Balance,
+    }
+
+    /// Emitted when erc20 token released
+    #[ink(event)]
+    #[metis(vesting)]
+    pub struct TokenReleased {
+        #[ink(topic)]
+        pub token: AccountId,
+        pub amount: Balance,
+    }
+
+    // impl
+    impl VestingWallet {
+        #[ink(constructor)]
+        pub fn new(
+            beneficiary: AccountId,
+            start: Timestamp,
+            duration: Timestamp,
+            cliff: Option<Timestamp>,
+        ) -> Self {
+            let mut instance = Self {
+                vesting: vesting::Data::new(),
+            };
+
+            vesting::Impl::init(&mut instance, beneficiary, start, duration, cliff);
+
+            instance
+        }
+
+        #[ink(message, payable)]
+        pub fn receive(&mut self) {}
+
+        #[ink(message)]
+        pub fn beneficiary(&self) -> AccountId {
+            vesting::Impl::beneficiary(self)
+        }
+
+        #[ink(message)]
+        pub fn start(&self) -> Timestamp {
+            vesting::Impl::start(self)
+        }
+
+        #[ink(message)]
+        pub fn duration(&self) -> Timestamp {
+            vesting::Impl::duration(self)
+        }
+
+        #[ink(message)]
+        pub fn released(&self) -> Balance {
+            vesting::Impl::released(self)
+        }
+
+        #[ink(message)]
+        pub fn releasable(&self) -> Balance {
+            vesting::Impl::releasable(self)
+        }
+
+        #[ink(message)]
+        pub fn release(&mut self) {
+            vesting::Impl::release(self)
+        }
+
+        #[ink(message)]
+        pub fn vested_amount(&self, timestamp: Timestamp) -> Balance {
+            vesting::Impl::vested_amount(self, timestamp)
+        }
+
+        #[ink(message)]
+        pub fn erc20_released(&self, token: AccountId) -> Balance {
+            vesting::Impl::erc20_released(self, &token)
+        }
+
+        #[ink(message)]
+        pub fn erc20_releasable(&self, token: AccountId) -> Balance {
+            vesting::Impl::erc20_releasable(self, &token)
+        }
+
+        #[ink(message)]
+        pub fn erc20_release(&mut self, token: AccountId) {
+            vesting::Impl::erc20_release(self, token)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+        use ink_prelude::string::String;
+        use metis_erc20_stub::{
+            Erc20 as Token,
+            Erc20Stub,
+            Error,
+            Result,
+        };
+        use metis_test::{
+            advance_after,
+            block_timestamp,
+            contract_id,
+            default_accounts,
+            get_balance,
+            set_balance,
+            set_caller,
+        };
+        use std::{
+            cell::RefCell,
+            collections::HashMap,
+            rc::Rc,
+        };
+
+        const DURATION: Timestamp = 1000;
+        const AMOUNT: Balance = 100000;
+
+        /// A erc20 contract which only keeps the balances.
+        #[derive(Default)]
+        struct MockToken {
+            balances: HashMap<AccountId, Balance>,
+        }
+
+        impl Token for MockToken {
+            fn name(&self) -> String {
+                String::from("MockToken")
+            }
+
+            fn symbol(&self) -> String {
+                String::from("MT")
+            }
+
+            fn decimals(&self) -> u8 {
+                18
+            }
+
+            fn total_supply(&self) -> Balance {
+                self.balances.values().sum()
+            }
+
+            fn balance_of(&self, account: AccountId) -> Balance {
+                self.balances.get(&account).cloned().unwrap_or_default()
+            }
+
+            fn allowance(&self, _owner: AccountId, _spender: AccountId) -> Balance {
+                0
+            }
+
+            fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+                let from = ink_env::caller::<ink_env::DefaultEnvironment>()
+                    .expect("Cannot get caller");
+                let from_balance = self.balance_of(from);
+                if from_balance < value {
+                    return Err(Error::InsufficientBalance)
+                }
+
+                self.balances.insert(from, from_balance - value);
+                *self.balances.entry(to).or_default() += value;
+
+                Ok(())
+            }
+
+            fn approve(&mut self, _spender: AccountId, _amount: Balance) -> Result<()> {
+                Ok(())
+            }
+
+            fn transfer_from(
+                &mut self,
+                _from: AccountId,
+                _to: AccountId,
+                _amount: Balance,
+            ) -> Result<()> {
+                Err(Error::InsufficientAllowance)
+            }
+        }
+
+        /// Mock the erc20 token at eve, the wallet holds `AMOUNT` tokens.
+        fn create_token() -> (AccountId, Rc<RefCell<MockToken>>) {
+            let accounts = default_accounts();
+
+            let token = Rc::new(RefCell::new(MockToken::default()));
+            Erc20Stub::mock(accounts.eve, token.clone());
+            token.borrow_mut().balances.insert(contract_id(), AMOUNT);
+
+            (accounts.eve, token)
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let start = block_timestamp();
+
+            let wallet = create_contract(start, None);
+
+            assert_eq!(wallet.beneficiary(), accounts.bob, "beneficiary init");
+            assert_eq!(wallet.start(), start, "start init");
+            assert_eq!(wallet.duration(), DURATION, "duration init");
+            assert_eq!(wallet.released(), 0, "nothing released");
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "VestingWallet: cliff is longer than duration")]
+        fn new_with_long_cliff_should_panic() {
+            create_contract(block_timestamp(), Some(DURATION + 1));
+        }
+
+        #[ink::test]
+        fn vested_amount_linear() {
+            let start = block_timestamp() + 100;
+
+            let wallet = create_contract(start, None);
+
+            assert_eq!(wallet.vested_amount(start - 1), 0, "nothing before start");
+            assert_eq!(wallet.vested_amount(start), 0, "nothing at start");
+            assert_eq!(
+                wallet.vested_amount(start + DURATION / 4),
+                AMOUNT / 4,
+                "a quarter vested"
+            );
+            assert_eq!(
+                wallet.vested_amount(start + DURATION / 2),
+                AMOUNT / 2,
+                "half vested"
+            );
+            assert_eq!(
+                wallet.vested_amount(start + DURATION),
+                AMOUNT,
+                "all vested at end"
+            );
+            assert_eq!(
+                wallet.vested_amount(start + DURATION * 2),
+                AMOUNT,
+                "all vested after end"
+            );
+        }
+
+        #[ink::test]
+        fn vested_amount_with_cliff() {
+            let start = block_timestamp();
+
+            let wallet = create_contract(start, Some(DURATION / 2));
+
+            assert_eq!(
+                wallet.vested_amount(start + DURATION / 4),
+                0,
+                "nothing vested before cliff"
+            );
+            assert_eq!(
+                wallet.vested_amount(start + DURATION / 2),
+                AMOUNT / 2,
+                "vested linear after cliff"
+            );
+            assert_eq!(
+                wallet.vested_amount(start + DURATION),
+                AMOUNT,
+                "all vested at end"
+            );
+        }
+
+        #[ink::test]
+        fn release_ok() {
+            let accounts = default_accounts();
+            let start = block_timestamp();
+
+            let mut wallet = create_contract(start, None);
+
+            advance_after(start + DURATION / 2);
+
+            let now = block_timestamp();
+            let expected = AMOUNT * ((now - start) as Balance) / (DURATION as Balance);
+            assert_eq!(wallet.releasable(), expected, "releasable by time");
+
+            set_balance(accounts.bob, 0);
+            wallet.release();
+
+            assert_eq!(get_balance(accounts.bob), expected, "beneficiary released");
+            assert_eq!(wallet.released(), expected, "released recorded");
+            assert_eq!(
+                wallet.releasable(),
+                0,
+                "nothing releasable at the same time"
+            );
+
+            advance_after(start + DURATION);
+
+            wallet.release();
+
+            assert_eq!(get_balance(accounts.bob), AMOUNT, "all released");
+            assert_eq!(wallet.released(), AMOUNT, "all released recorded");
+            assert_eq!(
+                get_balance(contract_id()),
+                minimum_balance(),
+                "wallet keeps the minimum balance"
+            );
+        }
+
+        #[ink::test]
+        fn vested_amount_of_large_balance_should_not_overflow() {
+            let start = block_timestamp();
+
+            let total = Balance::MAX / 2;
+            let wallet = create_contract(start, None);
+            set_balance(contract_id(), minimum_balance() + total);
+
+            let elapsed = (DURATION / 4) as Balance;
+            let duration = DURATION as Balance;
+            assert_eq!(
+                wallet.vested_amount(start + DURATION / 4),
+                total / duration * elapsed + total % duration * elapsed / duration,
+                "a quarter vested"
+            );
+            assert_eq!(
+                wallet.vested_amount(start + DURATION),
+                total,
+                "all vested at end"
+            );
+        }
+
+        #[ink::test]
+        fn release_before_cliff_nothing() {
+            let accounts = default_accounts();
+            let start = block_timestamp();
+
+            let mut wallet = create_contract(start, Some(DURATION / 2));
+
+            set_balance(accounts.bob, 0);
+            wallet.release();
+
+            assert_eq!(get_balance(accounts.bob), 0, "nothing released");
+            assert_eq!(wallet.released(), 0, "nothing released recorded");
+        }
+
+        #[ink::test]
+        fn erc20_release_ok() {
+            let accounts = default_accounts();
+            let start = block_timestamp();
+
+            let mut wallet = create_contract(start, None);
+            let (token, mock) = create_token();
+
+            advance_after(start + DURATION / 4);
+
+            let now = block_timestamp();
+            let expected = AMOUNT * ((now - start) as Balance) / (DURATION as Balance);
+            assert_eq!(
+                wallet.erc20_releasable(token),
+                expected,
+                "releasable by time"
+            );
+
+            wallet.erc20_release(token);
+
+            assert_eq!(
+                mock.borrow().balance_of(accounts.bob),
+                expected,
+                "beneficiary released"
+            );
+            assert_eq!(wallet.erc20_released(token), expected, "released recorded");
+            assert_eq!(
+                wallet.erc20_releasable(token),
+                0,
+                "nothing releasable at the same time"
+            );
+
+            advance_after(start + DURATION);
+
+            wallet.erc20_release(token);
+
+            assert_eq!(
+                mock.borrow().balance_of(accounts.bob),
+                AMOUNT,
+                "all released"
+            );
+            assert_eq!(
+                wallet.erc20_released(token),
+                AMOUNT,
+                "all released recorded"
+            );
+            assert_eq!(
+                mock.borrow().balance_of(contract_id()),
+                0,
+                "wallet is empty"
+            );
+        }
+
+        #[ink::test]
+        fn erc20_partial_release_after_cliff() {
+            let accounts = default_accounts();
+            let start = block_timestamp();
+
+            let mut wallet = create_contract(start, Some(DURATION / 2));
+            let (token, mock) = create_token();
+
+            // nothing vested before cliff
+            assert_eq!(wallet.erc20_releasable(token), 0, "nothing before cliff");
+            wallet.erc20_release(token);
+            assert_eq!(
+                mock.borrow().balance_of(accounts.bob),
+                0,
+                "nothing released before cliff"
+            );
+
+            advance_after(start + DURATION / 2);
+
+            // vested linear from start once the cliff passed
+            let now = block_timestamp();
+            let first = AMOUNT * ((now - start) as Balance) / (DURATION as Balance);
+            assert!(first >= AMOUNT / 2 && first < AMOUNT, "partial vested");
+            assert_eq!(
+                wallet.erc20_releasable(token),
+                first,
+                "releasable after cliff"
+            );
+
+            wallet.erc20_release(token);
+            assert_eq!(
+                mock.borrow().balance_of(accounts.bob),
+                first,
+                "partial released"
+            );
+
+            // the tokens received later are vested by the same schedule
+            *mock.borrow_mut().balances.entry(contract_id()).or_default() += AMOUNT;
+
+            advance_after(block_timestamp());
+
+            let now = block_timestamp();
+            let vested = 2 * AMOUNT * ((now - start) as Balance) / (DURATION as Balance);
+            assert_eq!(
+                wallet.erc20_releasable(token),
+                vested - first,
+                "releasable of total allocation"
+            );
+
+            wallet.erc20_release(token);
+            assert_eq!(mock.borrow().balance_of(accounts.bob), vested);
+            assert_eq!(wallet.erc20_released(token), vested);
+        }
+
+        /// Create the wallet which holds `AMOUNT`, the contract keeps the
+        /// minimum balance besides the allocation.
+        fn create_contract(start: Timestamp, cliff: Option<Timestamp>) -> VestingWallet {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+            set_balance(contract_id(), minimum_balance() + AMOUNT);
+            VestingWallet::new(accounts.bob, start, DURATION, cliff)
+        }
+
+        fn minimum_balance() -> Balance {
+            ink_env::minimum_balance::<ink_env::DefaultEnvironment>()
+                .expect("Cannot get minimum balance")
+        }
+    }
+}
@@ -0,0 +1,424 @@
+//! Multisignature wallet, allows multiple owners to agree on transactions
+//! before execution.
+//!
+//! An owner submits a transaction which calls a message of a target contract
+//! (or transfers native balance to an account), the other owners confirm it,
+//! and once the confirmations reach the `threshold` any owner can execute it.
+//!
+//! The owners and the threshold can only be changed by the wallet itself,
+//! which means by a transaction targeting the wallet confirmed by the owners.
+//! These self-calls are dispatched inside the wallet instead of calling back
+//! to the contract, an invalid self-call fails like a failed call so the
+//! transaction can be executed again.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod module;
+mod types;
+
+use ink_prelude::vec::Vec;
+pub use metis_lang::{
+    Context,
+    Env,
+    EnvAccess,
+    Storage,
+};
+use scale::Decode;
+
+pub use module::Data;
+pub use types::{
+    Transaction,
+    TransactionId,
+};
+
+/// The max count of the owners.
+pub const MAX_OWNER_COUNT: u32 = 50;
+
+/// The `EventEmit` impl the event emit api for multisig component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emitted when the transaction `transaction_id` is submitted.
+    fn emit_event_submission(&mut self, transaction_id: TransactionId);
+
+    /// Emitted when `sender` confirms the transaction `transaction_id`.
+    fn emit_event_confirmation(
+        &mut self,
+        sender: E::AccountId,
+        transaction_id: TransactionId,
+    );
+
+    /// Emitted when `sender` revokes the confirmation of the transaction
+    /// `transaction_id`.
+    fn emit_event_revocation(
+        &mut self,
+        sender: E::AccountId,
+        transaction_id: TransactionId,
+    );
+
+    /// Emitted when the transaction `transaction_id` is executed.
+    fn emit_event_execution(&mut self, transaction_id: TransactionId);
+
+    /// Emitted when the call of the transaction `transaction_id` failed, the
+    /// transaction can be executed again.
+    fn emit_event_execution_failure(&mut self, transaction_id: TransactionId);
+
+    /// Emitted when `owner` is added.
+    fn emit_event_owner_addition(&mut self, owner: E::AccountId);
+
+    /// Emitted when `owner` is removed.
+    fn emit_event_owner_removal(&mut self, owner: E::AccountId);
+
+    /// Emitted when the threshold is changed to `threshold`.
+    fn emit_event_threshold_change(&mut self, threshold: u32);
+}
+
+/// The `Impl` define multisig component impl funcs
+pub trait Impl<E>: EventEmit<E> + Storage<E, Data<E>> + Context<E>
+where
+    E: Env,
+{
+    /// Initialize the wallet with the `owners` and the `threshold`.
+    fn init(&mut self, owners: Vec<E::AccountId>, threshold: u32) {
+        _ensure_valid_requirement(owners.len() as u32, threshold);
+
+        for owner in owners.into_iter() {
+            assert!(
+                owner != E::AccountId::default(),
+                "Multisig: owner is the zero account"
+            );
+            assert!(!self.is_owner(&owner), "Multisig: owner already exists");
+
+            Storage::<E, Data<E>>::get_mut(self).add_owner(owner.clone());
+            self.emit_event_owner_addition(owner);
+        }
+
+        Storage::<E, Data<E>>::get_mut(self).set_threshold(threshold);
+        self.emit_event_threshold_change(threshold);
+    }
+
+    /// Returns the owners of the wallet.
+    fn owners(&self) -> Vec<E::AccountId> {
+        Storage::<E, Data<E>>::get(self).owners().clone()
+    }
+
+    /// Returns whether the `account` is an owner.
+    fn is_owner(&self, account: &E::AccountId) -> bool {
+        Storage::<E, Data<E>>::get(self).is_owner(account)
+    }
+
+    /// Returns the number of the confirmations required to execute a
+    /// transaction.
+    fn threshold(&self) -> u32 {
+        Storage::<E, Data<E>>::get(self).threshold()
+    }
+
+    /// Returns the count of the submitted transactions.
+    fn transaction_count(&self) -> TransactionId {
+        Storage::<E, Data<E>>::get(self).transaction_count()
+    }
+
+    /// Returns the transaction `id`.
+    fn transaction(
+        &self,
+        id: TransactionId,
+    ) -> Option<Transaction<E::AccountId, E::Balance>> {
+        Storage::<E, Data<E>>::get(self).transaction(id).cloned()
+    }
+
+    /// Returns whether the `owner` confirmed the transaction `id`.
+    fn is_confirmed_by(&self, id: TransactionId, owner: &E::AccountId) -> bool {
+        Storage::<E, Data<E>>::get(self).is_confirmed_by(id, owner)
+    }
+
+    /// Returns the number of the confirmations of the transaction `id`, only
+    /// the confirmations of the current owners are counted.
+    fn confirmation_count(&self, id: TransactionId) -> u32 {
+        let data = Storage::<E, Data<E>>::get(self);
+        data.owners()
+            .iter()
+            .filter(|owner| data.is_confirmed_by(id, owner))
+            .count() as u32
+    }
+
+    /// Returns whether the transaction `id` has enough confirmations.
+    fn is_confirmed(&self, id: TransactionId) -> bool {
+        self.confirmation_count(id) >= self.threshold()
+    }
+
+    /// Panic if the caller is not an owner.
+    fn ensure_caller_is_owner(&self) {
+        assert!(
+            self.is_owner(&self._msg_sender()),
+            "Multisig: caller is not an owner"
+        );
+    }
+
+    /// Submit a transaction which calls the message with `selector` of
+    /// `target` by the encoded args `input`, with `value` native balance
+    /// transferred. `selector` is `None` for a plain transfer. The
+    /// transaction is confirmed by the caller.
+    ///
+    /// Emits `Submission` and `Confirmation` events.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be an owner.
+    fn submit_transaction(
+        &mut self,
+        target: E::AccountId,
+        selector: Option<[u8; 4]>,
+        input: Vec<u8>,
+        value: E::Balance,
+    ) -> TransactionId {
+        self.ensure_caller_is_owner();
+
+        if target == Self::account_id() {
+            assert!(selector.is_some(), "Multisig: self call without selector");
+            assert!(
+                value == E::Balance::from(0_u8),
+                "Multisig: self call with value"
+            );
+        }
+
+        let id = Storage::<E, Data<E>>::get_mut(self).add_transaction(Transaction {
+            target,
+            selector,
+            input,
+            value,
+            executed: false,
+        });
+        self.emit_event_submission(id);
+
+        self.confirm(id);
+
+        id
+    }
+
+    /// Confirm the transaction `id` by the caller.
+    ///
+    /// Emits a `Confirmation` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be an owner.
+    /// - the transaction must exist and not be executed.
+    /// - the caller has not confirmed the transaction.
+    fn confirm(&mut self, id: TransactionId) {
+        self.ensure_caller_is_owner();
+        self._ensure_not_executed(id);
+
+        let caller = self._msg_sender();
+        assert!(
+            !self.is_confirmed_by(id, &caller),
+            "Multisig: transaction already confirmed"
+        );
+
+        Storage::<E, Data<E>>::get_mut(self).set_confirmation(id, &caller, true);
+        self.emit_event_confirmation(caller, id);
+    }
+
+    /// Revoke the confirmation of the transaction `id` by the caller.
+    ///
+    /// Emits a `Revocation` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be an owner.
+    /// - the transaction must exist and not be executed.
+    /// - the caller has confirmed the transaction.
+    fn revoke_confirmation(&mut self, id: TransactionId) {
+        self.ensure_caller_is_owner();
+        self._ensure_not_executed(id);
+
+        let caller = self._msg_sender();
+        assert!(
+            self.is_confirmed_by(id, &caller),
+            "Multisig: transaction not confirmed"
+        );
+
+        Storage::<E, Data<E>>::get_mut(self).set_confirmation(id, &caller, false);
+        self.emit_event_revocation(caller, id);
+    }
+
+    /// Execute the confirmed transaction `id`. If the call failed, the
+    /// transaction keeps not executed, so it can be executed again.
+    ///
+    /// Emits an `Execution` event, or an `ExecutionFailure` event if the call
+    /// failed.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be an owner.
+    /// - the transaction must exist and not be executed.
+    /// - the transaction must have enough confirmations.
+    fn execute(&mut self, id: TransactionId) {
+        self.ensure_caller_is_owner();
+        self._ensure_not_executed(id);
+        assert!(self.is_confirmed(id), "Multisig: transaction not confirmed");
+
+        let transaction = self.transaction(id).expect("transaction should exist");
+
+        Storage::<E, Data<E>>::get_mut(self).set_executed(id, true);
+
+        if self._call(&transaction) {
+            self.emit_event_execution(id);
+        } else {
+            Storage::<E, Data<E>>::get_mut(self).set_executed(id, false);
+            self.emit_event_execution_failure(id);
+        }
+    }
+
+    /// Panic if the transaction not exists or has been executed.
+    fn _ensure_not_executed(&self, id: TransactionId) {
+        let transaction = Storage::<E, Data<E>>::get(self)
+            .transaction(id)
+            .expect("Multisig: transaction not exists");
+        assert!(
+            !transaction.executed,
+            "Multisig: transaction already executed"
+        );
+    }
+
+    /// Make the call of the transaction, returns whether it succeeded.
+    fn _call(&mut self, transaction: &Transaction<E::AccountId, E::Balance>) -> bool {
+        match transaction.selector {
+            Some(selector) if transaction.target == Self::account_id() => {
+                self._self_call(selector, &transaction.input)
+            }
+            Some(selector) => {
+                metis_lang::call::invoke::<E>(
+                    transaction.target.clone(),
+                    selector,
+                    &transaction.input,
+                    transaction.value,
+                )
+                .is_ok()
+            }
+            None => Self::transfer(transaction.target.clone(), transaction.value).is_ok(),
+        }
+    }
+
+    /// Dispatch the self-call to the owner management funcs, returns false if
+    /// the selector is unknown, the input can not be decoded or the change is
+    /// invalid.
+    fn _self_call(&mut self, selector: [u8; 4], input: &Vec<u8>) -> bool {
+        let input = &mut &input[..];
+
+        if selector == metis_lang::selector_id!(add_owner) {
+            match <E::AccountId as Decode>::decode(input) {
+                Ok(owner) => self._add_owner(owner),
+                Err(_) => false,
+            }
+        } else if selector == metis_lang::selector_id!(remove_owner) {
+            match <E::AccountId as Decode>::decode(input) {
+                Ok(owner) => self._remove_owner(owner),
+                Err(_) => false,
+            }
+        } else if selector == metis_lang::selector_id!(replace_owner) {
+            match <(E::AccountId, E::AccountId) as Decode>::decode(input) {
+                Ok((owner, new_owner)) => self._replace_owner(owner, new_owner),
+                Err(_) => false,
+            }
+        } else if selector == metis_lang::selector_id!(change_threshold) {
+            match <u32 as Decode>::decode(input) {
+                Ok(threshold) => self._change_threshold(threshold),
+                Err(_) => false,
+            }
+        } else {
+            false
+        }
+    }
+
+    /// Add a new owner without access restriction, returns false without any
+    /// change if the `owner` is the zero account, is already an owner or the
+    /// owners will be too many.
+    ///
+    /// Emits an `OwnerAddition` event.
+    fn _add_owner(&mut self, owner: E::AccountId) -> bool {
+        if owner == E::AccountId::default()
+            || self.is_owner(&owner)
+            || !_is_valid_requirement(self.owners().len() as u32 + 1, self.threshold())
+        {
+            return false
+        }
+
+        Storage::<E, Data<E>>::get_mut(self).add_owner(owner.clone());
+        self.emit_event_owner_addition(owner);
+
+        true
+    }
+
+    /// Remove an owner without access restriction, the threshold will be
+    /// decreased if it is more than the count of the owners. Returns false
+    /// without any change if the `owner` is not an owner or is the last one.
+    ///
+    /// Emits an `OwnerRemoval` event.
+    fn _remove_owner(&mut self, owner: E::AccountId) -> bool {
+        if !self.is_owner(&owner) || self.owners().len() <= 1 {
+            return false
+        }
+
+        Storage::<E, Data<E>>::get_mut(self).remove_owner(&owner);
+        self.emit_event_owner_removal(owner);
+
+        let owners_count = self.owners().len() as u32;
+        if self.threshold() > owners_count {
+            Storage::<E, Data<E>>::get_mut(self).set_threshold(owners_count);
+            self.emit_event_threshold_change(owners_count);
+        }
+
+        true
+    }
+
+    /// Replace an owner with a new owner without access restriction, returns
+    /// false without any change if the `owner` is not an owner, or the
+    /// `new_owner` is the zero account or is already an owner.
+    ///
+    /// Emits `OwnerRemoval` and `OwnerAddition` events.
+    fn _replace_owner(&mut self, owner: E::AccountId, new_owner: E::AccountId) -> bool {
+        if !self.is_owner(&owner)
+            || new_owner == E::AccountId::default()
+            || self.is_owner(&new_owner)
+        {
+            return false
+        }
+
+        Storage::<E, Data<E>>::get_mut(self).replace_owner(&owner, new_owner.clone());
+
+        self.emit_event_owner_removal(owner);
+        self.emit_event_owner_addition(new_owner);
+
+        true
+    }
+
+    /// Change the number of the confirmations required without access
+    /// restriction, returns false without any change if the `threshold` is
+    /// invalid for the current owners.
+    ///
+    /// Emits a `ThresholdChange` event.
+    fn _change_threshold(&mut self, threshold: u32) -> bool {
+        if !_is_valid_requirement(self.owners().len() as u32, threshold) {
+            return false
+        }
+
+        Storage::<E, Data<E>>::get_mut(self).set_threshold(threshold);
+        self.emit_event_threshold_change(threshold);
+
+        true
+    }
+}
+
+/// Returns whether the `threshold` is valid for `owner_count` owners.
+fn _is_valid_requirement(owner_count: u32, threshold: u32) -> bool {
+    owner_count <= MAX_OWNER_COUNT && threshold != 0 && threshold <= owner_count
+}
+
+/// Panic if the `threshold` is invalid for `owner_count` owners.
+fn _ensure_valid_requirement(owner_count: u32, threshold: u32) {
+    assert!(owner_count <= MAX_OWNER_COUNT, "Multisig: too many owners");
+    assert!(
+        threshold != 0 && threshold <= owner_count,
+        "Multisig: invalid threshold"
+    );
+}
+
+impl<E: Env, T: EventEmit<E> + Storage<E, Data<E>> + Context<E>> Impl<E> for T {}
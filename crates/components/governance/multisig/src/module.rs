@@ -0,0 +1,153 @@
+use crate::types::{
+    Transaction,
+    TransactionId,
+};
+use ink_prelude::vec::Vec;
+pub use metis_lang::Env;
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    collections::HashMap as StorageHashMap,
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The Data of multisig component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E>
+where
+    E: Env,
+{
+    /// The owners of the wallet
+    pub owners: Lazy<Vec<E::AccountId>>,
+    /// The number of the confirmations required to execute a transaction
+    pub threshold: Lazy<u32>,
+    /// The count of the submitted transactions
+    pub transaction_count: Lazy<TransactionId>,
+    /// Mapping from transaction id to the transaction
+    pub transactions:
+        StorageHashMap<TransactionId, Transaction<E::AccountId, E::Balance>>,
+    /// Mapping from (transaction id, owner) to whether the owner confirmed
+    pub confirmations: StorageHashMap<(TransactionId, E::AccountId), bool>,
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            owners: Lazy::new(Vec::new()),
+            threshold: Lazy::new(0),
+            transaction_count: Lazy::new(0),
+            transactions: StorageHashMap::new(),
+            confirmations: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    /// Get the owners of the wallet
+    pub fn owners(&self) -> &Vec<E::AccountId> {
+        &self.owners
+    }
+
+    /// Returns whether the `account` is an owner
+    pub fn is_owner(&self, account: &E::AccountId) -> bool {
+        self.owners.contains(account)
+    }
+
+    /// Add an owner
+    pub fn add_owner(&mut self, owner: E::AccountId) {
+        self.owners.push(owner);
+    }
+
+    /// Remove an owner
+    pub fn remove_owner(&mut self, owner: &E::AccountId) {
+        self.owners.retain(|o| o != owner);
+    }
+
+    /// Replace the `owner` by `new_owner` at the same position
+    pub fn replace_owner(&mut self, owner: &E::AccountId, new_owner: E::AccountId) {
+        if let Some(o) = self.owners.iter_mut().find(|o| *o == owner) {
+            *o = new_owner;
+        }
+    }
+
+    /// Get the number of the confirmations required
+    pub fn threshold(&self) -> u32 {
+        *self.threshold
+    }
+
+    /// Set the number of the confirmations required
+    pub fn set_threshold(&mut self, threshold: u32) {
+        Lazy::set(&mut self.threshold, threshold);
+    }
+
+    /// Get the count of the submitted transactions
+    pub fn transaction_count(&self) -> TransactionId {
+        *self.transaction_count
+    }
+
+    /// Get the transaction by `id`
+    pub fn transaction(
+        &self,
+        id: TransactionId,
+    ) -> Option<&Transaction<E::AccountId, E::Balance>> {
+        self.transactions.get(&id)
+    }
+
+    /// Add a transaction, returns the id of it
+    pub fn add_transaction(
+        &mut self,
+        transaction: Transaction<E::AccountId, E::Balance>,
+    ) -> TransactionId {
+        let id = self.transaction_count();
+        self.transactions.insert(id, transaction);
+        Lazy::set(&mut self.transaction_count, id + 1);
+
+        id
+    }
+
+    /// Set whether the transaction has been executed
+    pub fn set_executed(&mut self, id: TransactionId, executed: bool) {
+        if let Some(transaction) = self.transactions.get_mut(&id) {
+            transaction.executed = executed;
+        }
+    }
+
+    /// Returns whether the `owner` confirmed the transaction
+    pub fn is_confirmed_by(&self, id: TransactionId, owner: &E::AccountId) -> bool {
+        self.confirmations
+            .get(&(id, owner.clone()))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Set whether the `owner` confirmed the transaction
+    pub fn set_confirmation(
+        &mut self,
+        id: TransactionId,
+        owner: &E::AccountId,
+        confirmed: bool,
+    ) {
+        if confirmed {
+            self.confirmations.insert((id, owner.clone()), true);
+        } else {
+            self.confirmations.take(&(id, owner.clone()));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use ink_prelude::vec::Vec;
+use scale::{
+    Decode,
+    Encode,
+};
+
+use ink_storage::traits::{
+    PackedLayout,
+    SpreadLayout,
+};
+
+#[cfg(feature = "std")]
+use ink_storage::traits::StorageLayout;
+
+#[cfg(feature = "std")]
+use scale_info::TypeInfo;
+
+/// The id of the transactions, by the order of submission.
+pub type TransactionId = u32;
+
+/// A transaction submitted to the multisig wallet.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo, StorageLayout))]
+#[derive(SpreadLayout, PackedLayout)]
+pub struct Transaction<AccountId, Balance> {
+    /// The account called by the transaction.
+    pub target: AccountId,
+    /// The selector of the called message, `None` for a plain transfer of
+    /// the native balance.
+    pub selector: Option<[u8; 4]>,
+    /// The encoded args of the called message.
+    pub input: Vec<u8>,
+    /// The native balance transferred by the transaction.
+    pub value: Balance,
+    /// Whether the transaction has been executed successfully.
+    pub executed: bool,
+}
@@ -0,0 +1,161 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod contract {
+    use ink_prelude::vec::Vec;
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_multisig as multisig;
+    pub use metis_multisig::{
+        Transaction,
+        TransactionId,
+    };
+
+    #[ink(storage)]
+    #[import(multisig)]
+    pub struct Multisig {
+        multisig: multisig::Data<Multisig>,
+    }
+
+    #[ink(event)]
+    #[metis(multisig)]
+    pub struct Submission {
+        #[ink(topic)]
+        pub transaction_id: TransactionId,
+    }
+
+    #[ink(event)]
+    #[metis(multisig)]
+    pub struct Confirmation {
+        #[ink(topic)]
+        pub sender: AccountId,
+        #[ink(topic)]
+        pub transaction_id: TransactionId,
+    }
+
+    #[ink(event)]
+    #[metis(multisig)]
+    pub struct Revocation {
+        #[ink(topic)]
+        pub sender: AccountId,
+        #[ink(topic)]
+        pub transaction_id: TransactionId,
+    }
+
+    #[ink(event)]
+    #[metis(multisig)]
+    pub struct Execution {
+        #[ink(topic)]
+        pub transaction_id: TransactionId,
+    }
+
+    #[ink(event)]
+    #[metis(multisig)]
+    pub struct ExecutionFailure {
+        #[ink(topic)]
+        pub transaction_id: TransactionId,
+    }
+
+    #[ink(event)]
+    #[metis(multisig)]
+    pub struct OwnerAddition {
+        #[ink(topic)]
+        pub owner: AccountId,
+    }
+
+    #[ink(event)]
+    #[metis(multisig)]
+    pub struct OwnerRemoval {
+        #[ink(topic)]
+        pub owner: AccountId,
+    }
+
+    #[ink(event)]
+    #[metis(multisig)]
+    pub struct ThresholdChange {
+        pub threshold: u32,
+    }
+
+    impl Multisig {
+        #[ink(constructor)]
+        pub fn new(owners: Vec<AccountId>, threshold: u32) -> Self {
+            let mut instance = Self {
+                multisig: multisig::Data::new(),
+            };
+
+            multisig::Impl::init(&mut instance, owners, threshold);
+            instance
+        }
+
+        #[ink(message)]
+        pub fn owners(&self) -> Vec<AccountId> {
+            multisig::Impl::owners(self)
+        }
+
+        #[ink(message)]
+        pub fn is_owner(&self, account: AccountId) -> bool {
+            multisig::Impl::is_owner(self, &account)
+        }
+
+        #[ink(message)]
+        pub fn threshold(&self) -> u32 {
+            multisig::Impl::threshold(self)
+        }
+
+        #[ink(message)]
+        pub fn transaction_count(&self) -> TransactionId {
+            multisig::Impl::transaction_count(self)
+        }
+
+        #[ink(message)]
+        pub fn transaction(
+            &self,
+            id: TransactionId,
+        ) -> Option<Transaction<AccountId, Balance>> {
+            multisig::Impl::transaction(self, id)
+        }
+
+        #[ink(message)]
+        pub fn is_confirmed_by(&self, id: TransactionId, owner: AccountId) -> bool {
+            multisig::Impl::is_confirmed_by(self, id, &owner)
+        }
+
+        #[ink(message)]
+        pub fn confirmation_count(&self, id: TransactionId) -> u32 {
+            multisig::Impl::confirmation_count(self, id)
+        }
+
+        #[ink(message)]
+        pub fn is_confirmed(&self, id: TransactionId) -> bool {
+            multisig::Impl::is_confirmed(self, id)
+        }
+
+        #[ink(message)]
+        pub fn submit_transaction(
+            &mut self,
+            target: AccountId,
+            selector: Option<[u8; 4]>,
+            input: Vec<u8>,
+            value: Balance,
+        ) -> TransactionId {
+            multisig::Impl::submit_transaction(self, target, selector, input, value)
+        }
+
+        #[ink(message)]
+        pub fn confirm(&mut self, id: TransactionId) {
+            multisig::Impl::confirm(self, id)
+        }
+
+        #[ink(message)]
+        pub fn revoke_confirmation(&mut self, id: TransactionId) {
+            multisig::Impl::revoke_confirmation(self, id)
+        }
+
+        #[ink(message)]
+        pub fn execute(&mut self, id: TransactionId) {
+            multisig::Impl::execute(self, id)
+        }
+    }
+}
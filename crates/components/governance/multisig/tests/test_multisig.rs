@@ -0,0 +1,535 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod basic;
+}
+
+mod multisig_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::vec::Vec;
+    use metis_lang::mock;
+    use metis_test::*;
+    use mocks::basic::contract::*;
+    use scale::Encode;
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    const SELECTOR: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+
+    fn init() -> Multisig {
+        let accounts = default_accounts();
+
+        Multisig::new(vec![accounts.alice, accounts.bob, accounts.charlie], 2)
+    }
+
+    /// Register a target at `account` which records the calls.
+    fn mock_target(account: AccountId) -> Rc<RefCell<Vec<mock::MockCallInfo>>> {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+
+        let target_calls = calls.clone();
+        mock::register(account, move |call| {
+            target_calls.borrow_mut().push(call.clone());
+            Ok(Vec::new())
+        });
+
+        calls
+    }
+
+    /// Submit a transaction by alice and confirm by bob.
+    fn submit_confirmed(
+        multisig: &mut Multisig,
+        target: AccountId,
+        selector: Option<[u8; 4]>,
+        input: Vec<u8>,
+        value: Balance,
+    ) -> TransactionId {
+        let accounts = default_accounts();
+
+        let id = call_as(accounts.alice, || {
+            multisig.submit_transaction(target, selector, input, value)
+        });
+        call_as(accounts.bob, || multisig.confirm(id));
+        assert!(multisig.is_confirmed(id));
+
+        id
+    }
+
+    fn last_event<T: scale::Decode>() -> T {
+        let events = get_emitted_events();
+        decode_event(events.last().expect("should have events"))
+            .expect("encountered unexpected event kind")
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let accounts = default_accounts();
+        let multisig = init();
+
+        assert_eq!(
+            multisig.owners(),
+            vec![accounts.alice, accounts.bob, accounts.charlie]
+        );
+        assert!(multisig.is_owner(accounts.bob));
+        assert!(!multisig.is_owner(accounts.django));
+        assert_eq!(multisig.threshold(), 2);
+        assert_eq!(multisig.transaction_count(), 0);
+
+        let emitted_events = get_emitted_events();
+        assert_eq!(emitted_events.len(), 4);
+        let ThresholdChange { threshold } =
+            decode_event(&emitted_events[3]).expect("should be a ThresholdChange");
+        assert_eq!(threshold, 2);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: invalid threshold")]
+    fn init_with_zero_threshold_should_panic() {
+        let accounts = default_accounts();
+
+        Multisig::new(vec![accounts.alice, accounts.bob], 0);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: invalid threshold")]
+    fn init_with_threshold_exceeds_owners_should_panic() {
+        let accounts = default_accounts();
+
+        Multisig::new(vec![accounts.alice, accounts.bob], 3);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: owner already exists")]
+    fn init_with_duplicate_owner_should_panic() {
+        let accounts = default_accounts();
+
+        Multisig::new(vec![accounts.alice, accounts.alice], 1);
+    }
+
+    #[ink::test]
+    fn submit_transaction_works() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id =
+            multisig.submit_transaction(accounts.django, Some(SELECTOR), vec![0x01], 10);
+
+        assert_eq!(id, 0);
+        assert_eq!(multisig.transaction_count(), 1);
+        assert_eq!(
+            multisig.transaction(id),
+            Some(Transaction {
+                target: accounts.django,
+                selector: Some(SELECTOR),
+                input: vec![0x01],
+                value: 10,
+                executed: false,
+            })
+        );
+        assert!(multisig.is_confirmed_by(id, accounts.alice));
+        assert_eq!(multisig.confirmation_count(id), 1);
+        assert!(!multisig.is_confirmed(id));
+
+        let Confirmation {
+            sender,
+            transaction_id,
+        } = last_event();
+        assert_eq!((sender, transaction_id), (accounts.alice, id));
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: caller is not an owner")]
+    fn submit_transaction_by_not_owner_should_panic() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        call_as(accounts.django, || {
+            multisig.submit_transaction(accounts.django, None, Vec::new(), 0)
+        });
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: transaction already confirmed")]
+    fn confirm_twice_should_panic() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id = multisig.submit_transaction(accounts.django, None, Vec::new(), 0);
+        multisig.confirm(id);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: transaction not exists")]
+    fn confirm_not_exists_should_panic() {
+        let mut multisig = init();
+
+        multisig.confirm(1);
+    }
+
+    #[ink::test]
+    fn revoke_confirmation_works() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id = multisig.submit_transaction(accounts.django, None, Vec::new(), 0);
+        call_as(accounts.bob, || multisig.confirm(id));
+        assert_eq!(multisig.confirmation_count(id), 2);
+
+        call_as(accounts.bob, || multisig.revoke_confirmation(id));
+
+        assert!(!multisig.is_confirmed_by(id, accounts.bob));
+        assert_eq!(multisig.confirmation_count(id), 1);
+        assert!(!multisig.is_confirmed(id));
+
+        let Revocation {
+            sender,
+            transaction_id,
+        } = last_event();
+        assert_eq!((sender, transaction_id), (accounts.bob, id));
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: transaction not confirmed")]
+    fn revoke_not_confirmed_should_panic() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id = multisig.submit_transaction(accounts.django, None, Vec::new(), 0);
+        call_as(accounts.bob, || multisig.revoke_confirmation(id));
+    }
+
+    #[ink::test]
+    fn execute_call_works() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+        let calls = mock_target(accounts.django);
+
+        let input = (accounts.eve, 100_u128).encode();
+        let id = submit_confirmed(
+            &mut multisig,
+            accounts.django,
+            Some(SELECTOR),
+            input.clone(),
+            0,
+        );
+
+        call_as(accounts.charlie, || multisig.execute(id));
+
+        assert!(multisig.transaction(id).unwrap().executed);
+
+        let calls = calls.borrow();
+        assert_eq!(calls.len(), 1, "target should be called once");
+        assert_eq!(calls[0].caller, contract_id());
+        assert_eq!(calls[0].selector, SELECTOR);
+        assert_eq!(calls[0].input, input);
+
+        let Execution { transaction_id } = last_event();
+        assert_eq!(transaction_id, id);
+    }
+
+    #[ink::test]
+    fn execute_call_with_value_works() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+        let calls = mock_target(accounts.django);
+
+        set_balance(contract_id(), 1000);
+        let django_balance = get_balance(accounts.django);
+
+        let id = submit_confirmed(
+            &mut multisig,
+            accounts.django,
+            Some(SELECTOR),
+            Vec::new(),
+            100,
+        );
+        multisig.execute(id);
+
+        assert_eq!(calls.borrow()[0].transferred_value, 100);
+        assert_eq!(get_balance(contract_id()), 900);
+        assert_eq!(get_balance(accounts.django), django_balance + 100);
+    }
+
+    #[ink::test]
+    fn execute_transfer_works() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        set_balance(contract_id(), 1000);
+        set_balance(accounts.eve, 0);
+
+        let id = submit_confirmed(&mut multisig, accounts.eve, None, Vec::new(), 100);
+        multisig.execute(id);
+
+        assert!(multisig.transaction(id).unwrap().executed);
+        assert_eq!(get_balance(contract_id()), 900);
+        assert_eq!(get_balance(accounts.eve), 100);
+    }
+
+    #[ink::test]
+    fn execute_failure_can_retry() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+        mock::register_revert(accounts.django);
+
+        let id = submit_confirmed(
+            &mut multisig,
+            accounts.django,
+            Some(SELECTOR),
+            Vec::new(),
+            0,
+        );
+        multisig.execute(id);
+
+        assert!(!multisig.transaction(id).unwrap().executed);
+        let ExecutionFailure { transaction_id } = last_event();
+        assert_eq!(transaction_id, id);
+
+        let calls = mock_target(accounts.django);
+        multisig.execute(id);
+
+        assert!(multisig.transaction(id).unwrap().executed);
+        assert_eq!(calls.borrow().len(), 1);
+    }
+
+    #[ink::test]
+    fn execute_call_to_account_without_code_fails() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id = submit_confirmed(
+            &mut multisig,
+            accounts.django,
+            Some(SELECTOR),
+            Vec::new(),
+            0,
+        );
+        multisig.execute(id);
+
+        assert!(!multisig.transaction(id).unwrap().executed);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: transaction not confirmed")]
+    fn execute_not_confirmed_should_panic() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id = multisig.submit_transaction(accounts.django, None, Vec::new(), 0);
+        multisig.execute(id);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: transaction already executed")]
+    fn execute_twice_should_panic() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+        mock_target(accounts.django);
+
+        let id = submit_confirmed(
+            &mut multisig,
+            accounts.django,
+            Some(SELECTOR),
+            Vec::new(),
+            0,
+        );
+        multisig.execute(id);
+        multisig.execute(id);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Multisig: caller is not an owner")]
+    fn execute_by_not_owner_should_panic() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id = submit_confirmed(&mut multisig, accounts.django, None, Vec::new(), 0);
+        call_as(accounts.django, || multisig.execute(id));
+    }
+
+    #[ink::test]
+    fn add_owner_by_self_call_works() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id = submit_confirmed(
+            &mut multisig,
+            contract_id(),
+            Some(metis_lang::selector_id!(add_owner)),
+            accounts.django.encode(),
+            0,
+        );
+        multisig.execute(id);
+
+        assert!(multisig.transaction(id).unwrap().executed);
+        assert!(multisig.is_owner(accounts.django));
+        assert_eq!(multisig.owners().len(), 4);
+
+        let emitted_events = get_emitted_events();
+        let OwnerAddition { owner } =
+            decode_event(&emitted_events[emitted_events.len() - 2])
+                .expect("should be an OwnerAddition");
+        assert_eq!(owner, accounts.django);
+    }
+
+    #[ink::test]
+    fn remove_owner_by_self_call_works() {
+        let accounts = default_accounts();
+        let mut multisig =
+            Multisig::new(vec![accounts.alice, accounts.bob, accounts.charlie], 3);
+
+        let id = multisig.submit_transaction(
+            contract_id(),
+            Some(metis_lang::selector_id!(remove_owner)),
+            accounts.charlie.encode(),
+            0,
+        );
+        call_as(accounts.bob, || multisig.confirm(id));
+        assert!(!multisig.is_confirmed(id));
+        call_as(accounts.charlie, || multisig.confirm(id));
+        multisig.execute(id);
+
+        assert!(!multisig.is_owner(accounts.charlie));
+        assert_eq!(multisig.owners(), vec![accounts.alice, accounts.bob]);
+        assert_eq!(multisig.threshold(), 2, "threshold should be decreased");
+    }
+
+    #[ink::test]
+    fn replace_owner_by_self_call_works() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        // bob confirmed the pending transaction
+        let pending =
+            submit_confirmed(&mut multisig, accounts.django, None, Vec::new(), 0);
+        assert_eq!(multisig.confirmation_count(pending), 2);
+
+        let id = submit_confirmed(
+            &mut multisig,
+            contract_id(),
+            Some(metis_lang::selector_id!(replace_owner)),
+            (accounts.bob, accounts.eve).encode(),
+            0,
+        );
+        multisig.execute(id);
+
+        assert_eq!(
+            multisig.owners(),
+            vec![accounts.alice, accounts.eve, accounts.charlie]
+        );
+        assert_eq!(
+            multisig.confirmation_count(pending),
+            1,
+            "confirmation of removed owner should not be counted"
+        );
+    }
+
+    #[ink::test]
+    fn change_threshold_by_self_call_works() {
+        let mut multisig = init();
+
+        let id = submit_confirmed(
+            &mut multisig,
+            contract_id(),
+            Some(metis_lang::selector_id!(change_threshold)),
+            3_u32.encode(),
+            0,
+        );
+        multisig.execute(id);
+
+        assert_eq!(multisig.threshold(), 3);
+
+        let ThresholdChange { threshold } =
+            decode_event(&get_emitted_events()[get_emitted_events().len() - 2])
+                .expect("should be a ThresholdChange");
+        assert_eq!(threshold, 3);
+    }
+
+    #[ink::test]
+    fn self_call_with_unknown_selector_fails() {
+        let mut multisig = init();
+
+        let id =
+            submit_confirmed(&mut multisig, contract_id(), Some(SELECTOR), Vec::new(), 0);
+        multisig.execute(id);
+
+        assert!(!multisig.transaction(id).unwrap().executed);
+        let ExecutionFailure { transaction_id } = last_event();
+        assert_eq!(transaction_id, id);
+    }
+
+    #[ink::test]
+    fn change_threshold_exceeds_owners_fails_and_can_retry() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id = submit_confirmed(
+            &mut multisig,
+            contract_id(),
+            Some(metis_lang::selector_id!(change_threshold)),
+            4_u32.encode(),
+            0,
+        );
+        multisig.execute(id);
+
+        assert!(!multisig.transaction(id).unwrap().executed);
+        assert_eq!(multisig.threshold(), 2);
+        let ExecutionFailure { transaction_id } = last_event();
+        assert_eq!(transaction_id, id);
+
+        let add = submit_confirmed(
+            &mut multisig,
+            contract_id(),
+            Some(metis_lang::selector_id!(add_owner)),
+            accounts.django.encode(),
+            0,
+        );
+        multisig.execute(add);
+        multisig.execute(id);
+
+        assert!(multisig.transaction(id).unwrap().executed);
+        assert_eq!(multisig.threshold(), 4);
+    }
+
+    #[ink::test]
+    fn add_existing_owner_by_self_call_fails() {
+        let accounts = default_accounts();
+        let mut multisig = init();
+
+        let id = submit_confirmed(
+            &mut multisig,
+            contract_id(),
+            Some(metis_lang::selector_id!(add_owner)),
+            accounts.bob.encode(),
+            0,
+        );
+        multisig.execute(id);
+
+        assert!(!multisig.transaction(id).unwrap().executed);
+        assert_eq!(multisig.owners().len(), 3);
+        let ExecutionFailure { transaction_id } = last_event();
+        assert_eq!(transaction_id, id);
+    }
+
+    #[ink::test]
+    fn remove_last_owner_by_self_call_fails() {
+        let accounts = default_accounts();
+        let mut multisig = Multisig::new(vec![accounts.alice], 1);
+
+        let id = multisig.submit_transaction(
+            contract_id(),
+            Some(metis_lang::selector_id!(remove_owner)),
+            accounts.alice.encode(),
+            0,
+        );
+        multisig.execute(id);
+
+        assert!(!multisig.transaction(id).unwrap().executed);
+        assert!(multisig.is_owner(accounts.alice));
+        let ExecutionFailure { transaction_id } = last_event();
+        assert_eq!(transaction_id, id);
+    }
+}
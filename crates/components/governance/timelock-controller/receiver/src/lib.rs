@@ -1,26 +1,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use ink_lang as ink;
+use ink_prelude::vec::Vec;
 
-#[ink::contract]
-mod stub {
-    use ink_prelude::vec::Vec;
+/// The messages of the target called by the timelock controller
+#[metis_lang::stub_contract(Receiver)]
+pub trait CallReceiver {
+    fn default() -> Self;
 
-    #[ink(storage)]
-    #[metis_lang::stub]
-    pub struct Receiver {}
-
-    impl Receiver {
-        #[ink(constructor)]
-        pub fn default() -> Self {
-            unimplemented!()
-        }
-
-        #[ink(message, payable)]
-        pub fn on_call(&mut self, _operator: AccountId, _data: Vec<u8>) -> bool {
-            unimplemented!()
-        }
-    }
+    /// Execute the call from the timelock, returns whether it succeeded.
+    #[ink(payable)]
+    fn on_call(&mut self, operator: AccountId, data: Vec<u8>) -> bool;
 }
-
-pub use stub::Receiver;
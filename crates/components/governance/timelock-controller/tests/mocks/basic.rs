@@ -0,0 +1,237 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod contract {
+    use access_control::RoleId;
+    use ink_prelude::vec::Vec;
+    use metis_access_control as access_control;
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_timelock_controller as timelock_controller;
+    pub use metis_timelock_controller::{
+        Error,
+        Result,
+    };
+
+    #[ink(storage)]
+    #[import(timelock_controller, access_control)]
+    pub struct TimelockController {
+        timelock_controller: timelock_controller::Data<TimelockController>,
+        access_control: access_control::Data<TimelockController>,
+    }
+
+    impl timelock_controller::Impl<TimelockController> for TimelockController {}
+
+    #[ink(event)]
+    #[metis(timelock_controller)]
+    pub struct CallScheduled {
+        #[ink(topic)]
+        pub id: [u8; 32],
+        pub target: AccountId,
+        pub value: Balance,
+        pub data: Vec<u8>,
+        pub predecessor: Option<[u8; 32]>,
+        pub delay: Timestamp,
+    }
+
+    #[ink(event)]
+    #[metis(timelock_controller)]
+    pub struct CallExecuted {
+        #[ink(topic)]
+        pub id: [u8; 32],
+        pub target: AccountId,
+        pub value: Balance,
+        pub data: Vec<u8>,
+    }
+
+    #[ink(event)]
+    #[metis(timelock_controller)]
+    pub struct Cancelled {
+        #[ink(topic)]
+        pub id: [u8; 32],
+    }
+
+    #[ink(event)]
+    #[metis(timelock_controller)]
+    pub struct MinDelayChange {
+        pub old_duration: Timestamp,
+        pub new_duration: Timestamp,
+    }
+
+    #[ink(event)]
+    #[metis(access_control)]
+    pub struct RoleAdminChanged {
+        #[ink(topic)]
+        pub role: RoleId,
+        #[ink(topic)]
+        pub previous_admin_role: Option<RoleId>,
+        #[ink(topic)]
+        pub new_admin_role: RoleId,
+    }
+
+    #[ink(event)]
+    #[metis(access_control)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        pub role: RoleId,
+        #[ink(topic)]
+        pub account: AccountId,
+        #[ink(topic)]
+        pub sender: AccountId,
+    }
+
+    #[ink(event)]
+    #[metis(access_control)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        pub role: RoleId,
+        #[ink(topic)]
+        pub account: AccountId,
+        #[ink(topic)]
+        pub sender: AccountId,
+    }
+
+    // impl
+    impl TimelockController {
+        #[ink(constructor)]
+        pub fn new(
+            min_delay: Timestamp,
+            proposers: Vec<AccountId>,
+            executors: Vec<AccountId>,
+        ) -> Self {
+            let mut instance = Self {
+                timelock_controller: timelock_controller::Data::new(),
+                access_control: access_control::Data::new(),
+            };
+
+            timelock_controller::Impl::init(
+                &mut instance,
+                min_delay,
+                proposers,
+                executors,
+            );
+            instance
+        }
+
+        #[ink(message)]
+        pub fn has_role(&self, role: RoleId, account: AccountId) -> bool {
+            access_control::Impl::has_role(self, role, account)
+        }
+
+        #[ink(message)]
+        pub fn get_role_admin(&self, role: RoleId) -> Option<RoleId> {
+            access_control::Impl::get_role_admin(self, role)
+        }
+
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: RoleId, account: AccountId) {
+            access_control::Impl::grant_role(self, role, account)
+        }
+
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: RoleId, account: AccountId) {
+            access_control::Impl::revoke_role(self, role, account)
+        }
+
+        #[ink(message)]
+        pub fn renounce_role(&mut self, role: RoleId, account: AccountId) {
+            access_control::Impl::renounce_role(self, role, account)
+        }
+
+        #[ink(message)]
+        pub fn is_operation(&self, id: [u8; 32]) -> bool {
+            timelock_controller::Impl::is_operation(self, &id)
+        }
+
+        #[ink(message)]
+        pub fn is_operation_pending(&self, id: [u8; 32]) -> bool {
+            timelock_controller::Impl::is_operation_pending(self, &id)
+        }
+
+        #[ink(message)]
+        pub fn is_operation_ready(&self, id: [u8; 32]) -> bool {
+            timelock_controller::Impl::is_operation_ready(self, &id)
+        }
+
+        #[ink(message)]
+        pub fn is_operation_done(&self, id: [u8; 32]) -> bool {
+            timelock_controller::Impl::is_operation_done(self, &id)
+        }
+
+        #[ink(message)]
+        pub fn get_timestamp(&self, id: [u8; 32]) -> Timestamp {
+            timelock_controller::Impl::get_timestamp(self, &id)
+        }
+
+        #[ink(message)]
+        pub fn get_min_delay(&self) -> Timestamp {
+            timelock_controller::Impl::get_min_delay(self)
+        }
+
+        #[ink(message)]
+        pub fn hash_operation(
+            &self,
+            target: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+            predecessor: Option<[u8; 32]>,
+            salt: [u8; 32],
+        ) -> [u8; 32] {
+            timelock_controller::Impl::hash_operation(
+                self,
+                &target,
+                &value,
+                &data,
+                &predecessor,
+                &salt,
+            )
+        }
+
+        #[ink(message)]
+        pub fn schedule(
+            &mut self,
+            target: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+            predecessor: Option<[u8; 32]>,
+            salt: [u8; 32],
+            delay: Timestamp,
+        ) {
+            timelock_controller::Impl::schedule(
+                self,
+                target,
+                value,
+                data,
+                predecessor,
+                salt,
+                delay,
+            )
+        }
+
+        #[ink(message)]
+        pub fn cancel(&mut self, id: [u8; 32]) {
+            timelock_controller::Impl::cancel(self, id)
+        }
+
+        #[ink(message, payable)]
+        pub fn execute(
+            &mut self,
+            target: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+            predecessor: Option<[u8; 32]>,
+            salt: [u8; 32],
+        ) {
+            timelock_controller::Impl::execute(
+                self,
+                target,
+                value,
+                data,
+                predecessor,
+                salt,
+            )
+        }
+    }
+}
@@ -0,0 +1,34 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod receiver {
+    use ink_prelude::vec::Vec;
+
+    /// The operator, the data and the transferred value of a call.
+    pub type Call = (AccountId, Vec<u8>, Balance);
+
+    /// A target contract which records the last call from the timelock.
+    #[ink(storage)]
+    pub struct Target {
+        last_call: Option<Call>,
+    }
+
+    impl Target {
+        #[ink(constructor)]
+        pub fn default() -> Self {
+            Self { last_call: None }
+        }
+
+        #[ink(message)]
+        pub fn last_call(&self) -> Option<Call> {
+            self.last_call.clone()
+        }
+
+        #[ink(message, payable)]
+        pub fn on_call(&mut self, operator: AccountId, data: Vec<u8>) -> bool {
+            self.last_call = Some((operator, data, Self::env().transferred_balance()));
+
+            true
+        }
+    }
+}
@@ -0,0 +1,201 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod basic;
+}
+
+mod timelock_controller_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::vec::Vec;
+    use metis_lang::mock;
+    use metis_test::*;
+    use metis_timelock_controller::{
+        EXECUTOR_ROLE,
+        PROPOSER_ROLE,
+    };
+    use metis_timelock_controller_receiver::{
+        CallReceiver,
+        Receiver,
+    };
+    use mocks::basic::contract::TimelockController;
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    const MIN_DELAY: Timestamp = 10;
+    const SALT: [u8; 32] = [0x01; 32];
+
+    /// A target contract which records the calls from the timelock.
+    #[derive(Default)]
+    struct MockTarget {
+        calls: Vec<(AccountId, Vec<u8>, Balance)>,
+    }
+
+    impl CallReceiver for MockTarget {
+        fn on_call(&mut self, operator: AccountId, data: Vec<u8>) -> bool {
+            let value = ink_env::transferred_balance::<ink_env::DefaultEnvironment>()
+                .expect("Cannot get transferred balance");
+            self.calls.push((operator, data, value));
+
+            true
+        }
+    }
+
+    fn init() -> TimelockController {
+        let accounts = default_accounts();
+        let timelock = TimelockController::new(
+            MIN_DELAY,
+            vec![accounts.alice],
+            vec![accounts.alice],
+        );
+
+        assert!(timelock.has_role(PROPOSER_ROLE, accounts.alice));
+        assert!(timelock.has_role(EXECUTOR_ROLE, accounts.alice));
+
+        timelock
+    }
+
+    /// Schedule a call to `target` and wait until it is ready.
+    fn schedule_ready(
+        timelock: &mut TimelockController,
+        target: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> [u8; 32] {
+        timelock.schedule(target, value, data.clone(), None, SALT, MIN_DELAY);
+
+        let id = timelock.hash_operation(target, value, data, None, SALT);
+        assert!(timelock.is_operation_pending(id));
+
+        advance_after(timelock.get_timestamp(id));
+        assert!(timelock.is_operation_ready(id));
+
+        id
+    }
+
+    #[ink::test]
+    fn execute_call_works() {
+        let accounts = default_accounts();
+        let mut timelock = init();
+
+        let target = Rc::new(RefCell::new(MockTarget::default()));
+        Receiver::mock(accounts.django, target.clone());
+
+        let data = vec![0x01, 0x02, 0x03];
+        let id = schedule_ready(&mut timelock, accounts.django, 0, data.clone());
+
+        timelock.execute(accounts.django, 0, data.clone(), None, SALT);
+
+        assert!(timelock.is_operation_done(id));
+        assert_eq!(
+            target.borrow().calls,
+            vec![(accounts.alice, data, 0)],
+            "target should be called by the timelock"
+        );
+    }
+
+    #[ink::test]
+    fn execute_call_with_value_works() {
+        let accounts = default_accounts();
+        let mut timelock = init();
+
+        let target = Rc::new(RefCell::new(MockTarget::default()));
+        Receiver::mock(accounts.django, target.clone());
+
+        set_balance(contract_id(), 1000);
+        let django_balance = get_balance(accounts.django);
+
+        let id = schedule_ready(&mut timelock, accounts.django, 100, Vec::new());
+
+        timelock.execute(accounts.django, 100, Vec::new(), None, SALT);
+
+        assert!(timelock.is_operation_done(id));
+        assert_eq!(get_balance(contract_id()), 900);
+        assert_eq!(get_balance(accounts.django), django_balance + 100);
+        assert_eq!(
+            target.borrow().calls,
+            vec![(accounts.alice, Vec::new(), 100)],
+            "target should receive the value"
+        );
+    }
+
+    #[ink::test]
+    fn execute_call_with_predecessor_works() {
+        let accounts = default_accounts();
+        let mut timelock = init();
+
+        let target = Rc::new(RefCell::new(MockTarget::default()));
+        Receiver::mock(accounts.django, target.clone());
+
+        let first = schedule_ready(&mut timelock, accounts.django, 0, vec![0x01]);
+        timelock.execute(accounts.django, 0, vec![0x01], None, SALT);
+        assert!(timelock.is_operation_done(first));
+
+        timelock.schedule(accounts.django, 0, vec![0x02], Some(first), SALT, MIN_DELAY);
+        let second =
+            timelock.hash_operation(accounts.django, 0, vec![0x02], Some(first), SALT);
+        advance_after(timelock.get_timestamp(second));
+
+        timelock.execute(accounts.django, 0, vec![0x02], Some(first), SALT);
+
+        assert!(timelock.is_operation_done(second));
+        assert_eq!(target.borrow().calls.len(), 2);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "TimelockController: underlying transaction reverted")]
+    fn execute_call_failed() {
+        let accounts = default_accounts();
+        let mut timelock = init();
+
+        mock::register(accounts.django, |call| {
+            assert_eq!(call.selector, metis_lang::selector_id!(on_call));
+            Ok(scale::Encode::encode(&false))
+        });
+
+        schedule_ready(&mut timelock, accounts.django, 0, Vec::new());
+
+        timelock.execute(accounts.django, 0, Vec::new(), None, SALT);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "TimelockController: underlying transaction reverted")]
+    fn execute_call_reverted() {
+        let accounts = default_accounts();
+        let mut timelock = init();
+
+        mock::register_revert(accounts.django);
+
+        schedule_ready(&mut timelock, accounts.django, 0, Vec::new());
+
+        timelock.execute(accounts.django, 0, Vec::new(), None, SALT);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "TimelockController: underlying transaction reverted")]
+    fn execute_call_to_account_without_code() {
+        let accounts = default_accounts();
+        let mut timelock = init();
+
+        schedule_ready(&mut timelock, accounts.django, 0, Vec::new());
+
+        timelock.execute(accounts.django, 0, Vec::new(), None, SALT);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "TimelockController: operation is not ready")]
+    fn execute_call_not_ready() {
+        let accounts = default_accounts();
+        let mut timelock = init();
+
+        let target = Rc::new(RefCell::new(MockTarget::default()));
+        Receiver::mock(accounts.django, target);
+
+        timelock.schedule(accounts.django, 0, Vec::new(), None, SALT, MIN_DELAY);
+
+        timelock.execute(accounts.django, 0, Vec::new(), None, SALT);
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod receiver;
+}
+
+mod timelock_receiver_stub_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::vec::Vec;
+    use metis_lang::mock::check_messages;
+    use metis_test::*;
+    use metis_timelock_controller_receiver::{
+        CallReceiver,
+        Receiver,
+    };
+    use mocks::receiver::receiver::Target;
+
+    extern "Rust" {
+        fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+    }
+
+    /// The messages of the stub must be the messages of the real contract
+    /// with the same signatures.
+    impl CallReceiver for Target {
+        fn on_call(&mut self, operator: AccountId, data: Vec<u8>) -> bool {
+            Target::on_call(self, operator, data)
+        }
+    }
+
+    #[test]
+    fn stub_messages_should_match_contract() {
+        let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+            .expect("metadata should be serialized");
+
+        assert_eq!(check_messages(&metadata, Receiver::MESSAGES), Ok(()));
+    }
+
+    #[ink::test]
+    fn on_call_should_record_the_call() {
+        let accounts = default_accounts();
+        let mut target = Target::default();
+
+        set_caller_with_value(accounts.eve, 10);
+        assert!(CallReceiver::on_call(
+            &mut target,
+            accounts.alice,
+            Vec::from([0x01])
+        ));
+
+        assert_eq!(
+            target.last_call(),
+            Some((accounts.alice, Vec::from([0x01]), 10))
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! ERC20 facade of a token type of an ERC1155 contract.
+//!
+//! The wallets which only understand ERC20 can not display the balances of
+//! the ERC1155 tokens, this component exposes a single token type `id` of an
+//! underlying ERC1155 contract as an ERC20 compatible contract. The facade
+//! holds no balances itself, all of them are forwarded to the underlying by
+//! cross-contract calls:
+//!
+//! - `balance_of` returns the balance of `id` of the account in the
+//!   underlying.
+//! - `transfer` and `transfer_from` move the tokens of `id` by the
+//!   `safe_transfer_from` of the underlying, so the owner of the tokens must
+//!   approve the facade as its operator by `set_approval_for_all` first.
+//! - `approve` keeps the ERC20 allowances in the facade, as the ERC1155 has
+//!   no allowance of a token type, the allowance only limits the amount which
+//!   the spender can move by `transfer_from` of the facade.
+//!
+//! The `Transfer` and `Approval` events are emitted by the facade like an
+//! ERC20, but the transfers made directly by the underlying emit no event of
+//! the facade. As the ERC1155 has no supply of a token type, the facade has no
+//! `total_supply`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod module;
+
+use core::convert::TryFrom;
+use ink_lang::{
+    ForwardCall,
+    ForwardCallMut,
+};
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+pub use metis_lang::{
+    Context,
+    Env,
+    EnvAccess,
+    FromAccountId,
+    Storage,
+};
+
+pub use metis_erc20_types::{
+    Error,
+    Result,
+};
+pub use metis_erc721_types::TokenId;
+pub use module::Data;
+
+use metis_erc1155_stub::Erc1155Stub;
+
+/// The `EventEmit` impl the event emit api for erc1155 to erc20 facade component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emitted when `value` tokens are moved from one account (`from`) to
+    /// another (`to`) by the facade.
+    fn emit_event_transfer(
+        &mut self,
+        from: Option<E::AccountId>,
+        to: Option<E::AccountId>,
+        value: E::Balance,
+    );
+
+    /// Emitted when the allowance of a `spender` for an `owner` is set by
+    /// a call to {approve}. `value` is the new allowance.
+    fn emit_event_approval(
+        &mut self,
+        owner: E::AccountId,
+        spender: E::AccountId,
+        value: E::Balance,
+    );
+}
+
+/// The `Impl` define erc1155 to erc20 facade component impl funcs
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Context<E> {
+    /// Initialize the facade of the token type `id` of the `erc1155` contract.
+    fn init(
+        &mut self,
+        erc1155: E::AccountId,
+        id: TokenId,
+        name: String,
+        symbol: String,
+        decimals: u8,
+    ) {
+        assert!(
+            erc1155 != E::AccountId::default(),
+            "ERC1155ERC20: underlying is zero address"
+        );
+
+        self.get_mut().set_underlying(erc1155, id);
+        self.get_mut().set_symbols(name, symbol, decimals);
+    }
+
+    /// Returns the underlying erc1155 contract.
+    fn erc1155(&self) -> E::AccountId {
+        self.get().erc1155().clone()
+    }
+
+    /// Returns the token type id of the underlying exposed by the facade.
+    fn id(&self) -> TokenId {
+        self.get().id()
+    }
+
+    /// Returns the name of the token.
+    fn name(&self) -> String {
+        self.get().name().clone()
+    }
+
+    /// Returns the symbol of the token, usually a shorter version of the name.
+    fn symbol(&self) -> String {
+        self.get().symbol().clone()
+    }
+
+    /// Returns the number of decimals used to get its user representation.
+    fn decimals(&self) -> u8 {
+        self.get().decimals()
+    }
+
+    /// Returns the amount of the tokens of `id` owned by `account` in the
+    /// underlying.
+    fn balance_of(&self, account: E::AccountId) -> E::Balance {
+        let erc1155 = <Erc1155Stub as FromAccountId<E>>::from_account_id(self.erc1155());
+        let balance = erc1155
+            .call()
+            .balance_of(account.into(), self.id())
+            .fire()
+            .expect("ERC1155ERC20: get underlying balance error");
+
+        E::Balance::try_from(balance)
+            .unwrap_or_else(|_| panic!("ERC1155ERC20: underlying balance overflow"))
+    }
+
+    /// Returns the remaining number of tokens that `spender` will be
+    /// allowed to spend on behalf of `owner` through `transfer_from`. This is
+    /// zero by default.
+    fn allowance(&self, owner: E::AccountId, spender: E::AccountId) -> E::Balance {
+        self.get().allowance(owner, spender)
+    }
+
+    /// Moves `amount` tokens from the caller's account to `recipient`.
+    ///
+    /// Emits a `Transfer` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must approve the facade as its operator in the underlying.
+    fn transfer(&mut self, to: E::AccountId, value: E::Balance) -> Result<()> {
+        let caller = self._msg_sender();
+        self._transfer_from_to(caller, to, value)
+    }
+
+    /// Sets `amount` as the allowance of `spender` over the caller's tokens.
+    ///
+    /// Emits an `Approval` event.
+    fn approve(&mut self, spender: E::AccountId, amount: E::Balance) -> Result<()> {
+        let caller = self._msg_sender();
+        self._approve(caller, spender, amount)
+    }
+
+    /// Moves `amount` tokens from `from` to `to` using the allowance
+    /// mechanism. `amount` is deducted from the caller's allowance before the
+    /// call to the underlying, and restored if the transfer fails.
+    ///
+    /// Emits an `Approval` event and a `Transfer` event.
+    ///
+    /// Requirements:
+    ///
+    /// - `from` must approve the facade as its operator in the underlying.
+    fn transfer_from(
+        &mut self,
+        from: E::AccountId,
+        to: E::AccountId,
+        amount: E::Balance,
+    ) -> Result<()> {
+        let caller = self._msg_sender();
+
+        let current_allowance = self.get().allowance(from.clone(), caller.clone());
+        if current_allowance < amount {
+            return Err(Error::InsufficientAllowance)
+        }
+
+        // deduct the allowance before the call to the underlying, so a
+        // re-entrant `transfer_from` can not spend it again.
+        self._approve(from.clone(), caller.clone(), current_allowance - amount)?;
+
+        if let Err(err) = self._transfer_from_to(from.clone(), to, amount) {
+            self._approve(from, caller, current_allowance)?;
+            return Err(err)
+        }
+
+        Ok(())
+    }
+
+    /// The implementation of approve, for extensions call
+    fn _approve(
+        &mut self,
+        owner: E::AccountId,
+        spender: E::AccountId,
+        amount: E::Balance,
+    ) -> Result<()> {
+        let null_account = E::AccountId::default();
+
+        if owner == null_account || spender == null_account {
+            return Err(Error::AccountIsZero)
+        }
+
+        self.get_mut()
+            .set_allowance(owner.clone(), spender.clone(), amount);
+        self.emit_event_approval(owner, spender, amount);
+
+        Ok(())
+    }
+
+    /// Moves `amount` tokens of `id` from `sender` to `recipient` in the
+    /// underlying, by the facade as the operator of the `sender`.
+    ///
+    /// Emits a `Transfer` event.
+    ///
+    /// Requirements:
+    ///
+    /// - `sender` and `recipient` cannot be the zero address.
+    /// - `sender` must have a balance of at least `amount`.
+    /// - `sender` must approve the facade as its operator in the underlying.
+    fn _transfer_from_to(
+        &mut self,
+        sender: E::AccountId,
+        recipient: E::AccountId,
+        amount: E::Balance,
+    ) -> Result<()> {
+        let null_account = E::AccountId::default();
+
+        if sender == null_account || recipient == null_account {
+            return Err(Error::AccountIsZero)
+        }
+
+        if self.balance_of(sender.clone()) < amount {
+            return Err(Error::InsufficientBalance)
+        }
+
+        let mut erc1155 =
+            <Erc1155Stub as FromAccountId<E>>::from_account_id(self.erc1155());
+        let approved = erc1155
+            .call()
+            .is_approved_for_all(sender.clone().into(), Self::account_id().into())
+            .fire()
+            .expect("ERC1155ERC20: get underlying approval error");
+        assert!(approved, "ERC1155ERC20: facade is not approved as operator");
+
+        let res = erc1155
+            .call_mut()
+            .safe_transfer_from(
+                sender.clone().into(),
+                recipient.clone().into(),
+                self.id(),
+                amount.into(),
+                Vec::new(),
+            )
+            .fire();
+        assert!(
+            matches!(res, Ok(Ok(()))),
+            "ERC1155ERC20: underlying transfer error"
+        );
+
+        self.emit_event_transfer(Some(sender), Some(recipient), amount);
+
+        Ok(())
+    }
+}
+
+impl<E: Env, T: Storage<E, Data<E>> + EventEmit<E> + Context<E>> Impl<E> for T {}
@@ -0,0 +1,101 @@
+use ink_prelude::string::String;
+use metis_erc721_types::TokenId;
+pub use metis_lang::Env;
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    collections::HashMap as StorageHashMap,
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The Data of ERC1155 to ERC20 facade component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E: Env> {
+    /// The underlying erc1155 contract.
+    pub erc1155: Lazy<E::AccountId>,
+    /// The token type id of the underlying exposed by the facade.
+    pub id: Lazy<TokenId>,
+    /// Mapping of the token amount which an account is allowed to withdraw
+    /// from another account by the facade.
+    pub allowances: StorageHashMap<(E::AccountId, E::AccountId), E::Balance>,
+    /// Metadatas of the facade, by (decimals, name, symbol)
+    pub metadatas: Lazy<(u8, String, String)>,
+}
+
+impl<E: Env> Data<E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            erc1155: Lazy::default(),
+            id: Lazy::default(),
+            allowances: StorageHashMap::new(),
+            metadatas: Lazy::default(),
+        }
+    }
+}
+
+impl<E: Env> Data<E> {
+    /// Set the underlying erc1155 contract and the token type id.
+    pub fn set_underlying(&mut self, erc1155: E::AccountId, id: TokenId) {
+        Lazy::set(&mut self.erc1155, erc1155);
+        Lazy::set(&mut self.id, id);
+    }
+
+    /// Get the underlying erc1155 contract
+    pub fn erc1155(&self) -> &E::AccountId {
+        &self.erc1155
+    }
+
+    /// Get the token type id exposed by the facade
+    pub fn id(&self) -> TokenId {
+        *self.id
+    }
+
+    /// Set the name, symbol and decimals of the facade
+    pub fn set_symbols(&mut self, name: String, symbol: String, decimals: u8) {
+        Lazy::set(&mut self.metadatas, (decimals, name, symbol));
+    }
+
+    /// Get name of the facade
+    pub fn name(&self) -> &String {
+        &self.metadatas.1
+    }
+
+    /// Get symbol of the facade
+    pub fn symbol(&self) -> &String {
+        &self.metadatas.2
+    }
+
+    /// Get decimals of the facade
+    pub fn decimals(&self) -> u8 {
+        self.metadatas.0
+    }
+
+    /// Returns the allowance from {owner} to {spender}
+    pub fn allowance(&self, owner: E::AccountId, spender: E::AccountId) -> E::Balance {
+        self.allowances
+            .get(&(owner, spender))
+            .copied()
+            .unwrap_or(E::Balance::from(0_u8))
+    }
+
+    /// Set the allowance from {owner} to {spender}
+    pub fn set_allowance(
+        &mut self,
+        owner: E::AccountId,
+        spender: E::AccountId,
+        amount: E::Balance,
+    ) {
+        self.allowances.insert((owner, spender), amount);
+    }
+}
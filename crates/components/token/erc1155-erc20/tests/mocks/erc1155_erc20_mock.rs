@@ -0,0 +1,120 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod erc1155_erc20 {
+    use ink_prelude::string::String;
+    use metis_erc1155_erc20 as erc1155_erc20;
+    pub use metis_erc1155_erc20::{
+        Error,
+        Result,
+        TokenId,
+    };
+    use metis_lang::{
+        import,
+        metis,
+    };
+
+    /// A ERC-20 facade of a token type of an ERC-1155 contract.
+    #[ink(storage)]
+    #[import(erc1155_erc20)]
+    pub struct Erc20Facade {
+        erc1155_erc20: erc1155_erc20::Data<Erc20Facade>,
+    }
+
+    /// Event emitted when a token transfer occurs.
+    #[ink(event)]
+    #[metis(erc1155_erc20)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub value: Balance,
+    }
+
+    /// Event emitted when an approval occurs that `spender` is allowed to withdraw
+    /// up to the amount of `value` tokens from `owner`.
+    #[ink(event)]
+    #[metis(erc1155_erc20)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: AccountId,
+        pub value: Balance,
+    }
+
+    // impl
+    impl Erc20Facade {
+        #[ink(constructor)]
+        pub fn new(
+            erc1155: AccountId,
+            id: TokenId,
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Self {
+            let mut instance = Self {
+                erc1155_erc20: erc1155_erc20::Data::new(),
+            };
+
+            erc1155_erc20::Impl::init(&mut instance, erc1155, id, name, symbol, decimals);
+            instance
+        }
+
+        #[ink(message)]
+        pub fn erc1155(&self) -> AccountId {
+            erc1155_erc20::Impl::erc1155(self)
+        }
+
+        #[ink(message)]
+        pub fn id(&self) -> TokenId {
+            erc1155_erc20::Impl::id(self)
+        }
+
+        #[ink(message)]
+        pub fn name(&self) -> String {
+            erc1155_erc20::Impl::name(self)
+        }
+
+        #[ink(message)]
+        pub fn symbol(&self) -> String {
+            erc1155_erc20::Impl::symbol(self)
+        }
+
+        #[ink(message)]
+        pub fn decimals(&self) -> u8 {
+            erc1155_erc20::Impl::decimals(self)
+        }
+
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            erc1155_erc20::Impl::balance_of(self, owner)
+        }
+
+        #[ink(message)]
+        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            erc1155_erc20::Impl::allowance(self, owner, spender)
+        }
+
+        #[ink(message)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            erc1155_erc20::Impl::transfer(self, to, value)
+        }
+
+        #[ink(message)]
+        pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            erc1155_erc20::Impl::approve(self, spender, value)
+        }
+
+        #[ink(message)]
+        pub fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<()> {
+            erc1155_erc20::Impl::transfer_from(self, from, to, value)
+        }
+    }
+}
@@ -0,0 +1,491 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc1155_erc20_mock;
+}
+
+mod erc1155_erc20_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_erc1155_stub::{
+        Erc1155 as Underlying,
+        Erc1155Stub,
+        Error as Erc1155Error,
+        Result as Erc1155Result,
+    };
+    use metis_test::*;
+    use mocks::erc1155_erc20_mock::erc1155_erc20::{
+        Approval,
+        Erc20Facade,
+        Error,
+        TokenId,
+        Transfer,
+    };
+    use std::{
+        cell::RefCell,
+        collections::HashMap,
+        rc::Rc,
+    };
+
+    const ID: TokenId = TokenId::new([0x01; 32]);
+    const OTHER_ID: TokenId = TokenId::new([0x02; 32]);
+
+    /// A underlying erc1155 contract which only keeps the balances and the
+    /// operators.
+    #[derive(Default)]
+    struct MockUnderlying {
+        balances: HashMap<(AccountId, TokenId), Balance>,
+        operators: HashMap<(AccountId, AccountId), bool>,
+        /// Called after the balances are moved by `safe_transfer_from`, like
+        /// the receiver hook of the erc1155, where a re-entrant call happens.
+        on_transfer: Option<Box<dyn FnMut()>>,
+    }
+
+    impl Underlying for MockUnderlying {
+        fn url(&self, _id: TokenId) -> String {
+            String::from("https://test/")
+        }
+
+        fn balance_of(&self, account: AccountId, id: TokenId) -> Balance {
+            self.balances
+                .get(&(account, id))
+                .cloned()
+                .unwrap_or_default()
+        }
+
+        fn balance_of_batch(
+            &self,
+            accounts: Vec<AccountId>,
+            ids: Vec<TokenId>,
+        ) -> Vec<Balance> {
+            accounts
+                .into_iter()
+                .zip(ids)
+                .map(|(account, id)| self.balance_of(account, id))
+                .collect()
+        }
+
+        fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) {
+            self.operators.insert((caller(), operator), approved);
+        }
+
+        fn is_approved_for_all(&self, account: AccountId, operator: AccountId) -> bool {
+            self.operators
+                .get(&(account, operator))
+                .cloned()
+                .unwrap_or_default()
+        }
+
+        fn safe_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            id: TokenId,
+            amount: Balance,
+            _data: Vec<u8>,
+        ) -> Erc1155Result<()> {
+            let operator = caller();
+            assert!(
+                from == operator || self.is_approved_for_all(from, operator),
+                "ERC1155: caller is not owner nor approved"
+            );
+
+            let from_balance = self.balance_of(from, id);
+            if from_balance < amount {
+                return Err(Erc1155Error::InsufficientBalance)
+            }
+
+            self.balances.insert((from, id), from_balance - amount);
+            *self.balances.entry((to, id)).or_default() += amount;
+
+            if let Some(on_transfer) = self.on_transfer.as_mut() {
+                on_transfer();
+            }
+
+            Ok(())
+        }
+
+        fn safe_batch_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            ids: Vec<TokenId>,
+            amounts: Vec<Balance>,
+            data: Vec<u8>,
+        ) -> Erc1155Result<()> {
+            for (id, amount) in ids.into_iter().zip(amounts) {
+                self.safe_transfer_from(from, to, id, amount, data.clone())?;
+            }
+
+            Ok(())
+        }
+    }
+
+    fn caller() -> AccountId {
+        ink_env::caller::<ink_env::DefaultEnvironment>().expect("Cannot get caller")
+    }
+
+    /// Create the facade of `ID` with the underlying at eve, alice has 1000
+    /// tokens of `ID` and 500 tokens of `OTHER_ID`, and approves the facade as
+    /// her operator.
+    fn init() -> (Erc20Facade, Rc<RefCell<MockUnderlying>>) {
+        let accounts = default_accounts();
+
+        let underlying = Rc::new(RefCell::new(MockUnderlying::default()));
+        Erc1155Stub::mock(accounts.eve, underlying.clone());
+
+        let facade = Erc20Facade::new(
+            accounts.eve,
+            ID,
+            String::from("FacadeToken"),
+            String::from("FCT"),
+            0,
+        );
+
+        let mut mock = underlying.borrow_mut();
+        mock.balances.insert((accounts.alice, ID), 1000);
+        mock.balances.insert((accounts.alice, OTHER_ID), 500);
+        mock.operators.insert((accounts.alice, contract_id()), true);
+        drop(mock);
+
+        (facade, underlying)
+    }
+
+    /// The facade balance of each account should be the underlying balance
+    /// of `ID`, and the total of the balances should be kept.
+    fn assert_consistent(facade: &Erc20Facade, underlying: &RefCell<MockUnderlying>) {
+        let accounts = default_accounts();
+        let mut total = 0;
+        for account in [
+            accounts.alice,
+            accounts.bob,
+            accounts.charlie,
+            accounts.django,
+        ]
+        .iter()
+        {
+            let balance = facade.balance_of(*account);
+            assert_eq!(balance, underlying.borrow().balance_of(*account, ID));
+            total += balance;
+        }
+
+        assert_eq!(total, 1000);
+        assert_eq!(
+            underlying.borrow().balance_of(accounts.alice, OTHER_ID),
+            500
+        );
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let accounts = default_accounts();
+        let (facade, _) = init();
+
+        assert_eq!(facade.erc1155(), accounts.eve);
+        assert_eq!(facade.id(), ID);
+        assert_eq!(facade.name(), String::from("FacadeToken"));
+        assert_eq!(facade.symbol(), String::from("FCT"));
+        assert_eq!(facade.decimals(), 0);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC1155ERC20: underlying is zero address")]
+    fn init_with_zero_underlying_should_panic() {
+        Erc20Facade::new(
+            AccountId::from([0x00; 32]),
+            ID,
+            String::from("FacadeToken"),
+            String::from("FCT"),
+            0,
+        );
+    }
+
+    #[ink::test]
+    fn balance_of_should_forward_to_underlying() {
+        let accounts = default_accounts();
+        let (facade, underlying) = init();
+
+        assert_eq!(facade.balance_of(accounts.alice), 1000);
+        assert_eq!(facade.balance_of(accounts.bob), 0);
+
+        underlying
+            .borrow_mut()
+            .balances
+            .insert((accounts.bob, ID), 42);
+        underlying
+            .borrow_mut()
+            .balances
+            .insert((accounts.bob, OTHER_ID), 7);
+
+        assert_eq!(facade.balance_of(accounts.bob), 42);
+    }
+
+    #[ink::test]
+    fn transfer_works() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        assert_eq!(
+            call_as(accounts.alice, || facade.transfer(accounts.bob, 300)),
+            Ok(())
+        );
+
+        assert_eq!(facade.balance_of(accounts.alice), 700);
+        assert_eq!(facade.balance_of(accounts.bob), 300);
+        assert_consistent(&facade, &underlying);
+
+        let Transfer { from, to, value } = expect_event::<Transfer>();
+        assert_eq!(from, Some(accounts.alice));
+        assert_eq!(to, Some(accounts.bob));
+        assert_eq!(value, 300);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC1155ERC20: facade is not approved as operator")]
+    fn transfer_without_operator_approval_should_panic() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        underlying
+            .borrow_mut()
+            .operators
+            .insert((accounts.alice, contract_id()), false);
+
+        let _ = call_as(accounts.alice, || facade.transfer(accounts.bob, 300));
+    }
+
+    #[ink::test]
+    fn transfer_exceeds_balance_should_fail() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        assert_eq!(
+            call_as(accounts.alice, || facade.transfer(accounts.bob, 1001)),
+            Err(Error::InsufficientBalance)
+        );
+
+        assert_eq!(facade.balance_of(accounts.alice), 1000);
+        assert_consistent(&facade, &underlying);
+    }
+
+    #[ink::test]
+    fn transfer_to_zero_account_should_fail() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        assert_eq!(
+            call_as(accounts.alice, || {
+                facade.transfer(AccountId::from([0x00; 32]), 100)
+            }),
+            Err(Error::AccountIsZero)
+        );
+
+        assert_consistent(&facade, &underlying);
+    }
+
+    #[ink::test]
+    fn approve_works() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        assert_eq!(
+            call_as(accounts.alice, || facade.approve(accounts.bob, 200)),
+            Ok(())
+        );
+
+        assert_eq!(facade.allowance(accounts.alice, accounts.bob), 200);
+        // the allowance of the facade is not an operator of the underlying.
+        assert!(!underlying
+            .borrow()
+            .is_approved_for_all(accounts.alice, accounts.bob));
+
+        let Approval {
+            owner,
+            spender,
+            value,
+        } = expect_event::<Approval>();
+        assert_eq!(owner, accounts.alice);
+        assert_eq!(spender, accounts.bob);
+        assert_eq!(value, 200);
+    }
+
+    #[ink::test]
+    fn transfer_from_works() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        assert_eq!(
+            call_as(accounts.alice, || facade.approve(accounts.bob, 200)),
+            Ok(())
+        );
+        assert_eq!(
+            call_as(accounts.bob, || {
+                facade.transfer_from(accounts.alice, accounts.charlie, 150)
+            }),
+            Ok(())
+        );
+
+        assert_eq!(facade.balance_of(accounts.alice), 850);
+        assert_eq!(facade.balance_of(accounts.charlie), 150);
+        assert_eq!(facade.allowance(accounts.alice, accounts.bob), 50);
+        assert_consistent(&facade, &underlying);
+
+        let emitted_events = assert_emitted_event_len(3);
+        let Approval { value, .. } =
+            decode_event::<Approval>(&emitted_events[1]).expect("should be Approval");
+        assert_eq!(value, 50);
+
+        let Transfer { from, to, value } = expect_event::<Transfer>();
+        assert_eq!(from, Some(accounts.alice));
+        assert_eq!(to, Some(accounts.charlie));
+        assert_eq!(value, 150);
+    }
+
+    #[ink::test]
+    fn transfer_from_exceeds_allowance_should_fail() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        assert_eq!(
+            call_as(accounts.alice, || facade.approve(accounts.bob, 200)),
+            Ok(())
+        );
+        assert_eq!(
+            call_as(accounts.bob, || {
+                facade.transfer_from(accounts.alice, accounts.charlie, 201)
+            }),
+            Err(Error::InsufficientAllowance)
+        );
+
+        assert_eq!(facade.allowance(accounts.alice, accounts.bob), 200);
+        assert_consistent(&facade, &underlying);
+    }
+
+    #[ink::test]
+    fn transfer_from_should_deduct_allowance_before_underlying_call() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        // the allowance seen by a re-entrant call in the underlying, which is
+        // the value of the last `Approval` before the call.
+        let seen = Rc::new(RefCell::new(None));
+        let seen_in_call = seen.clone();
+        underlying.borrow_mut().on_transfer = Some(Box::new(move || {
+            let approval = get_emitted_events()
+                .iter()
+                .rev()
+                .find_map(|event| decode_event::<Approval>(event));
+            *seen_in_call.borrow_mut() = approval.map(|approval| approval.value);
+        }));
+
+        assert_eq!(
+            call_as(accounts.alice, || facade.approve(accounts.bob, 200)),
+            Ok(())
+        );
+        assert_eq!(
+            call_as(accounts.bob, || {
+                facade.transfer_from(accounts.alice, accounts.charlie, 150)
+            }),
+            Ok(())
+        );
+
+        assert_eq!(*seen.borrow(), Some(50));
+        assert_eq!(facade.allowance(accounts.alice, accounts.bob), 50);
+        assert_consistent(&facade, &underlying);
+    }
+
+    #[ink::test]
+    fn transfer_from_exceeds_balance_should_keep_allowance() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        assert_eq!(
+            call_as(accounts.alice, || facade.approve(accounts.bob, 2000)),
+            Ok(())
+        );
+        assert_eq!(
+            call_as(accounts.bob, || {
+                facade.transfer_from(accounts.alice, accounts.charlie, 1001)
+            }),
+            Err(Error::InsufficientBalance)
+        );
+
+        assert_eq!(facade.allowance(accounts.alice, accounts.bob), 2000);
+        assert_consistent(&facade, &underlying);
+    }
+
+    #[ink::test]
+    fn accounting_should_be_consistent_with_underlying() {
+        let accounts = default_accounts();
+        let (mut facade, underlying) = init();
+
+        // bob and charlie also approve the facade as their operator.
+        for account in [accounts.bob, accounts.charlie].iter() {
+            call_as(*account, || {
+                underlying
+                    .borrow_mut()
+                    .set_approval_for_all(contract_id(), true)
+            });
+        }
+
+        assert_eq!(
+            call_as(accounts.alice, || facade.transfer(accounts.bob, 400)),
+            Ok(())
+        );
+        assert_consistent(&facade, &underlying);
+
+        assert_eq!(
+            call_as(accounts.bob, || facade.transfer(accounts.charlie, 150)),
+            Ok(())
+        );
+        assert_consistent(&facade, &underlying);
+
+        // the transfers by the underlying directly are seen by the facade.
+        assert_eq!(
+            call_as(accounts.charlie, || {
+                underlying.borrow_mut().safe_transfer_from(
+                    accounts.charlie,
+                    accounts.django,
+                    ID,
+                    50,
+                    Vec::new(),
+                )
+            }),
+            Ok(())
+        );
+        assert_consistent(&facade, &underlying);
+
+        assert_eq!(
+            call_as(accounts.charlie, || facade.approve(accounts.alice, 100)),
+            Ok(())
+        );
+        assert_eq!(
+            call_as(accounts.alice, || {
+                facade.transfer_from(accounts.charlie, accounts.alice, 100)
+            }),
+            Ok(())
+        );
+        assert_consistent(&facade, &underlying);
+
+        assert_eq!(facade.balance_of(accounts.alice), 700);
+        assert_eq!(facade.balance_of(accounts.bob), 250);
+        assert_eq!(facade.balance_of(accounts.charlie), 0);
+        assert_eq!(facade.balance_of(accounts.django), 50);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC1155ERC20: get underlying balance error")]
+    fn balance_of_reverted_underlying_should_panic() {
+        let accounts = default_accounts();
+        let (facade, _) = init();
+
+        metis_lang::mock::register_revert(accounts.eve);
+
+        facade.balance_of(accounts.alice);
+    }
+}
@@ -6,6 +6,7 @@ use ink_prelude::{
     vec::Vec,
 };
 pub use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     FromAccountId,
@@ -14,23 +15,13 @@ pub use metis_lang::{
 
 use metis_erc1155_receiver::ERC1155ReceiverStub as Receiver;
 
-/// The ERC-1155 error types.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-pub enum Error {
-    /// Returned if not enough balance to fulfill a request is available.
-    InsufficientBalance,
-    /// Returned if not enough allowance to fulfill a request is available.
-    InsufficientAllowance,
-    /// Returned if account is zero
-    AccountIsZero,
-}
+pub use metis_erc1155_types::{
+    Error,
+    Result,
+};
 
 pub use crate::TokenId;
 
-/// The ERC-1155 result type.
-pub type Result<T> = core::result::Result<T, Error>;
-
 /// The `EventEmit` impl the event emit api for erc1155 component.
 pub trait EventEmit<E: Env>: EnvAccess<E> {
     /// Emitted when `value` tokens of token type `id` are transferred from `from` to `to` by `operator`.
@@ -83,7 +74,7 @@ pub trait EventEmit<E: Env>: EnvAccess<E> {
 ///         Ok(())
 ///     }
 /// }
-pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Context<E> {
     /// Initialize the erc1155 component
     fn init(&mut self, url: String) {
         self.get_mut().set_url(url)
@@ -147,7 +138,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// - `operator` cannot be the caller.
     fn set_approval_for_all(&mut self, operator: E::AccountId, approved: bool) {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
 
         assert!(
             caller != operator,
@@ -189,7 +180,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         amount: E::Balance,
         data: Vec<u8>,
     ) -> Result<()> {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
         assert!(
             from == caller || self.is_approved_for_all(&from, &caller),
             "ERC1155: caller is not owner nor approved"
@@ -215,7 +206,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         amount: Vec<E::Balance>,
         data: Vec<u8>,
     ) -> Result<()> {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
         assert!(
             from == caller || self.is_approved_for_all(&from, &caller),
             "ERC1155: transfer caller is not owner nor approved"
@@ -246,7 +237,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
             return Err(Error::AccountIsZero)
         }
 
-        let operator = Self::caller();
+        let operator = self._msg_sender();
 
         self._before_token_transfer(
             &operator,
@@ -258,13 +249,14 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         )?;
 
         let from_balance = self.get().balance_of(&id, &from);
-        let to_balance = self.get().balance_of(&id, &to);
         assert!(
             from_balance >= amount,
             "ERC1155: insufficient balance for transfer"
         );
         self.get_mut()
             .set_balance(&from, &id, from_balance - amount);
+        // read after the update, `to` may be `from`
+        let to_balance = self.get().balance_of(&id, &to);
         self.get_mut().set_balance(&to, &id, to_balance + amount);
 
         self._do_safe_transfer_acceptance_check(
@@ -305,7 +297,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
             return Err(Error::AccountIsZero)
         }
 
-        let operator = Self::caller();
+        let operator = self._msg_sender();
 
         self._before_token_transfer(
             &operator,
@@ -321,13 +313,13 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
             let amount = amounts[i];
 
             let from_balance = self.get().balance_of(&id, &from);
-            let to_balance = self.get().balance_of(&id, &to);
             assert!(
                 from_balance >= amount,
                 "ERC1155: insufficient balance for transfer"
             );
             self.get_mut()
                 .set_balance(&from, &id, from_balance - amount);
+            let to_balance = self.get().balance_of(&id, &to);
             self.get_mut().set_balance(&to, &id, to_balance + amount);
         }
 
@@ -385,7 +377,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
             return Err(Error::AccountIsZero)
         }
 
-        let operator = Self::caller();
+        let operator = self._msg_sender();
 
         self._before_token_transfer(
             &operator,
@@ -429,7 +421,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
             return Err(Error::AccountIsZero)
         }
 
-        let operator = Self::caller();
+        let operator = self._msg_sender();
 
         self._before_token_transfer(&operator, &None, &Some(&to), &ids, &amounts, &data)?;
 
@@ -464,7 +456,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
             return Err(Error::AccountIsZero)
         }
 
-        let operator = Self::caller();
+        let operator = self._msg_sender();
 
         self._before_token_transfer(
             &operator,
@@ -508,7 +500,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
             return Err(Error::AccountIsZero)
         }
 
-        let operator = Self::caller();
+        let operator = self._msg_sender();
 
         self._before_token_transfer(
             &operator,
@@ -1,4 +1,7 @@
-use metis_lang::Env;
+use metis_lang::{
+    Context,
+    Env,
+};
 
 use crate::{
     Impl as ERC1155,
@@ -24,7 +27,7 @@ where
         id: TokenId,
         value: E::Balance,
     ) -> Result<()> {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
         assert!(
             account == caller || self.is_approved_for_all(&account, &caller),
             "ERC1155: caller is not owner nor approved"
@@ -44,7 +47,7 @@ where
         ids: Vec<TokenId>,
         values: Vec<E::Balance>,
     ) -> Result<()> {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
         assert!(
             account == caller || self.is_approved_for_all(&account, &caller),
             "ERC1155: caller is not owner nor approved"
@@ -7,6 +7,7 @@ mod extensions;
 mod module;
 
 pub use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     Storage,
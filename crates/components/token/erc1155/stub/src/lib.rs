@@ -0,0 +1,59 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+
+pub use metis_erc1155_types::{
+    Error,
+    Result,
+};
+pub use metis_erc721_types::TokenId;
+
+/// The messages of the ERC1155 component
+#[metis_lang::stub_contract(Erc1155Stub)]
+pub trait Erc1155 {
+    fn new(url: String) -> Self;
+
+    /// Returns the URI for token type `id`.
+    fn url(&self, id: TokenId) -> String;
+
+    /// Returns the amount of tokens of token type `id` owned by `account`.
+    fn balance_of(&self, account: AccountId, id: TokenId) -> Balance;
+
+    /// Batched version of `balance_of`.
+    fn balance_of_batch(
+        &self,
+        accounts: Vec<AccountId>,
+        ids: Vec<TokenId>,
+    ) -> Vec<Balance>;
+
+    /// Grants or revokes permission to `operator` to transfer the caller's
+    /// tokens, according to `approved`.
+    fn set_approval_for_all(&mut self, operator: AccountId, approved: bool);
+
+    /// Returns true if `operator` is approved to transfer `account`'s tokens.
+    fn is_approved_for_all(&self, account: AccountId, operator: AccountId) -> bool;
+
+    /// Transfers `amount` tokens of token type `id` from `from` to `to`, the
+    /// caller must be `from` or an approved operator of it.
+    fn safe_transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        id: TokenId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<()>;
+
+    /// Batched version of `safe_transfer_from`.
+    fn safe_batch_transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        ids: Vec<TokenId>,
+        amounts: Vec<Balance>,
+        data: Vec<u8>,
+    ) -> Result<()>;
+}
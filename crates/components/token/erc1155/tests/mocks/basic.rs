@@ -76,7 +76,19 @@ pub mod contract {
     }
 
     // for test message
-    impl Erc1155 {}
+    impl Erc1155 {
+        /// For test to mint
+        #[ink(message)]
+        pub fn mint(
+            &mut self,
+            to: AccountId,
+            id: TokenId,
+            amount: Balance,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            erc1155::Impl::_mint(self, to, id, amount, data)
+        }
+    }
 
     // impl
     impl Erc1155 {
@@ -78,7 +78,19 @@ pub mod contract {
     }
 
     // for test message
-    impl Erc1155Burnable {}
+    impl Erc1155Burnable {
+        /// For test to mint
+        #[ink(message)]
+        pub fn mint(
+            &mut self,
+            to: AccountId,
+            id: TokenId,
+            amount: Balance,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            erc1155::Impl::_mint(self, to, id, amount, data)
+        }
+    }
 
     // impl
     impl Erc1155Burnable {
@@ -199,4 +211,110 @@ pub mod contract {
             erc1155::burnable::Impl::burn_batch(self, account, ids, values)
         }
     }
+
+    type Event = <Erc1155Burnable as ink_lang::BaseEvent>::Type;
+
+    impl metis_test::Erc1155Invariant for Erc1155Burnable {
+        type TokenId = TokenId;
+        type Error = Error;
+
+        fn new_erc1155() -> Self {
+            Self::new(String::from("https://test/"))
+        }
+
+        fn token_id(seed: u8) -> TokenId {
+            TokenId::new([seed; 32])
+        }
+
+        fn decode_transfer_event(
+            event: &ink_env::test::EmittedEvent,
+        ) -> Option<(
+            Option<AccountId>,
+            Option<AccountId>,
+            Vec<(TokenId, Balance)>,
+        )> {
+            match <Event as scale::Decode>::decode(&mut &event.data[..]).ok()? {
+                Event::TransferSingle(TransferSingle {
+                    from,
+                    to,
+                    id,
+                    value,
+                    ..
+                }) => Some((from, to, vec![(id, value)])),
+                Event::TransferBatch(TransferBatch {
+                    from,
+                    to,
+                    id,
+                    value,
+                    ..
+                }) => Some((from, to, id.into_iter().zip(value).collect())),
+                _ => None,
+            }
+        }
+
+        fn decode_approval_for_all_event(
+            event: &ink_env::test::EmittedEvent,
+        ) -> Option<(AccountId, AccountId, bool)> {
+            match <Event as scale::Decode>::decode(&mut &event.data[..]).ok()? {
+                Event::ApprovalForAll(ApprovalForAll {
+                    owner,
+                    operator,
+                    approved,
+                }) => Some((owner, operator, approved)),
+                _ => None,
+            }
+        }
+
+        fn balance_of(&self, account: AccountId, id: TokenId) -> Balance {
+            Erc1155Burnable::balance_of(self, &account, &id)
+        }
+
+        fn is_approved_for_all(&self, account: AccountId, operator: AccountId) -> bool {
+            Erc1155Burnable::is_approved_for_all(self, &account, &operator)
+        }
+
+        fn mint(&mut self, to: AccountId, id: TokenId, amount: Balance) -> Result<()> {
+            Erc1155Burnable::mint(self, to, id, amount, Vec::new())
+        }
+
+        fn burn(
+            &mut self,
+            from: AccountId,
+            id: TokenId,
+            amount: Balance,
+        ) -> Option<Result<()>> {
+            Some(Erc1155Burnable::burn(self, from, id, amount))
+        }
+
+        fn safe_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            id: TokenId,
+            amount: Balance,
+        ) -> Result<()> {
+            Erc1155Burnable::safe_transfer_from(self, from, to, id, amount, Vec::new())
+        }
+
+        fn safe_batch_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            ids: Vec<TokenId>,
+            amounts: Vec<Balance>,
+        ) -> Result<()> {
+            Erc1155Burnable::safe_batch_transfer_from(
+                self,
+                from,
+                to,
+                ids,
+                amounts,
+                Vec::new(),
+            )
+        }
+
+        fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) {
+            Erc1155Burnable::set_approval_for_all(self, operator, approved)
+        }
+    }
 }
@@ -0,0 +1,56 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod burnable;
+}
+
+mod erc1155_invariants_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use metis_test::{
+        erc1155_ops,
+        run_erc1155_invariants,
+    };
+    use mocks::burnable::contract::Erc1155Burnable;
+    use proptest::prelude::*;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn erc1155_burnable_invariants_hold(ops in erc1155_ops(100, 48)) {
+            run_erc1155_invariants::<Erc1155Burnable>(&ops);
+        }
+    }
+
+    #[ink_lang::test]
+    fn transfer_to_self_keeps_balance() {
+        let accounts = metis_test::default_accounts();
+        let mut erc1155 = Erc1155Burnable::new(String::from("https://test/"));
+        let token = mocks::burnable::contract::TokenId::new([0x01; 32]);
+
+        assert_eq!(erc1155.mint(accounts.alice, token, 100, Vec::new()), Ok(()));
+        assert_eq!(
+            erc1155.safe_transfer_from(
+                accounts.alice,
+                accounts.alice,
+                token,
+                60,
+                Vec::new()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            erc1155.safe_batch_transfer_from(
+                accounts.alice,
+                accounts.alice,
+                vec![token, token],
+                vec![60, 60],
+                Vec::new()
+            ),
+            Ok(())
+        );
+
+        assert_eq!(erc1155.balance_of(&accounts.alice, &token), 100);
+    }
+}
@@ -0,0 +1,233 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod basic;
+}
+
+mod erc1155_receiver_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_erc1155_receiver::{
+        ERC1155Receiver,
+        ERC1155ReceiverStub,
+    };
+    use metis_lang::mock;
+    use metis_test::*;
+    use mocks::basic::contract::{
+        Erc1155,
+        TokenId,
+    };
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    /// A receiver contract which records the received tokens.
+    #[derive(Default)]
+    struct MockReceiver {
+        accept: bool,
+        received: Vec<(Option<AccountId>, Vec<TokenId>, Vec<Balance>)>,
+    }
+
+    impl ERC1155Receiver for MockReceiver {
+        fn on_erc1155_received(
+            &mut self,
+            _operator: AccountId,
+            from: Option<AccountId>,
+            id: TokenId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> [u8; 4] {
+            self.received.push((from, vec![id], vec![value]));
+
+            if self.accept {
+                metis_lang::selector_id!(on_erc1155_received)
+            } else {
+                [0x00; 4]
+            }
+        }
+
+        fn on_erc1155_batch_received(
+            &mut self,
+            _operator: AccountId,
+            from: Option<AccountId>,
+            ids: Vec<TokenId>,
+            values: Vec<Balance>,
+            _data: Vec<u8>,
+        ) -> [u8; 4] {
+            self.received.push((from, ids, values));
+
+            if self.accept {
+                metis_lang::selector_id!(on_erc1155_batch_received)
+            } else {
+                [0x00; 4]
+            }
+        }
+    }
+
+    fn token_a() -> TokenId {
+        TokenId::new([0x0a; 32])
+    }
+
+    fn token_b() -> TokenId {
+        TokenId::new([0x0b; 32])
+    }
+
+    fn init_with_tokens(owner: AccountId) -> Erc1155 {
+        let mut erc1155 = Erc1155::new(String::from("MockErc1155Token"));
+
+        assert_eq!(erc1155.mint(owner, token_a(), 100, Vec::new()), Ok(()));
+        assert_eq!(erc1155.mint(owner, token_b(), 200, Vec::new()), Ok(()));
+
+        erc1155
+    }
+
+    #[ink::test]
+    fn safe_transfer_to_account_without_code_works() {
+        let accounts = default_accounts();
+        let mut erc1155 = init_with_tokens(accounts.alice);
+
+        assert_eq!(
+            erc1155.safe_transfer_from(
+                accounts.alice,
+                accounts.bob,
+                token_a(),
+                10,
+                Vec::new()
+            ),
+            Ok(())
+        );
+        assert_eq!(erc1155.balance_of(&accounts.bob, &token_a()), 10);
+    }
+
+    #[ink::test]
+    fn mint_to_receiver_accepted() {
+        let accounts = default_accounts();
+        let mut erc1155 = Erc1155::new(String::from("MockErc1155Token"));
+
+        let receiver = Rc::new(RefCell::new(MockReceiver {
+            accept: true,
+            ..Default::default()
+        }));
+        ERC1155ReceiverStub::mock(accounts.bob, receiver.clone());
+
+        assert_eq!(
+            erc1155.mint(accounts.bob, token_a(), 100, Vec::new()),
+            Ok(())
+        );
+        assert_eq!(erc1155.balance_of(&accounts.bob, &token_a()), 100);
+
+        assert_eq!(
+            receiver.borrow().received,
+            vec![(None, vec![token_a()], vec![100])],
+            "receiver should be called with the mint"
+        );
+    }
+
+    #[ink::test]
+    fn safe_transfer_to_receiver_accepted() {
+        let accounts = default_accounts();
+        let mut erc1155 = init_with_tokens(accounts.alice);
+
+        let receiver = Rc::new(RefCell::new(MockReceiver {
+            accept: true,
+            ..Default::default()
+        }));
+        ERC1155ReceiverStub::mock(accounts.bob, receiver.clone());
+
+        assert_eq!(
+            erc1155.safe_transfer_from(
+                accounts.alice,
+                accounts.bob,
+                token_a(),
+                10,
+                Vec::new()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            erc1155.safe_batch_transfer_from(
+                accounts.alice,
+                accounts.bob,
+                vec![token_a(), token_b()],
+                vec![20, 30],
+                Vec::new()
+            ),
+            Ok(())
+        );
+
+        assert_eq!(erc1155.balance_of(&accounts.bob, &token_a()), 30);
+        assert_eq!(erc1155.balance_of(&accounts.bob, &token_b()), 30);
+
+        assert_eq!(
+            receiver.borrow().received,
+            vec![
+                (Some(accounts.alice), vec![token_a()], vec![10]),
+                (
+                    Some(accounts.alice),
+                    vec![token_a(), token_b()],
+                    vec![20, 30]
+                ),
+            ],
+            "receiver should be called with the transfers"
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC1155: transfer to non ERC1155Receiver implementer")]
+    fn safe_transfer_to_receiver_rejected() {
+        let accounts = default_accounts();
+        let mut erc1155 = init_with_tokens(accounts.alice);
+
+        let receiver = Rc::new(RefCell::new(MockReceiver::default()));
+        ERC1155ReceiverStub::mock(accounts.bob, receiver);
+
+        let _ = erc1155.safe_transfer_from(
+            accounts.alice,
+            accounts.bob,
+            token_a(),
+            10,
+            Vec::new(),
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC1155: transfer to non ERC1155Receiver implementer")]
+    fn safe_batch_transfer_to_receiver_rejected() {
+        let accounts = default_accounts();
+        let mut erc1155 = init_with_tokens(accounts.alice);
+
+        let receiver = Rc::new(RefCell::new(MockReceiver::default()));
+        ERC1155ReceiverStub::mock(accounts.bob, receiver);
+
+        let _ = erc1155.safe_batch_transfer_from(
+            accounts.alice,
+            accounts.bob,
+            vec![token_a(), token_b()],
+            vec![20, 30],
+            Vec::new(),
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC1155: transfer to non ERC1155Receiver implementer")]
+    fn safe_transfer_to_receiver_reverted() {
+        let accounts = default_accounts();
+        let mut erc1155 = init_with_tokens(accounts.alice);
+
+        mock::register_revert(accounts.bob);
+
+        let _ = erc1155.safe_transfer_from(
+            accounts.alice,
+            accounts.bob,
+            token_a(),
+            10,
+            Vec::new(),
+        );
+    }
+}
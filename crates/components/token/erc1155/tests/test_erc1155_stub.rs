@@ -0,0 +1,182 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod basic;
+}
+
+mod erc1155_stub_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang::{
+        self as ink,
+        ForwardCall,
+        ForwardCallMut,
+    };
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_erc1155_stub::{
+        Erc1155 as Erc1155Messages,
+        Erc1155Stub,
+    };
+    use metis_lang::{
+        mock::check_messages,
+        FromAccountId,
+    };
+    use metis_test::*;
+    use mocks::basic::contract::{
+        Erc1155,
+        Result,
+        TokenId,
+    };
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    extern "Rust" {
+        fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+    }
+
+    /// The messages of the stub must be the messages of the real contract
+    /// with the same signatures.
+    impl Erc1155Messages for Erc1155 {
+        fn url(&self, id: TokenId) -> String {
+            Erc1155::url(self, id)
+        }
+
+        fn balance_of(&self, account: AccountId, id: TokenId) -> Balance {
+            Erc1155::balance_of(self, &account, &id)
+        }
+
+        fn balance_of_batch(
+            &self,
+            accounts: Vec<AccountId>,
+            ids: Vec<TokenId>,
+        ) -> Vec<Balance> {
+            Erc1155::balance_of_batch(self, accounts, ids)
+        }
+
+        fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) {
+            Erc1155::set_approval_for_all(self, operator, approved)
+        }
+
+        fn is_approved_for_all(&self, account: AccountId, operator: AccountId) -> bool {
+            Erc1155::is_approved_for_all(self, &account, &operator)
+        }
+
+        fn safe_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            id: TokenId,
+            amount: Balance,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            Erc1155::safe_transfer_from(self, from, to, id, amount, data)
+        }
+
+        fn safe_batch_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            ids: Vec<TokenId>,
+            amounts: Vec<Balance>,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            Erc1155::safe_batch_transfer_from(self, from, to, ids, amounts, data)
+        }
+    }
+
+    #[test]
+    fn stub_messages_should_match_contract() {
+        let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+            .expect("metadata should be serialized");
+
+        assert_eq!(check_messages(&metadata, Erc1155Stub::MESSAGES), Ok(()));
+    }
+
+    #[ink::test]
+    fn call_by_stub_should_dispatch_to_contract() {
+        let accounts = default_accounts();
+        let token_a = TokenId::new([0x0a; 32]);
+        let token_b = TokenId::new([0x0b; 32]);
+
+        let erc1155 =
+            Rc::new(RefCell::new(Erc1155::new(String::from("MockErc1155Token"))));
+        Erc1155Stub::mock(accounts.eve, erc1155.clone());
+
+        let mut stub =
+            <Erc1155Stub as FromAccountId<Erc1155>>::from_account_id(accounts.eve);
+        assert_eq!(
+            stub.call().url(token_a.clone()).fire(),
+            Ok(String::from("MockErc1155Token"))
+        );
+
+        // the contract which calls the stub is the caller of the message
+        erc1155
+            .borrow_mut()
+            .mint(contract_id(), token_a.clone(), 100, Vec::new())
+            .expect("mint should be ok");
+        erc1155
+            .borrow_mut()
+            .mint(contract_id(), token_b.clone(), 200, Vec::new())
+            .expect("mint should be ok");
+
+        assert_eq!(
+            stub.call()
+                .balance_of(contract_id(), token_a.clone())
+                .fire(),
+            Ok(100)
+        );
+
+        assert_eq!(
+            stub.call_mut()
+                .set_approval_for_all(accounts.charlie, true)
+                .fire(),
+            Ok(())
+        );
+        assert_eq!(
+            stub.call()
+                .is_approved_for_all(contract_id(), accounts.charlie)
+                .fire(),
+            Ok(true)
+        );
+
+        assert_eq!(
+            stub.call_mut()
+                .safe_transfer_from(
+                    contract_id(),
+                    accounts.bob,
+                    token_a.clone(),
+                    10,
+                    Vec::new()
+                )
+                .fire(),
+            Ok(Ok(()))
+        );
+        assert_eq!(
+            stub.call_mut()
+                .safe_batch_transfer_from(
+                    contract_id(),
+                    accounts.bob,
+                    vec![token_a.clone(), token_b.clone()],
+                    vec![10, 20],
+                    Vec::new()
+                )
+                .fire(),
+            Ok(Ok(()))
+        );
+
+        assert_eq!(
+            stub.call()
+                .balance_of_batch(
+                    vec![accounts.bob, accounts.bob, contract_id()],
+                    vec![token_a.clone(), token_b.clone(), token_a]
+                )
+                .fire(),
+            Ok(vec![20, 20, 80])
+        );
+    }
+}
@@ -0,0 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// The ERC-1155 error types.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    /// Returned if not enough balance to fulfill a request is available.
+    InsufficientBalance,
+    /// Returned if not enough allowance to fulfill a request is available.
+    InsufficientAllowance,
+    /// Returned if account is zero
+    AccountIsZero,
+}
+
+/// The ERC-1155 result type.
+pub type Result<T> = core::result::Result<T, Error>;
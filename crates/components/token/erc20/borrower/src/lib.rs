@@ -0,0 +1,21 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_prelude::vec::Vec;
+
+/// The messages of the ERC3156 flash borrower
+#[metis_lang::stub_contract(FlashBorrowerStub)]
+pub trait FlashBorrower {
+    fn default() -> Self;
+
+    /// Receive a flash loan of `amount` `token` initiated by `initiator`,
+    /// the borrower should approve `amount + fee` to the lender before
+    /// returning, returns the selector of `on_flash_loan` to accept the loan.
+    fn on_flash_loan(
+        &mut self,
+        initiator: AccountId,
+        token: AccountId,
+        amount: Balance,
+        fee: Balance,
+        data: Vec<u8>,
+    ) -> [u8; 4];
+}
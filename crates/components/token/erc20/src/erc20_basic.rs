@@ -1,25 +1,16 @@
 pub use super::module::Data;
 use ink_prelude::string::String;
 pub use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     Storage,
 };
 
-/// The ERC-20 error types.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-pub enum Error {
-    /// Returned if not enough balance to fulfill a request is available.
-    InsufficientBalance,
-    /// Returned if not enough allowance to fulfill a request is available.
-    InsufficientAllowance,
-    /// Returned if account is zero
-    AccountIsZero,
-}
-
-/// The ERC-20 result type.
-pub type Result<T> = core::result::Result<T, Error>;
+pub use metis_erc20_types::{
+    Error,
+    Result,
+};
 
 /// The `EventEmit` impl the event emit api for erc20 component.
 pub trait EventEmit<E: Env>: EnvAccess<E> {
@@ -57,7 +48,7 @@ pub trait EventEmit<E: Env>: EnvAccess<E> {
 ///         Ok(())
 ///     }
 /// }
-pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Context<E> {
     /// Initialize the erc20 component
     fn init(
         &mut self,
@@ -66,7 +57,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         decimals: u8,
         initial_supply: E::Balance,
     ) {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
 
         self.get_mut().set_total_supply(initial_supply);
         self.get_mut().set_balance(caller.clone(), initial_supply);
@@ -143,7 +134,8 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// Emits a `Transfer` event.
     fn transfer(&mut self, to: E::AccountId, value: E::Balance) -> Result<()> {
-        self._transfer_from_to(Self::caller(), to, value)
+        let caller = self._msg_sender();
+        self._transfer_from_to(caller, to, value)
     }
 
     /// Sets `amount` as the allowance of `spender` over the caller's tokens.
@@ -159,7 +151,8 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// Emits an `Approval` event.
     fn approve(&mut self, spender: E::AccountId, amount: E::Balance) -> Result<()> {
-        self._approve(Self::caller(), spender, amount)
+        let caller = self._msg_sender();
+        self._approve(caller, spender, amount)
     }
 
     /// Moves `amount` tokens from `sender` to `recipient` using the
@@ -175,7 +168,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         to: E::AccountId,
         amount: E::Balance,
     ) -> Result<()> {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
 
         let current_allowance = self.get().allowance(from.clone(), caller.clone());
         if current_allowance < amount {
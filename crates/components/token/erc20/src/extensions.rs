@@ -1,4 +1,8 @@
 pub mod burnable;
 pub mod capped;
+pub mod flash_mint;
 pub mod hookable;
+pub mod merkle_distributor;
+pub mod native_wrapper;
 pub mod pausable;
+pub mod wrapper;
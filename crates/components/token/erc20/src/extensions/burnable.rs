@@ -1,4 +1,7 @@
-use metis_lang::Env;
+use metis_lang::{
+    Context,
+    Env,
+};
 
 use crate::erc20::{
     Error,
@@ -14,7 +17,8 @@ where
 {
     /// Destroys `amount` tokens from the caller.
     fn burn(&mut self, amount: E::Balance) -> Result<()> {
-        self._burn(Self::caller(), amount)
+        let caller = self._msg_sender();
+        self._burn(caller, amount)
     }
 
     /// Destroys `amount` tokens from `account`, deducting from the caller's
@@ -27,7 +31,7 @@ where
     /// - the caller must have allowance for ``accounts``'s tokens of at least
     /// `amount`.
     fn burn_from(&mut self, account: E::AccountId, amount: E::Balance) -> Result<()> {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
         let current_allowance = self.get().allowance(account.clone(), caller.clone());
         if current_allowance < amount {
             return Err(Error::InsufficientAllowance)
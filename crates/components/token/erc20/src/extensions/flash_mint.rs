@@ -0,0 +1,119 @@
+//! Implementation of the ERC3156 Flash loans extension, as defined in
+//! <https://eips.ethereum.org/EIPS/eip-3156>.
+//!
+//! Adds the `flash_loan` method, which provides flash loan support at the
+//! token level. By default there is no fee, but this can be changed by
+//! overriding `flash_fee`.
+use crate::{
+    erc20::Result,
+    Impl as ERC20,
+};
+use ink_lang::ForwardCallMut;
+use ink_prelude::vec::Vec;
+use metis_erc20_borrower::FlashBorrowerStub as Borrower;
+use metis_lang::{
+    Context,
+    Env,
+    FromAccountId,
+};
+use num_traits::{
+    Bounded,
+    CheckedAdd,
+};
+
+/// Extension of {ERC20} that adds the ERC3156 flash loans.
+pub trait Impl<E>: ERC20<E>
+where
+    E: Env,
+{
+    /// Returns the maximum amount of tokens available for loan, which is
+    /// the tokens can be minted, returns zero if `token` is not this.
+    fn max_flash_loan(&self, token: E::AccountId) -> E::Balance {
+        if token == Self::account_id() {
+            E::Balance::max_value() - ERC20::total_supply(self)
+        } else {
+            E::Balance::from(0_u8)
+        }
+    }
+
+    /// Returns the fee applied when doing flash loans. By default this
+    /// implementation has 0 fees. This function can be overloaded to make
+    /// the flash loan mechanism deflationary.
+    ///
+    /// Requirements:
+    ///
+    /// - `token` must be this.
+    fn flash_fee(&self, token: E::AccountId, _amount: E::Balance) -> E::Balance {
+        assert!(token == Self::account_id(), "ERC20FlashMint: wrong token");
+
+        E::Balance::from(0_u8)
+    }
+
+    /// Performs a flash loan. New tokens are minted and sent to the
+    /// `receiver`, who is required to implement the `FlashBorrower`
+    /// messages. By the end of the flash loan, the receiver is expected to
+    /// own `amount + fee` tokens and have them approved back to the token
+    /// contract itself so they can be burned.
+    ///
+    /// Requirements:
+    ///
+    /// - `amount` should not exceed the `max_flash_loan`.
+    /// - `receiver` should return the selector of `on_flash_loan`.
+    /// - `receiver` should approve `amount + fee` to this.
+    /// - `receiver` should own `amount + fee` tokens at the end.
+    fn flash_loan(
+        &mut self,
+        receiver: E::AccountId,
+        token: E::AccountId,
+        amount: E::Balance,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        assert!(
+            amount <= self.max_flash_loan(token.clone()),
+            "ERC20FlashMint: amount exceeds max_flash_loan"
+        );
+
+        let fee = self.flash_fee(token.clone(), amount);
+        let repayment = amount
+            .checked_add(&fee)
+            .expect("ERC20FlashMint: repayment overflow");
+        ERC20::_mint(self, receiver.clone(), amount)?;
+
+        let mut borrower =
+            <Borrower as FromAccountId<E>>::from_account_id(receiver.clone());
+        let resp = borrower
+            .call_mut()
+            .on_flash_loan(
+                self._msg_sender().into(),
+                token.into(),
+                amount.into(),
+                fee.into(),
+                data,
+            )
+            .fire();
+        assert!(
+            matches!(resp, Ok(selector_id) if selector_id == metis_lang::selector_id!(on_flash_loan)),
+            "ERC20FlashMint: invalid return value"
+        );
+
+        // the loan had been minted, so the repayment errors should trap to revert
+        let this = Self::account_id();
+        let current_allowance = ERC20::allowance(self, receiver.clone(), this.clone());
+        assert!(
+            current_allowance >= repayment,
+            "ERC20FlashMint: allowance does not allow refund"
+        );
+
+        let res =
+            ERC20::_approve(self, receiver.clone(), this, current_allowance - repayment);
+        assert!(res.is_ok(), "ERC20FlashMint: approve the refund error");
+
+        let res = ERC20::_burn(self, receiver, repayment);
+        assert!(res.is_ok(), "ERC20FlashMint: burn the refund error");
+
+        Ok(())
+    }
+}
+
+// No impl this for default
+// impl<E: Env, T: ERC20<E>> Impl<E> for T {}
@@ -0,0 +1,148 @@
+//! Extension of the ERC20 token contract to airdrop tokens by a Merkle tree.
+//!
+//! The leaves of the tree are the blake2x256 hashes of the encoded tuple
+//! `(index, account, amount)`, each account can claim its `amount` tokens
+//! once by the proof of the leaf, the tokens are minted to the account.
+use crate::{
+    erc20::Result,
+    Impl as ERC20,
+};
+use ink_env::hash::Blake2x256;
+use ink_prelude::vec::Vec;
+use metis_lang::{
+    merkle_proof::{
+        self,
+        Node,
+    },
+    storage::BitMap,
+    Env,
+    EnvAccess,
+    Storage,
+};
+use scale::Encode;
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The Data of ERC20 merkle distributor component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data {
+    /// The root of the Merkle tree of the airdrop.
+    merkle_root: Lazy<Node>,
+    /// The bitmap of the claimed indexes.
+    claimed_bitmap: BitMap,
+}
+
+impl Data {
+    /// Sets the `merkle_root` of the airdrop. This value is immutable, it can
+    /// only be set once during construction.
+    pub fn new(merkle_root: Node) -> Self {
+        let mut res = Self::default();
+        res.merkle_root = Lazy::new(merkle_root);
+        res
+    }
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self {
+            merkle_root: Lazy::default(),
+            claimed_bitmap: BitMap::new(),
+        }
+    }
+}
+
+impl Data {
+    /// Returns the root of the Merkle tree of the airdrop.
+    pub fn merkle_root(&self) -> &Node {
+        &self.merkle_root
+    }
+
+    /// Returns whether the `index` has been claimed.
+    pub fn is_claimed(&self, index: u64) -> bool {
+        self.claimed_bitmap.get(index)
+    }
+
+    /// Marks the `index` as claimed.
+    pub fn set_claimed(&mut self, index: u64) {
+        self.claimed_bitmap.set(index);
+    }
+}
+
+/// The `EventEmit` impl the event emit api for merkle distributor component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emitted when the `amount` tokens of the `index` are claimed by the
+    /// `account`.
+    fn emit_event_claimed(
+        &mut self,
+        index: u64,
+        account: E::AccountId,
+        amount: E::Balance,
+    );
+}
+
+/// Extension of {ERC20} that airdrops tokens by a Merkle tree.
+pub trait Impl<E>: ERC20<E> + Storage<E, Data> + EventEmit<E>
+where
+    E: Env,
+{
+    /// Returns the root of the Merkle tree of the airdrop.
+    fn merkle_root(&self) -> Node {
+        *Storage::<E, Data>::get(self).merkle_root()
+    }
+
+    /// Returns whether the `index` has been claimed.
+    fn is_claimed(&self, index: u64) -> bool {
+        Storage::<E, Data>::get(self).is_claimed(index)
+    }
+
+    /// Claim the `amount` tokens of the `index` to the `account` by the
+    /// `proof` of the leaf, anyone can claim for the account.
+    ///
+    /// Emits a `Claimed` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the `index` has not been claimed.
+    /// - the `proof` is valid for the leaf of `(index, account, amount)`.
+    fn claim(
+        &mut self,
+        index: u64,
+        account: E::AccountId,
+        amount: E::Balance,
+        proof: Vec<Node>,
+    ) -> Result<()> {
+        assert!(
+            !self.is_claimed(index),
+            "MerkleDistributor: drop already claimed"
+        );
+
+        let leaf = self._hash_leaf(index, &account, amount);
+        assert!(
+            self._verify(&proof, self.merkle_root(), leaf),
+            "MerkleDistributor: invalid proof"
+        );
+
+        ERC20::_mint(self, account.clone(), amount)?;
+
+        Storage::<E, Data>::get_mut(self).set_claimed(index);
+        self.emit_event_claimed(index, account, amount);
+
+        Ok(())
+    }
+
+    /// Returns the leaf of `(index, account, amount)`.
+    fn _hash_leaf(&self, index: u64, account: &E::AccountId, amount: E::Balance) -> Node {
+        Self::hash_bytes::<Blake2x256>(&(index, account, amount).encode())
+    }
+
+    /// Returns whether the `leaf` is in the tree of the `root` by the
+    /// `proof`.
+    fn _verify(&self, proof: &[Node], root: Node, leaf: Node) -> bool {
+        merkle_proof::verify::<E, Self, Blake2x256>(proof, root, leaf)
+    }
+}
@@ -0,0 +1,65 @@
+//! Extension of the ERC20 token contract to wrap the native currency, like
+//! the WETH.
+//!
+//! Users can deposit the native currency by a payable message and receive a
+//! matching number of wrapped tokens, then burn the wrapped tokens to
+//! withdraw the native currency.
+use crate::{
+    erc20::{
+        Error,
+        Result,
+    },
+    Impl as ERC20,
+};
+use metis_lang::{
+    Context,
+    Env,
+    EnvAccess,
+};
+use num_traits::CheckedSub;
+
+/// Extension of {ERC20} that wraps the native currency.
+pub trait Impl<E>: ERC20<E>
+where
+    E: Env,
+{
+    /// Mint the wrapped tokens to `account` by the native currency
+    /// transferred with the call, so the message should be payable.
+    fn deposit_for(&mut self, account: E::AccountId) -> Result<()> {
+        ERC20::_mint(self, account, Self::transferred_balance())
+    }
+
+    /// Allow a user to burn a number of wrapped tokens and withdraw the
+    /// corresponding number of native currency to `account`.
+    fn withdraw_to(&mut self, account: E::AccountId, amount: E::Balance) -> Result<()> {
+        let caller = self._msg_sender();
+        ERC20::_burn(self, caller, amount)?;
+
+        let res = <Self as EnvAccess<E>>::transfer(account, amount);
+        assert!(res.is_ok(), "ERC20NativeWrapper: transfer native error");
+
+        Ok(())
+    }
+
+    /// Mint wrapped token to cover any native currency that would have been
+    /// transferred by mistake, returns the amount minted. Internal function
+    /// that can be exposed with access control if desired.
+    ///
+    /// The minimum balance of this is kept for the existence of the contract,
+    /// which is not the native currency of the wrapped tokens.
+    ///
+    /// Returns `InsufficientBalance` error if the balance of this excluding
+    /// the minimum balance is less than the total supply.
+    fn _recover(&mut self, account: E::AccountId) -> Result<E::Balance> {
+        let value = Self::balance()
+            .checked_sub(&Self::minimum_balance())
+            .and_then(|balance| balance.checked_sub(&ERC20::total_supply(self)))
+            .ok_or(Error::InsufficientBalance)?;
+        ERC20::_mint(self, account, value)?;
+
+        Ok(value)
+    }
+}
+
+// No impl this for default
+// impl<E: Env, T: ERC20<E>> Impl<E> for T {}
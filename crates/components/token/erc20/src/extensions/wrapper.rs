@@ -0,0 +1,155 @@
+//! Extension of the ERC20 token contract to support token wrapping.
+//!
+//! Users can deposit and withdraw "underlying tokens" and receive a matching
+//! number of "wrapped tokens". This is useful in conjunction with other
+//! modules. For example, combining this wrapping mechanism with {ERC20Votes}
+//! will allow the wrapping of an existing "basic" ERC20 into a governance
+//! token.
+use crate::{
+    erc20::{
+        Error,
+        Result,
+    },
+    Impl as ERC20,
+};
+use core::convert::TryFrom;
+use ink_lang::{
+    ForwardCall,
+    ForwardCallMut,
+};
+use metis_erc20_stub::Erc20Stub;
+use metis_lang::{
+    Context,
+    Env,
+    FromAccountId,
+    Storage,
+};
+use num_traits::CheckedSub;
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The Data of ERC20 wrapper component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E: Env> {
+    /// The underlying token being wrapped.
+    underlying: Lazy<E::AccountId>,
+}
+
+impl<E: Env> Data<E> {
+    /// Sets the `underlying` token. This value is immutable, it can only be
+    /// set once during construction.
+    pub fn new(underlying: E::AccountId) -> Self {
+        let mut res = Self::default();
+        res.underlying = Lazy::new(underlying);
+        res
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            underlying: Lazy::default(),
+        }
+    }
+}
+
+impl<E: Env> Data<E> {
+    /// Returns the underlying token being wrapped.
+    pub fn underlying(&self) -> &E::AccountId {
+        &self.underlying
+    }
+}
+
+/// Extension of {ERC20} that wraps the underlying erc20 token.
+pub trait Impl<E>: ERC20<E> + Storage<E, Data<E>>
+where
+    E: Env,
+{
+    /// Returns the address of the underlying token being wrapped.
+    fn underlying(&self) -> E::AccountId {
+        Storage::<E, Data<E>>::get(self).underlying().clone()
+    }
+
+    /// Allow a user to deposit underlying tokens and mint the corresponding
+    /// number of wrapped tokens to `account`.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must approve `amount` underlying tokens to this.
+    fn deposit_for(&mut self, account: E::AccountId, amount: E::Balance) -> Result<()> {
+        ERC20::_mint(self, account, amount)?;
+
+        let mut underlying =
+            <Erc20Stub as FromAccountId<E>>::from_account_id(self.underlying());
+        let res = underlying
+            .call_mut()
+            .transfer_from(
+                self._msg_sender().into(),
+                Self::account_id().into(),
+                amount.into(),
+            )
+            .fire();
+        assert!(
+            matches!(res, Ok(Ok(()))),
+            "ERC20Wrapper: transfer from underlying error"
+        );
+
+        Ok(())
+    }
+
+    /// Allow a user to burn a number of wrapped tokens and withdraw the
+    /// corresponding number of underlying tokens to `account`.
+    fn withdraw_to(&mut self, account: E::AccountId, amount: E::Balance) -> Result<()> {
+        let caller = self._msg_sender();
+        ERC20::_burn(self, caller, amount)?;
+
+        let mut underlying =
+            <Erc20Stub as FromAccountId<E>>::from_account_id(self.underlying());
+        let res = underlying
+            .call_mut()
+            .transfer(account.into(), amount.into())
+            .fire();
+        assert!(
+            matches!(res, Ok(Ok(()))),
+            "ERC20Wrapper: transfer underlying error"
+        );
+
+        Ok(())
+    }
+
+    /// Mint wrapped token to cover any underlying tokens that would have
+    /// been transferred by mistake, returns the amount minted. Internal
+    /// function that can be exposed with access control if desired.
+    ///
+    /// Returns `InsufficientBalance` error if the underlying balance of this
+    /// is less than the total supply.
+    fn _recover(&mut self, account: E::AccountId) -> Result<E::Balance> {
+        let underlying =
+            <Erc20Stub as FromAccountId<E>>::from_account_id(self.underlying());
+        let balance = underlying
+            .call()
+            .balance_of(Self::account_id().into())
+            .fire()
+            .expect("ERC20Wrapper: get underlying balance error");
+        let underlying_balance = E::Balance::try_from(balance)
+            .unwrap_or_else(|_| panic!("ERC20Wrapper: underlying balance overflow"));
+
+        let value = underlying_balance
+            .checked_sub(&ERC20::total_supply(self))
+            .ok_or(Error::InsufficientBalance)?;
+        ERC20::_mint(self, account, value)?;
+
+        Ok(value)
+    }
+}
+
+// No impl this for default
+// impl<E: Env, T: ERC20<E> + Storage<E, Data<E>>> Impl<E> for T {}
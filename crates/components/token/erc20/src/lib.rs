@@ -8,6 +8,7 @@ mod extensions;
 mod module;
 
 pub use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     Storage,
@@ -38,6 +39,10 @@ pub mod burnable {
     pub use super::extensions::burnable::Impl;
 }
 
+pub mod flash_mint {
+    pub use super::extensions::flash_mint::Impl;
+}
+
 pub mod capped {
     pub use super::extensions::capped::{
         Data,
@@ -45,4 +50,23 @@ pub mod capped {
     };
 }
 
+pub mod wrapper {
+    pub use super::extensions::wrapper::{
+        Data,
+        Impl,
+    };
+}
+
+pub mod native_wrapper {
+    pub use super::extensions::native_wrapper::Impl;
+}
+
+pub mod merkle_distributor {
+    pub use super::extensions::merkle_distributor::{
+        Data,
+        EventEmit,
+        Impl,
+    };
+}
+
 // impl<E: Env, T: Storage<E, Data<E>> + EventEmit<E>> Impl<E> for T {}
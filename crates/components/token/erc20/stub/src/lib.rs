@@ -0,0 +1,48 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_prelude::string::String;
+
+pub use metis_erc20_types::{
+    Error,
+    Result,
+};
+
+/// The messages of the ERC20 component
+#[metis_lang::stub_contract(Erc20Stub)]
+pub trait Erc20 {
+    fn new(name: String, symbol: String, decimals: u8, initial_supply: Balance) -> Self;
+
+    /// Returns the name of the token.
+    fn name(&self) -> String;
+
+    /// Returns the symbol of the token.
+    fn symbol(&self) -> String;
+
+    /// Returns the number of decimals used to get its user representation.
+    fn decimals(&self) -> u8;
+
+    /// Returns the amount of tokens in existence.
+    fn total_supply(&self) -> Balance;
+
+    /// Returns the amount of tokens owned by `account`.
+    fn balance_of(&self, account: AccountId) -> Balance;
+
+    /// Returns the remaining number of tokens that `spender` will be
+    /// allowed to spend on behalf of `owner` through `transfer_from`.
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance;
+
+    /// Moves `amount` tokens from the caller's account to `recipient`.
+    fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()>;
+
+    /// Sets `amount` as the allowance of `spender` over the caller's tokens.
+    fn approve(&mut self, spender: AccountId, amount: Balance) -> Result<()>;
+
+    /// Moves `amount` tokens from `sender` to `recipient` using the
+    /// allowance mechanism.
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+    ) -> Result<()>;
+}
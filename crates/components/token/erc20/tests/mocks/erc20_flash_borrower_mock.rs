@@ -0,0 +1,54 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod erc20_flash_borrower {
+    use ink_prelude::vec::Vec;
+
+    /// The initiator, the token, the amount, the fee and the data of a loan.
+    pub type Loan = (AccountId, AccountId, Balance, Balance, Vec<u8>);
+
+    /// A borrower contract which records the last flash loan.
+    #[ink(storage)]
+    pub struct FlashBorrower {
+        accept: bool,
+        last_loan: Option<Loan>,
+    }
+
+    impl FlashBorrower {
+        #[ink(constructor)]
+        pub fn new(accept: bool) -> Self {
+            Self {
+                accept,
+                last_loan: None,
+            }
+        }
+
+        #[ink(constructor)]
+        pub fn default() -> Self {
+            Self::new(true)
+        }
+
+        #[ink(message)]
+        pub fn last_loan(&self) -> Option<Loan> {
+            self.last_loan.clone()
+        }
+
+        #[ink(message)]
+        pub fn on_flash_loan(
+            &mut self,
+            initiator: AccountId,
+            token: AccountId,
+            amount: Balance,
+            fee: Balance,
+            data: Vec<u8>,
+        ) -> [u8; 4] {
+            self.last_loan = Some((initiator, token, amount, fee, data));
+
+            if self.accept {
+                metis_lang::selector_id!(on_flash_loan)
+            } else {
+                [0x00; 4]
+            }
+        }
+    }
+}
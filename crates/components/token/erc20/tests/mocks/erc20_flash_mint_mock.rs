@@ -0,0 +1,127 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod erc20_flash_mint {
+    pub use erc20::{
+        Error,
+        Result,
+    };
+    use ink_prelude::vec::Vec;
+    use metis_erc20 as erc20;
+    use metis_lang::{
+        import,
+        metis,
+    };
+
+    /// The fee rate of the flash loans, in per mille.
+    pub const FLASH_FEE_RATE: Balance = 10;
+
+    /// A ERC-20 contract with the flash loans.
+    #[ink(storage)]
+    #[import(erc20)]
+    pub struct Erc20 {
+        erc20: erc20::Data<Erc20>,
+    }
+
+    #[cfg(not(feature = "ink-as-dependency"))]
+    impl erc20::Impl<Erc20> for Erc20 {}
+
+    #[cfg(not(feature = "ink-as-dependency"))]
+    impl erc20::flash_mint::Impl<Erc20> for Erc20 {
+        fn flash_fee(&self, token: AccountId, amount: Balance) -> Balance {
+            assert!(
+                token == Self::env().account_id(),
+                "ERC20FlashMint: wrong token"
+            );
+
+            amount * FLASH_FEE_RATE / 1000
+        }
+    }
+
+    /// Event emitted when a token transfer occurs.
+    #[ink(event)]
+    #[metis(erc20)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub value: Balance,
+    }
+
+    /// Event emitted when an approval occurs that `spender` is allowed to withdraw
+    /// up to the amount of `value` tokens from `owner`.
+    #[ink(event)]
+    #[metis(erc20)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: AccountId,
+        pub value: Balance,
+    }
+
+    // impl
+    impl Erc20 {
+        #[ink(constructor)]
+        pub fn new(
+            name: String,
+            symbol: String,
+            decimals: u8,
+            initial_supply: Balance,
+        ) -> Self {
+            let mut instance = Self {
+                erc20: erc20::Data::new(),
+            };
+
+            erc20::Impl::init(&mut instance, name, symbol, decimals, initial_supply);
+            instance
+        }
+
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            erc20::Impl::total_supply(self)
+        }
+
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            erc20::Impl::balance_of(self, owner)
+        }
+
+        #[ink(message)]
+        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            erc20::Impl::allowance(self, owner, spender)
+        }
+
+        #[ink(message)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            erc20::Impl::transfer(self, to, value)
+        }
+
+        #[ink(message)]
+        pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            erc20::Impl::approve(self, spender, value)
+        }
+
+        #[ink(message)]
+        pub fn max_flash_loan(&self, token: AccountId) -> Balance {
+            erc20::flash_mint::Impl::max_flash_loan(self, token)
+        }
+
+        #[ink(message)]
+        pub fn flash_fee(&self, token: AccountId, amount: Balance) -> Balance {
+            erc20::flash_mint::Impl::flash_fee(self, token, amount)
+        }
+
+        #[ink(message)]
+        pub fn flash_loan(
+            &mut self,
+            receiver: AccountId,
+            token: AccountId,
+            amount: Balance,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            erc20::flash_mint::Impl::flash_loan(self, receiver, token, amount, data)
+        }
+    }
+}
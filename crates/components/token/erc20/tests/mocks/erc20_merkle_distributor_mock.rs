@@ -0,0 +1,113 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod erc20_merkle_distributor {
+    pub use erc20::{
+        merkle_distributor,
+        Error,
+        Result,
+    };
+    use ink_prelude::vec::Vec;
+    use metis_erc20 as erc20;
+    use metis_lang::{
+        import,
+        metis,
+    };
+
+    /// A ERC-20 contract which airdrops tokens by a Merkle tree.
+    #[ink(storage)]
+    #[import(erc20, merkle_distributor)]
+    pub struct Erc20 {
+        erc20: erc20::Data<Erc20>,
+        merkle_distributor: merkle_distributor::Data,
+    }
+
+    #[cfg(not(feature = "ink-as-dependency"))]
+    impl erc20::Impl<Erc20> for Erc20 {}
+
+    #[cfg(not(feature = "ink-as-dependency"))]
+    impl merkle_distributor::Impl<Erc20> for Erc20 {}
+
+    /// Event emitted when a token transfer occurs.
+    #[ink(event)]
+    #[metis(erc20)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub value: Balance,
+    }
+
+    /// Event emitted when an approval occurs that `spender` is allowed to withdraw
+    /// up to the amount of `value` tokens from `owner`.
+    #[ink(event)]
+    #[metis(erc20)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: AccountId,
+        pub value: Balance,
+    }
+
+    /// Event emitted when the airdrop of `index` is claimed.
+    #[ink(event)]
+    #[metis(merkle_distributor)]
+    pub struct Claimed {
+        pub index: u64,
+        #[ink(topic)]
+        pub account: AccountId,
+        pub amount: Balance,
+    }
+
+    // impl
+    impl Erc20 {
+        #[ink(constructor)]
+        pub fn new(
+            merkle_root: [u8; 32],
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Self {
+            let mut instance = Self {
+                erc20: erc20::Data::new(),
+                merkle_distributor: merkle_distributor::Data::new(merkle_root),
+            };
+
+            erc20::Impl::init(&mut instance, name, symbol, decimals, 0);
+            instance
+        }
+
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            erc20::Impl::total_supply(self)
+        }
+
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            erc20::Impl::balance_of(self, owner)
+        }
+
+        #[ink(message)]
+        pub fn merkle_root(&self) -> [u8; 32] {
+            merkle_distributor::Impl::merkle_root(self)
+        }
+
+        #[ink(message)]
+        pub fn is_claimed(&self, index: u64) -> bool {
+            merkle_distributor::Impl::is_claimed(self, index)
+        }
+
+        #[ink(message)]
+        pub fn claim(
+            &mut self,
+            index: u64,
+            account: AccountId,
+            amount: Balance,
+            proof: Vec<[u8; 32]>,
+        ) -> Result<()> {
+            merkle_distributor::Impl::claim(self, index, account, amount, proof)
+        }
+    }
+}
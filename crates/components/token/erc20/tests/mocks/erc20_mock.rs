@@ -2,7 +2,6 @@
 
 #[metis_lang::contract]
 pub mod erc20_contract {
-    use super::super::behavior;
     pub use erc20::{
         Error,
         Result,
@@ -49,7 +48,11 @@ pub mod erc20_contract {
         pub value: Balance,
     }
 
-    impl behavior::IERC20New<Erc20> for Erc20 {
+    impl metis_test::Erc20Behaviour for Erc20 {
+        type Error = Error;
+
+        const NAME: &'static str = "Erc20";
+
         fn new_erc20(
             name: String,
             symbol: String,
@@ -59,69 +62,92 @@ pub mod erc20_contract {
             Self::new(name, symbol, decimals, initial_supply)
         }
 
-        fn next_call_by(account: AccountId) {
-            // Get contract address.
-            let callee = ink_env::account_id::<ink_env::DefaultEnvironment>()
-                .unwrap_or([0x0; 32].into());
-            // Create call.
-            let mut data =
-                ink_env::test::CallData::new(ink_env::call::Selector::new([0x00; 4]));
-
-            data.push_arg(&account.clone());
-
-            // Push the new execution context to set from as caller.
-            ink_env::test::push_execution_context::<ink_env::DefaultEnvironment>(
-                account.clone(),
-                callee,
-                1000000,
-                1000000,
-                data,
-            );
+        fn error(kind: metis_test::Erc20ErrorKind) -> Error {
+            match kind {
+                metis_test::Erc20ErrorKind::InsufficientBalance => {
+                    Error::InsufficientBalance
+                }
+                metis_test::Erc20ErrorKind::InsufficientAllowance => {
+                    Error::InsufficientAllowance
+                }
+                metis_test::Erc20ErrorKind::AccountIsZero => Error::AccountIsZero,
+            }
         }
-    }
 
-    impl behavior::IERC20Event<Erc20> for Erc20 {
         fn decode_transfer_event(
             event: &ink_env::test::EmittedEvent,
-        ) -> (Option<AccountId>, Option<AccountId>, Balance) {
-            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
-                .expect("encountered invalid contract event data buffer");
-            if let Event::Transfer(Transfer { from, to, value }) = decoded_event {
-                return (from, to, value)
+        ) -> Option<(Option<AccountId>, Option<AccountId>, Balance)> {
+            match <Event as scale::Decode>::decode(&mut &event.data[..]).ok()? {
+                Event::Transfer(Transfer { from, to, value }) => Some((from, to, value)),
+                _ => None,
             }
-            panic!("encountered unexpected event kind: expected a Transfer event")
         }
 
         fn decode_approval_event(
             event: &ink_env::test::EmittedEvent,
-        ) -> (AccountId, AccountId, Balance) {
-            let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
-                .expect("encountered invalid contract event data buffer");
-            if let Event::Approval(Approval {
-                owner,
-                spender,
-                value,
-            }) = decoded_event
-            {
-                return (owner, spender, value)
+        ) -> Option<(AccountId, AccountId, Balance)> {
+            match <Event as scale::Decode>::decode(&mut &event.data[..]).ok()? {
+                Event::Approval(Approval {
+                    owner,
+                    spender,
+                    value,
+                }) => Some((owner, spender, value)),
+                _ => None,
             }
-            panic!("encountered unexpected event kind: expected a Transfer event")
         }
 
-        fn assert_topics(
-            event: &ink_env::test::EmittedEvent,
-            expected_topics: &Vec<Hash>,
-        ) {
-            for (n, (actual_topic, expected_topic)) in
-                event.topics.iter().zip(expected_topics).enumerate()
-            {
-                let topic = actual_topic
-                    .decode::<Hash>()
-                    .expect("encountered invalid topic encoding");
-                assert_eq!(topic, *expected_topic, "encountered invalid topic at {}", n);
-            }
+        fn name(&self) -> String {
+            Erc20::name(self)
+        }
+
+        fn symbol(&self) -> String {
+            Erc20::symbol(self)
+        }
+
+        fn decimals(&self) -> u8 {
+            Erc20::decimals(self)
+        }
+
+        fn total_supply(&self) -> Balance {
+            Erc20::total_supply(self)
+        }
+
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            Erc20::balance_of(self, owner)
+        }
+
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            Erc20::allowance(self, owner, spender)
+        }
+
+        fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            Erc20::transfer(self, to, value)
+        }
+
+        fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            Erc20::approve(self, spender, value)
+        }
+
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<()> {
+            Erc20::transfer_from(self, from, to, value)
+        }
+    }
+
+    impl metis_test::Erc20Invariant for Erc20 {
+        fn mint(&mut self, to: AccountId, value: Balance) -> Option<Result<()>> {
+            Some(Erc20::mint(self, to, value))
+        }
+
+        fn burn(&mut self, from: AccountId, value: Balance) -> Option<Result<()>> {
+            Some(Erc20::burn(self, from, value))
         }
     }
+
     // impl
     impl Erc20 {
         #[ink(constructor)]
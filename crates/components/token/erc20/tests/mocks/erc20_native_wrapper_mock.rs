@@ -0,0 +1,89 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod erc20_native_wrapper {
+    pub use erc20::{
+        native_wrapper,
+        Error,
+        Result,
+    };
+    use metis_erc20 as erc20;
+    use metis_lang::{
+        import,
+        metis,
+    };
+
+    /// A ERC-20 contract which wraps the native currency.
+    #[ink(storage)]
+    #[import(erc20)]
+    pub struct Erc20 {
+        erc20: erc20::Data<Erc20>,
+    }
+
+    #[cfg(not(feature = "ink-as-dependency"))]
+    impl erc20::Impl<Erc20> for Erc20 {}
+
+    #[cfg(not(feature = "ink-as-dependency"))]
+    impl native_wrapper::Impl<Erc20> for Erc20 {}
+
+    /// Event emitted when a token transfer occurs.
+    #[ink(event)]
+    #[metis(erc20)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub value: Balance,
+    }
+
+    /// Event emitted when an approval occurs that `spender` is allowed to withdraw
+    /// up to the amount of `value` tokens from `owner`.
+    #[ink(event)]
+    #[metis(erc20)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: AccountId,
+        pub value: Balance,
+    }
+
+    // impl
+    impl Erc20 {
+        #[ink(constructor)]
+        pub fn new(name: String, symbol: String, decimals: u8) -> Self {
+            let mut instance = Self {
+                erc20: erc20::Data::new(),
+            };
+
+            erc20::Impl::init(&mut instance, name, symbol, decimals, 0);
+            instance
+        }
+
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            erc20::Impl::total_supply(self)
+        }
+
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            erc20::Impl::balance_of(self, owner)
+        }
+
+        #[ink(message, payable)]
+        pub fn deposit_for(&mut self, account: AccountId) -> Result<()> {
+            native_wrapper::Impl::deposit_for(self, account)
+        }
+
+        #[ink(message)]
+        pub fn withdraw_to(&mut self, account: AccountId, amount: Balance) -> Result<()> {
+            native_wrapper::Impl::withdraw_to(self, account, amount)
+        }
+
+        #[ink(message)]
+        pub fn recover(&mut self, account: AccountId) -> Result<Balance> {
+            native_wrapper::Impl::_recover(self, account)
+        }
+    }
+}
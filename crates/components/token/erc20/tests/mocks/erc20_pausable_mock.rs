@@ -0,0 +1,315 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod erc20_pausable {
+    pub use erc20::{
+        capped,
+        Error,
+        Result,
+    };
+    use metis_erc20 as erc20;
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_pausable as pausable;
+
+    /// A simple ERC-20 contract.
+    #[ink(storage)]
+    #[import(erc20, capped, pausable)]
+    pub struct Erc20 {
+        erc20: erc20::Data<Erc20>,
+        capped: capped::Data<Erc20>,
+        pausable: pausable::Data,
+    }
+
+    // TODO: gen by marco with erc20 component
+    #[cfg(not(feature = "ink-as-dependency"))]
+    impl erc20::pausable::Impl<Erc20> for Erc20 {}
+
+    type Event = <Erc20 as ink_lang::BaseEvent>::Type;
+
+    #[cfg(not(feature = "ink-as-dependency"))]
+    impl capped::Impl<Erc20> for Erc20 {}
+
+    /// Event emitted when a token transfer occurs.
+    #[ink(event)]
+    #[metis(erc20)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub value: Balance,
+    }
+
+    /// Event emitted when an approval occurs that `spender` is allowed to withdraw
+    /// up to the amount of `value` tokens from `owner`.
+    #[ink(event)]
+    #[metis(erc20)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: AccountId,
+        pub value: Balance,
+    }
+
+    /// Event emitted when Pause
+    #[ink(event)]
+    #[metis(pausable)]
+    pub struct Paused {
+        /// paused caller
+        #[ink(topic)]
+        pub account: AccountId,
+    }
+
+    /// Event emitted when unPause
+    #[ink(event)]
+    #[metis(pausable)]
+    pub struct Unpaused {
+        /// unpaused caller
+        #[ink(topic)]
+        pub account: AccountId,
+    }
+
+    impl metis_test::Erc20Behaviour for Erc20 {
+        type Error = Error;
+
+        const NAME: &'static str = "Erc20";
+
+        fn new_erc20(
+            name: String,
+            symbol: String,
+            decimals: u8,
+            initial_supply: Balance,
+        ) -> Self {
+            Self::new(name, symbol, decimals, initial_supply, initial_supply * 2)
+        }
+
+        fn error(kind: metis_test::Erc20ErrorKind) -> Error {
+            match kind {
+                metis_test::Erc20ErrorKind::InsufficientBalance => {
+                    Error::InsufficientBalance
+                }
+                metis_test::Erc20ErrorKind::InsufficientAllowance => {
+                    Error::InsufficientAllowance
+                }
+                metis_test::Erc20ErrorKind::AccountIsZero => Error::AccountIsZero,
+            }
+        }
+
+        fn decode_transfer_event(
+            event: &ink_env::test::EmittedEvent,
+        ) -> Option<(Option<AccountId>, Option<AccountId>, Balance)> {
+            match <Event as scale::Decode>::decode(&mut &event.data[..]).ok()? {
+                Event::Transfer(Transfer { from, to, value }) => Some((from, to, value)),
+                _ => None,
+            }
+        }
+
+        fn decode_approval_event(
+            event: &ink_env::test::EmittedEvent,
+        ) -> Option<(AccountId, AccountId, Balance)> {
+            match <Event as scale::Decode>::decode(&mut &event.data[..]).ok()? {
+                Event::Approval(Approval {
+                    owner,
+                    spender,
+                    value,
+                }) => Some((owner, spender, value)),
+                _ => None,
+            }
+        }
+
+        fn name(&self) -> String {
+            Erc20::name(self)
+        }
+
+        fn symbol(&self) -> String {
+            Erc20::symbol(self)
+        }
+
+        fn decimals(&self) -> u8 {
+            Erc20::decimals(self)
+        }
+
+        fn total_supply(&self) -> Balance {
+            Erc20::total_supply(self)
+        }
+
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            Erc20::balance_of(self, owner)
+        }
+
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            Erc20::allowance(self, owner, spender)
+        }
+
+        fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            Erc20::transfer(self, to, value)
+        }
+
+        fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            Erc20::approve(self, spender, value)
+        }
+
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<()> {
+            Erc20::transfer_from(self, from, to, value)
+        }
+    }
+
+    impl metis_test::Erc20Invariant for Erc20 {
+        fn cap(&self) -> Option<Balance> {
+            Some(Erc20::cap(self))
+        }
+
+        fn mint(&mut self, to: AccountId, value: Balance) -> Option<Result<()>> {
+            Some(Erc20::mint(self, to, value))
+        }
+
+        fn burn(&mut self, from: AccountId, value: Balance) -> Option<Result<()>> {
+            Some(Erc20::burn(self, from, value))
+        }
+
+        fn paused(&self) -> Option<bool> {
+            Some(Erc20::paused(self))
+        }
+
+        fn pause(&mut self) -> Option<()> {
+            Some(Erc20::pause(self))
+        }
+
+        fn unpause(&mut self) -> Option<()> {
+            Some(Erc20::unpause(self))
+        }
+    }
+
+    // impl
+    impl Erc20 {
+        #[ink(constructor)]
+        pub fn new(
+            name: String,
+            symbol: String,
+            decimals: u8,
+            initial_supply: Balance,
+            cap_supply: Balance,
+        ) -> Self {
+            let mut instance = Self {
+                erc20: erc20::Data::new(),
+                capped: capped::Data::new(cap_supply),
+                pausable: pausable::Data::new(),
+            };
+
+            erc20::Impl::init(&mut instance, name, symbol, decimals, initial_supply);
+            pausable::Impl::init(&mut instance);
+            instance
+        }
+
+        // ERC20 messages
+        #[ink(message)]
+        pub fn name(&self) -> String {
+            erc20::Impl::name(self)
+        }
+
+        #[ink(message)]
+        pub fn symbol(&self) -> String {
+            erc20::Impl::symbol(self)
+        }
+
+        #[ink(message)]
+        pub fn decimals(&self) -> u8 {
+            erc20::Impl::decimals(self)
+        }
+
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            erc20::Impl::total_supply(self)
+        }
+
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            erc20::Impl::balance_of(self, owner)
+        }
+
+        #[ink(message)]
+        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            erc20::Impl::allowance(self, owner, spender)
+        }
+
+        #[ink(message)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            erc20::Impl::transfer(self, to, value)
+        }
+
+        #[ink(message)]
+        pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            erc20::Impl::approve(self, spender, value)
+        }
+
+        #[ink(message)]
+        pub fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<()> {
+            erc20::Impl::transfer_from(self, from, to, value)
+        }
+
+        #[ink(message)]
+        pub fn cap(&self) -> Balance {
+            capped::Impl::cap(self)
+        }
+
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            // TODO: its maybe forget by developers: not erc20::Impl::_mint(self, &to, value)
+            capped::Impl::_mint(self, to, value)
+        }
+
+        #[ink(message)]
+        pub fn burn(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            erc20::Impl::_burn(self, to, value)
+        }
+
+        #[ink(message)]
+        pub fn paused(&self) -> bool {
+            pausable::Impl::paused(self)
+        }
+
+        #[ink(message)]
+        pub fn pause(&mut self) {
+            pausable::Impl::_pause(self)
+        }
+
+        #[ink(message)]
+        pub fn unpause(&mut self) {
+            pausable::Impl::_unpause(self)
+        }
+
+        #[ink(message)]
+        pub fn transfer_internal(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<()> {
+            erc20::Impl::_transfer_from_to(self, from, to, value)
+        }
+
+        #[ink(message)]
+        pub fn approve_internal(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            value: Balance,
+        ) -> Result<()> {
+            erc20::Impl::_approve(self, owner, spender, value)
+        }
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc20_flash_borrower_mock;
+}
+
+mod erc20_borrower_stub_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::vec::Vec;
+    use metis_erc20_borrower::{
+        FlashBorrower as FlashBorrowerMessages,
+        FlashBorrowerStub,
+    };
+    use metis_lang::mock::check_messages;
+    use metis_test::*;
+    use mocks::erc20_flash_borrower_mock::erc20_flash_borrower::FlashBorrower;
+
+    extern "Rust" {
+        fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+    }
+
+    /// The messages of the stub must be the messages of the real contract
+    /// with the same signatures.
+    impl FlashBorrowerMessages for FlashBorrower {
+        fn on_flash_loan(
+            &mut self,
+            initiator: AccountId,
+            token: AccountId,
+            amount: Balance,
+            fee: Balance,
+            data: Vec<u8>,
+        ) -> [u8; 4] {
+            FlashBorrower::on_flash_loan(self, initiator, token, amount, fee, data)
+        }
+    }
+
+    #[test]
+    fn stub_messages_should_match_contract() {
+        let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+            .expect("metadata should be serialized");
+
+        assert_eq!(
+            check_messages(&metadata, FlashBorrowerStub::MESSAGES),
+            Ok(())
+        );
+    }
+
+    #[ink::test]
+    fn on_flash_loan_should_record_the_loan() {
+        let accounts = default_accounts();
+        let mut borrower = FlashBorrower::new(true);
+
+        assert_eq!(
+            FlashBorrowerMessages::on_flash_loan(
+                &mut borrower,
+                accounts.alice,
+                accounts.eve,
+                100,
+                1,
+                Vec::from([0x01]),
+            ),
+            metis_lang::selector_id!(on_flash_loan)
+        );
+        assert_eq!(
+            borrower.last_loan(),
+            Some((accounts.alice, accounts.eve, 100, 1, Vec::from([0x01])))
+        );
+
+        let mut rejecter = FlashBorrower::new(false);
+        assert_eq!(
+            rejecter.on_flash_loan(accounts.alice, accounts.eve, 100, 1, Vec::new()),
+            [0x00; 4]
+        );
+    }
+}
@@ -0,0 +1,27 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc20_capped_mock;
+}
+
+mod erc20_capped_invariants_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use metis_test::{
+        erc20_ops,
+        run_erc20_invariants,
+    };
+    use mocks::erc20_capped_mock::erc20_capped;
+    use proptest::prelude::*;
+
+    const INIT_AMOUNT: u128 = 1000;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn erc20_capped_invariants_hold(ops in erc20_ops(INIT_AMOUNT * 2, 48)) {
+            run_erc20_invariants::<erc20_capped::Erc20>(INIT_AMOUNT, &ops);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc20_mock;
+}
+
+mod erc20_events_tests {
+    use super::*;
+    use erc20_contract::Erc20;
+    use ink::ContractEnv;
+    use ink_lang as ink;
+    use ink_prelude::string::String;
+    use metis_lang::events::{
+        ContractEvents,
+        Error,
+        Event,
+    };
+    use metis_test::*;
+    use mocks::erc20_mock::erc20_contract;
+
+    type AccountId = <<Erc20 as ContractEnv>::Env as ink_env::Environment>::AccountId;
+    type Balance = <<Erc20 as ContractEnv>::Env as ink_env::Environment>::Balance;
+
+    /// The events part of the metadata of the mock.
+    const METADATA: &str = r#"{
+        "metadataVersion": "0.1.0",
+        "contract": {"name": "erc20_mock", "version": "0.1.0"},
+        "V1": {
+            "spec": {
+                "constructors": [],
+                "messages": [],
+                "events": [
+                    {
+                        "name": "Transfer",
+                        "args": [
+                            {"name": "from", "indexed": true, "type": {"displayName": ["Option"], "type": 1}},
+                            {"name": "to", "indexed": true, "type": {"displayName": ["Option"], "type": 1}},
+                            {"name": "value", "indexed": false, "type": {"displayName": ["Balance"], "type": 2}}
+                        ]
+                    },
+                    {
+                        "name": "Approval",
+                        "args": [
+                            {"name": "owner", "indexed": true, "type": {"displayName": ["AccountId"], "type": 3}},
+                            {"name": "spender", "indexed": true, "type": {"displayName": ["AccountId"], "type": 3}},
+                            {"name": "value", "indexed": false, "type": {"displayName": ["Balance"], "type": 2}}
+                        ]
+                    }
+                ]
+            },
+            "types": []
+        }
+    }"#;
+
+    /// The event type decoded by an indexer.
+    #[derive(Debug, PartialEq, Eq, scale::Decode)]
+    struct Transfer {
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    impl Event for Transfer {
+        const NAME: &'static str = "Transfer";
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Decode)]
+    struct Approval {
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+    }
+
+    impl Event for Approval {
+        const NAME: &'static str = "Approval";
+    }
+
+    fn events() -> ContractEvents {
+        ContractEvents::from_metadata("Erc20", METADATA)
+            .expect("metadata should be parsed")
+    }
+
+    fn topics(event: &ink_env::test::EmittedEvent) -> Vec<Hash> {
+        event
+            .topics
+            .iter()
+            .map(|topic| {
+                scale::Decode::decode(&mut topic.as_slice())
+                    .expect("topic should be a hash")
+            })
+            .collect()
+    }
+
+    fn init() -> Erc20 {
+        Erc20::new(
+            String::from("MockErc20Token"),
+            String::from("MET"),
+            18_u8,
+            1000,
+        )
+    }
+
+    #[ink::test]
+    fn parse_metadata_works() {
+        let events = events();
+
+        assert_eq!(events.events().len(), 2);
+        assert_eq!(events.event("Transfer").unwrap().index, 0);
+        assert_eq!(events.event("Approval").unwrap().index, 1);
+        assert!(events.event("Approval").unwrap().fields[0].indexed);
+        assert!(!events.event("Approval").unwrap().fields[2].indexed);
+        assert!(matches!(events.event("Burn"), Err(Error::UnknownEvent(_))));
+
+        assert_eq!(events.signature("Transfer").unwrap(), "Erc20::Transfer");
+        assert!(matches!(
+            ContractEvents::from_metadata("Erc20", "{}"),
+            Err(Error::Metadata(_))
+        ));
+    }
+
+    #[ink::test]
+    fn topics_should_same_as_emitted() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        assert!(erc20.approve(accounts.bob, 100).is_ok());
+
+        let events = events();
+        let emitted = get_last_emitted_event();
+        assert_topics(
+            &emitted,
+            &[
+                events.signature_topic("Approval").unwrap(),
+                events
+                    .field_topic("Approval", "owner", &accounts.alice)
+                    .unwrap(),
+                events
+                    .field_topic("Approval", "spender", &accounts.bob)
+                    .unwrap(),
+            ],
+        );
+
+        // same as the helpers of the tests.
+        assert_eq!(
+            events.signature_topic("Approval").unwrap(),
+            signature_topic(b"Erc20::Approval")
+        );
+
+        // only the topic fields have the topics.
+        assert!(matches!(
+            events.field_topic("Approval", "value", &100_u128),
+            Err(Error::UnknownTopic(_))
+        ));
+    }
+
+    #[ink::test]
+    fn decode_emitted_events_works() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        assert!(erc20.transfer(accounts.bob, 10).is_ok());
+        assert!(erc20.approve(accounts.charlie, 20).is_ok());
+
+        let events = events();
+        let emitted = assert_emitted_event_len(3);
+
+        // the init mint.
+        assert_eq!(
+            events.decode::<Transfer>(&emitted[0].data).unwrap(),
+            Transfer {
+                from: None,
+                to: Some(accounts.alice),
+                value: 1000,
+            }
+        );
+
+        assert_eq!(
+            events
+                .decode_with_topics::<Transfer>(&emitted[1].data, &topics(&emitted[1]))
+                .unwrap(),
+            Transfer {
+                from: Some(accounts.alice),
+                to: Some(accounts.bob),
+                value: 10,
+            }
+        );
+
+        let approval = &emitted[2];
+        assert_eq!(
+            events
+                .identify(&approval.data, &topics(approval))
+                .map(|event| event.name.as_str()),
+            Some("Approval")
+        );
+        assert_eq!(
+            events.decode::<Approval>(&approval.data).unwrap(),
+            Approval {
+                owner: accounts.alice,
+                spender: accounts.charlie,
+                value: 20,
+            }
+        );
+
+        // an approval is not decoded as a transfer.
+        assert!(matches!(
+            events.decode::<Transfer>(&approval.data),
+            Err(Error::Mismatch(_))
+        ));
+        assert!(matches!(
+            events.decode_with_topics::<Approval>(&approval.data, &topics(&emitted[1])),
+            Err(Error::Mismatch(_))
+        ));
+    }
+}
@@ -0,0 +1,216 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc20_flash_mint_mock;
+}
+
+mod erc20_flash_mint_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_erc20_borrower::{
+        FlashBorrower,
+        FlashBorrowerStub,
+    };
+    use metis_test::*;
+    use mocks::erc20_flash_mint_mock::erc20_flash_mint::*;
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    const INIT_AMOUNT: Balance = 100000;
+
+    /// A borrower contract which records the loans.
+    #[derive(Default)]
+    struct MockBorrower {
+        accept: bool,
+        loans: Vec<(AccountId, AccountId, Balance, Balance, Vec<u8>)>,
+    }
+
+    impl FlashBorrower for MockBorrower {
+        fn on_flash_loan(
+            &mut self,
+            initiator: AccountId,
+            token: AccountId,
+            amount: Balance,
+            fee: Balance,
+            data: Vec<u8>,
+        ) -> [u8; 4] {
+            self.loans.push((initiator, token, amount, fee, data));
+
+            if self.accept {
+                metis_lang::selector_id!(on_flash_loan)
+            } else {
+                [0x00; 4]
+            }
+        }
+    }
+
+    fn init() -> Erc20 {
+        Erc20::new(
+            String::from("MockFlashMintToken"),
+            String::from("MET"),
+            18_u8,
+            INIT_AMOUNT,
+        )
+    }
+
+    fn mock_borrower(account: AccountId, accept: bool) -> Rc<RefCell<MockBorrower>> {
+        let borrower = Rc::new(RefCell::new(MockBorrower {
+            accept,
+            ..Default::default()
+        }));
+        FlashBorrowerStub::mock(account, borrower.clone());
+
+        borrower
+    }
+
+    #[ink::test]
+    fn max_flash_loan_works() {
+        let accounts = default_accounts();
+        let erc20 = init();
+
+        assert_eq!(
+            erc20.max_flash_loan(contract_id()),
+            Balance::MAX - INIT_AMOUNT
+        );
+        assert_eq!(erc20.max_flash_loan(accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn flash_fee_works() {
+        let erc20 = init();
+
+        assert_eq!(erc20.flash_fee(contract_id(), 10000), 100);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC20FlashMint: wrong token")]
+    fn flash_fee_with_wrong_token_should_panic() {
+        let accounts = default_accounts();
+        let erc20 = init();
+
+        erc20.flash_fee(accounts.bob, 10000);
+    }
+
+    #[ink::test]
+    fn flash_loan_works() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+        let token = contract_id();
+
+        let amount = 10000;
+        let fee = erc20.flash_fee(token, amount);
+
+        // bob own the fee and approve the refund to the token
+        assert_eq!(erc20.transfer(accounts.bob, fee), Ok(()));
+        assert_eq!(
+            call_as(accounts.bob, || erc20.approve(token, amount + fee)),
+            Ok(())
+        );
+
+        let borrower = mock_borrower(accounts.bob, true);
+        let events_count = get_emitted_events().len();
+
+        assert_eq!(
+            erc20.flash_loan(accounts.bob, token, amount, vec![0x01, 0x02]),
+            Ok(())
+        );
+
+        assert_eq!(
+            borrower.borrow().loans,
+            vec![(accounts.alice, token, amount, fee, vec![0x01, 0x02])],
+            "borrower should be called with the loan"
+        );
+
+        assert_eq!(erc20.balance_of(accounts.bob), 0);
+        assert_eq!(erc20.allowance(accounts.bob, token), 0);
+        assert_eq!(erc20.total_supply(), INIT_AMOUNT - fee);
+
+        let emitted_events = get_emitted_events();
+        assert_eq!(emitted_events.len(), events_count + 3);
+
+        let minted: Transfer = decode_event(&emitted_events[events_count])
+            .expect("the loan should be minted");
+        assert_eq!(
+            (minted.from, minted.to, minted.value),
+            (None, Some(accounts.bob), amount)
+        );
+
+        let burned: Transfer = decode_event(&emitted_events[events_count + 2])
+            .expect("the refund should be burned");
+        assert_eq!(
+            (burned.from, burned.to, burned.value),
+            (Some(accounts.bob), None, amount + fee)
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC20FlashMint: invalid return value")]
+    fn flash_loan_rejected_should_panic() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+        let token = contract_id();
+
+        mock_borrower(accounts.bob, false);
+
+        let _ = erc20.flash_loan(accounts.bob, token, 10000, Vec::new());
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC20FlashMint: invalid return value")]
+    fn flash_loan_to_account_without_code_should_panic() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+        let token = contract_id();
+
+        let _ = erc20.flash_loan(accounts.bob, token, 10000, Vec::new());
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC20FlashMint: allowance does not allow refund")]
+    fn flash_loan_without_allowance_should_panic() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+        let token = contract_id();
+
+        mock_borrower(accounts.bob, true);
+
+        let _ = erc20.flash_loan(accounts.bob, token, 10000, Vec::new());
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC20FlashMint: burn the refund error")]
+    fn flash_loan_without_fee_should_panic() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+        let token = contract_id();
+
+        let amount = 10000;
+        let fee = erc20.flash_fee(token, amount);
+        assert_eq!(
+            call_as(accounts.bob, || erc20.approve(token, amount + fee)),
+            Ok(())
+        );
+
+        mock_borrower(accounts.bob, true);
+
+        let _ = erc20.flash_loan(accounts.bob, token, amount, Vec::new());
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC20FlashMint: wrong token")]
+    fn flash_loan_with_wrong_token_should_panic() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        mock_borrower(accounts.bob, true);
+
+        let _ = erc20.flash_loan(accounts.bob, accounts.charlie, 0, Vec::new());
+    }
+}
@@ -0,0 +1,244 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc20_merkle_distributor_mock;
+}
+
+mod erc20_merkle_distributor_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_env::hash::Blake2x256;
+    use ink_lang as ink;
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_lang::merkle_proof;
+    use metis_test::{
+        merkle::hash,
+        *,
+    };
+    use mocks::erc20_merkle_distributor_mock::erc20_merkle_distributor::{
+        Claimed,
+        Erc20,
+    };
+    use scale::Encode;
+
+    type Node = [u8; 32];
+
+    /// The airdrop list, the index of each item is its position.
+    fn drops() -> Vec<(AccountId, Balance)> {
+        let accounts = default_accounts();
+
+        vec![
+            (accounts.alice, 100),
+            (accounts.bob, 200),
+            (accounts.charlie, 300),
+            (accounts.django, 400),
+            (accounts.eve, 500),
+        ]
+    }
+
+    fn leaf(index: u64, account: AccountId, amount: Balance) -> Node {
+        hash::<Blake2x256>(&(index, account, amount).encode())
+    }
+
+    fn tree() -> MerkleTree<Blake2x256> {
+        let leaves = drops()
+            .iter()
+            .enumerate()
+            .map(|(index, (account, amount))| leaf(index as u64, *account, *amount))
+            .collect::<Vec<_>>();
+
+        MerkleTree::new(&leaves)
+    }
+
+    fn init() -> Erc20 {
+        Erc20::new(
+            tree().root(),
+            String::from("MerkleToken"),
+            String::from("MKT"),
+            18,
+        )
+    }
+
+    #[ink::test]
+    fn new_works() {
+        let erc20 = init();
+
+        assert_eq!(erc20.merkle_root(), tree().root());
+        assert_eq!(erc20.total_supply(), 0);
+        for index in 0..drops().len() as u64 {
+            assert!(!erc20.is_claimed(index));
+        }
+    }
+
+    #[ink::test]
+    fn claim_works() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+        let tree = tree();
+
+        // anyone can claim for the account
+        let res = call_as(accounts.frank, || {
+            erc20.claim(1, accounts.bob, 200, tree.proof(1))
+        });
+        assert_eq!(res, Ok(()));
+
+        assert!(erc20.is_claimed(1));
+        assert!(!erc20.is_claimed(0));
+        assert_eq!(erc20.balance_of(accounts.bob), 200);
+        assert_eq!(erc20.total_supply(), 200);
+
+        let Claimed {
+            index,
+            account,
+            amount,
+        } = expect_event::<Claimed>();
+        assert_eq!((index, account, amount), (1, accounts.bob, 200));
+    }
+
+    #[ink::test]
+    fn claim_all_works() {
+        let mut erc20 = init();
+        let tree = tree();
+
+        for (index, (account, amount)) in drops().into_iter().enumerate() {
+            let proof = tree.proof(index);
+            assert_eq!(erc20.claim(index as u64, account, amount, proof), Ok(()));
+            assert_eq!(erc20.balance_of(account), amount);
+        }
+
+        assert_eq!(erc20.total_supply(), 1500);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "MerkleDistributor: drop already claimed")]
+    fn claim_twice_should_panic() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+        let tree = tree();
+
+        assert_eq!(erc20.claim(0, accounts.alice, 100, tree.proof(0)), Ok(()));
+        let _ = erc20.claim(0, accounts.alice, 100, tree.proof(0));
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "MerkleDistributor: invalid proof")]
+    fn claim_with_wrong_amount_should_panic() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        let _ = erc20.claim(0, accounts.alice, 101, tree().proof(0));
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "MerkleDistributor: invalid proof")]
+    fn claim_with_wrong_account_should_panic() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        let _ = erc20.claim(0, accounts.frank, 100, tree().proof(0));
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "MerkleDistributor: invalid proof")]
+    fn claim_with_proof_of_other_index_should_panic() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        let _ = erc20.claim(0, accounts.alice, 100, tree().proof(1));
+    }
+
+    #[ink::test]
+    fn claimed_bitmap_spans_words() {
+        let accounts = default_accounts();
+        let leaves = (0..300_u64)
+            .map(|index| leaf(index, accounts.alice, 1))
+            .collect::<Vec<_>>();
+        let tree = MerkleTree::<Blake2x256>::new(&leaves);
+        let mut erc20 = Erc20::new(tree.root(), String::from("M"), String::from("M"), 0);
+
+        for index in [0_u64, 127, 128, 255, 299].iter() {
+            let proof = tree.proof(*index as usize);
+            assert_eq!(erc20.claim(*index, accounts.alice, 1, proof), Ok(()));
+        }
+
+        for index in 0..300_u64 {
+            assert_eq!(
+                erc20.is_claimed(index),
+                [0, 127, 128, 255, 299].contains(&index)
+            );
+        }
+    }
+
+    #[ink::test]
+    fn verify_proof_works() {
+        let tree = tree();
+        let leaves = drops()
+            .iter()
+            .enumerate()
+            .map(|(index, (account, amount))| leaf(index as u64, *account, *amount))
+            .collect::<Vec<_>>();
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            assert!(merkle_proof::verify::<Erc20, Erc20, Blake2x256>(
+                &tree.proof(index),
+                tree.root(),
+                *leaf
+            ));
+        }
+
+        assert!(!merkle_proof::verify::<Erc20, Erc20, Blake2x256>(
+            &tree.proof(0),
+            tree.root(),
+            leaves[1]
+        ));
+    }
+
+    #[ink::test]
+    fn verify_multi_proof_works() {
+        let tree = tree();
+
+        for indices in [vec![0], vec![1, 3], vec![0, 2, 4], vec![0, 1, 2, 3, 4]].iter() {
+            let (proof, proof_flags, leaves) = tree.multi_proof(indices);
+            assert!(
+                merkle_proof::multi_proof_verify::<Erc20, Erc20, Blake2x256>(
+                    &proof,
+                    &proof_flags,
+                    tree.root(),
+                    &leaves
+                )
+            );
+        }
+    }
+
+    #[ink::test]
+    fn verify_invalid_multi_proof_fails() {
+        let tree = tree();
+        let (proof, proof_flags, mut leaves) = tree.multi_proof(&[1, 3]);
+
+        // the leaves are not in the tree
+        let mut wrong_leaves = leaves.clone();
+        wrong_leaves[0] = leaf(9, default_accounts().frank, 1);
+        assert!(
+            !merkle_proof::multi_proof_verify::<Erc20, Erc20, Blake2x256>(
+                &proof,
+                &proof_flags,
+                tree.root(),
+                &wrong_leaves
+            )
+        );
+
+        // the lengths do not match
+        leaves.pop();
+        assert_eq!(
+            merkle_proof::process_multi_proof::<Erc20, Erc20, Blake2x256>(
+                &proof,
+                &proof_flags,
+                &leaves
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc20_native_wrapper_mock;
+}
+
+mod erc20_native_wrapper_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::string::String;
+    use metis_test::*;
+    use mocks::erc20_native_wrapper_mock::erc20_native_wrapper::{
+        Erc20,
+        Error,
+    };
+
+    /// Create the wrapper, which only keeps the minimum balance.
+    fn init() -> Erc20 {
+        let erc20 = Erc20::new(String::from("WrappedNative"), String::from("WNT"), 18);
+        set_balance(contract_id(), minimum_balance());
+
+        erc20
+    }
+
+    fn minimum_balance() -> Balance {
+        ink_env::minimum_balance::<ink_env::DefaultEnvironment>()
+            .expect("Cannot get minimum balance")
+    }
+
+    /// Deposit `value` native currency for `account` by `caller`.
+    fn deposit(erc20: &mut Erc20, caller: AccountId, account: AccountId, value: Balance) {
+        set_caller_with_value(caller, value);
+        // the off-chain environment not transfer the value to the contract
+        set_balance(contract_id(), get_balance(contract_id()) + value);
+
+        assert_eq!(erc20.deposit_for(account), Ok(()));
+        ink_env::test::pop_execution_context();
+    }
+
+    #[ink::test]
+    fn deposit_for_works() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        deposit(&mut erc20, accounts.alice, accounts.bob, 100);
+
+        assert_eq!(erc20.balance_of(accounts.bob), 100);
+        assert_eq!(erc20.total_supply(), 100);
+    }
+
+    #[ink::test]
+    fn withdraw_to_works() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        deposit(&mut erc20, accounts.alice, accounts.bob, 100);
+        set_balance(accounts.charlie, 0);
+
+        assert_eq!(
+            call_as(accounts.bob, || erc20.withdraw_to(accounts.charlie, 40)),
+            Ok(())
+        );
+
+        assert_eq!(erc20.balance_of(accounts.bob), 60);
+        assert_eq!(erc20.total_supply(), 60);
+        assert_eq!(get_balance(accounts.charlie), 40);
+        assert_eq!(get_balance(contract_id()), minimum_balance() + 60);
+    }
+
+    #[ink::test]
+    fn withdraw_to_exceeds_balance_should_fail() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        deposit(&mut erc20, accounts.alice, accounts.bob, 100);
+
+        assert_eq!(
+            call_as(accounts.bob, || erc20.withdraw_to(accounts.charlie, 101)),
+            Err(Error::InsufficientBalance)
+        );
+        assert_eq!(get_balance(contract_id()), minimum_balance() + 100);
+    }
+
+    #[ink::test]
+    fn recover_works() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        deposit(&mut erc20, accounts.alice, accounts.bob, 100);
+
+        // native currency transferred without deposit
+        set_balance(contract_id(), minimum_balance() + 150);
+
+        assert_eq!(erc20.recover(accounts.alice), Ok(50));
+        assert_eq!(erc20.balance_of(accounts.alice), 50);
+        assert_eq!(erc20.total_supply(), 150);
+
+        assert_eq!(erc20.recover(accounts.alice), Ok(0));
+    }
+
+    #[ink::test]
+    fn recover_should_keep_minimum_balance() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        deposit(&mut erc20, accounts.alice, accounts.bob, 100);
+
+        // the minimum balance is not recovered as the wrapped tokens
+        assert_eq!(erc20.recover(accounts.alice), Ok(0));
+        assert_eq!(erc20.balance_of(accounts.alice), 0);
+        assert_eq!(erc20.total_supply(), 100);
+    }
+
+    #[ink::test]
+    fn recover_less_than_supply_should_fail() {
+        let accounts = default_accounts();
+        let mut erc20 = init();
+
+        deposit(&mut erc20, accounts.alice, accounts.bob, 100);
+
+        // the balance of the wrapper is less than the supply
+        set_balance(contract_id(), minimum_balance() + 50);
+
+        assert_eq!(
+            erc20.recover(accounts.alice),
+            Err(Error::InsufficientBalance)
+        );
+        assert_eq!(erc20.total_supply(), 100);
+    }
+}
@@ -0,0 +1,130 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc20_mock;
+}
+
+mod erc20_stub_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang::{
+        self as ink,
+        ForwardCall,
+        ForwardCallMut,
+    };
+    use ink_prelude::string::String;
+    use metis_erc20_stub::{
+        Erc20 as Erc20Messages,
+        Erc20Stub,
+    };
+    use metis_lang::{
+        mock::check_messages,
+        FromAccountId,
+    };
+    use metis_test::*;
+    use mocks::erc20_mock::erc20_contract::{
+        Erc20,
+        Error,
+        Result,
+    };
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    extern "Rust" {
+        fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+    }
+
+    /// The messages of the stub must be the messages of the real contract
+    /// with the same signatures.
+    impl Erc20Messages for Erc20 {
+        fn name(&self) -> String {
+            Erc20::name(self)
+        }
+
+        fn symbol(&self) -> String {
+            Erc20::symbol(self)
+        }
+
+        fn decimals(&self) -> u8 {
+            Erc20::decimals(self)
+        }
+
+        fn total_supply(&self) -> Balance {
+            Erc20::total_supply(self)
+        }
+
+        fn balance_of(&self, account: AccountId) -> Balance {
+            Erc20::balance_of(self, account)
+        }
+
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            Erc20::allowance(self, owner, spender)
+        }
+
+        fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            Erc20::transfer(self, to, value)
+        }
+
+        fn approve(&mut self, spender: AccountId, amount: Balance) -> Result<()> {
+            Erc20::approve(self, spender, amount)
+        }
+
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            Erc20::transfer_from(self, from, to, amount)
+        }
+    }
+
+    #[test]
+    fn stub_messages_should_match_contract() {
+        let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+            .expect("metadata should be serialized");
+
+        assert_eq!(check_messages(&metadata, Erc20Stub::MESSAGES), Ok(()));
+    }
+
+    #[ink::test]
+    fn call_by_stub_should_dispatch_to_contract() {
+        let accounts = default_accounts();
+
+        let erc20 = Rc::new(RefCell::new(Erc20::new(
+            String::from("MyToken"),
+            String::from("MT"),
+            18,
+            1000,
+        )));
+        Erc20Stub::mock(accounts.eve, erc20.clone());
+
+        let mut stub = <Erc20Stub as FromAccountId<Erc20>>::from_account_id(accounts.eve);
+        assert_eq!(stub.call().name().fire(), Ok(String::from("MyToken")));
+        assert_eq!(stub.call().symbol().fire(), Ok(String::from("MT")));
+        assert_eq!(stub.call().decimals().fire(), Ok(18));
+        assert_eq!(stub.call().total_supply().fire(), Ok(1000));
+
+        // the contract which calls the stub is the caller of the message
+        erc20.borrow_mut().transfer(contract_id(), 100).unwrap();
+        assert_eq!(
+            stub.call_mut().transfer(accounts.bob, 10).fire(),
+            Ok(Ok(()))
+        );
+        assert_eq!(stub.call_mut().approve(accounts.bob, 20).fire(), Ok(Ok(())));
+
+        assert_eq!(stub.call().balance_of(accounts.bob).fire(), Ok(10));
+        assert_eq!(
+            stub.call().allowance(contract_id(), accounts.bob).fire(),
+            Ok(20)
+        );
+        assert_eq!(
+            stub.call_mut()
+                .transfer_from(accounts.alice, accounts.bob, 10)
+                .fire(),
+            Ok(Err(Error::InsufficientAllowance))
+        );
+    }
+}
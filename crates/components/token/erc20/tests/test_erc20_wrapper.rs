@@ -0,0 +1,253 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc20_wrapper_mock;
+}
+
+mod erc20_wrapper_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::string::String;
+    use metis_erc20_stub::{
+        Erc20 as Underlying,
+        Erc20Stub,
+    };
+    use metis_test::*;
+    use mocks::erc20_wrapper_mock::erc20_wrapper::{
+        Erc20,
+        Error,
+        Result,
+    };
+    use std::{
+        cell::RefCell,
+        collections::HashMap,
+        rc::Rc,
+    };
+
+    /// A underlying erc20 contract which only keeps the balances.
+    #[derive(Default)]
+    struct MockUnderlying {
+        balances: HashMap<AccountId, Balance>,
+        allowances: HashMap<(AccountId, AccountId), Balance>,
+    }
+
+    impl MockUnderlying {
+        fn move_balance(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<()> {
+            let from_balance = self.balance_of(from);
+            if from_balance < value {
+                return Err(Error::InsufficientBalance)
+            }
+
+            self.balances.insert(from, from_balance - value);
+            *self.balances.entry(to).or_default() += value;
+
+            Ok(())
+        }
+    }
+
+    impl Underlying for MockUnderlying {
+        fn name(&self) -> String {
+            String::from("MockUnderlying")
+        }
+
+        fn symbol(&self) -> String {
+            String::from("MUT")
+        }
+
+        fn decimals(&self) -> u8 {
+            18
+        }
+
+        fn total_supply(&self) -> Balance {
+            self.balances.values().sum()
+        }
+
+        fn balance_of(&self, account: AccountId) -> Balance {
+            self.balances.get(&account).cloned().unwrap_or_default()
+        }
+
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            self.allowances
+                .get(&(owner, spender))
+                .cloned()
+                .unwrap_or_default()
+        }
+
+        fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            self.move_balance(caller(), to, value)
+        }
+
+        fn approve(&mut self, spender: AccountId, amount: Balance) -> Result<()> {
+            self.allowances.insert((caller(), spender), amount);
+            Ok(())
+        }
+
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            amount: Balance,
+        ) -> Result<()> {
+            let spender = caller();
+            let allowance = self.allowance(from, spender);
+            if allowance < amount {
+                return Err(Error::InsufficientAllowance)
+            }
+
+            self.move_balance(from, to, amount)?;
+            self.allowances.insert((from, spender), allowance - amount);
+
+            Ok(())
+        }
+    }
+
+    fn caller() -> AccountId {
+        ink_env::caller::<ink_env::DefaultEnvironment>().expect("Cannot get caller")
+    }
+
+    /// Create the wrapper with the underlying at eve, alice has 1000
+    /// underlying tokens and approves all to the wrapper.
+    fn init() -> (Erc20, Rc<RefCell<MockUnderlying>>) {
+        let accounts = default_accounts();
+
+        let underlying = Rc::new(RefCell::new(MockUnderlying::default()));
+        Erc20Stub::mock(accounts.eve, underlying.clone());
+
+        let erc20 = Erc20::new(
+            accounts.eve,
+            String::from("WrappedToken"),
+            String::from("WMUT"),
+            18,
+        );
+
+        underlying
+            .borrow_mut()
+            .balances
+            .insert(accounts.alice, 1000);
+        underlying
+            .borrow_mut()
+            .allowances
+            .insert((accounts.alice, contract_id()), 1000);
+
+        (erc20, underlying)
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let accounts = default_accounts();
+        let (erc20, _) = init();
+
+        assert_eq!(erc20.underlying(), accounts.eve);
+        assert_eq!(erc20.total_supply(), 0);
+    }
+
+    #[ink::test]
+    fn deposit_for_works() {
+        let accounts = default_accounts();
+        let (mut erc20, underlying) = init();
+
+        assert_eq!(erc20.deposit_for(accounts.bob, 300), Ok(()));
+
+        assert_eq!(erc20.balance_of(accounts.bob), 300);
+        assert_eq!(erc20.total_supply(), 300);
+        assert_eq!(underlying.borrow().balance_of(accounts.alice), 700);
+        assert_eq!(underlying.borrow().balance_of(contract_id()), 300);
+        assert_eq!(
+            underlying.borrow().allowance(accounts.alice, contract_id()),
+            700
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC20Wrapper: transfer from underlying error")]
+    fn deposit_for_exceeds_allowance_should_panic() {
+        let accounts = default_accounts();
+        let (mut erc20, _) = init();
+
+        let _ = erc20.deposit_for(accounts.bob, 1001);
+    }
+
+    #[ink::test]
+    fn deposit_for_zero_account_should_fail() {
+        let (mut erc20, underlying) = init();
+
+        assert_eq!(
+            erc20.deposit_for(AccountId::from([0x00; 32]), 300),
+            Err(Error::AccountIsZero)
+        );
+        assert_eq!(underlying.borrow().balance_of(contract_id()), 0);
+    }
+
+    #[ink::test]
+    fn withdraw_to_works() {
+        let accounts = default_accounts();
+        let (mut erc20, underlying) = init();
+
+        assert_eq!(erc20.deposit_for(accounts.bob, 300), Ok(()));
+        assert_eq!(
+            call_as(accounts.bob, || erc20.withdraw_to(accounts.charlie, 100)),
+            Ok(())
+        );
+
+        assert_eq!(erc20.balance_of(accounts.bob), 200);
+        assert_eq!(erc20.total_supply(), 200);
+        assert_eq!(underlying.borrow().balance_of(accounts.charlie), 100);
+        assert_eq!(underlying.borrow().balance_of(contract_id()), 200);
+    }
+
+    #[ink::test]
+    fn withdraw_to_exceeds_balance_should_fail() {
+        let accounts = default_accounts();
+        let (mut erc20, underlying) = init();
+
+        assert_eq!(erc20.deposit_for(accounts.bob, 300), Ok(()));
+        assert_eq!(
+            call_as(accounts.bob, || erc20.withdraw_to(accounts.charlie, 301)),
+            Err(Error::InsufficientBalance)
+        );
+
+        assert_eq!(underlying.borrow().balance_of(accounts.charlie), 0);
+        assert_eq!(underlying.borrow().balance_of(contract_id()), 300);
+    }
+
+    #[ink::test]
+    fn recover_works() {
+        let accounts = default_accounts();
+        let (mut erc20, underlying) = init();
+
+        assert_eq!(erc20.deposit_for(accounts.bob, 300), Ok(()));
+
+        // alice transfer the underlying to the wrapper by mistake
+        underlying.borrow_mut().balances.insert(contract_id(), 350);
+
+        assert_eq!(erc20.recover(accounts.alice), Ok(50));
+        assert_eq!(erc20.balance_of(accounts.alice), 50);
+        assert_eq!(erc20.total_supply(), 350);
+
+        assert_eq!(erc20.recover(accounts.alice), Ok(0));
+    }
+
+    #[ink::test]
+    fn recover_less_than_supply_should_fail() {
+        let accounts = default_accounts();
+        let (mut erc20, underlying) = init();
+
+        assert_eq!(erc20.deposit_for(accounts.bob, 300), Ok(()));
+
+        // the underlying balance of the wrapper is less than the supply
+        underlying.borrow_mut().balances.insert(contract_id(), 200);
+
+        assert_eq!(
+            erc20.recover(accounts.alice),
+            Err(Error::InsufficientBalance)
+        );
+        assert_eq!(erc20.balance_of(accounts.alice), 0);
+        assert_eq!(erc20.total_supply(), 300);
+    }
+}
@@ -1,34 +1,20 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use ink_lang as ink;
+use ink_prelude::vec::Vec;
 use metis_erc721_types::TokenId;
 
-#[ink::contract]
-mod stub {
-    use ink_prelude::vec::Vec;
+/// The messages of the ERC721 receiver
+#[metis_lang::stub_contract(ERC721ReceiverStub)]
+pub trait ERC721Receiver {
+    fn default() -> Self;
 
-    use super::TokenId;
-
-    #[ink(storage)]
-    #[metis_lang::stub]
-    pub struct ERC721Receiver {}
-
-    impl ERC721Receiver {
-        #[ink(constructor)]
-        pub fn default() -> Self {
-            unimplemented!()
-        }
-        #[ink(message)]
-        pub fn on_erc721_received(
-            &mut self,
-            _operator: AccountId,
-            _from: AccountId,
-            _token_id: TokenId,
-            _data: Vec<u8>,
-        ) -> [u8; 4] {
-            unimplemented!()
-        }
-    }
+    /// Handle the receipt of an NFT, returns the selector of
+    /// `on_erc721_received` to accept the transfer.
+    fn on_erc721_received(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        token_id: TokenId,
+        data: Vec<u8>,
+    ) -> [u8; 4];
 }
-
-pub use stub::ERC721Receiver as ERC721ReceiverStub;
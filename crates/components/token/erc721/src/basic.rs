@@ -5,31 +5,22 @@ use ink_prelude::{
     vec::Vec,
 };
 pub use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     FromAccountId,
     Storage,
 };
 
-/// The ERC-20 error types.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-pub enum Error {
-    /// Returned if not enough balance to fulfill a request is available.
-    InsufficientBalance,
-    /// Returned if not enough allowance to fulfill a request is available.
-    InsufficientAllowance,
-    /// Returned if account is zero
-    AccountIsZero,
-}
+pub use metis_erc721_types::{
+    Error,
+    Result,
+};
 
 pub use crate::TokenId;
 
 use metis_erc721_receiver::ERC721ReceiverStub as Receiver;
 
-/// The ERC-20 result type.
-pub type Result<T> = core::result::Result<T, Error>;
-
 /// The `EventEmit` impl the event emit api for erc20 component.
 pub trait EventEmit<E: Env>: EnvAccess<E> {
     /// Emitted when `token_id` token is transferred from `from` to `to`.
@@ -70,7 +61,7 @@ pub trait EventEmit<E: Env>: EnvAccess<E> {
 ///         Ok(())
 ///     }
 /// }
-pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Context<E> {
     /// Initialize the erc20 component
     fn init(&mut self, name: String, symbol: String) {
         self.get_mut().set_symbols(name, symbol);
@@ -182,7 +173,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     /// Emits an `Approval` event.
     fn approve(&mut self, to: Option<E::AccountId>, token_id: &TokenId) {
         let owner = self.owner_of(token_id);
-        let caller = Self::caller();
+        let caller = self._msg_sender();
 
         assert!(
             to.is_none() || to.as_ref().unwrap() != &owner,
@@ -207,7 +198,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// Emits an `ApprovalForAll` event.
     fn set_approval_for_all(&mut self, operator: E::AccountId, approved: bool) {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
         assert!(operator != caller, "ERC721: approve to caller");
 
         self.get_mut()
@@ -236,7 +227,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         token_id: TokenId,
     ) -> Result<()> {
         assert!(
-            self._is_approved_or_owner(&Self::caller(), &token_id),
+            self._is_approved_or_owner(&self._msg_sender(), &token_id),
             "ERC721: transfer caller is not owner nor approved"
         );
 
@@ -288,7 +279,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         data: Vec<u8>,
     ) -> Result<()> {
         assert!(
-            self._is_approved_or_owner(&Self::caller(), &token_id),
+            self._is_approved_or_owner(&self._msg_sender(), &token_id),
             "ERC721: transfer caller is not owner nor approved"
         );
 
@@ -513,7 +504,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         data: Vec<u8>,
     ) -> bool {
         let mut receiver = <Receiver as FromAccountId<E>>::from_account_id(to.clone());
-        let caller = Self::caller();
+        let caller = self._msg_sender();
 
         let resp = receiver
             .call_mut()
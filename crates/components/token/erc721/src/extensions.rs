@@ -1,4 +1,7 @@
 pub mod burnable;
 pub mod enumerable;
+pub mod onchain_metadata;
 pub mod pausable;
 pub mod urlstorage;
+pub mod votes;
+pub mod wrapper;
@@ -1,4 +1,7 @@
-use metis_lang::Env;
+use metis_lang::{
+    Context,
+    Env,
+};
 
 use crate::{
     Impl as ERC721,
@@ -18,7 +21,7 @@ where
     ///
     /// - The caller must own `tokenId` or be an approved operator.
     fn burn(&mut self, token_id: &TokenId) -> Result<()> {
-        let caller = &Self::caller();
+        let caller = &self._msg_sender();
         assert!(
             ERC721::_is_approved_or_owner(self, caller, token_id),
             "ERC721Burnable: caller is not owner nor approved"
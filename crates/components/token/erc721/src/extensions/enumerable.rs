@@ -9,33 +9,25 @@ use crate::{
 };
 
 use metis_lang::{
+    storage::{
+        EnumerableSet,
+        EnumerableSets,
+    },
     Env,
     Storage,
 };
 
 #[cfg(not(feature = "ink-as-dependency"))]
-use ::ink_storage::{
-    collections::{
-        HashMap as StorageHashMap,
-        Vec as StorageVec,
-    },
-    traits::SpreadLayout,
-};
+use ::ink_storage::traits::SpreadLayout;
 
 #[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
 #[derive(Debug, SpreadLayout)]
 pub struct Data<E: Env> {
-    /// Mapping from owner to list of owned token IDs
-    owned_tokens: StorageHashMap<(E::AccountId, u64), TokenId>,
+    /// Mapping from owner to the set of owned token IDs
+    owned_tokens: EnumerableSets<E::AccountId, TokenId>,
 
-    /// Mapping from token ID to index of the owner tokens list
-    owned_tokens_index: StorageHashMap<TokenId, u64>,
-
-    /// Array with all token ids, used for enumeration
-    all_tokens: StorageVec<TokenId>,
-
-    /// Mapping from token id to position in the allTokens array
-    all_tokens_index: StorageHashMap<TokenId, u32>,
+    /// Set with all token ids, used for enumeration
+    all_tokens: EnumerableSet<TokenId>,
 }
 
 impl<E> Default for Data<E>
@@ -44,10 +36,8 @@ where
 {
     fn default() -> Self {
         Self {
-            owned_tokens: StorageHashMap::default(),
-            owned_tokens_index: StorageHashMap::default(),
-            all_tokens: StorageVec::default(),
-            all_tokens_index: StorageHashMap::default(),
+            owned_tokens: EnumerableSets::default(),
+            all_tokens: EnumerableSet::default(),
         }
     }
 }
@@ -60,33 +50,21 @@ where
     /// @param to address representing the new owner of the given token ID
     /// @param token_id uint256 ID of the token to be added to the tokens list of the given address
     fn _add_token_to_owner_enumeration(&mut self, to: E::AccountId, token_id: &TokenId) {
-        let length = ERC721::balance_of(self, &to);
-
         Storage::<E, Data<E>>::get_mut(self)
             .owned_tokens
-            .insert((to, length), token_id.clone());
-        Storage::<E, Data<E>>::get_mut(self)
-            .owned_tokens_index
-            .insert(token_id.clone(), length);
+            .add(&to, token_id.clone());
     }
 
     /// @dev Private function to add a token to this extension's token tracking data structures.
     /// @param token_id uint256 ID of the token to be added to the tokens list
     fn _add_token_to_all_tokens_enumeration(&mut self, token_id: TokenId) {
-        let len = Storage::<E, Data<E>>::get_mut(self).all_tokens.len();
-
-        Storage::<E, Data<E>>::get_mut(self)
-            .all_tokens_index
-            .insert(token_id.clone(), len);
         Storage::<E, Data<E>>::get_mut(self)
             .all_tokens
-            .push(token_id);
+            .add(token_id);
     }
 
-    /// @dev Private function to remove a token from this extension's ownership-tracking data structures. Note that
-    /// while the token is not assigned a new owner, the `owned_tokens_index` mapping is _not_ updated: this allows for
-    /// gas optimizations e.g. when performing a transfer operation (avoiding double writes).
-    /// This has O(1) time complexity, but alters the order of the owned_tokens array.
+    /// @dev Private function to remove a token from this extension's ownership-tracking data structures.
+    /// This has O(1) time complexity, but alters the order of the owned tokens of `from`.
     /// @param from address representing the previous owner of the given token ID
     /// @param token_id uint256 ID of the token to be removed from the tokens list of the given address
     fn _remove_token_from_owner_enumeration(
@@ -94,69 +72,20 @@ where
         from: E::AccountId,
         token_id: &TokenId,
     ) {
-        // To prevent a gap in from's tokens array, we store the last token in the index of the token to delete, and
-        // then delete the last slot (swap and pop).
-
-        let last_token_index = ERC721::balance_of(self, &from) - 1_u64;
-
-        let data = Storage::<E, Data<E>>::get_mut(self);
-        let token_index = data
-            .owned_tokens_index
-            .get(token_id)
-            .expect("ERC721Enumerable: no found index for token id")
-            .clone();
-
-        // When the token to delete is the last token, the swap operation is unnecessary
-        if token_index != last_token_index {
-            let last_token_id = data
-                .owned_tokens
-                .get(&(from.clone(), last_token_index.clone()))
-                .expect("ERC721Enumerable: no found token id from owned_tokens")
-                .clone();
-
-            data.owned_tokens
-                .insert((from.clone(), token_index.clone()), last_token_id); // Move the last token to the slot of the to-delete token
-            data.owned_tokens_index.insert(last_token_id, token_index); // Update the moved token's index
-        }
-
-        // This also deletes the contents at the last position of the array
-        data.owned_tokens_index.take(token_id);
-        data.owned_tokens.take(&(from, last_token_index));
+        let removed = Storage::<E, Data<E>>::get_mut(self)
+            .owned_tokens
+            .remove(&from, token_id);
+        assert!(removed, "ERC721Enumerable: no found index for token id");
     }
 
     /// @dev Private function to remove a token from this extension's token tracking data structures.
-    /// This has O(1) time complexity, but alters the order of the all_tokens array.
+    /// This has O(1) time complexity, but alters the order of the all tokens.
     /// @param token_id uint256 ID of the token to be removed from the tokens list
     fn _remove_token_from_all_tokens_enumeration(&mut self, token_id: &TokenId) {
-        // To prevent a gap in the tokens array, we store the last token in the index of the token to delete, and
-        // then delete the last slot (swap and pop).
-
-        let data = Storage::<E, Data<E>>::get_mut(self);
-
-        let last_token_index = data.all_tokens.len() - 1;
-        let token_index = data
-            .all_tokens_index
-            .get(token_id)
-            .expect("ERC721Enumerable: no found index in all token")
-            .clone();
-
-        // When the token to delete is the last token, the swap operation is unnecessary. However, since this occurs so
-        // rarely (when the last minted token is burnt) that we still do the swap here to avoid the gas cost of adding
-        // an 'if' statement (like in _removeTokenFromOwnerEnumeration)
-        let last_token_id = data
+        let removed = Storage::<E, Data<E>>::get_mut(self)
             .all_tokens
-            .get(last_token_index)
-            .expect("ERC721Enumerable: no found last index in all token")
-            .clone();
-
-        data.all_tokens
-            .set(token_index, last_token_id)
-            .expect("ERC721Enumerable: set token index panic"); // Move the last token to the slot of the to-delete token
-        data.all_tokens_index.insert(last_token_id, token_index); // Update the moved token's index
-
-        // This also deletes the contents at the last position of the array
-        data.all_tokens_index.take(token_id);
-        data.all_tokens.pop();
+            .remove(token_id);
+        assert!(removed, "ERC721Enumerable: no found index in all token");
     }
 }
 
@@ -164,6 +93,41 @@ pub trait Impl<E>: _Impl<E>
 where
     E: Env,
 {
+    /// Returns the total amount of tokens stored by the contract.
+    fn total_supply(&self) -> u32 {
+        Storage::<E, Data<E>>::get(self).all_tokens.len()
+    }
+
+    /// Returns a token ID owned by `owner` at a given `index` of its token list.
+    /// Use along with `balance_of` to enumerate all of ``owner``'s tokens.
+    fn token_of_owner_by_index(&self, owner: &E::AccountId, index: u64) -> TokenId {
+        assert!(
+            index < ERC721::balance_of(self, owner),
+            "ERC721Enumerable: owner index out of bounds"
+        );
+
+        Storage::<E, Data<E>>::get(self)
+            .owned_tokens
+            .at(owner, index as u32)
+            .expect("ERC721Enumerable: no found token id from owned_tokens")
+            .clone()
+    }
+
+    /// Returns a token ID at a given `index` of all the tokens stored by the contract.
+    /// Use along with `total_supply` to enumerate all tokens.
+    fn token_by_index(&self, index: u32) -> TokenId {
+        assert!(
+            index < self.total_supply(),
+            "ERC721Enumerable: global index out of bounds"
+        );
+
+        Storage::<E, Data<E>>::get(self)
+            .all_tokens
+            .at(index)
+            .expect("ERC721Enumerable: no found token id from all_tokens")
+            .clone()
+    }
+
     fn before_token_transfer(
         &mut self,
         from: Option<E::AccountId>,
@@ -0,0 +1,378 @@
+//! Extension of the ERC721 token contract to store the metadata of the
+//! tokens on chain.
+//!
+//! The `token_url` of a token is rendered from its stored name, description
+//! and attributes as the JSON of the ERC721 metadata, or the base64 data url
+//! of it if `_metadata_base64` returns true:
+//!
+//! ```json
+//! {"name":"..","description":"..","attributes":[{"trait_type":"..","value":".."}]}
+//! ```
+//!
+//! The sizes of the metadata are bounded, so the rendering costs are bounded.
+//! The setters are guarded by `_check_metadata_setter`, which should be impl
+//! by the contract with its access policy, such as the owner only.
+use crate::{
+    Impl as ERC721,
+    Result,
+    TokenId,
+};
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+use metis_lang::{
+    Env,
+    EnvAccess,
+    Storage,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use ::ink_storage::traits::{
+    PackedLayout,
+    SpreadLayout,
+};
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::collections::HashMap as StorageHashMap;
+
+#[cfg(feature = "std")]
+use ::ink_storage::traits::StorageLayout;
+
+#[cfg(feature = "std")]
+use scale_info::TypeInfo;
+
+/// The max length of the name of a token.
+pub const MAX_NAME_LEN: usize = 128;
+/// The max length of the description of a token.
+pub const MAX_DESCRIPTION_LEN: usize = 1024;
+/// The max count of the attributes of a token.
+pub const MAX_ATTRIBUTES: usize = 32;
+/// The max length of the trait type or the value of an attribute.
+pub const MAX_ATTRIBUTE_LEN: usize = 128;
+
+/// A trait of a token, such as `{"trait_type":"Color","value":"Red"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo, StorageLayout))]
+#[derive(SpreadLayout, PackedLayout)]
+pub struct Attribute {
+    pub trait_type: String,
+    pub value: String,
+}
+
+impl Attribute {
+    pub fn new(trait_type: String, value: String) -> Self {
+        Self { trait_type, value }
+    }
+}
+
+/// The metadata of a token.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo, StorageLayout))]
+#[derive(SpreadLayout, PackedLayout)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub description: String,
+    pub attributes: Vec<Attribute>,
+}
+
+/// The Data of ERC721 onchain metadata component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data {
+    /// Mapping from token ID to its metadata
+    metadatas: StorageHashMap<TokenId, TokenMetadata>,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self {
+            metadatas: StorageHashMap::default(),
+        }
+    }
+}
+
+impl Data {
+    /// Returns the metadata of the `token_id`.
+    pub fn get(&self, token_id: &TokenId) -> Option<&TokenMetadata> {
+        self.metadatas.get(token_id)
+    }
+
+    /// Sets the metadata of the `token_id`.
+    pub fn set(&mut self, token_id: TokenId, metadata: TokenMetadata) {
+        self.metadatas.insert(token_id, metadata);
+    }
+
+    /// Removes the metadata of the `token_id`.
+    pub fn remove(&mut self, token_id: &TokenId) -> Option<TokenMetadata> {
+        self.metadatas.take(token_id)
+    }
+}
+
+/// The `EventEmit` impl the event emit api for onchain metadata component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emitted when the metadata of the `token_id` is updated, so the
+    /// indexers can refresh it, same as the `MetadataUpdate` of EIP-4906.
+    fn emit_event_metadata_update(&mut self, token_id: TokenId);
+}
+
+/// Extension of {ERC721} that stores the metadata of the tokens on chain.
+pub trait Impl<E>: ERC721<E> + Storage<E, Data> + EventEmit<E>
+where
+    E: Env,
+{
+    /// Panic if the caller can not set the metadata of the tokens, impl by
+    /// the contract with its access policy.
+    fn _check_metadata_setter(&self);
+
+    /// Whether the `token_url` is the base64 data url of the JSON, else the
+    /// JSON itself.
+    fn _metadata_base64(&self) -> bool {
+        false
+    }
+
+    /// Returns the metadata of the `token_id`.
+    fn token_metadata(&self, token_id: &TokenId) -> Option<TokenMetadata> {
+        Storage::<E, Data>::get(self).get(token_id).cloned()
+    }
+
+    /// Returns the metadata JSON of the `token_id`.
+    ///
+    /// Requirements:
+    ///
+    /// - `token_id` must exist.
+    fn token_json(&self, token_id: &TokenId) -> String {
+        assert!(
+            self._exists(token_id),
+            "ERC721Metadata: URI query for nonexistent token"
+        );
+
+        match Storage::<E, Data>::get(self).get(token_id) {
+            Some(metadata) => render_json(metadata),
+            None => render_json(&TokenMetadata::default()),
+        }
+    }
+
+    /// Returns the Uniform Resource Identifier (URI) for `token_id` token,
+    /// which is rendered from the metadata.
+    fn token_url(&self, token_id: &TokenId) -> String {
+        let json = self.token_json(token_id);
+
+        if !self._metadata_base64() {
+            return json
+        }
+
+        let mut url = String::from("data:application/json;base64,");
+        url.push_str(&base64_encode(json.as_bytes()));
+        url
+    }
+
+    /// Sets the metadata of the `token_id`.
+    ///
+    /// Emits a `MetadataUpdate` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller should be allowed by `_check_metadata_setter`.
+    /// - `token_id` must exist.
+    /// - the sizes of the metadata should be in the bounds.
+    fn set_token_metadata(
+        &mut self,
+        token_id: TokenId,
+        name: String,
+        description: String,
+        attributes: Vec<Attribute>,
+    ) {
+        self._check_metadata_setter();
+
+        self._set_token_metadata(
+            token_id,
+            TokenMetadata {
+                name,
+                description,
+                attributes,
+            },
+        );
+    }
+
+    /// Sets the `value` of the attribute `trait_type` of the `token_id`, the
+    /// attribute is added if not exists.
+    ///
+    /// Emits a `MetadataUpdate` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller should be allowed by `_check_metadata_setter`.
+    /// - `token_id` must exist.
+    /// - the sizes of the metadata should be in the bounds.
+    fn set_token_attribute(
+        &mut self,
+        token_id: TokenId,
+        trait_type: String,
+        value: String,
+    ) {
+        self._check_metadata_setter();
+
+        let mut metadata = self.token_metadata(&token_id).unwrap_or_default();
+        match metadata
+            .attributes
+            .iter_mut()
+            .find(|attribute| attribute.trait_type == trait_type)
+        {
+            Some(attribute) => attribute.value = value,
+            None => metadata.attributes.push(Attribute::new(trait_type, value)),
+        }
+
+        self._set_token_metadata(token_id, metadata);
+    }
+
+    /// Removes the attribute `trait_type` of the `token_id`.
+    ///
+    /// Emits a `MetadataUpdate` event.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller should be allowed by `_check_metadata_setter`.
+    /// - the token should have the attribute.
+    fn remove_token_attribute(&mut self, token_id: TokenId, trait_type: String) {
+        self._check_metadata_setter();
+
+        let mut metadata = self.token_metadata(&token_id).unwrap_or_default();
+        let len = metadata.attributes.len();
+        metadata
+            .attributes
+            .retain(|attribute| attribute.trait_type != trait_type);
+        assert!(
+            metadata.attributes.len() != len,
+            "ERC721OnchainMetadata: attribute not exists"
+        );
+
+        self._set_token_metadata(token_id, metadata);
+    }
+
+    /// Sets the metadata of the `token_id`, without the access checks.
+    ///
+    /// Emits a `MetadataUpdate` event.
+    fn _set_token_metadata(&mut self, token_id: TokenId, metadata: TokenMetadata) {
+        assert!(
+            self._exists(&token_id),
+            "ERC721OnchainMetadata: metadata set of nonexistent token"
+        );
+        assert!(
+            metadata.name.len() <= MAX_NAME_LEN,
+            "ERC721OnchainMetadata: name too long"
+        );
+        assert!(
+            metadata.description.len() <= MAX_DESCRIPTION_LEN,
+            "ERC721OnchainMetadata: description too long"
+        );
+        assert!(
+            metadata.attributes.len() <= MAX_ATTRIBUTES,
+            "ERC721OnchainMetadata: too many attributes"
+        );
+        assert!(
+            metadata.attributes.iter().all(|attribute| {
+                attribute.trait_type.len() <= MAX_ATTRIBUTE_LEN
+                    && attribute.value.len() <= MAX_ATTRIBUTE_LEN
+            }),
+            "ERC721OnchainMetadata: attribute too long"
+        );
+
+        Storage::<E, Data>::get_mut(self).set(token_id, metadata);
+        self.emit_event_metadata_update(token_id);
+    }
+
+    /// Clears the metadata of the burned tokens, should be called in the
+    /// `_before_token_transfer` of the contract.
+    fn before_token_transfer(
+        &mut self,
+        _from: Option<E::AccountId>,
+        to: Option<E::AccountId>,
+        token_id: &TokenId,
+    ) -> Result<()> {
+        if to.is_none() {
+            Storage::<E, Data>::get_mut(self).remove(token_id);
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders the metadata as the JSON.
+fn render_json(metadata: &TokenMetadata) -> String {
+    let mut json = String::from("{\"name\":");
+    push_json_string(&mut json, &metadata.name);
+    json.push_str(",\"description\":");
+    push_json_string(&mut json, &metadata.description);
+    json.push_str(",\"attributes\":[");
+    for (index, attribute) in metadata.attributes.iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        json.push_str("{\"trait_type\":");
+        push_json_string(&mut json, &attribute.trait_type);
+        json.push_str(",\"value\":");
+        push_json_string(&mut json, &attribute.value);
+        json.push('}');
+    }
+    json.push_str("]}");
+
+    json
+}
+
+/// Pushes the `value` as a quoted and escaped JSON string.
+fn push_json_string(json: &mut String, value: &str) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                json.push_str("\\u00");
+                json.push(HEX[(c as usize) >> 4] as char);
+                json.push(HEX[(c as usize) & 0x0f] as char);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Encodes the `data` by the standard base64 with the paddings.
+fn base64_encode(data: &[u8]) -> String {
+    const TABLE: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut res = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or_default(),
+            chunk.get(2).copied().unwrap_or_default(),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        res.push(TABLE[(n >> 18) as usize & 0x3f] as char);
+        res.push(TABLE[(n >> 12) as usize & 0x3f] as char);
+        if chunk.len() > 1 {
+            res.push(TABLE[(n >> 6) as usize & 0x3f] as char);
+        } else {
+            res.push('=');
+        }
+        if chunk.len() > 2 {
+            res.push(TABLE[n as usize & 0x3f] as char);
+        } else {
+            res.push('=');
+        }
+    }
+
+    res
+}
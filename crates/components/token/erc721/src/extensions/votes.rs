@@ -0,0 +1,216 @@
+//! Extension of the ERC721 token contract to support the voting and the
+//! delegation, each token counts as one unit of the voting power.
+//!
+//! The voting power of an account is the number of the tokens delegated to
+//! it, the tokens of an account are not counted until the account delegates
+//! them, to itself or to another account, by `delegate`.
+//!
+//! The history of the voting power of each account is kept as the checkpoints
+//! of the block numbers, so the past votes can be looked up by `get_past_votes`,
+//! such as the votes at the block a proposal was created.
+//!
+//! The votes are moved in `before_token_transfer`, which should be called in
+//! the `_before_token_transfer` of the contract.
+use crate::{
+    Impl as ERC721,
+    Result,
+    TokenId,
+};
+use metis_lang::{
+    storage::CheckpointsMap,
+    Env,
+    EnvAccess,
+    Storage,
+};
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    collections::HashMap as StorageHashMap,
+    traits::SpreadLayout,
+};
+
+/// The Data of ERC721 votes component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E: Env> {
+    /// Mapping from account to its delegate
+    delegates: StorageHashMap<E::AccountId, E::AccountId>,
+    /// The checkpoints of the votes of each account
+    checkpoints: CheckpointsMap<E::AccountId, E::BlockNumber, u64>,
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            delegates: StorageHashMap::new(),
+            checkpoints: CheckpointsMap::new(),
+        }
+    }
+}
+
+impl<E: Env> Data<E> {
+    /// Returns the delegate of the `account`.
+    pub fn delegate_of(&self, account: &E::AccountId) -> Option<E::AccountId> {
+        self.delegates.get(account).cloned()
+    }
+
+    /// Sets the delegate of the `account`.
+    pub fn set_delegate(&mut self, account: E::AccountId, delegatee: E::AccountId) {
+        self.delegates.insert(account, delegatee);
+    }
+
+    /// Returns the checkpoints of the votes of each account.
+    pub fn checkpoints(&self) -> &CheckpointsMap<E::AccountId, E::BlockNumber, u64> {
+        &self.checkpoints
+    }
+
+    /// Returns the mutable checkpoints of the votes of each account.
+    pub fn checkpoints_mut(
+        &mut self,
+    ) -> &mut CheckpointsMap<E::AccountId, E::BlockNumber, u64> {
+        &mut self.checkpoints
+    }
+}
+
+/// The `EventEmit` impl the event emit api for votes component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emitted when the `delegator` changes its delegate from the
+    /// `from_delegate` to the `to_delegate`.
+    fn emit_event_delegate_changed(
+        &mut self,
+        delegator: E::AccountId,
+        from_delegate: Option<E::AccountId>,
+        to_delegate: Option<E::AccountId>,
+    );
+
+    /// Emitted when the votes of the `delegate` changed, from the
+    /// `previous_balance` to the `new_balance`.
+    fn emit_event_delegate_votes_changed(
+        &mut self,
+        delegate: E::AccountId,
+        previous_balance: u64,
+        new_balance: u64,
+    );
+}
+
+/// Extension of {ERC721} to support the voting and the delegation, each token
+/// counts as one unit of the voting power.
+pub trait Impl<E>: ERC721<E> + Storage<E, Data<E>> + EventEmit<E>
+where
+    E: Env,
+{
+    /// Returns the delegate that the `account` has chosen.
+    fn delegates(&self, account: &E::AccountId) -> Option<E::AccountId> {
+        Storage::<E, Data<E>>::get(self).delegate_of(account)
+    }
+
+    /// Returns the current votes of the `account`.
+    fn get_votes(&self, account: &E::AccountId) -> u64 {
+        Storage::<E, Data<E>>::get(self)
+            .checkpoints()
+            .latest(account)
+    }
+
+    /// Returns the votes of the `account` at the end of the `block_number`.
+    ///
+    /// Requirements:
+    ///
+    /// - `block_number` must have been already mined
+    fn get_past_votes(
+        &self,
+        account: &E::AccountId,
+        block_number: E::BlockNumber,
+    ) -> u64 {
+        assert!(
+            block_number < Self::block_number(),
+            "ERC721Votes: block not yet mined"
+        );
+
+        Storage::<E, Data<E>>::get(self)
+            .checkpoints()
+            .upper_lookup(account, block_number)
+    }
+
+    /// Delegates the votes of the tokens of the caller to the `delegatee`.
+    ///
+    /// Emits a `DelegateChanged` event, and `DelegateVotesChanged` events
+    /// if the votes are moved.
+    fn delegate(&mut self, delegatee: E::AccountId) {
+        let delegator = self._msg_sender();
+        self._delegate(delegator, delegatee)
+    }
+
+    /// Changes the delegate of the `delegator` to the `delegatee`, and moves
+    /// the votes of all the tokens of the `delegator`.
+    ///
+    /// Emits a `DelegateChanged` event, and `DelegateVotesChanged` events
+    /// if the votes are moved.
+    fn _delegate(&mut self, delegator: E::AccountId, delegatee: E::AccountId) {
+        let current_delegate = self.delegates(&delegator);
+        let amount = self.balance_of(&delegator);
+
+        Storage::<E, Data<E>>::get_mut(self)
+            .set_delegate(delegator.clone(), delegatee.clone());
+
+        self.emit_event_delegate_changed(
+            delegator,
+            current_delegate.clone(),
+            Some(delegatee.clone()),
+        );
+
+        self._move_voting_power(current_delegate, Some(delegatee), amount);
+    }
+
+    /// Moves the `amount` of votes from the delegate `src` to the delegate `dst`.
+    ///
+    /// Emits `DelegateVotesChanged` events for the changed delegates.
+    fn _move_voting_power(
+        &mut self,
+        src: Option<E::AccountId>,
+        dst: Option<E::AccountId>,
+        amount: u64,
+    ) {
+        if src == dst || amount == 0 {
+            return
+        }
+
+        let block_number = Self::block_number();
+
+        if let Some(src) = src {
+            let checkpoints = Storage::<E, Data<E>>::get_mut(self).checkpoints_mut();
+            let votes = checkpoints.latest(&src);
+            assert!(votes >= amount, "ERC721Votes: votes underflow");
+
+            let (old, new) = checkpoints.push(&src, block_number, votes - amount);
+            self.emit_event_delegate_votes_changed(src, old, new);
+        }
+
+        if let Some(dst) = dst {
+            let checkpoints = Storage::<E, Data<E>>::get_mut(self).checkpoints_mut();
+            let votes = checkpoints.latest(&dst);
+
+            let (old, new) = checkpoints.push(&dst, block_number, votes + amount);
+            self.emit_event_delegate_votes_changed(dst, old, new);
+        }
+    }
+
+    /// Moves one unit of the votes from the delegate of the `from` to the
+    /// delegate of the `to`, should be called in the `_before_token_transfer`
+    /// of the contract.
+    fn before_token_transfer(
+        &mut self,
+        from: Option<E::AccountId>,
+        to: Option<E::AccountId>,
+        _token_id: &TokenId,
+    ) -> Result<()> {
+        let src = from.and_then(|from| self.delegates(&from));
+        let dst = to.and_then(|to| self.delegates(&to));
+
+        self._move_voting_power(src, dst, 1);
+
+        Ok(())
+    }
+}
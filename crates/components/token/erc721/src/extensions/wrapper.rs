@@ -0,0 +1,209 @@
+//! Extension of the ERC721 token contract to support token wrapping.
+//!
+//! Users can deposit and withdraw an "underlying token" and receive a
+//! "wrapped token" with a matching token id. This is useful in conjunction
+//! with other modules. For example, combining this wrapping mechanism with
+//! {ERC721Votes} will allow the wrapping of an existing "basic" ERC721 into a
+//! governance token.
+//!
+//! The underlying tokens can be deposited by `deposit_for`, which needs the
+//! approval of the wrapper in the underlying, or by a safe transfer of the
+//! underlying to the wrapper, the contract should expose the
+//! `on_erc721_received` of this component as its message.
+use crate::{
+    Impl as ERC721,
+    Result,
+    TokenId,
+};
+use ink_lang::{
+    ForwardCall,
+    ForwardCallMut,
+};
+use ink_prelude::vec::Vec;
+use metis_erc721_stub::Erc721Stub;
+use metis_lang::{
+    Env,
+    FromAccountId,
+    Storage,
+};
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The Data of ERC721 wrapper component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E: Env> {
+    /// The underlying token being wrapped.
+    underlying: Lazy<E::AccountId>,
+}
+
+impl<E: Env> Data<E> {
+    /// Sets the `underlying` token. This value is immutable, it can only be
+    /// set once during construction.
+    pub fn new(underlying: E::AccountId) -> Self {
+        let mut res = Self::default();
+        res.underlying = Lazy::new(underlying);
+        res
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            underlying: Lazy::default(),
+        }
+    }
+}
+
+impl<E: Env> Data<E> {
+    /// Returns the underlying token being wrapped.
+    pub fn underlying(&self) -> &E::AccountId {
+        &self.underlying
+    }
+}
+
+/// Extension of {ERC721} that wraps the underlying erc721 token 1:1.
+pub trait Impl<E>: ERC721<E> + Storage<E, Data<E>>
+where
+    E: Env,
+{
+    /// Returns the address of the underlying token being wrapped.
+    fn underlying(&self) -> E::AccountId {
+        Storage::<E, Data<E>>::get(self).underlying().clone()
+    }
+
+    /// Allow a user to deposit the underlying tokens of `token_ids` and mint
+    /// the wrapped tokens with the same ids to `account`.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must approve the underlying tokens to this.
+    fn deposit_for(
+        &mut self,
+        account: E::AccountId,
+        token_ids: Vec<TokenId>,
+    ) -> Result<()> {
+        let caller = self._msg_sender();
+        let mut underlying =
+            <Erc721Stub as FromAccountId<E>>::from_account_id(self.underlying());
+
+        for token_id in token_ids.into_iter() {
+            let res = underlying
+                .call_mut()
+                .transfer_from(
+                    caller.clone().into(),
+                    Self::account_id().into(),
+                    token_id.clone(),
+                )
+                .fire();
+            assert!(
+                matches!(res, Ok(Ok(()))),
+                "ERC721Wrapper: transfer from underlying error"
+            );
+
+            ERC721::_safe_mint(self, account.clone(), token_id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Allow a user to burn the wrapped tokens of `token_ids` and withdraw
+    /// the underlying tokens with the same ids to `account`.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be the owner or approved of the wrapped tokens.
+    fn withdraw_to(
+        &mut self,
+        account: E::AccountId,
+        token_ids: Vec<TokenId>,
+    ) -> Result<()> {
+        let caller = self._msg_sender();
+        let mut underlying =
+            <Erc721Stub as FromAccountId<E>>::from_account_id(self.underlying());
+
+        for token_id in token_ids.into_iter() {
+            assert!(
+                self._is_approved_or_owner(&caller, &token_id),
+                "ERC721Wrapper: caller is not token owner or approved"
+            );
+
+            ERC721::_burn(self, &token_id)?;
+
+            let res = underlying
+                .call_mut()
+                .safe_transfer_from(
+                    Self::account_id().into(),
+                    account.clone().into(),
+                    token_id,
+                )
+                .fire();
+            assert!(
+                matches!(res, Ok(Ok(()))),
+                "ERC721Wrapper: transfer underlying error"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Mints the wrapped token of `token_id` to `from` when the underlying
+    /// token is safely transferred to this, returns the selector of
+    /// `on_erc721_received` to accept the transfer.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be the underlying token.
+    fn on_erc721_received(
+        &mut self,
+        _operator: E::AccountId,
+        from: E::AccountId,
+        token_id: TokenId,
+        _data: Vec<u8>,
+    ) -> [u8; 4] {
+        assert!(
+            Self::caller() == self.underlying(),
+            "ERC721Wrapper: caller is not the underlying token"
+        );
+
+        assert!(
+            ERC721::_safe_mint(self, from, token_id).is_ok(),
+            "ERC721Wrapper: mint wrapped token error"
+        );
+
+        metis_lang::selector_id!(on_erc721_received)
+    }
+
+    /// Mint a wrapped token to cover the underlying token of `token_id` that
+    /// would have been transferred by mistake. Internal function that can be
+    /// exposed with access control if desired.
+    ///
+    /// Requirements:
+    ///
+    /// - the underlying token of `token_id` must be owned by this.
+    fn _recover(&mut self, account: E::AccountId, token_id: TokenId) -> Result<()> {
+        let underlying =
+            <Erc721Stub as FromAccountId<E>>::from_account_id(self.underlying());
+        let owner = underlying
+            .call()
+            .owner_of(token_id.clone())
+            .fire()
+            .expect("ERC721Wrapper: get underlying owner error");
+        assert!(
+            owner == Self::account_id().into(),
+            "ERC721Wrapper: wrapper is not token owner"
+        );
+
+        ERC721::_safe_mint(self, account, token_id)
+    }
+}
+
+// No impl this for default
+// impl<E: Env, T: ERC721<E> + Storage<E, Data<E>>> Impl<E> for T {}
@@ -7,6 +7,7 @@ mod extensions;
 mod module;
 
 pub use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     Storage,
@@ -38,6 +39,20 @@ pub mod enumerable {
     };
 }
 
+pub mod onchain_metadata {
+    pub use super::extensions::onchain_metadata::{
+        Attribute,
+        Data,
+        EventEmit,
+        Impl,
+        TokenMetadata,
+        MAX_ATTRIBUTES,
+        MAX_ATTRIBUTE_LEN,
+        MAX_DESCRIPTION_LEN,
+        MAX_NAME_LEN,
+    };
+}
+
 pub mod pausable {
     pub use super::extensions::pausable::Impl;
 }
@@ -48,4 +63,19 @@ pub mod urlstorage {
         Impl,
     };
 }
+
+pub mod votes {
+    pub use super::extensions::votes::{
+        Data,
+        EventEmit,
+        Impl,
+    };
+}
+
+pub mod wrapper {
+    pub use super::extensions::wrapper::{
+        Data,
+        Impl,
+    };
+}
 // impl<E: Env, T: Storage<E, Data<E>> + EventEmit<E>> Impl<E> for T {}
@@ -0,0 +1,70 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_prelude::{
+    string::String,
+    vec::Vec,
+};
+
+pub use metis_erc721_types::{
+    Error,
+    Result,
+    TokenId,
+};
+
+/// The messages of the ERC721 component
+#[metis_lang::stub_contract(Erc721Stub)]
+pub trait Erc721 {
+    fn new(name: String, symbol: String) -> Self;
+
+    /// Returns the name of the token.
+    fn name(&self) -> String;
+
+    /// Returns the symbol of the token, usually a shorter version of the name.
+    fn symbol(&self) -> String;
+
+    /// Returns the number of tokens in `owner`'s account.
+    fn balance_of(&self, owner: AccountId) -> u64;
+
+    /// Returns the owner of the `token_id` token.
+    fn owner_of(&self, token_id: TokenId) -> AccountId;
+
+    /// Returns the account approved for `token_id` token.
+    fn get_approved(&self, token_id: TokenId) -> Option<AccountId>;
+
+    /// Returns if the `operator` is allowed to manage all of the assets of `owner`.
+    fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool;
+
+    /// Gives permission to `to` to transfer `token_id` token to another account.
+    fn approve(&mut self, to: Option<AccountId>, token_id: TokenId);
+
+    /// Approve or remove `operator` as an operator for the caller.
+    fn set_approval_for_all(&mut self, operator: AccountId, approved: bool);
+
+    /// Transfers `token_id` token from `from` to `to`, the caller must be
+    /// the owner, an approved or an operator of the token.
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        token_id: TokenId,
+    ) -> Result<()>;
+
+    /// Safely transfers `token_id` token from `from` to `to`, checking first
+    /// that contract recipients are aware of the ERC721 protocol.
+    fn safe_transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        token_id: TokenId,
+    ) -> Result<()>;
+
+    /// Same as `safe_transfer_from`, with an additional `data` parameter
+    /// which is forwarded in `on_erc721_received` to contract recipients.
+    fn safe_transfer_from_with_data(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        token_id: TokenId,
+        data: Vec<u8>,
+    ) -> Result<()>;
+}
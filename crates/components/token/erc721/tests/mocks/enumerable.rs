@@ -249,5 +249,136 @@ pub mod contract {
         ) -> Result<()> {
             erc721::Impl::safe_transfer_from_with_data(self, from, to, token_id, data)
         }
+
+        /// @dev Returns the total amount of tokens stored by the contract.
+        #[ink(message)]
+        pub fn total_supply(&self) -> u32 {
+            enumerable::Impl::total_supply(self)
+        }
+
+        /// @dev Returns a token ID owned by `owner` at a given `index` of its token list.
+        #[ink(message)]
+        pub fn token_of_owner_by_index(&self, owner: AccountId, index: u64) -> TokenId {
+            enumerable::Impl::token_of_owner_by_index(self, &owner, index)
+        }
+
+        /// @dev Returns a token ID at a given `index` of all the tokens stored by the contract.
+        #[ink(message)]
+        pub fn token_by_index(&self, index: u32) -> TokenId {
+            enumerable::Impl::token_by_index(self, index)
+        }
+    }
+
+    type Event = <Erc721 as ink_lang::BaseEvent>::Type;
+
+    impl metis_test::Erc721Invariant for Erc721 {
+        type TokenId = TokenId;
+        type Error = Error;
+
+        fn new_erc721() -> Self {
+            Self::new(String::from("MockErc721Token"), String::from("MET"))
+        }
+
+        fn token_id(seed: u8) -> TokenId {
+            TokenId::new([seed; 32])
+        }
+
+        fn decode_transfer_event(
+            event: &ink_env::test::EmittedEvent,
+        ) -> Option<(Option<AccountId>, Option<AccountId>, TokenId)> {
+            match <Event as scale::Decode>::decode(&mut &event.data[..]).ok()? {
+                Event::Transfer(Transfer { from, to, token_id }) => {
+                    Some((from, to, token_id))
+                }
+                _ => None,
+            }
+        }
+
+        fn decode_approval_event(
+            event: &ink_env::test::EmittedEvent,
+        ) -> Option<(AccountId, Option<AccountId>, TokenId)> {
+            match <Event as scale::Decode>::decode(&mut &event.data[..]).ok()? {
+                Event::Approval(Approval {
+                    owner,
+                    spender,
+                    token_id,
+                }) => Some((owner, spender, token_id)),
+                _ => None,
+            }
+        }
+
+        fn decode_approval_for_all_event(
+            event: &ink_env::test::EmittedEvent,
+        ) -> Option<(AccountId, AccountId, bool)> {
+            match <Event as scale::Decode>::decode(&mut &event.data[..]).ok()? {
+                Event::ApprovalForAll(ApprovalForAll {
+                    owner,
+                    operator,
+                    approved,
+                }) => Some((owner, operator, approved)),
+                _ => None,
+            }
+        }
+
+        fn balance_of(&self, owner: AccountId) -> u64 {
+            erc721::Impl::balance_of(self, &owner)
+        }
+
+        fn owner_of(&self, token_id: TokenId) -> Option<AccountId> {
+            if erc721::Impl::_exists(self, &token_id) {
+                Some(erc721::Impl::owner_of(self, &token_id))
+            } else {
+                None
+            }
+        }
+
+        fn get_approved(&self, token_id: TokenId) -> Option<AccountId> {
+            erc721::Impl::get_approved(self, &token_id)
+        }
+
+        fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool {
+            erc721::Impl::is_approved_for_all(self, &owner, &operator)
+        }
+
+        fn mint(&mut self, to: AccountId, token_id: TokenId) -> Result<()> {
+            Erc721::mint(self, to, token_id)
+        }
+
+        fn burn(&mut self, token_id: TokenId) -> Option<Result<()>> {
+            Some(Erc721::burn(self, token_id))
+        }
+
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+        ) -> Result<()> {
+            Erc721::transfer_from(self, from, to, token_id)
+        }
+
+        fn approve(&mut self, to: Option<AccountId>, token_id: TokenId) {
+            Erc721::approve(self, to, token_id)
+        }
+
+        fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) {
+            Erc721::set_approval_for_all(self, operator, approved)
+        }
+
+        fn total_supply(&self) -> Option<u32> {
+            Some(Erc721::total_supply(self))
+        }
+
+        fn token_by_index(&self, index: u32) -> Option<TokenId> {
+            Some(Erc721::token_by_index(self, index))
+        }
+
+        fn token_of_owner_by_index(
+            &self,
+            owner: AccountId,
+            index: u64,
+        ) -> Option<TokenId> {
+            Some(Erc721::token_of_owner_by_index(self, owner, index))
+        }
     }
 }
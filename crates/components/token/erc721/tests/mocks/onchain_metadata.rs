@@ -0,0 +1,206 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod contract {
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_erc721 as erc721;
+    pub use metis_erc721::{
+        onchain_metadata,
+        Error,
+        Result,
+        TokenId,
+    };
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_ownable as ownable;
+    pub use onchain_metadata::{
+        Attribute,
+        TokenMetadata,
+    };
+
+    /// A ERC721 contract with the metadata of the tokens on chain, which can
+    /// only be set by the owner.
+    #[ink(storage)]
+    #[import(erc721, ownable, onchain_metadata)]
+    pub struct Erc721 {
+        erc721: erc721::Data<Erc721>,
+        ownable: ownable::Data<Erc721>,
+        onchain_metadata: onchain_metadata::Data,
+
+        base64: bool,
+    }
+
+    impl erc721::Impl<Erc721> for Erc721 {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<AccountId>,
+            to: Option<AccountId>,
+            token_id: &TokenId,
+        ) -> Result<()> {
+            onchain_metadata::Impl::before_token_transfer(self, from, to, token_id)
+        }
+
+        fn _base_url(&self) -> String {
+            String::from("")
+        }
+    }
+
+    impl onchain_metadata::Impl<Erc721> for Erc721 {
+        fn _check_metadata_setter(&self) {
+            ownable::Impl::ensure_caller_is_owner(self)
+        }
+
+        fn _metadata_base64(&self) -> bool {
+            self.base64
+        }
+    }
+
+    /// Emitted when `token_id` token is transferred from `from` to `to`.
+    #[ink(event)]
+    #[metis(erc721)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub token_id: TokenId,
+    }
+
+    /// Emitted when `owner` enables `approved` to manage the `token_id` token.
+    #[ink(event)]
+    #[metis(erc721)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: Option<AccountId>,
+        pub token_id: TokenId,
+    }
+
+    /// Emitted when `owner` enables or disables (`approved`) `operator` to manage all of its assets.
+    #[ink(event)]
+    #[metis(erc721)]
+    pub struct ApprovalForAll {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub operator: AccountId,
+        pub approved: bool,
+    }
+
+    /// Event emitted when Owner AccountId Transferred
+    #[ink(event)]
+    #[metis(ownable)]
+    pub struct OwnershipTransferred {
+        /// previous owner account id
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
+        /// new owner account id
+        #[ink(topic)]
+        new_owner: Option<AccountId>,
+    }
+
+    /// Emitted when the metadata of `token_id` is updated.
+    #[ink(event)]
+    #[metis(onchain_metadata)]
+    pub struct MetadataUpdate {
+        pub token_id: TokenId,
+    }
+
+    // for test message
+    impl Erc721 {
+        /// For test to mint
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, token_id: TokenId) -> Result<()> {
+            erc721::Impl::_mint(self, &to, &token_id)
+        }
+
+        /// For test to burn
+        #[ink(message)]
+        pub fn burn(&mut self, token_id: TokenId) -> Result<()> {
+            erc721::Impl::_burn(self, &token_id)
+        }
+    }
+
+    // impl
+    impl Erc721 {
+        #[ink(constructor)]
+        pub fn new(name: String, symbol: String, base64: bool) -> Self {
+            let mut instance = Self {
+                erc721: erc721::Data::new(),
+                ownable: ownable::Data::new(),
+                onchain_metadata: onchain_metadata::Data::default(),
+                base64,
+            };
+
+            erc721::Impl::init(&mut instance, name, symbol);
+            ownable::Impl::init(&mut instance);
+            instance
+        }
+
+        /// Returns the metadata url of the `token_id`, which is the JSON or
+        /// the base64 data url of it.
+        #[ink(message)]
+        pub fn token_url(&self, token_id: TokenId) -> String {
+            onchain_metadata::Impl::token_url(self, &token_id)
+        }
+
+        /// Returns the metadata of the `token_id`.
+        #[ink(message)]
+        pub fn token_metadata(&self, token_id: TokenId) -> Option<TokenMetadata> {
+            onchain_metadata::Impl::token_metadata(self, &token_id)
+        }
+
+        #[ink(message)]
+        pub fn set_token_metadata(
+            &mut self,
+            token_id: TokenId,
+            name: String,
+            description: String,
+            attributes: Vec<Attribute>,
+        ) {
+            onchain_metadata::Impl::set_token_metadata(
+                self,
+                token_id,
+                name,
+                description,
+                attributes,
+            )
+        }
+
+        #[ink(message)]
+        pub fn set_token_attribute(
+            &mut self,
+            token_id: TokenId,
+            trait_type: String,
+            value: String,
+        ) {
+            onchain_metadata::Impl::set_token_attribute(self, token_id, trait_type, value)
+        }
+
+        #[ink(message)]
+        pub fn remove_token_attribute(&mut self, token_id: TokenId, trait_type: String) {
+            onchain_metadata::Impl::remove_token_attribute(self, token_id, trait_type)
+        }
+
+        #[ink(message)]
+        pub fn owner_of(&self, token_id: TokenId) -> AccountId {
+            erc721::Impl::owner_of(self, &token_id)
+        }
+
+        #[ink(message)]
+        pub fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+        ) -> Result<()> {
+            erc721::Impl::transfer_from(self, from, to, token_id)
+        }
+    }
+}
@@ -0,0 +1,193 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod contract {
+    use ink_prelude::string::String;
+    use metis_erc721 as erc721;
+    pub use metis_erc721::{
+        enumerable,
+        votes,
+        Error,
+        Result,
+        TokenId,
+    };
+    use metis_lang::{
+        import,
+        metis,
+    };
+
+    /// A ERC721 contract for the membership of a DAO, each token counts as
+    /// one vote of its holder.
+    #[ink(storage)]
+    #[import(erc721, enumerable, votes)]
+    pub struct Erc721 {
+        erc721: erc721::Data<Erc721>,
+        enumerable: enumerable::Data<Erc721>,
+        votes: votes::Data<Erc721>,
+    }
+
+    impl erc721::Impl<Erc721> for Erc721 {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<AccountId>,
+            to: Option<AccountId>,
+            token_id: &TokenId,
+        ) -> Result<()> {
+            enumerable::Impl::before_token_transfer(
+                self,
+                from.clone(),
+                to.clone(),
+                token_id,
+            )?;
+            votes::Impl::before_token_transfer(self, from, to, token_id)
+        }
+
+        fn _base_url(&self) -> String {
+            String::from("https://test/")
+        }
+    }
+    impl enumerable::Impl<Erc721> for Erc721 {}
+    impl votes::Impl<Erc721> for Erc721 {}
+
+    /// Emitted when `token_id` token is transferred from `from` to `to`.
+    #[ink(event)]
+    #[metis(erc721)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub token_id: TokenId,
+    }
+
+    /// Emitted when `owner` enables `approved` to manage the `token_id` token.
+    #[ink(event)]
+    #[metis(erc721)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: Option<AccountId>,
+        pub token_id: TokenId,
+    }
+
+    /// Emitted when `owner` enables or disables (`approved`) `operator` to manage all of its assets.
+    #[ink(event)]
+    #[metis(erc721)]
+    pub struct ApprovalForAll {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub operator: AccountId,
+        pub approved: bool,
+    }
+
+    /// Emitted when `delegator` changes its delegate from `from_delegate` to `to_delegate`.
+    #[ink(event)]
+    #[metis(votes)]
+    pub struct DelegateChanged {
+        #[ink(topic)]
+        pub delegator: AccountId,
+        #[ink(topic)]
+        pub from_delegate: Option<AccountId>,
+        #[ink(topic)]
+        pub to_delegate: Option<AccountId>,
+    }
+
+    /// Emitted when the votes of `delegate` changed.
+    #[ink(event)]
+    #[metis(votes)]
+    pub struct DelegateVotesChanged {
+        #[ink(topic)]
+        pub delegate: AccountId,
+        pub previous_balance: u64,
+        pub new_balance: u64,
+    }
+
+    // for test message
+    impl Erc721 {
+        /// For test to mint
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, token_id: TokenId) -> Result<()> {
+            erc721::Impl::_mint(self, &to, &token_id)
+        }
+
+        /// For test to burn
+        #[ink(message)]
+        pub fn burn(&mut self, token_id: TokenId) -> Result<()> {
+            erc721::Impl::_burn(self, &token_id)
+        }
+    }
+
+    // impl
+    impl Erc721 {
+        #[ink(constructor)]
+        pub fn new(name: String, symbol: String) -> Self {
+            let mut instance = Self {
+                erc721: erc721::Data::new(),
+                enumerable: enumerable::Data::default(),
+                votes: votes::Data::default(),
+            };
+
+            erc721::Impl::init(&mut instance, name, symbol);
+            instance
+        }
+
+        /// @dev Returns the number of tokens in ``owner``'s account.
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> u64 {
+            erc721::Impl::balance_of(self, &owner)
+        }
+
+        /// @dev Returns the owner of the `token_id` token.
+        #[ink(message)]
+        pub fn owner_of(&self, token_id: TokenId) -> AccountId {
+            erc721::Impl::owner_of(self, &token_id)
+        }
+
+        /// @dev Transfers `token_id` token from `from` to `to`.
+        #[ink(message)]
+        pub fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+        ) -> Result<()> {
+            erc721::Impl::transfer_from(self, from, to, token_id)
+        }
+
+        /// @dev Returns the total amount of tokens stored by the contract.
+        #[ink(message)]
+        pub fn total_supply(&self) -> u32 {
+            enumerable::Impl::total_supply(self)
+        }
+
+        /// Returns the delegate that `account` has chosen.
+        #[ink(message)]
+        pub fn delegates(&self, account: AccountId) -> Option<AccountId> {
+            votes::Impl::delegates(self, &account)
+        }
+
+        /// Returns the current votes of `account`.
+        #[ink(message)]
+        pub fn get_votes(&self, account: AccountId) -> u64 {
+            votes::Impl::get_votes(self, &account)
+        }
+
+        /// Returns the votes of `account` at the end of `block_number`.
+        #[ink(message)]
+        pub fn get_past_votes(
+            &self,
+            account: AccountId,
+            block_number: BlockNumber,
+        ) -> u64 {
+            votes::Impl::get_past_votes(self, &account, block_number)
+        }
+
+        /// Delegates the votes of the tokens of the caller to `delegatee`.
+        #[ink(message)]
+        pub fn delegate(&mut self, delegatee: AccountId) {
+            votes::Impl::delegate(self, delegatee)
+        }
+    }
+}
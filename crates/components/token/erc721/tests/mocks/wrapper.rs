@@ -0,0 +1,148 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod contract {
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_erc721 as erc721;
+    pub use metis_erc721::{
+        wrapper,
+        Error,
+        Result,
+        TokenId,
+    };
+    use metis_lang::{
+        import,
+        metis,
+    };
+
+    /// A ERC721 contract which wraps an underlying ERC721 token 1:1.
+    #[ink(storage)]
+    #[import(erc721, wrapper)]
+    pub struct Erc721 {
+        erc721: erc721::Data<Erc721>,
+        wrapper: wrapper::Data<Erc721>,
+    }
+
+    impl erc721::Impl<Erc721> for Erc721 {
+        fn _before_token_transfer(
+            &mut self,
+            _from: Option<AccountId>,
+            _to: Option<AccountId>,
+            _token_id: &TokenId,
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn _base_url(&self) -> String {
+            String::from("https://test/")
+        }
+    }
+    impl wrapper::Impl<Erc721> for Erc721 {}
+
+    /// Emitted when `token_id` token is transferred from `from` to `to`.
+    #[ink(event)]
+    #[metis(erc721)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub token_id: TokenId,
+    }
+
+    /// Emitted when `owner` enables `approved` to manage the `token_id` token.
+    #[ink(event)]
+    #[metis(erc721)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: Option<AccountId>,
+        pub token_id: TokenId,
+    }
+
+    /// Emitted when `owner` enables or disables (`approved`) `operator` to manage all of its assets.
+    #[ink(event)]
+    #[metis(erc721)]
+    pub struct ApprovalForAll {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub operator: AccountId,
+        pub approved: bool,
+    }
+
+    // impl
+    impl Erc721 {
+        #[ink(constructor)]
+        pub fn new(underlying: AccountId, name: String, symbol: String) -> Self {
+            let mut instance = Self {
+                erc721: erc721::Data::new(),
+                wrapper: wrapper::Data::new(underlying),
+            };
+
+            erc721::Impl::init(&mut instance, name, symbol);
+            instance
+        }
+
+        /// @dev Returns the number of tokens in ``owner``'s account.
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> u64 {
+            erc721::Impl::balance_of(self, &owner)
+        }
+
+        /// @dev Returns the owner of the `token_id` token.
+        #[ink(message)]
+        pub fn owner_of(&self, token_id: TokenId) -> AccountId {
+            erc721::Impl::owner_of(self, &token_id)
+        }
+
+        /// @dev Gives permission to `to` to transfer `token_id` token to another account.
+        #[ink(message)]
+        pub fn approve(&mut self, to: Option<AccountId>, token_id: TokenId) {
+            erc721::Impl::approve(self, to, &token_id)
+        }
+
+        #[ink(message)]
+        pub fn underlying(&self) -> AccountId {
+            wrapper::Impl::underlying(self)
+        }
+
+        #[ink(message)]
+        pub fn deposit_for(
+            &mut self,
+            account: AccountId,
+            token_ids: Vec<TokenId>,
+        ) -> Result<()> {
+            wrapper::Impl::deposit_for(self, account, token_ids)
+        }
+
+        #[ink(message)]
+        pub fn withdraw_to(
+            &mut self,
+            account: AccountId,
+            token_ids: Vec<TokenId>,
+        ) -> Result<()> {
+            wrapper::Impl::withdraw_to(self, account, token_ids)
+        }
+
+        #[ink(message)]
+        pub fn on_erc721_received(
+            &mut self,
+            operator: AccountId,
+            from: AccountId,
+            token_id: TokenId,
+            data: Vec<u8>,
+        ) -> [u8; 4] {
+            wrapper::Impl::on_erc721_received(self, operator, from, token_id, data)
+        }
+
+        #[ink(message)]
+        pub fn recover(&mut self, account: AccountId, token_id: TokenId) -> Result<()> {
+            wrapper::Impl::_recover(self, account, token_id)
+        }
+    }
+}
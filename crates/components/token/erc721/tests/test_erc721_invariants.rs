@@ -0,0 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod enumerable;
+}
+
+mod erc721_invariants_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use metis_test::{
+        erc721_ops,
+        run_erc721_invariants,
+    };
+    use mocks::enumerable::contract::Erc721;
+    use proptest::prelude::*;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn erc721_enumerable_invariants_hold(ops in erc721_ops(48)) {
+            run_erc721_invariants::<Erc721>(&ops);
+        }
+    }
+}
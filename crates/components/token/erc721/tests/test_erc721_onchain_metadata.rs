@@ -0,0 +1,332 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod onchain_metadata;
+}
+
+mod erc721_onchain_metadata_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_erc721::onchain_metadata::{
+        MAX_ATTRIBUTES,
+        MAX_ATTRIBUTE_LEN,
+        MAX_DESCRIPTION_LEN,
+        MAX_NAME_LEN,
+    };
+    use metis_test::*;
+    use mocks::onchain_metadata::contract::{
+        Attribute,
+        Erc721,
+        MetadataUpdate,
+        TokenId,
+        TokenMetadata,
+    };
+
+    const TOKEN_A: TokenId = TokenId::new([0x0a; 32]);
+    const TOKEN_B: TokenId = TokenId::new([0x0b; 32]);
+
+    fn attribute(trait_type: &str, value: &str) -> Attribute {
+        Attribute::new(String::from(trait_type), String::from(value))
+    }
+
+    fn init(base64: bool) -> Erc721 {
+        let accounts = default_accounts();
+        let mut erc721 =
+            Erc721::new(String::from("MockErc721Token"), String::from("MET"), base64);
+
+        assert_eq!(erc721.mint(accounts.alice, TOKEN_A), Ok(()));
+        assert_eq!(erc721.mint(accounts.bob, TOKEN_B), Ok(()));
+
+        erc721
+    }
+
+    fn set_metadata(erc721: &mut Erc721) {
+        erc721.set_token_metadata(
+            TOKEN_A,
+            String::from("Token A"),
+            String::from("The first token"),
+            vec![attribute("Color", "Red"), attribute("Level", "1")],
+        );
+    }
+
+    #[ink::test]
+    fn token_url_without_metadata_should_empty_json() {
+        let erc721 = init(false);
+
+        assert_eq!(erc721.token_metadata(TOKEN_A), None);
+        assert_eq!(
+            erc721.token_url(TOKEN_A),
+            r#"{"name":"","description":"","attributes":[]}"#
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721Metadata: URI query for nonexistent token")]
+    fn token_url_of_nonexistent_token_should_panic() {
+        let erc721 = init(false);
+
+        erc721.token_url(TokenId::new([0x0c; 32]));
+    }
+
+    #[ink::test]
+    fn set_token_metadata_should_work() {
+        let mut erc721 = init(false);
+
+        set_metadata(&mut erc721);
+
+        assert_eq!(
+            erc721.token_metadata(TOKEN_A),
+            Some(TokenMetadata {
+                name: String::from("Token A"),
+                description: String::from("The first token"),
+                attributes: vec![attribute("Color", "Red"), attribute("Level", "1")],
+            })
+        );
+        assert_eq!(
+            erc721.token_url(TOKEN_A),
+            r#"{"name":"Token A","description":"The first token","attributes":[{"trait_type":"Color","value":"Red"},{"trait_type":"Level","value":"1"}]}"#
+        );
+
+        let MetadataUpdate { token_id } = expect_event::<MetadataUpdate>();
+        assert_eq!(token_id, TOKEN_A);
+
+        // the metadata of other tokens are not changed
+        assert_eq!(erc721.token_metadata(TOKEN_B), None);
+    }
+
+    #[ink::test]
+    fn token_url_should_escape_json() {
+        let mut erc721 = init(false);
+
+        erc721.set_token_metadata(
+            TOKEN_A,
+            String::from("\"Quoted\" \\ name"),
+            String::from("line\nbreak\u{1}"),
+            Vec::new(),
+        );
+
+        assert_eq!(
+            erc721.token_url(TOKEN_A),
+            r#"{"name":"\"Quoted\" \\ name","description":"line\nbreak\u0001","attributes":[]}"#
+        );
+    }
+
+    #[ink::test]
+    fn token_url_should_be_base64_data_url() {
+        let mut erc721 = init(true);
+
+        assert_eq!(
+            erc721.token_url(TOKEN_A),
+            "data:application/json;base64,eyJuYW1lIjoiIiwiZGVzY3JpcHRpb24iOiIiLCJhdHRyaWJ1dGVzIjpbXX0="
+        );
+
+        erc721.set_token_metadata(
+            TOKEN_A,
+            String::from("A"),
+            String::from(""),
+            Vec::new(),
+        );
+        assert_eq!(
+            erc721.token_url(TOKEN_A),
+            "data:application/json;base64,eyJuYW1lIjoiQSIsImRlc2NyaXB0aW9uIjoiIiwiYXR0cmlidXRlcyI6W119"
+        );
+    }
+
+    #[ink::test]
+    fn set_token_attribute_should_work() {
+        let mut erc721 = init(false);
+
+        // add to the token without metadata
+        erc721.set_token_attribute(TOKEN_B, String::from("Color"), String::from("Blue"));
+        assert_eq!(
+            erc721.token_metadata(TOKEN_B).unwrap().attributes,
+            vec![attribute("Color", "Blue")]
+        );
+
+        set_metadata(&mut erc721);
+
+        // update the exists one
+        erc721.set_token_attribute(TOKEN_A, String::from("Level"), String::from("2"));
+        // add a new one
+        erc721.set_token_attribute(TOKEN_A, String::from("Shape"), String::from("Round"));
+
+        let metadata = erc721.token_metadata(TOKEN_A).unwrap();
+        assert_eq!(metadata.name, String::from("Token A"));
+        assert_eq!(
+            metadata.attributes,
+            vec![
+                attribute("Color", "Red"),
+                attribute("Level", "2"),
+                attribute("Shape", "Round")
+            ]
+        );
+    }
+
+    #[ink::test]
+    fn remove_token_attribute_should_work() {
+        let mut erc721 = init(false);
+        set_metadata(&mut erc721);
+
+        erc721.remove_token_attribute(TOKEN_A, String::from("Color"));
+
+        assert_eq!(
+            erc721.token_metadata(TOKEN_A).unwrap().attributes,
+            vec![attribute("Level", "1")]
+        );
+        let MetadataUpdate { token_id } = expect_event::<MetadataUpdate>();
+        assert_eq!(token_id, TOKEN_A);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721OnchainMetadata: attribute not exists")]
+    fn remove_not_exists_attribute_should_panic() {
+        let mut erc721 = init(false);
+        set_metadata(&mut erc721);
+
+        erc721.remove_token_attribute(TOKEN_A, String::from("Shape"));
+    }
+
+    #[ink::test]
+    fn metadata_should_be_kept_by_transfer_and_cleared_by_burn() {
+        let accounts = default_accounts();
+        let mut erc721 = init(false);
+        set_metadata(&mut erc721);
+
+        assert_eq!(
+            erc721.transfer_from(accounts.alice, accounts.charlie, TOKEN_A),
+            Ok(())
+        );
+        assert_eq!(erc721.owner_of(TOKEN_A), accounts.charlie);
+        assert_eq!(
+            erc721.token_metadata(TOKEN_A).unwrap().name,
+            String::from("Token A")
+        );
+
+        assert_eq!(erc721.burn(TOKEN_A), Ok(()));
+        assert_eq!(erc721.token_metadata(TOKEN_A), None);
+
+        // the metadata of a reminted token is empty
+        assert_eq!(erc721.mint(accounts.alice, TOKEN_A), Ok(()));
+        assert_eq!(erc721.token_metadata(TOKEN_A), None);
+    }
+
+    #[ink::test]
+    #[should_panic]
+    fn set_token_metadata_by_not_owner_should_panic() {
+        let accounts = default_accounts();
+        let mut erc721 = init(false);
+
+        set_caller(accounts.bob);
+        erc721.set_token_metadata(
+            TOKEN_B,
+            String::from("B"),
+            String::from(""),
+            Vec::new(),
+        );
+    }
+
+    #[ink::test]
+    #[should_panic]
+    fn set_token_attribute_by_not_owner_should_panic() {
+        let accounts = default_accounts();
+        let mut erc721 = init(false);
+
+        set_caller(accounts.bob);
+        erc721.set_token_attribute(TOKEN_B, String::from("Color"), String::from("Blue"));
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721OnchainMetadata: metadata set of nonexistent token")]
+    fn set_metadata_of_nonexistent_token_should_panic() {
+        let mut erc721 = init(false);
+
+        erc721.set_token_metadata(
+            TokenId::new([0x0c; 32]),
+            String::from("C"),
+            String::from(""),
+            Vec::new(),
+        );
+    }
+
+    #[ink::test]
+    fn metadata_in_bounds_should_work() {
+        let mut erc721 = init(false);
+
+        erc721.set_token_metadata(
+            TOKEN_A,
+            "n".repeat(MAX_NAME_LEN),
+            "d".repeat(MAX_DESCRIPTION_LEN),
+            (0..MAX_ATTRIBUTES)
+                .map(|i| {
+                    Attribute::new(
+                        format!("{:0width$}", i, width = MAX_ATTRIBUTE_LEN),
+                        "v".repeat(MAX_ATTRIBUTE_LEN),
+                    )
+                })
+                .collect(),
+        );
+
+        assert_eq!(
+            erc721.token_metadata(TOKEN_A).unwrap().attributes.len(),
+            MAX_ATTRIBUTES
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721OnchainMetadata: name too long")]
+    fn name_too_long_should_panic() {
+        let mut erc721 = init(false);
+
+        erc721.set_token_metadata(
+            TOKEN_A,
+            "n".repeat(MAX_NAME_LEN + 1),
+            String::from(""),
+            Vec::new(),
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721OnchainMetadata: description too long")]
+    fn description_too_long_should_panic() {
+        let mut erc721 = init(false);
+
+        erc721.set_token_metadata(
+            TOKEN_A,
+            String::from(""),
+            "d".repeat(MAX_DESCRIPTION_LEN + 1),
+            Vec::new(),
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721OnchainMetadata: too many attributes")]
+    fn too_many_attributes_should_panic() {
+        let mut erc721 = init(false);
+
+        erc721.set_token_metadata(
+            TOKEN_A,
+            String::from(""),
+            String::from(""),
+            (0..=MAX_ATTRIBUTES)
+                .map(|i| attribute(&i.to_string(), "v"))
+                .collect(),
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721OnchainMetadata: attribute too long")]
+    fn attribute_too_long_should_panic() {
+        let mut erc721 = init(false);
+
+        erc721.set_token_attribute(
+            TOKEN_A,
+            String::from("Color"),
+            "v".repeat(MAX_ATTRIBUTE_LEN + 1),
+        );
+    }
+}
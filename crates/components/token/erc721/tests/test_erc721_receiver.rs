@@ -0,0 +1,176 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod basic;
+}
+
+mod erc721_receiver_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_erc721_receiver::{
+        ERC721Receiver,
+        ERC721ReceiverStub,
+    };
+    use metis_lang::mock;
+    use metis_test::*;
+    use mocks::basic::contract::{
+        Erc721,
+        TokenId,
+    };
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    /// A receiver contract which records the received tokens.
+    #[derive(Default)]
+    struct MockReceiver {
+        accept: bool,
+        received: Vec<(AccountId, AccountId, TokenId, Vec<u8>)>,
+    }
+
+    impl ERC721Receiver for MockReceiver {
+        fn on_erc721_received(
+            &mut self,
+            operator: AccountId,
+            from: AccountId,
+            token_id: TokenId,
+            data: Vec<u8>,
+        ) -> [u8; 4] {
+            self.received.push((operator, from, token_id, data));
+
+            if self.accept {
+                metis_lang::selector_id!(on_erc721_received)
+            } else {
+                [0x00; 4]
+            }
+        }
+    }
+
+    fn init_with_token(owner: AccountId, token_id: &TokenId) -> Erc721 {
+        let mut erc721 =
+            Erc721::new(String::from("MockErc721Token"), String::from("MET"));
+
+        assert_eq!(erc721.mint(owner, token_id), Ok(()), "mint should be ok");
+
+        erc721
+    }
+
+    #[ink::test]
+    fn safe_transfer_to_account_without_code_works() {
+        let accounts = default_accounts();
+        let token_id = TokenId::new([0x0a; 32]);
+        let mut erc721 = init_with_token(accounts.alice, &token_id);
+
+        assert!(!mock::is_registered(&accounts.bob));
+        assert_eq!(
+            erc721.safe_transfer_from(accounts.alice, accounts.bob, token_id),
+            Ok(())
+        );
+        assert_eq!(erc721.owner_of(&token_id), accounts.bob);
+    }
+
+    #[ink::test]
+    fn safe_transfer_to_receiver_accepted() {
+        let accounts = default_accounts();
+        let token_id = TokenId::new([0x0a; 32]);
+        let mut erc721 = init_with_token(accounts.alice, &token_id);
+
+        let receiver = Rc::new(RefCell::new(MockReceiver {
+            accept: true,
+            ..Default::default()
+        }));
+        ERC721ReceiverStub::mock(accounts.bob, receiver.clone());
+
+        assert_eq!(
+            erc721.safe_transfer_from_with_data(
+                accounts.alice,
+                accounts.bob,
+                token_id,
+                vec![0x01, 0x02]
+            ),
+            Ok(())
+        );
+        assert_eq!(erc721.owner_of(&token_id), accounts.bob);
+
+        assert_eq!(
+            receiver.borrow().received,
+            vec![(accounts.alice, accounts.alice, token_id, vec![0x01, 0x02])],
+            "receiver should be called with the transfer"
+        );
+    }
+
+    #[ink::test]
+    fn safe_transfer_to_receiver_by_closure_accepted() {
+        let accounts = default_accounts();
+        let token_id = TokenId::new([0x0a; 32]);
+        let mut erc721 = init_with_token(accounts.alice, &token_id);
+
+        let contract = contract_id();
+        mock::register(accounts.bob, move |call| {
+            assert_eq!(call.caller, contract, "caller should be the erc721");
+            assert_eq!(call.selector, metis_lang::selector_id!(on_erc721_received));
+
+            let (operator, from, id, data): (AccountId, AccountId, TokenId, Vec<u8>) =
+                call.decode_input()?;
+            assert_eq!(operator, from);
+            assert_eq!(id, TokenId::new([0x0a; 32]));
+            assert!(data.is_empty());
+
+            Ok(scale::Encode::encode(&metis_lang::selector_id!(
+                on_erc721_received
+            )))
+        });
+
+        assert_eq!(
+            erc721.safe_transfer_from(accounts.alice, accounts.bob, token_id),
+            Ok(())
+        );
+        assert_eq!(erc721.owner_of(&token_id), accounts.bob);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721: transfer to non ERC721Receiver implementer")]
+    fn safe_transfer_to_receiver_rejected() {
+        let accounts = default_accounts();
+        let token_id = TokenId::new([0x0a; 32]);
+        let mut erc721 = init_with_token(accounts.alice, &token_id);
+
+        let receiver = Rc::new(RefCell::new(MockReceiver::default()));
+        ERC721ReceiverStub::mock(accounts.bob, receiver);
+
+        let _ = erc721.safe_transfer_from(accounts.alice, accounts.bob, token_id);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721: transfer to non ERC721Receiver implementer")]
+    fn safe_transfer_to_receiver_reverted() {
+        let accounts = default_accounts();
+        let token_id = TokenId::new([0x0a; 32]);
+        let mut erc721 = init_with_token(accounts.alice, &token_id);
+
+        mock::register_revert(accounts.bob);
+
+        let _ = erc721.safe_transfer_from(accounts.alice, accounts.bob, token_id);
+    }
+
+    #[ink::test]
+    fn transfer_from_not_check_receiver() {
+        let accounts = default_accounts();
+        let token_id = TokenId::new([0x0a; 32]);
+        let mut erc721 = init_with_token(accounts.alice, &token_id);
+
+        mock::register_revert(accounts.bob);
+
+        assert_eq!(
+            erc721.transfer_from(accounts.alice, accounts.bob, token_id),
+            Ok(())
+        );
+        assert_eq!(erc721.owner_of(&token_id), accounts.bob);
+    }
+}
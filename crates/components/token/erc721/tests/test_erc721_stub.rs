@@ -0,0 +1,176 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod basic;
+}
+
+mod erc721_stub_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang::{
+        self as ink,
+        ForwardCall,
+        ForwardCallMut,
+    };
+    use ink_prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use metis_erc721_stub::{
+        Erc721 as Erc721Messages,
+        Erc721Stub,
+    };
+    use metis_lang::{
+        mock::check_messages,
+        FromAccountId,
+    };
+    use metis_test::*;
+    use mocks::basic::contract::{
+        Erc721,
+        Result,
+        TokenId,
+    };
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    extern "Rust" {
+        fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+    }
+
+    /// The messages of the stub must be the messages of the real contract
+    /// with the same signatures.
+    impl Erc721Messages for Erc721 {
+        fn name(&self) -> String {
+            Erc721::name(self)
+        }
+
+        fn symbol(&self) -> String {
+            Erc721::symbol(self)
+        }
+
+        fn balance_of(&self, owner: AccountId) -> u64 {
+            Erc721::balance_of(self, owner)
+        }
+
+        fn owner_of(&self, token_id: TokenId) -> AccountId {
+            Erc721::owner_of(self, &token_id)
+        }
+
+        fn get_approved(&self, token_id: TokenId) -> Option<AccountId> {
+            Erc721::get_approved(self, &token_id)
+        }
+
+        fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool {
+            Erc721::is_approved_for_all(self, &owner, &operator)
+        }
+
+        fn approve(&mut self, to: Option<AccountId>, token_id: TokenId) {
+            Erc721::approve(self, to, &token_id)
+        }
+
+        fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) {
+            Erc721::set_approval_for_all(self, operator, approved)
+        }
+
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+        ) -> Result<()> {
+            Erc721::transfer_from(self, from, to, token_id)
+        }
+
+        fn safe_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+        ) -> Result<()> {
+            Erc721::safe_transfer_from(self, from, to, token_id)
+        }
+
+        fn safe_transfer_from_with_data(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            Erc721::safe_transfer_from_with_data(self, from, to, token_id, data)
+        }
+    }
+
+    #[test]
+    fn stub_messages_should_match_contract() {
+        let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+            .expect("metadata should be serialized");
+
+        assert_eq!(check_messages(&metadata, Erc721Stub::MESSAGES), Ok(()));
+    }
+
+    #[ink::test]
+    fn call_by_stub_should_dispatch_to_contract() {
+        let accounts = default_accounts();
+        let token_id = TokenId::new([0x0a; 32]);
+
+        let erc721 = Rc::new(RefCell::new(Erc721::new(
+            String::from("MockErc721Token"),
+            String::from("MET"),
+        )));
+        Erc721Stub::mock(accounts.eve, erc721.clone());
+
+        let mut stub =
+            <Erc721Stub as FromAccountId<Erc721>>::from_account_id(accounts.eve);
+        assert_eq!(
+            stub.call().name().fire(),
+            Ok(String::from("MockErc721Token"))
+        );
+        assert_eq!(stub.call().symbol().fire(), Ok(String::from("MET")));
+
+        // the contract which calls the stub is the caller of the message
+        erc721
+            .borrow_mut()
+            .mint(contract_id(), &token_id)
+            .expect("mint should be ok");
+        assert_eq!(stub.call().balance_of(contract_id()).fire(), Ok(1));
+        assert_eq!(
+            stub.call().owner_of(token_id.clone()).fire(),
+            Ok(contract_id())
+        );
+
+        assert_eq!(
+            stub.call_mut()
+                .approve(Some(accounts.bob), token_id.clone())
+                .fire(),
+            Ok(())
+        );
+        assert_eq!(
+            stub.call().get_approved(token_id.clone()).fire(),
+            Ok(Some(accounts.bob))
+        );
+
+        assert_eq!(
+            stub.call_mut()
+                .set_approval_for_all(accounts.charlie, true)
+                .fire(),
+            Ok(())
+        );
+        assert_eq!(
+            stub.call()
+                .is_approved_for_all(contract_id(), accounts.charlie)
+                .fire(),
+            Ok(true)
+        );
+
+        assert_eq!(
+            stub.call_mut()
+                .transfer_from(contract_id(), accounts.bob, token_id.clone())
+                .fire(),
+            Ok(Ok(()))
+        );
+        assert_eq!(stub.call().owner_of(token_id).fire(), Ok(accounts.bob));
+    }
+}
@@ -0,0 +1,280 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod votes;
+}
+
+mod erc721_votes_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::string::String;
+    use metis_test::*;
+    use mocks::votes::contract::{
+        DelegateChanged,
+        DelegateVotesChanged,
+        Erc721,
+        TokenId,
+    };
+
+    const TOKEN_A: TokenId = TokenId::new([0x0a; 32]);
+    const TOKEN_B: TokenId = TokenId::new([0x0b; 32]);
+    const TOKEN_C: TokenId = TokenId::new([0x0c; 32]);
+
+    fn init() -> Erc721 {
+        let accounts = default_accounts();
+        let mut erc721 =
+            Erc721::new(String::from("MockErc721Token"), String::from("MET"));
+
+        assert_eq!(erc721.mint(accounts.alice, TOKEN_A), Ok(()));
+        assert_eq!(erc721.mint(accounts.alice, TOKEN_B), Ok(()));
+        assert_eq!(erc721.mint(accounts.bob, TOKEN_C), Ok(()));
+
+        erc721
+    }
+
+    fn expect_delegate_changed(index: usize) -> DelegateChanged {
+        let event = &get_emitted_events()[index];
+        decode_event_with_signature(event, b"Erc721::DelegateChanged")
+            .expect("encountered unexpected event kind: expected a DelegateChanged")
+    }
+
+    fn expect_delegate_votes_changed(index: usize) -> DelegateVotesChanged {
+        let event = &get_emitted_events()[index];
+        decode_event_with_signature(event, b"Erc721::DelegateVotesChanged")
+            .expect("encountered unexpected event kind: expected a DelegateVotesChanged")
+    }
+
+    #[ink::test]
+    fn tokens_should_not_count_before_delegate() {
+        let accounts = default_accounts();
+        let erc721 = init();
+
+        assert_eq!(erc721.balance_of(accounts.alice), 2);
+        assert_eq!(erc721.total_supply(), 3);
+        assert_eq!(erc721.delegates(accounts.alice), None);
+        assert_eq!(erc721.get_votes(accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn delegate_should_work() {
+        let accounts = default_accounts();
+        let mut erc721 = init();
+        let len = get_emitted_events().len();
+
+        set_caller(accounts.alice);
+        erc721.delegate(accounts.alice);
+
+        assert_eq!(erc721.delegates(accounts.alice), Some(accounts.alice));
+        assert_eq!(erc721.get_votes(accounts.alice), 2);
+
+        assert_emitted_event_len(len + 2);
+        let DelegateChanged {
+            delegator,
+            from_delegate,
+            to_delegate,
+        } = expect_delegate_changed(len);
+        assert_eq!(delegator, accounts.alice);
+        assert_eq!(from_delegate, None);
+        assert_eq!(to_delegate, Some(accounts.alice));
+
+        let DelegateVotesChanged {
+            delegate,
+            previous_balance,
+            new_balance,
+        } = expect_delegate_votes_changed(len + 1);
+        assert_eq!(delegate, accounts.alice);
+        assert_eq!(previous_balance, 0);
+        assert_eq!(new_balance, 2);
+    }
+
+    #[ink::test]
+    fn redelegate_should_move_all_votes() {
+        let accounts = default_accounts();
+        let mut erc721 = init();
+
+        set_caller(accounts.alice);
+        erc721.delegate(accounts.alice);
+        let len = get_emitted_events().len();
+
+        erc721.delegate(accounts.charlie);
+
+        assert_eq!(erc721.get_votes(accounts.alice), 0);
+        assert_eq!(erc721.get_votes(accounts.charlie), 2);
+
+        assert_emitted_event_len(len + 3);
+        let DelegateChanged {
+            from_delegate,
+            to_delegate,
+            ..
+        } = expect_delegate_changed(len);
+        assert_eq!(from_delegate, Some(accounts.alice));
+        assert_eq!(to_delegate, Some(accounts.charlie));
+
+        let DelegateVotesChanged {
+            delegate,
+            previous_balance,
+            new_balance,
+        } = expect_delegate_votes_changed(len + 1);
+        assert_eq!(
+            (delegate, previous_balance, new_balance),
+            (accounts.alice, 2, 0)
+        );
+
+        let DelegateVotesChanged {
+            delegate,
+            previous_balance,
+            new_balance,
+        } = expect_delegate_votes_changed(len + 2);
+        assert_eq!(
+            (delegate, previous_balance, new_balance),
+            (accounts.charlie, 0, 2)
+        );
+    }
+
+    #[ink::test]
+    fn delegate_to_same_delegate_should_not_move_votes() {
+        let accounts = default_accounts();
+        let mut erc721 = init();
+
+        set_caller(accounts.alice);
+        erc721.delegate(accounts.alice);
+        let len = get_emitted_events().len();
+
+        erc721.delegate(accounts.alice);
+
+        assert_eq!(erc721.get_votes(accounts.alice), 2);
+        assert_emitted_event_len(len + 1);
+        expect_delegate_changed(len);
+    }
+
+    #[ink::test]
+    fn mint_should_add_votes_to_delegate() {
+        let accounts = default_accounts();
+        let mut erc721 = init();
+
+        set_caller(accounts.bob);
+        erc721.delegate(accounts.charlie);
+        assert_eq!(erc721.get_votes(accounts.charlie), 1);
+
+        assert_eq!(erc721.mint(accounts.bob, TokenId::new([0x0d; 32])), Ok(()));
+
+        assert_eq!(erc721.get_votes(accounts.bob), 0);
+        assert_eq!(erc721.get_votes(accounts.charlie), 2);
+    }
+
+    #[ink::test]
+    fn transfer_should_move_one_vote_between_delegates() {
+        let accounts = default_accounts();
+        let mut erc721 = init();
+
+        set_caller(accounts.alice);
+        erc721.delegate(accounts.alice);
+        set_caller(accounts.bob);
+        erc721.delegate(accounts.bob);
+
+        set_caller(accounts.alice);
+        assert_eq!(
+            erc721.transfer_from(accounts.alice, accounts.bob, TOKEN_A),
+            Ok(())
+        );
+
+        assert_eq!(erc721.owner_of(TOKEN_A), accounts.bob);
+        assert_eq!(erc721.get_votes(accounts.alice), 1);
+        assert_eq!(erc721.get_votes(accounts.bob), 2);
+    }
+
+    #[ink::test]
+    fn transfer_to_undelegated_account_should_remove_vote() {
+        let accounts = default_accounts();
+        let mut erc721 = init();
+
+        set_caller(accounts.alice);
+        erc721.delegate(accounts.alice);
+        assert_eq!(
+            erc721.transfer_from(accounts.alice, accounts.django, TOKEN_A),
+            Ok(())
+        );
+
+        assert_eq!(erc721.get_votes(accounts.alice), 1);
+        assert_eq!(erc721.get_votes(accounts.django), 0);
+
+        // the votes come back when the receiver delegates.
+        set_caller(accounts.django);
+        erc721.delegate(accounts.django);
+        assert_eq!(erc721.get_votes(accounts.django), 1);
+    }
+
+    #[ink::test]
+    fn burn_should_remove_vote() {
+        let accounts = default_accounts();
+        let mut erc721 = init();
+
+        set_caller(accounts.alice);
+        erc721.delegate(accounts.alice);
+        assert_eq!(erc721.burn(TOKEN_A), Ok(()));
+
+        assert_eq!(erc721.get_votes(accounts.alice), 1);
+        assert_eq!(erc721.total_supply(), 2);
+    }
+
+    #[ink::test]
+    fn get_past_votes_should_lookup_checkpoints() {
+        let accounts = default_accounts();
+        let mut erc721 = init();
+
+        set_caller(accounts.alice);
+        erc721.delegate(accounts.alice);
+        let delegated_at = block_number();
+        advance_block();
+
+        assert_eq!(
+            erc721.transfer_from(accounts.alice, accounts.bob, TOKEN_A),
+            Ok(())
+        );
+        let transferred_at = block_number();
+        advance_block();
+        advance_block();
+
+        assert_eq!(erc721.burn(TOKEN_B), Ok(()));
+        let burned_at = block_number();
+        advance_block();
+
+        assert_eq!(erc721.get_past_votes(accounts.alice, delegated_at), 2);
+        assert_eq!(erc721.get_past_votes(accounts.alice, transferred_at), 1);
+        assert_eq!(erc721.get_past_votes(accounts.alice, transferred_at + 1), 1);
+        assert_eq!(erc721.get_past_votes(accounts.alice, burned_at), 0);
+        assert_eq!(erc721.get_past_votes(accounts.bob, burned_at), 0);
+        assert_eq!(erc721.get_votes(accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn get_past_votes_should_use_last_change_in_block() {
+        let accounts = default_accounts();
+        let mut erc721 = init();
+
+        set_caller(accounts.alice);
+        erc721.delegate(accounts.alice);
+        assert_eq!(
+            erc721.transfer_from(accounts.alice, accounts.bob, TOKEN_A),
+            Ok(())
+        );
+        assert_eq!(
+            erc721.mint(accounts.alice, TokenId::new([0x0d; 32])),
+            Ok(())
+        );
+        let at = block_number();
+        advance_block();
+
+        assert_eq!(erc721.get_past_votes(accounts.alice, at), 2);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721Votes: block not yet mined")]
+    fn get_past_votes_of_current_block_should_panic() {
+        let accounts = default_accounts();
+        let erc721 = init();
+
+        erc721.get_past_votes(accounts.alice, block_number());
+    }
+}
@@ -0,0 +1,350 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod wrapper;
+}
+
+mod erc721_wrapper_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::{
+        string::String,
+        vec,
+        vec::Vec,
+    };
+    use metis_erc721_stub::{
+        Erc721 as Underlying,
+        Erc721Stub,
+    };
+    use metis_test::*;
+    use mocks::wrapper::contract::{
+        Erc721,
+        Error,
+        Result,
+        TokenId,
+    };
+    use std::{
+        cell::RefCell,
+        collections::{
+            HashMap,
+            HashSet,
+        },
+        rc::Rc,
+    };
+
+    const TOKEN_A: TokenId = TokenId::new([0x0a; 32]);
+    const TOKEN_B: TokenId = TokenId::new([0x0b; 32]);
+    const TOKEN_C: TokenId = TokenId::new([0x0c; 32]);
+
+    /// A underlying erc721 contract which only keeps the owners and approvals.
+    #[derive(Default)]
+    struct MockUnderlying {
+        owners: HashMap<TokenId, AccountId>,
+        approvals: HashMap<TokenId, AccountId>,
+        operators: HashSet<(AccountId, AccountId)>,
+    }
+
+    impl MockUnderlying {
+        fn move_token(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+        ) -> Result<()> {
+            let spender = caller();
+            if self.owner_of(token_id) != from {
+                return Err(Error::InsufficientBalance)
+            }
+
+            if spender != from
+                && self.get_approved(token_id) != Some(spender)
+                && !self.is_approved_for_all(from, spender)
+            {
+                return Err(Error::InsufficientAllowance)
+            }
+
+            self.approvals.remove(&token_id);
+            self.owners.insert(token_id, to);
+
+            Ok(())
+        }
+    }
+
+    impl Underlying for MockUnderlying {
+        fn name(&self) -> String {
+            String::from("MockUnderlying")
+        }
+
+        fn symbol(&self) -> String {
+            String::from("MUT")
+        }
+
+        fn balance_of(&self, owner: AccountId) -> u64 {
+            self.owners.values().filter(|o| **o == owner).count() as u64
+        }
+
+        fn owner_of(&self, token_id: TokenId) -> AccountId {
+            self.owners
+                .get(&token_id)
+                .cloned()
+                .expect("MockUnderlying: owner query for nonexistent token")
+        }
+
+        fn get_approved(&self, token_id: TokenId) -> Option<AccountId> {
+            self.approvals.get(&token_id).cloned()
+        }
+
+        fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool {
+            self.operators.contains(&(owner, operator))
+        }
+
+        fn approve(&mut self, to: Option<AccountId>, token_id: TokenId) {
+            match to {
+                Some(to) => self.approvals.insert(token_id, to),
+                None => self.approvals.remove(&token_id),
+            };
+        }
+
+        fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) {
+            if approved {
+                self.operators.insert((caller(), operator));
+            } else {
+                self.operators.remove(&(caller(), operator));
+            }
+        }
+
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+        ) -> Result<()> {
+            self.move_token(from, to, token_id)
+        }
+
+        fn safe_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+        ) -> Result<()> {
+            self.move_token(from, to, token_id)
+        }
+
+        fn safe_transfer_from_with_data(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+            _data: Vec<u8>,
+        ) -> Result<()> {
+            self.move_token(from, to, token_id)
+        }
+    }
+
+    fn caller() -> AccountId {
+        ink_env::caller::<ink_env::DefaultEnvironment>().expect("Cannot get caller")
+    }
+
+    /// Create the wrapper with the underlying at eve, alice owns the
+    /// underlying tokens A and B, and approves all to the wrapper.
+    fn init() -> (Erc721, Rc<RefCell<MockUnderlying>>) {
+        let accounts = default_accounts();
+
+        let underlying = Rc::new(RefCell::new(MockUnderlying::default()));
+        Erc721Stub::mock(accounts.eve, underlying.clone());
+
+        let erc721 = Erc721::new(
+            accounts.eve,
+            String::from("WrappedToken"),
+            String::from("WMUT"),
+        );
+
+        underlying
+            .borrow_mut()
+            .owners
+            .insert(TOKEN_A, accounts.alice);
+        underlying
+            .borrow_mut()
+            .owners
+            .insert(TOKEN_B, accounts.alice);
+        underlying
+            .borrow_mut()
+            .operators
+            .insert((accounts.alice, contract_id()));
+
+        (erc721, underlying)
+    }
+
+    #[ink::test]
+    fn init_works() {
+        let accounts = default_accounts();
+        let (erc721, _) = init();
+
+        assert_eq!(erc721.underlying(), accounts.eve);
+        assert_eq!(erc721.balance_of(accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn deposit_for_works() {
+        let accounts = default_accounts();
+        let (mut erc721, underlying) = init();
+
+        set_caller(accounts.alice);
+        assert_eq!(
+            erc721.deposit_for(accounts.bob, vec![TOKEN_A, TOKEN_B]),
+            Ok(())
+        );
+
+        assert_eq!(erc721.balance_of(accounts.bob), 2);
+        assert_eq!(erc721.owner_of(TOKEN_A), accounts.bob);
+        assert_eq!(erc721.owner_of(TOKEN_B), accounts.bob);
+        assert_eq!(underlying.borrow().owner_of(TOKEN_A), contract_id());
+        assert_eq!(underlying.borrow().owner_of(TOKEN_B), contract_id());
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721Wrapper: transfer from underlying error")]
+    fn deposit_for_not_approved_should_panic() {
+        let accounts = default_accounts();
+        let (mut erc721, underlying) = init();
+
+        underlying
+            .borrow_mut()
+            .operators
+            .remove(&(accounts.alice, contract_id()));
+
+        set_caller(accounts.alice);
+        let _ = erc721.deposit_for(accounts.bob, vec![TOKEN_A]);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721Wrapper: transfer from underlying error")]
+    fn deposit_for_not_owned_token_should_panic() {
+        let accounts = default_accounts();
+        let (mut erc721, _) = init();
+
+        set_caller(accounts.bob);
+        let _ = erc721.deposit_for(accounts.bob, vec![TOKEN_A]);
+    }
+
+    #[ink::test]
+    fn withdraw_to_works() {
+        let accounts = default_accounts();
+        let (mut erc721, underlying) = init();
+
+        set_caller(accounts.alice);
+        assert_eq!(
+            erc721.deposit_for(accounts.bob, vec![TOKEN_A, TOKEN_B]),
+            Ok(())
+        );
+
+        assert_eq!(
+            call_as(accounts.bob, || {
+                erc721.withdraw_to(accounts.charlie, vec![TOKEN_A])
+            }),
+            Ok(())
+        );
+
+        assert_eq!(erc721.balance_of(accounts.bob), 1);
+        assert_eq!(underlying.borrow().owner_of(TOKEN_A), accounts.charlie);
+        assert_eq!(underlying.borrow().owner_of(TOKEN_B), contract_id());
+    }
+
+    #[ink::test]
+    fn withdraw_to_by_approved_works() {
+        let accounts = default_accounts();
+        let (mut erc721, underlying) = init();
+
+        set_caller(accounts.alice);
+        assert_eq!(erc721.deposit_for(accounts.bob, vec![TOKEN_A]), Ok(()));
+
+        call_as(accounts.bob, || {
+            erc721.approve(Some(accounts.charlie), TOKEN_A)
+        });
+        assert_eq!(
+            call_as(accounts.charlie, || {
+                erc721.withdraw_to(accounts.charlie, vec![TOKEN_A])
+            }),
+            Ok(())
+        );
+
+        assert_eq!(erc721.balance_of(accounts.bob), 0);
+        assert_eq!(underlying.borrow().owner_of(TOKEN_A), accounts.charlie);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721Wrapper: caller is not token owner or approved")]
+    fn withdraw_to_by_not_owner_should_panic() {
+        let accounts = default_accounts();
+        let (mut erc721, _) = init();
+
+        set_caller(accounts.alice);
+        assert_eq!(erc721.deposit_for(accounts.bob, vec![TOKEN_A]), Ok(()));
+
+        set_caller(accounts.charlie);
+        let _ = erc721.withdraw_to(accounts.charlie, vec![TOKEN_A]);
+    }
+
+    #[ink::test]
+    fn on_erc721_received_works() {
+        let accounts = default_accounts();
+        let (mut erc721, underlying) = init();
+
+        // alice safely transfers the underlying token A to the wrapper
+        underlying
+            .borrow_mut()
+            .owners
+            .insert(TOKEN_A, contract_id());
+
+        assert_eq!(
+            call_as(accounts.eve, || {
+                erc721.on_erc721_received(
+                    accounts.alice,
+                    accounts.alice,
+                    TOKEN_A,
+                    Vec::new(),
+                )
+            }),
+            metis_lang::selector_id!(on_erc721_received)
+        );
+
+        assert_eq!(erc721.owner_of(TOKEN_A), accounts.alice);
+        assert_eq!(erc721.balance_of(accounts.alice), 1);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721Wrapper: caller is not the underlying token")]
+    fn on_erc721_received_from_other_token_should_panic() {
+        let accounts = default_accounts();
+        let (mut erc721, _) = init();
+
+        set_caller(accounts.frank);
+        erc721.on_erc721_received(accounts.alice, accounts.alice, TOKEN_A, Vec::new());
+    }
+
+    #[ink::test]
+    fn recover_works() {
+        let accounts = default_accounts();
+        let (mut erc721, underlying) = init();
+
+        // the underlying token C is transferred to the wrapper by mistake
+        underlying
+            .borrow_mut()
+            .owners
+            .insert(TOKEN_C, contract_id());
+
+        assert_eq!(erc721.recover(accounts.alice, TOKEN_C), Ok(()));
+        assert_eq!(erc721.owner_of(TOKEN_C), accounts.alice);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721Wrapper: wrapper is not token owner")]
+    fn recover_not_owned_token_should_panic() {
+        let accounts = default_accounts();
+        let (mut erc721, _) = init();
+
+        let _ = erc721.recover(accounts.alice, TOKEN_A);
+    }
+}
@@ -57,3 +57,18 @@ impl TokenId {
         String::from(::core::str::from_utf8(&output).unwrap())
     }
 }
+
+/// The ERC-721 error types.
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo))]
+pub enum Error {
+    /// Returned if not enough balance to fulfill a request is available.
+    InsufficientBalance,
+    /// Returned if not enough allowance to fulfill a request is available.
+    InsufficientAllowance,
+    /// Returned if account is zero
+    AccountIsZero,
+}
+
+/// The ERC-721 result type.
+pub type Result<T> = core::result::Result<T, Error>;
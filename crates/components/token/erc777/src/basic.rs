@@ -4,6 +4,7 @@ use ink_prelude::{
     vec::Vec,
 };
 pub use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     Storage,
@@ -106,7 +107,7 @@ pub trait EventEmit<E: Env>: EnvAccess<E> {
 }
 
 /// The `Impl` define erc777 component impl funcs, with `_before_token_transfer` as hook
-pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Context<E> {
     /// Initialize the erc777 component
     fn init(
         &mut self,
@@ -115,7 +116,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         decimals: u8,
         initial_supply: E::Balance,
     ) {
-        let caller = &Self::caller();
+        let caller = &self._msg_sender();
 
         self.get_mut().set_total_supply(initial_supply);
         self.get_mut().set_balance(caller, initial_supply);
@@ -187,14 +188,8 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         amount: E::Balance,
         data: Vec<u8>,
     ) -> Result<()> {
-        self._send(
-            Self::caller(),
-            recipient,
-            amount,
-            data,
-            Vec::default(),
-            true,
-        )
+        let caller = self._msg_sender();
+        self._send(caller, recipient, amount, data, Vec::default(), true)
     }
 
     /// Moves `amount` tokens from the caller's account to `recipient`.
@@ -204,7 +199,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     /// Emits a `Transfer` event.
     fn transfer(&mut self, recipient: &E::AccountId, amount: E::Balance) -> Result<()> {
         let null_account = &E::AccountId::default();
-        let from = &Self::caller();
+        let from = &self._msg_sender();
 
         if recipient == null_account {
             return Err(Error::AccountIsZero)
@@ -248,7 +243,8 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// - the caller must have at least `amount` tokens.
     fn burn(&mut self, amount: E::Balance, data: Vec<u8>) -> Result<()> {
-        self._burn(Self::caller(), amount, data, Vec::default())
+        let caller = self._msg_sender();
+        self._burn(caller, amount, data, Vec::default())
     }
 
     /// Returns true if an account is an operator of `token_holder`.
@@ -274,7 +270,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// - `operator` cannot be calling address.
     fn authorize_operator(&mut self, operator: E::AccountId) {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
         assert!(caller != operator, "ERC777: authorizing self as operator");
 
         let key = (caller.clone(), operator.clone());
@@ -298,7 +294,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// - `operator` cannot be calling address.
     fn revoke_operator(&mut self, operator: E::AccountId) {
-        let caller = Self::caller();
+        let caller = self._msg_sender();
         assert!(caller != operator, "ERC777: revoke self as operator");
 
         let key = (caller.clone(), operator.clone());
@@ -347,7 +343,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         operator_data: Vec<u8>,
     ) -> Result<()> {
         assert!(
-            self.get().is_operator_for(&Self::caller(), &sender),
+            self.get().is_operator_for(&self._msg_sender(), &sender),
             "ERC777: caller is not an operator for holder"
         );
 
@@ -375,7 +371,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         operator_data: Vec<u8>,
     ) -> Result<()> {
         assert!(
-            self.get().is_operator_for(&Self::caller(), &account),
+            self.get().is_operator_for(&self._msg_sender(), &account),
             "ERC777: caller is not an operator for holder"
         );
 
@@ -404,7 +400,8 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
     ///
     /// Emits an `Approval` event.
     fn approve(&mut self, spender: &E::AccountId, amount: E::Balance) -> Result<()> {
-        self._approve(&Self::caller(), spender, amount)
+        let caller = self._msg_sender();
+        self._approve(&caller, spender, amount)
     }
 
     /// Moves `amount` tokens from `sender` to `recipient` using the
@@ -420,7 +417,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         recipient: &E::AccountId,
         amount: E::Balance,
     ) -> Result<()> {
-        let caller = &Self::caller();
+        let caller = &self._msg_sender();
         let null_account = &E::AccountId::default();
 
         if recipient == null_account {
@@ -432,7 +429,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
             return Err(Error::InsufficientAllowance)
         }
 
-        let spender = Self::caller();
+        let spender = self._msg_sender();
 
         self._call_tokens_to_send(
             &spender,
@@ -520,7 +517,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
             return Err(Error::AccountIsZero)
         }
 
-        let operator = Self::caller();
+        let operator = self._msg_sender();
 
         self._before_token_transfer(&operator, &None, &Some(&account), &amount)?;
 
@@ -579,7 +576,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
         assert!(from != null_account, "ERC777: send from the zero address");
         assert!(to != null_account, "ERC777: send to the zero address");
 
-        let operator = Self::caller();
+        let operator = self._msg_sender();
 
         self._call_tokens_to_send(
             &operator,
@@ -624,7 +621,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> {
 
         assert!(from != null_account, "ERC777: burn from the zero address");
 
-        let operator = Self::caller();
+        let operator = self._msg_sender();
 
         self._call_tokens_to_send(
             &operator,
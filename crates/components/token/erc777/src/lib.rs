@@ -6,6 +6,7 @@ mod basic;
 mod module;
 
 pub use metis_lang::{
+    Context,
     Env,
     EnvAccess,
     Storage,
@@ -1,40 +1,25 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod mocks {
-    pub mod behavior;
     pub mod erc777_mock;
 }
 
-mod utils {
-    pub mod env;
-    pub mod event;
-}
-
 mod erc20_basic_tests {
     /// Imports all the definitions from the outer scope so we can use them here.
     use super::*;
     use erc777_contract::{
         Erc777,
         Error,
-        Result,
         Transfer,
     };
     use ink::ContractEnv;
     use ink_lang as ink;
     use ink_prelude::string::String;
-    use mocks::{
-        behavior::Erc20BehaviorChecker,
-        erc777_mock::erc777_contract,
-    };
-    use utils::{
-        env::*,
-        event::*,
-    };
+    use metis_test::*;
+    use mocks::erc777_mock::erc777_contract;
 
     type AccountId = <<Erc777 as ContractEnv>::Env as ink_env::Environment>::AccountId;
     type Balance = <<Erc777 as ContractEnv>::Env as ink_env::Environment>::Balance;
-    type Hash = <<Erc777 as ContractEnv>::Env as ink_env::Environment>::Hash;
-    type Event = <Erc777 as ink::BaseEvent>::Type;
 
     fn assert_transfer_event(
         event: &ink_env::test::EmittedEvent,
@@ -42,60 +27,25 @@ mod erc20_basic_tests {
         expected_to: Option<AccountId>,
         expected_value: Balance,
     ) {
-        let decoded_event = <Event as scale::Decode>::decode(&mut &event.data[..])
-            .expect("encountered invalid contract event data buffer");
-        if let Event::Transfer(Transfer { from, to, value }) = decoded_event {
-            assert_eq!(from, expected_from, "encountered invalid Transfer.from");
-            assert_eq!(to, expected_to, "encountered invalid Transfer.to");
-            assert_eq!(value, expected_value, "encountered invalid Trasfer.value");
-        } else {
-            panic!("encountered unexpected event kind: expected a Transfer event")
-        }
-        let expected_topics = vec![
-            encoded_into_hash(&PrefixedValue {
-                value: b"Erc777::Transfer",
-                prefix: b"",
-            }),
-            encoded_into_hash(&PrefixedValue {
-                prefix: b"Erc777::Transfer::from",
-                value: &expected_from,
-            }),
-            encoded_into_hash(&PrefixedValue {
-                prefix: b"Erc777::Transfer::to",
-                value: &expected_to,
-            }),
-            encoded_into_hash(&PrefixedValue {
-                prefix: b"Erc777::Transfer::value",
-                value: &expected_value,
-            }),
-        ];
-        for (n, (actual_topic, expected_topic)) in
-            event.topics.iter().zip(expected_topics).enumerate()
-        {
-            let topic = actual_topic
-                .decode::<Hash>()
-                .expect("encountered invalid topic encoding");
-            assert_eq!(topic, expected_topic, "encountered invalid topic at {}", n);
-        }
+        let Transfer { from, to, value } = decode_event(event)
+            .expect("encountered unexpected event kind: expected a Transfer event");
+        assert_eq!(from, expected_from, "encountered invalid Transfer.from");
+        assert_eq!(to, expected_to, "encountered invalid Transfer.to");
+        assert_eq!(value, expected_value, "encountered invalid Trasfer.value");
+
+        assert_topics(
+            event,
+            &[
+                signature_topic(b"Erc777::Transfer"),
+                field_topic(b"Erc777::Transfer::from", &expected_from),
+                field_topic(b"Erc777::Transfer::to", &expected_to),
+            ],
+        );
     }
 
     #[ink::test]
     fn should_erc20_behavior_work() {
-        let init_amount = 100000000000000000;
-        let default_account = AccountId::from([0x01; 32]);
-        let accounts = ink_env::test::default_accounts::<ink_env::DefaultEnvironment>()
-            .expect("Cannot get accounts");
-
-        Erc20BehaviorChecker::should_erc20_behavior_work(
-            init_amount,
-            default_account,
-            AccountId::from([0x00; 32]),
-            accounts.bob,
-            |erc20: &mut Erc777, from, to, amount| -> Result<()> {
-                next_call_by(from);
-                erc20.transfer(to.clone(), amount)
-            },
-        );
+        erc20_behaves_like::<Erc777>(100000000000000000);
     }
 
     /// The default constructor does its job.
@@ -0,0 +1,98 @@
+//! Contract component which makes the contract a holder of the ERC1155 tokens.
+//!
+//! The ERC1155 contracts only transfer the tokens to a contract if the
+//! contract implements `on_erc1155_received` and `on_erc1155_batch_received`
+//! and returns their selectors, this component implements them, so the
+//! contract can hold the tokens. The contract should expose both of them as
+//! its messages.
+//!
+//! By default the tokens of any contract are accepted. If the allowlist is
+//! enabled by `_set_restricted`, only the token contracts added by
+//! `_set_token_allowed` can transfer tokens to the holder, the receipts from
+//! the other contracts are rejected. Both are internal functions that can be
+//! exposed with access control if desired.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod module;
+
+use ink_prelude::vec::Vec;
+pub use metis_erc721_types::TokenId;
+pub use metis_lang::{
+    Env,
+    EnvAccess,
+    Storage,
+};
+
+pub use module::Data;
+
+/// The `Impl` define erc1155 holder component impl funcs
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EnvAccess<E> {
+    /// Returns if only the tokens of the allowed contracts can be received.
+    fn is_restricted(&self) -> bool {
+        self.get().is_restricted()
+    }
+
+    /// Returns if the tokens of the `token` contract can be received.
+    fn is_token_allowed(&self, token: &E::AccountId) -> bool {
+        !self.is_restricted() || self.get().is_allowed(token)
+    }
+
+    /// Handle the receipt of a single token type from the token contract
+    /// which is the caller, returns the selector of `on_erc1155_received` to
+    /// accept the transfer.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be allowed if the allowlist is enabled.
+    fn on_erc1155_received(
+        &mut self,
+        _operator: E::AccountId,
+        _from: Option<E::AccountId>,
+        _id: TokenId,
+        _value: E::Balance,
+        _data: Vec<u8>,
+    ) -> [u8; 4] {
+        assert!(
+            self.is_token_allowed(&Self::caller()),
+            "ERC1155Holder: token not allowed"
+        );
+
+        metis_lang::selector_id!(on_erc1155_received)
+    }
+
+    /// Handle the receipt of multiple token types from the token contract
+    /// which is the caller, returns the selector of
+    /// `on_erc1155_batch_received` to accept the transfer.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be allowed if the allowlist is enabled.
+    fn on_erc1155_batch_received(
+        &mut self,
+        _operator: E::AccountId,
+        _from: Option<E::AccountId>,
+        _ids: Vec<TokenId>,
+        _values: Vec<E::Balance>,
+        _data: Vec<u8>,
+    ) -> [u8; 4] {
+        assert!(
+            self.is_token_allowed(&Self::caller()),
+            "ERC1155Holder: token not allowed"
+        );
+
+        metis_lang::selector_id!(on_erc1155_batch_received)
+    }
+
+    /// Enables or disables the allowlist of the token contracts.
+    fn _set_restricted(&mut self, restricted: bool) {
+        self.get_mut().set_restricted(restricted);
+    }
+
+    /// Adds the `token` contract to the allowlist, or removes it.
+    fn _set_token_allowed(&mut self, token: E::AccountId, allowed: bool) {
+        self.get_mut().set_allowed(token, allowed);
+    }
+}
+
+impl<E: Env, T: Storage<E, Data<E>> + EnvAccess<E>> Impl<E> for T {}
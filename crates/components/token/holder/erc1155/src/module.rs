@@ -0,0 +1,62 @@
+pub use metis_lang::Env;
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    collections::HashMap as StorageHashMap,
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The Data of ERC1155 holder component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E: Env> {
+    /// If only the tokens of the allowed contracts can be received.
+    restricted: Lazy<bool>,
+    /// The token contracts allowed to transfer tokens to the holder.
+    allowed: StorageHashMap<E::AccountId, bool>,
+}
+
+impl<E: Env> Data<E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            restricted: Lazy::new(false),
+            allowed: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<E: Env> Data<E> {
+    /// Returns if only the tokens of the allowed contracts can be received.
+    pub fn is_restricted(&self) -> bool {
+        *self.restricted
+    }
+
+    /// Sets if only the tokens of the allowed contracts can be received.
+    pub fn set_restricted(&mut self, restricted: bool) {
+        Lazy::set(&mut self.restricted, restricted);
+    }
+
+    /// Returns if the `token` contract is in the allowlist.
+    pub fn is_allowed(&self, token: &E::AccountId) -> bool {
+        self.allowed.get(token).copied().unwrap_or(false)
+    }
+
+    /// Adds the `token` contract to the allowlist, or removes it.
+    pub fn set_allowed(&mut self, token: E::AccountId, allowed: bool) {
+        if allowed {
+            self.allowed.insert(token, true);
+        } else {
+            self.allowed.take(&token);
+        }
+    }
+}
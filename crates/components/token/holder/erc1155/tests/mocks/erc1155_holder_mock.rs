@@ -0,0 +1,75 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod erc1155_holder {
+    use ink_prelude::vec::Vec;
+    use metis_erc1155_holder as erc1155_holder;
+    pub use metis_erc1155_holder::TokenId;
+    use metis_lang::import;
+
+    /// A contract which holds the ERC1155 tokens.
+    #[ink(storage)]
+    #[import(erc1155_holder)]
+    pub struct Holder {
+        erc1155_holder: erc1155_holder::Data<Holder>,
+    }
+
+    impl Holder {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                erc1155_holder: erc1155_holder::Data::new(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn is_restricted(&self) -> bool {
+            erc1155_holder::Impl::is_restricted(self)
+        }
+
+        #[ink(message)]
+        pub fn is_token_allowed(&self, token: AccountId) -> bool {
+            erc1155_holder::Impl::is_token_allowed(self, &token)
+        }
+
+        #[ink(message)]
+        pub fn on_erc1155_received(
+            &mut self,
+            operator: AccountId,
+            from: Option<AccountId>,
+            id: TokenId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> [u8; 4] {
+            erc1155_holder::Impl::on_erc1155_received(
+                self, operator, from, id, value, data,
+            )
+        }
+
+        #[ink(message)]
+        pub fn on_erc1155_batch_received(
+            &mut self,
+            operator: AccountId,
+            from: Option<AccountId>,
+            ids: Vec<TokenId>,
+            values: Vec<Balance>,
+            data: Vec<u8>,
+        ) -> [u8; 4] {
+            erc1155_holder::Impl::on_erc1155_batch_received(
+                self, operator, from, ids, values, data,
+            )
+        }
+
+        /// For test to enable the allowlist
+        #[ink(message)]
+        pub fn set_restricted(&mut self, restricted: bool) {
+            erc1155_holder::Impl::_set_restricted(self, restricted)
+        }
+
+        /// For test to change the allowlist
+        #[ink(message)]
+        pub fn set_token_allowed(&mut self, token: AccountId, allowed: bool) {
+            erc1155_holder::Impl::_set_token_allowed(self, token, allowed)
+        }
+    }
+}
@@ -0,0 +1,144 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc1155_holder_mock;
+}
+
+mod erc1155_holder_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::{
+        vec,
+        vec::Vec,
+    };
+    use metis_erc1155_receiver::ERC1155ReceiverStub;
+    use metis_lang::mock::check_messages;
+    use metis_test::*;
+    use mocks::erc1155_holder_mock::erc1155_holder::{
+        Holder,
+        TokenId,
+    };
+
+    const TOKEN_A: TokenId = TokenId::new([0x0a; 32]);
+    const TOKEN_B: TokenId = TokenId::new([0x0b; 32]);
+
+    /// Receive 10 tokens of A from the token contract `token`.
+    fn receive(holder: &mut Holder, token: AccountId) -> [u8; 4] {
+        let accounts = default_accounts();
+
+        call_as(token, || {
+            holder.on_erc1155_received(
+                accounts.alice,
+                Some(accounts.alice),
+                TOKEN_A,
+                10,
+                Vec::new(),
+            )
+        })
+    }
+
+    /// Receive 10 tokens of A and 20 tokens of B from the token contract `token`.
+    fn receive_batch(holder: &mut Holder, token: AccountId) -> [u8; 4] {
+        let accounts = default_accounts();
+
+        call_as(token, || {
+            holder.on_erc1155_batch_received(
+                accounts.alice,
+                Some(accounts.alice),
+                vec![TOKEN_A, TOKEN_B],
+                vec![10, 20],
+                Vec::new(),
+            )
+        })
+    }
+
+    extern "Rust" {
+        fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+    }
+
+    #[test]
+    fn stub_messages_should_match_contract() {
+        let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+            .expect("metadata should be serialized");
+
+        assert_eq!(
+            check_messages(&metadata, ERC1155ReceiverStub::MESSAGES),
+            Ok(())
+        );
+    }
+
+    #[ink::test]
+    fn on_erc1155_received_should_return_selector() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        assert!(!holder.is_restricted());
+        assert_eq!(
+            receive(&mut holder, accounts.eve),
+            metis_lang::selector_id!(on_erc1155_received)
+        );
+        assert_eq!(
+            receive_batch(&mut holder, accounts.eve),
+            metis_lang::selector_id!(on_erc1155_batch_received)
+        );
+    }
+
+    #[ink::test]
+    fn allowed_token_should_be_received() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        holder.set_restricted(true);
+        holder.set_token_allowed(accounts.eve, true);
+
+        assert!(holder.is_token_allowed(accounts.eve));
+        assert!(!holder.is_token_allowed(accounts.frank));
+        assert_eq!(
+            receive(&mut holder, accounts.eve),
+            metis_lang::selector_id!(on_erc1155_received)
+        );
+        assert_eq!(
+            receive_batch(&mut holder, accounts.eve),
+            metis_lang::selector_id!(on_erc1155_batch_received)
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC1155Holder: token not allowed")]
+    fn not_allowed_token_should_panic() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        holder.set_restricted(true);
+        holder.set_token_allowed(accounts.eve, true);
+
+        receive(&mut holder, accounts.frank);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC1155Holder: token not allowed")]
+    fn not_allowed_token_batch_should_panic() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        holder.set_restricted(true);
+
+        receive_batch(&mut holder, accounts.eve);
+    }
+
+    #[ink::test]
+    fn disable_allowlist_should_accept_any_token() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        holder.set_restricted(true);
+        holder.set_restricted(false);
+
+        assert!(holder.is_token_allowed(accounts.frank));
+        assert_eq!(
+            receive(&mut holder, accounts.frank),
+            metis_lang::selector_id!(on_erc1155_received)
+        );
+    }
+}
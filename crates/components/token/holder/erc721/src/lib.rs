@@ -0,0 +1,74 @@
+//! Contract component which makes the contract a holder of the ERC721 tokens.
+//!
+//! The ERC721 contracts only transfer the tokens to a contract by the safe
+//! transfers if the contract implements `on_erc721_received` and returns its
+//! selector, this component implements it, so the contract can hold the
+//! tokens. The contract should expose the `on_erc721_received` of this
+//! component as its message.
+//!
+//! By default the tokens of any contract are accepted. If the allowlist is
+//! enabled by `_set_restricted`, only the token contracts added by
+//! `_set_token_allowed` can transfer tokens to the holder, the receipts from
+//! the other contracts are rejected. Both are internal functions that can be
+//! exposed with access control if desired.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod module;
+
+use ink_prelude::vec::Vec;
+pub use metis_erc721_types::TokenId;
+pub use metis_lang::{
+    Env,
+    EnvAccess,
+    Storage,
+};
+
+pub use module::Data;
+
+/// The `Impl` define erc721 holder component impl funcs
+pub trait Impl<E: Env>: Storage<E, Data<E>> + EnvAccess<E> {
+    /// Returns if only the tokens of the allowed contracts can be received.
+    fn is_restricted(&self) -> bool {
+        self.get().is_restricted()
+    }
+
+    /// Returns if the tokens of the `token` contract can be received.
+    fn is_token_allowed(&self, token: &E::AccountId) -> bool {
+        !self.is_restricted() || self.get().is_allowed(token)
+    }
+
+    /// Handle the receipt of the `token_id` token from the token contract
+    /// which is the caller, returns the selector of `on_erc721_received` to
+    /// accept the transfer.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be allowed if the allowlist is enabled.
+    fn on_erc721_received(
+        &mut self,
+        _operator: E::AccountId,
+        _from: E::AccountId,
+        _token_id: TokenId,
+        _data: Vec<u8>,
+    ) -> [u8; 4] {
+        assert!(
+            self.is_token_allowed(&Self::caller()),
+            "ERC721Holder: token not allowed"
+        );
+
+        metis_lang::selector_id!(on_erc721_received)
+    }
+
+    /// Enables or disables the allowlist of the token contracts.
+    fn _set_restricted(&mut self, restricted: bool) {
+        self.get_mut().set_restricted(restricted);
+    }
+
+    /// Adds the `token` contract to the allowlist, or removes it.
+    fn _set_token_allowed(&mut self, token: E::AccountId, allowed: bool) {
+        self.get_mut().set_allowed(token, allowed);
+    }
+}
+
+impl<E: Env, T: Storage<E, Data<E>> + EnvAccess<E>> Impl<E> for T {}
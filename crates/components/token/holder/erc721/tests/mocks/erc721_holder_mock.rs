@@ -0,0 +1,58 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod erc721_holder {
+    use ink_prelude::vec::Vec;
+    use metis_erc721_holder as erc721_holder;
+    pub use metis_erc721_holder::TokenId;
+    use metis_lang::import;
+
+    /// A contract which holds the ERC721 tokens.
+    #[ink(storage)]
+    #[import(erc721_holder)]
+    pub struct Holder {
+        erc721_holder: erc721_holder::Data<Holder>,
+    }
+
+    impl Holder {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                erc721_holder: erc721_holder::Data::new(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn is_restricted(&self) -> bool {
+            erc721_holder::Impl::is_restricted(self)
+        }
+
+        #[ink(message)]
+        pub fn is_token_allowed(&self, token: AccountId) -> bool {
+            erc721_holder::Impl::is_token_allowed(self, &token)
+        }
+
+        #[ink(message)]
+        pub fn on_erc721_received(
+            &mut self,
+            operator: AccountId,
+            from: AccountId,
+            token_id: TokenId,
+            data: Vec<u8>,
+        ) -> [u8; 4] {
+            erc721_holder::Impl::on_erc721_received(self, operator, from, token_id, data)
+        }
+
+        /// For test to enable the allowlist
+        #[ink(message)]
+        pub fn set_restricted(&mut self, restricted: bool) {
+            erc721_holder::Impl::_set_restricted(self, restricted)
+        }
+
+        /// For test to change the allowlist
+        #[ink(message)]
+        pub fn set_token_allowed(&mut self, token: AccountId, allowed: bool) {
+            erc721_holder::Impl::_set_token_allowed(self, token, allowed)
+        }
+    }
+}
@@ -0,0 +1,115 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod erc721_holder_mock;
+}
+
+mod erc721_holder_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::vec::Vec;
+    use metis_erc721_receiver::ERC721ReceiverStub;
+    use metis_lang::mock::check_messages;
+    use metis_test::*;
+    use mocks::erc721_holder_mock::erc721_holder::{
+        Holder,
+        TokenId,
+    };
+
+    const TOKEN_A: TokenId = TokenId::new([0x0a; 32]);
+
+    /// Receive the token A from the token contract `token`.
+    fn receive(holder: &mut Holder, token: AccountId) -> [u8; 4] {
+        let accounts = default_accounts();
+
+        call_as(token, || {
+            holder.on_erc721_received(accounts.alice, accounts.alice, TOKEN_A, Vec::new())
+        })
+    }
+
+    extern "Rust" {
+        fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+    }
+
+    #[test]
+    fn stub_messages_should_match_contract() {
+        let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+            .expect("metadata should be serialized");
+
+        assert_eq!(
+            check_messages(&metadata, ERC721ReceiverStub::MESSAGES),
+            Ok(())
+        );
+    }
+
+    #[ink::test]
+    fn on_erc721_received_should_return_selector() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        assert!(!holder.is_restricted());
+        assert!(holder.is_token_allowed(accounts.eve));
+        assert_eq!(
+            receive(&mut holder, accounts.eve),
+            metis_lang::selector_id!(on_erc721_received)
+        );
+    }
+
+    #[ink::test]
+    fn allowed_token_should_be_received() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        holder.set_restricted(true);
+        holder.set_token_allowed(accounts.eve, true);
+
+        assert!(holder.is_restricted());
+        assert!(holder.is_token_allowed(accounts.eve));
+        assert!(!holder.is_token_allowed(accounts.frank));
+        assert_eq!(
+            receive(&mut holder, accounts.eve),
+            metis_lang::selector_id!(on_erc721_received)
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721Holder: token not allowed")]
+    fn not_allowed_token_should_panic() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        holder.set_restricted(true);
+        holder.set_token_allowed(accounts.eve, true);
+
+        receive(&mut holder, accounts.frank);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC721Holder: token not allowed")]
+    fn removed_token_should_panic() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        holder.set_restricted(true);
+        holder.set_token_allowed(accounts.eve, true);
+        holder.set_token_allowed(accounts.eve, false);
+
+        receive(&mut holder, accounts.eve);
+    }
+
+    #[ink::test]
+    fn disable_allowlist_should_accept_any_token() {
+        let accounts = default_accounts();
+        let mut holder = Holder::new();
+
+        holder.set_restricted(true);
+        holder.set_restricted(false);
+
+        assert!(holder.is_token_allowed(accounts.frank));
+        assert_eq!(
+            receive(&mut holder, accounts.frank),
+            metis_lang::selector_id!(on_erc721_received)
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! Conditional Escrow
+//! Base abstract escrow to only allow withdrawal if a condition is met.
+//!
+//! The contract which use this component should impl the `withdrawal_allowed`
+//! to define the condition, the deposits and the events are all the same as
+//! the base `Escrow` component.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use metis_escrow as escrow;
+pub use metis_escrow::{
+    Data,
+    EventEmit,
+};
+use metis_lang::Env;
+
+/// The `Impl` define conditional escrow component impl funcs
+pub trait Impl<E: Env>: escrow::Impl<E> {
+    /// @dev Returns whether an address is allowed to withdraw their funds. To be
+    /// implemented by derived contracts.
+    /// @param payee The destination address of the funds.
+    fn withdrawal_allowed(&self, payee: &E::AccountId) -> bool;
+
+    /// @dev Withdraw accumulated balance for a payee, only if the
+    /// `withdrawal_allowed` for the payee.
+    ///
+    /// @param payee The address whose funds will be withdrawn and transferred to.
+    fn withdraw(&mut self, payee: E::AccountId) {
+        assert!(
+            self.withdrawal_allowed(&payee),
+            "ConditionalEscrow: payee is not allowed to withdraw"
+        );
+
+        escrow::Impl::withdraw(self, payee)
+    }
+}
@@ -0,0 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// The messages of the ConditionalEscrow component
+#[metis_lang::stub_contract(ConditionalEscrowStub)]
+pub trait ConditionalEscrow {
+    fn new() -> Self;
+
+    /// Return the deposits of payee
+    fn deposits_of(&self, payee: AccountId) -> Balance;
+
+    /// Returns whether an address is allowed to withdraw their funds.
+    fn withdrawal_allowed(&self, payee: AccountId) -> bool;
+
+    /// Stores the sent amount as credit to be withdrawn.
+    #[ink(payable)]
+    fn deposit(&mut self, payee: AccountId);
+
+    /// Withdraw accumulated balance for a payee, only if allowed.
+    fn withdraw(&mut self, payee: AccountId);
+}
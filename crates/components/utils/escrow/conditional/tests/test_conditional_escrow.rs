@@ -0,0 +1,219 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod mock {
+    use metis_escrow as escrow;
+    use metis_escrow_conditional as conditional_escrow;
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_ownable as ownable;
+
+    #[cfg(not(feature = "ink-as-dependency"))]
+    use ink_storage::collections::HashMap as StorageHashMap;
+
+    #[ink(storage)]
+    #[import(ownable, escrow)]
+    pub struct ConditionalEscrow {
+        ownable: ownable::Data<ConditionalEscrow>,
+        escrow: escrow::Data<ConditionalEscrow>,
+
+        allowed: StorageHashMap<AccountId, bool>,
+    }
+
+    impl conditional_escrow::Impl<ConditionalEscrow> for ConditionalEscrow {
+        fn withdrawal_allowed(&self, payee: &AccountId) -> bool {
+            self.allowed.get(payee).copied().unwrap_or(false)
+        }
+    }
+
+    /// Event emitted when payee deposit amount
+    #[ink(event)]
+    #[metis(escrow)]
+    pub struct Deposited {
+        #[ink(topic)]
+        pub payee: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Event emitted when payee withdraw
+    #[ink(event)]
+    #[metis(escrow)]
+    pub struct Withdrawn {
+        #[ink(topic)]
+        pub payee: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Event emitted when Owner AccountId Transferred
+    #[ink(event)]
+    #[metis(ownable)]
+    pub struct OwnershipTransferred {
+        /// previous owner account id
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
+        /// new owner account id
+        #[ink(topic)]
+        new_owner: Option<AccountId>,
+    }
+
+    // impl
+    impl ConditionalEscrow {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self {
+                ownable: ownable::Data::new(),
+                escrow: escrow::Data::new(),
+                allowed: StorageHashMap::default(),
+            };
+
+            ownable::Impl::init(&mut instance);
+
+            instance
+        }
+
+        #[ink(message)]
+        pub fn set_withdrawal_allowed(&mut self, payee: AccountId, allowed: bool) {
+            self.allowed.insert(payee, allowed);
+        }
+
+        #[ink(message)]
+        pub fn withdrawal_allowed(&self, payee: AccountId) -> bool {
+            conditional_escrow::Impl::withdrawal_allowed(self, &payee)
+        }
+
+        #[ink(message)]
+        pub fn deposits_of(&self, payee: AccountId) -> Balance {
+            escrow::Impl::deposits_of(self, &payee)
+        }
+
+        #[ink(message, payable)]
+        pub fn deposit(&mut self, payee: AccountId) {
+            escrow::Impl::deposit(self, payee)
+        }
+
+        #[ink(message)]
+        pub fn withdraw(&mut self, payee: AccountId) {
+            conditional_escrow::Impl::withdraw(self, payee)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_env::{
+            call,
+            test,
+        };
+        use ink_lang as ink;
+        use metis_escrow_conditional_stub::ConditionalEscrowStub;
+        use metis_lang::mock::check_messages;
+
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+        }
+
+        #[test]
+        fn stub_messages_should_match_contract() {
+            let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+                .expect("metadata should be serialized");
+
+            assert_eq!(
+                check_messages(&metadata, ConditionalEscrowStub::MESSAGES),
+                Ok(())
+            );
+        }
+
+        #[ink::test]
+        fn withdraw_allowed_ok() {
+            let accounts = default_accounts();
+
+            let mut escrow = create_contract(0);
+
+            set_sender(accounts.alice, 123456);
+            set_balance(accounts.alice, 0);
+            escrow.deposit(accounts.bob);
+
+            set_sender(accounts.alice, 0);
+            set_balance(accounts.bob, 0);
+            set_balance(contract_id(), 123456);
+
+            escrow.set_withdrawal_allowed(accounts.bob, true);
+            assert_eq!(
+                escrow.withdrawal_allowed(accounts.bob),
+                true,
+                "bob should be allowed"
+            );
+
+            escrow.withdraw(accounts.bob);
+
+            assert_eq!(
+                escrow.deposits_of(accounts.bob),
+                0,
+                "deposits now should ok"
+            );
+            assert_eq!(
+                get_balance(accounts.bob),
+                123456,
+                "after balance should add"
+            );
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "ConditionalEscrow: payee is not allowed to withdraw")]
+        fn withdraw_not_allowed_should_panic() {
+            let accounts = default_accounts();
+
+            let mut escrow = create_contract(0);
+
+            set_sender(accounts.alice, 123456);
+            set_balance(accounts.alice, 0);
+            escrow.deposit(accounts.bob);
+
+            set_sender(accounts.alice, 0);
+            set_balance(contract_id(), 123456);
+
+            escrow.withdraw(accounts.bob);
+        }
+
+        fn create_contract(initial_balance: Balance) -> ConditionalEscrow {
+            let accounts = default_accounts();
+            set_sender(accounts.alice, 0);
+            set_balance(contract_id(), initial_balance);
+            ConditionalEscrow::new()
+        }
+
+        fn contract_id() -> AccountId {
+            test::get_current_contract_account_id::<ink_env::DefaultEnvironment>()
+                .expect("Cannot get contract id")
+        }
+
+        fn set_sender(sender: AccountId, endowment: Balance) {
+            let callee = ink_env::account_id::<ink_env::DefaultEnvironment>()
+                .unwrap_or([0x0; 32].into());
+            test::push_execution_context::<Environment>(
+                sender,
+                callee,
+                1000000,
+                endowment,
+                test::CallData::new(call::Selector::new([0x00; 4])), // dummy
+            );
+        }
+
+        fn default_accounts() -> test::DefaultAccounts<ink_env::DefaultEnvironment> {
+            test::default_accounts::<ink_env::DefaultEnvironment>()
+                .expect("Off-chain environment should have been initialized already")
+        }
+
+        fn set_balance(account_id: AccountId, balance: Balance) {
+            test::set_account_balance::<ink_env::DefaultEnvironment>(account_id, balance)
+                .expect("Cannot set account balance");
+        }
+
+        fn get_balance(account_id: AccountId) -> Balance {
+            test::get_account_balance::<ink_env::DefaultEnvironment>(account_id)
+                .expect("Cannot set account balance")
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Refund Escrow
+//! Escrow that holds funds for a beneficiary, deposited from multiple
+//! parties.
+//!
+//! Intended usage: See `Escrow`. Same usage guidelines apply here.
+//! The owner account (that is, the contract that instantiates this
+//! contract) may deposit, close the deposit period, and allow for either
+//! withdrawal by the beneficiary, or refunds to the depositors. All interactions
+//! with `RefundEscrow` will be made through the owner contract.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod module;
+mod types;
+
+use metis_escrow as escrow;
+use metis_lang::{
+    Env,
+    EnvAccess,
+    Storage,
+};
+use metis_ownable::Impl as Ownable;
+use num_traits::CheckedAdd;
+
+pub use module::Data;
+pub use types::RefundEscrowState;
+
+/// The `EventEmit` impl the event emit api for refund escrow component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emit RefundsClosed event
+    fn emit_event_refunds_closed(&mut self);
+
+    /// Emit RefundsEnabled event
+    fn emit_event_refunds_enabled(&mut self);
+}
+
+/// The `Impl` define refund escrow component impl funcs
+pub trait Impl<E: Env>:
+    Storage<E, Data<E>> + EventEmit<E> + escrow::Impl<E> + Ownable<E>
+{
+    /// @dev Initializes the refund escrow with the `beneficiary`.
+    /// @param beneficiary The beneficiary of the deposits.
+    fn init(&mut self, beneficiary: E::AccountId) {
+        assert!(
+            beneficiary != E::AccountId::default(),
+            "RefundEscrow: beneficiary is the zero address"
+        );
+
+        Storage::<E, Data<E>>::get_mut(self).set_beneficiary(beneficiary);
+        Storage::<E, Data<E>>::get_mut(self).set_state(RefundEscrowState::Active);
+    }
+
+    /// @return The current state of the escrow.
+    fn state(&self) -> RefundEscrowState {
+        Storage::<E, Data<E>>::get(self).state()
+    }
+
+    /// @return The beneficiary of the escrow.
+    fn beneficiary(&self) -> E::AccountId {
+        Storage::<E, Data<E>>::get(self).beneficiary().clone()
+    }
+
+    /// @dev Stores funds that may later be refunded.
+    /// @param refundee The address funds will be sent to if a refund occurs.
+    fn deposit(&mut self, refundee: E::AccountId) {
+        assert!(
+            self.state() == RefundEscrowState::Active,
+            "RefundEscrow: can only deposit while active"
+        );
+
+        let total = Storage::<E, Data<E>>::get(self)
+            .total_deposited()
+            .checked_add(&Self::transferred_balance())
+            .expect("RefundEscrow: total deposited overflow");
+        escrow::Impl::deposit(self, refundee);
+
+        Storage::<E, Data<E>>::get_mut(self).set_total_deposited(total);
+    }
+
+    /// @dev Allows for the beneficiary to withdraw their funds, rejecting
+    /// further deposits.
+    fn close(&mut self) {
+        self.ensure_caller_is_owner();
+
+        assert!(
+            self.state() == RefundEscrowState::Active,
+            "RefundEscrow: can only close while active"
+        );
+
+        Storage::<E, Data<E>>::get_mut(self).set_state(RefundEscrowState::Closed);
+
+        self.emit_event_refunds_closed();
+    }
+
+    /// @dev Allows for refunds to take place, rejecting further deposits.
+    fn enable_refunds(&mut self) {
+        self.ensure_caller_is_owner();
+
+        assert!(
+            self.state() == RefundEscrowState::Active,
+            "RefundEscrow: can only enable refunds while active"
+        );
+
+        Storage::<E, Data<E>>::get_mut(self).set_state(RefundEscrowState::Refunding);
+
+        self.emit_event_refunds_enabled();
+    }
+
+    /// @dev Withdraws the beneficiary's funds, only the deposited funds are
+    /// transferred, the endowment of the contract is kept.
+    fn beneficiary_withdraw(&mut self) {
+        assert!(
+            self.state() == RefundEscrowState::Closed,
+            "RefundEscrow: beneficiary can only withdraw while closed"
+        );
+
+        let amount = Storage::<E, Data<E>>::get(self).total_deposited();
+        Storage::<E, Data<E>>::get_mut(self).set_total_deposited(E::Balance::from(0_u8));
+
+        let res = Self::transfer(self.beneficiary(), amount);
+        assert!(res.is_ok(), "RefundEscrow: transfer to beneficiary error");
+    }
+
+    /// @dev Returns whether refundees can withdraw their deposits (be refunded).
+    /// The overridden function receives a 'payee' argument, but we ignore it
+    /// here since the condition is global, not per-payee.
+    fn withdrawal_allowed(&self, _payee: &E::AccountId) -> bool {
+        self.state() == RefundEscrowState::Refunding
+    }
+
+    /// @dev Refunds the deposits of the `payee`, only allowed while refunding.
+    /// @param payee The address whose funds will be refunded.
+    fn withdraw(&mut self, payee: E::AccountId) {
+        assert!(
+            self.withdrawal_allowed(&payee),
+            "RefundEscrow: payee is not allowed to withdraw"
+        );
+
+        escrow::Impl::withdraw(self, payee)
+    }
+}
+
+impl<E, T> Impl<E> for T
+where
+    E: Env,
+    T: Storage<E, Data<E>> + EventEmit<E> + escrow::Impl<E> + Ownable<E>,
+{
+}
@@ -0,0 +1,83 @@
+pub use metis_lang::Env;
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+use crate::RefundEscrowState;
+
+/// The Data of refund escrow component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E>
+where
+    E: Env,
+{
+    /// The state of the refund escrow
+    pub state: Lazy<RefundEscrowState>,
+
+    /// The beneficiary which can withdraw all funds after closed
+    pub beneficiary: Lazy<E::AccountId>,
+
+    /// The total funds deposited and not yet withdrawn by the beneficiary
+    pub total_deposited: Lazy<E::Balance>,
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            state: Lazy::new(RefundEscrowState::Active),
+            beneficiary: Lazy::new(E::AccountId::default()),
+            total_deposited: Lazy::new(E::Balance::from(0_u8)),
+        }
+    }
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    /// Get the state of the refund escrow
+    pub fn state(&self) -> RefundEscrowState {
+        *self.state
+    }
+
+    /// Set the state of the refund escrow
+    pub fn set_state(&mut self, state: RefundEscrowState) {
+        Lazy::set(&mut self.state, state);
+    }
+
+    /// Get the beneficiary of the refund escrow
+    pub fn beneficiary(&self) -> &E::AccountId {
+        &self.beneficiary
+    }
+
+    /// Set the beneficiary of the refund escrow
+    pub fn set_beneficiary(&mut self, beneficiary: E::AccountId) {
+        Lazy::set(&mut self.beneficiary, beneficiary);
+    }
+
+    /// Get the total funds deposited
+    pub fn total_deposited(&self) -> E::Balance {
+        *self.total_deposited
+    }
+
+    /// Set the total funds deposited
+    pub fn set_total_deposited(&mut self, amount: E::Balance) {
+        Lazy::set(&mut self.total_deposited, amount);
+    }
+}
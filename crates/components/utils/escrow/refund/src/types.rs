@@ -0,0 +1,34 @@
+use scale::{
+    Decode,
+    Encode,
+};
+
+use ink_storage::traits::{
+    PackedLayout,
+    SpreadLayout,
+};
+
+#[cfg(feature = "std")]
+use ink_storage::traits::StorageLayout;
+
+#[cfg(feature = "std")]
+use scale_info::TypeInfo;
+
+/// The state of the refund escrow
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo, StorageLayout))]
+#[derive(SpreadLayout, PackedLayout)]
+pub enum RefundEscrowState {
+    /// Deposits are allowed, withdraws are not.
+    Active,
+    /// Refundees can withdraw their deposits, no more deposits.
+    Refunding,
+    /// The beneficiary can withdraw all funds, no more deposits.
+    Closed,
+}
+
+impl Default for RefundEscrowState {
+    fn default() -> Self {
+        Self::Active
+    }
+}
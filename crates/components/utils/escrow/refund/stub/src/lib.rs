@@ -0,0 +1,37 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use metis_escrow_refund::RefundEscrowState;
+
+/// The messages of the RefundEscrow component
+#[metis_lang::stub_contract(RefundEscrowStub)]
+pub trait RefundEscrow {
+    fn new(beneficiary: AccountId) -> Self;
+
+    /// The current state of the escrow.
+    fn state(&self) -> RefundEscrowState;
+
+    /// The beneficiary of the escrow.
+    fn beneficiary(&self) -> AccountId;
+
+    /// Return the deposits of payee
+    fn deposits_of(&self, payee: AccountId) -> Balance;
+
+    /// Returns whether refundees can withdraw their deposits.
+    fn withdrawal_allowed(&self, payee: AccountId) -> bool;
+
+    /// Stores funds that may later be refunded.
+    #[ink(payable)]
+    fn deposit(&mut self, refundee: AccountId);
+
+    /// Allows for the beneficiary to withdraw their funds.
+    fn close(&mut self);
+
+    /// Allows for refunds to take place.
+    fn enable_refunds(&mut self);
+
+    /// Withdraws the beneficiary's funds.
+    fn beneficiary_withdraw(&mut self);
+
+    /// Refunds the deposits of the payee.
+    fn withdraw(&mut self, payee: AccountId);
+}
@@ -0,0 +1,340 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod mock {
+    pub use metis_escrow_refund::RefundEscrowState;
+
+    use metis_escrow as escrow;
+    use metis_escrow_refund as refund_escrow;
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_ownable as ownable;
+
+    #[ink(storage)]
+    #[import(ownable, escrow, refund_escrow)]
+    pub struct RefundEscrow {
+        ownable: ownable::Data<RefundEscrow>,
+        escrow: escrow::Data<RefundEscrow>,
+        refund_escrow: refund_escrow::Data<RefundEscrow>,
+    }
+
+    /// Event emitted when payee deposit amount
+    #[ink(event)]
+    #[metis(escrow)]
+    pub struct Deposited {
+        #[ink(topic)]
+        pub payee: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Event emitted when payee withdraw
+    #[ink(event)]
+    #[metis(escrow)]
+    pub struct Withdrawn {
+        #[ink(topic)]
+        pub payee: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Event emitted when the escrow closed
+    #[ink(event)]
+    #[metis(refund_escrow)]
+    pub struct RefundsClosed {}
+
+    /// Event emitted when the escrow enable refunds
+    #[ink(event)]
+    #[metis(refund_escrow)]
+    pub struct RefundsEnabled {}
+
+    /// Event emitted when Owner AccountId Transferred
+    #[ink(event)]
+    #[metis(ownable)]
+    pub struct OwnershipTransferred {
+        /// previous owner account id
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
+        /// new owner account id
+        #[ink(topic)]
+        new_owner: Option<AccountId>,
+    }
+
+    // impl
+    impl RefundEscrow {
+        #[ink(constructor)]
+        pub fn new(beneficiary: AccountId) -> Self {
+            let mut instance = Self {
+                ownable: ownable::Data::new(),
+                escrow: escrow::Data::new(),
+                refund_escrow: refund_escrow::Data::new(),
+            };
+
+            ownable::Impl::init(&mut instance);
+            refund_escrow::Impl::init(&mut instance, beneficiary);
+
+            instance
+        }
+
+        #[ink(message)]
+        pub fn state(&self) -> RefundEscrowState {
+            refund_escrow::Impl::state(self)
+        }
+
+        #[ink(message)]
+        pub fn beneficiary(&self) -> AccountId {
+            refund_escrow::Impl::beneficiary(self)
+        }
+
+        #[ink(message)]
+        pub fn deposits_of(&self, payee: AccountId) -> Balance {
+            escrow::Impl::deposits_of(self, &payee)
+        }
+
+        #[ink(message)]
+        pub fn withdrawal_allowed(&self, payee: AccountId) -> bool {
+            refund_escrow::Impl::withdrawal_allowed(self, &payee)
+        }
+
+        #[ink(message, payable)]
+        pub fn deposit(&mut self, refundee: AccountId) {
+            refund_escrow::Impl::deposit(self, refundee)
+        }
+
+        #[ink(message)]
+        pub fn close(&mut self) {
+            refund_escrow::Impl::close(self)
+        }
+
+        #[ink(message)]
+        pub fn enable_refunds(&mut self) {
+            refund_escrow::Impl::enable_refunds(self)
+        }
+
+        #[ink(message)]
+        pub fn beneficiary_withdraw(&mut self) {
+            refund_escrow::Impl::beneficiary_withdraw(self)
+        }
+
+        #[ink(message)]
+        pub fn withdraw(&mut self, payee: AccountId) {
+            refund_escrow::Impl::withdraw(self, payee)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+        use metis_escrow_refund_stub::RefundEscrowStub;
+        use metis_lang::mock::check_messages;
+        use metis_test::{
+            contract_id,
+            default_accounts,
+            get_balance,
+            set_balance,
+            set_caller,
+            set_caller_with_value,
+        };
+
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+        }
+
+        #[test]
+        fn stub_messages_should_match_contract() {
+            let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+                .expect("metadata should be serialized");
+
+            assert_eq!(
+                check_messages(&metadata, RefundEscrowStub::MESSAGES),
+                Ok(())
+            );
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+
+            let escrow = create_contract(0);
+
+            assert_eq!(
+                escrow.state(),
+                RefundEscrowState::Active,
+                "state init should be active"
+            );
+            assert_eq!(
+                escrow.beneficiary(),
+                accounts.eve,
+                "beneficiary init should ok"
+            );
+            assert_eq!(
+                escrow.withdrawal_allowed(accounts.bob),
+                false,
+                "withdrawal should not be allowed while active"
+            );
+        }
+
+        #[ink::test]
+        fn refunds_ok() {
+            let accounts = default_accounts();
+
+            let mut escrow = create_contract(0);
+
+            set_caller_with_value(accounts.alice, 1000);
+            escrow.deposit(accounts.bob);
+
+            set_caller(accounts.alice);
+            escrow.enable_refunds();
+
+            assert_eq!(
+                escrow.state(),
+                RefundEscrowState::Refunding,
+                "state should be refunding"
+            );
+            assert_eq!(
+                escrow.withdrawal_allowed(accounts.bob),
+                true,
+                "withdrawal should be allowed while refunding"
+            );
+
+            set_balance(accounts.bob, 0);
+            set_balance(contract_id(), 1000);
+            escrow.withdraw(accounts.bob);
+
+            assert_eq!(escrow.deposits_of(accounts.bob), 0, "deposits should clean");
+            assert_eq!(
+                get_balance(accounts.bob),
+                1000,
+                "refundee should be refunded"
+            );
+        }
+
+        #[ink::test]
+        fn beneficiary_withdraw_ok() {
+            let accounts = default_accounts();
+
+            let mut escrow = create_contract(0);
+
+            set_caller_with_value(accounts.alice, 1000);
+            escrow.deposit(accounts.bob);
+            set_caller_with_value(accounts.alice, 2000);
+            escrow.deposit(accounts.charlie);
+
+            set_caller(accounts.alice);
+            escrow.close();
+
+            assert_eq!(
+                escrow.state(),
+                RefundEscrowState::Closed,
+                "state should be closed"
+            );
+
+            set_balance(accounts.eve, 0);
+            set_balance(contract_id(), 3000);
+
+            set_caller(accounts.bob);
+            escrow.beneficiary_withdraw();
+
+            assert_eq!(
+                get_balance(accounts.eve),
+                3000,
+                "beneficiary should withdraw all funds"
+            );
+            assert_eq!(get_balance(contract_id()), 0, "escrow should be empty");
+        }
+
+        #[ink::test]
+        fn beneficiary_withdraw_should_keep_endowment() {
+            let accounts = default_accounts();
+
+            let mut escrow = create_contract(500);
+
+            set_caller_with_value(accounts.alice, 1000);
+            escrow.deposit(accounts.bob);
+
+            set_caller(accounts.alice);
+            escrow.close();
+
+            // the endowment and the deposit
+            set_balance(accounts.eve, 0);
+            set_balance(contract_id(), 1500);
+
+            escrow.beneficiary_withdraw();
+
+            assert_eq!(
+                get_balance(accounts.eve),
+                1000,
+                "beneficiary should withdraw the deposits"
+            );
+            assert_eq!(
+                get_balance(contract_id()),
+                500,
+                "escrow should keep the endowment"
+            );
+
+            // all deposits are withdrawn, withdraw again transfers nothing
+            escrow.beneficiary_withdraw();
+            assert_eq!(get_balance(accounts.eve), 1000);
+            assert_eq!(get_balance(contract_id()), 500);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "RefundEscrow: can only deposit while active")]
+        fn deposit_after_close_should_panic() {
+            let accounts = default_accounts();
+
+            let mut escrow = create_contract(0);
+
+            escrow.close();
+
+            set_caller_with_value(accounts.alice, 1000);
+            escrow.deposit(accounts.bob);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "RefundEscrow: payee is not allowed to withdraw")]
+        fn withdraw_after_close_should_panic() {
+            let accounts = default_accounts();
+
+            let mut escrow = create_contract(0);
+
+            set_caller_with_value(accounts.alice, 1000);
+            escrow.deposit(accounts.bob);
+
+            set_caller(accounts.alice);
+            escrow.close();
+
+            escrow.withdraw(accounts.bob);
+        }
+
+        #[ink::test]
+        #[should_panic(
+            expected = "RefundEscrow: beneficiary can only withdraw while closed"
+        )]
+        fn beneficiary_withdraw_while_refunding_should_panic() {
+            let mut escrow = create_contract(0);
+
+            escrow.enable_refunds();
+            escrow.beneficiary_withdraw();
+        }
+
+        #[ink::test]
+        #[should_panic]
+        fn close_by_others_should_panic() {
+            let accounts = default_accounts();
+
+            let mut escrow = create_contract(0);
+
+            set_caller(accounts.bob);
+            escrow.close();
+        }
+
+        fn create_contract(initial_balance: Balance) -> RefundEscrow {
+            let accounts = default_accounts();
+            set_caller(accounts.alice);
+            set_balance(contract_id(), initial_balance);
+            RefundEscrow::new(accounts.eve)
+        }
+    }
+}
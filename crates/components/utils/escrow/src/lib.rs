@@ -47,11 +47,7 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Ownable<E> {
     fn deposit(&mut self, payee: E::AccountId) {
         self.ensure_caller_is_owner();
 
-        let amount = Self::transferred_balance();
-
-        Storage::<E, Data<E>>::get_mut(self).add(&payee, &amount);
-
-        self.emit_event_deposited(payee, amount);
+        self._deposit(payee, Self::transferred_balance());
     }
 
     /// @dev Withdraw accumulated balance for a payee, forwarding all gas to the
@@ -65,6 +61,21 @@ pub trait Impl<E: Env>: Storage<E, Data<E>> + EventEmit<E> + Ownable<E> {
     fn withdraw(&mut self, payee: E::AccountId) {
         self.ensure_caller_is_owner();
 
+        self._withdraw(payee)
+    }
+
+    /// @dev Stores the `amount` as credit to be withdrawn, without access
+    /// restriction, the `amount` should be already held by the contract.
+    /// @param payee The destination address of the funds.
+    fn _deposit(&mut self, payee: E::AccountId, amount: E::Balance) {
+        Storage::<E, Data<E>>::get_mut(self).add(&payee, &amount);
+
+        self.emit_event_deposited(payee, amount);
+    }
+
+    /// @dev Withdraw accumulated balance for a payee, without access restriction.
+    /// @param payee The address whose funds will be withdrawn and transferred to.
+    fn _withdraw(&mut self, payee: E::AccountId) {
         let payment = Storage::<E, Data<E>>::get(self).get(&payee);
 
         Storage::<E, Data<E>>::get_mut(self).clean(&payee);
@@ -1,34 +1,17 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use ink_lang as ink;
+/// The messages of the Escrow component
+#[metis_lang::stub_contract(EscrowStub)]
+pub trait Escrow {
+    fn new() -> Self;
 
-#[ink::contract]
-mod stub {
-    #[ink(storage)]
-    pub struct EscrowStub {}
+    /// Return the deposits of payee
+    fn deposits_of(&self, payee: AccountId) -> Balance;
 
-    // impl
-    impl EscrowStub {
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            unimplemented!()
-        }
+    /// Stores the sent amount as credit to be withdrawn.
+    #[ink(payable)]
+    fn deposit(&mut self, payee: AccountId);
 
-        #[ink(message)]
-        pub fn deposits_of(&self, _payee: AccountId) -> Balance {
-            unimplemented!()
-        }
-
-        #[ink(message, payable)]
-        pub fn deposit(&mut self, _payee: AccountId) {
-            unimplemented!()
-        }
-
-        #[ink(message)]
-        pub fn withdraw(&mut self, _payee: AccountId) {
-            unimplemented!()
-        }
-    }
+    /// Withdraw accumulated balance for a payee.
+    fn withdraw(&mut self, payee: AccountId);
 }
-
-pub use stub::EscrowStub;
@@ -84,6 +84,20 @@ pub mod mock {
             test,
         };
         use ink_lang as ink;
+        use metis_escrow_stub::EscrowStub;
+        use metis_lang::mock::check_messages;
+
+        extern "Rust" {
+            fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+        }
+
+        #[test]
+        fn stub_messages_should_match_contract() {
+            let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })
+                .expect("metadata should be serialized");
+
+            assert_eq!(check_messages(&metadata, EscrowStub::MESSAGES), Ok(()));
+        }
 
         #[ink::test]
         fn new_works() {
@@ -0,0 +1,122 @@
+//! The context of the contracts which trust a forwarder, when the caller is
+//! the trusted forwarder, the sender of the message is the account set by
+//! the forwarder before it forwards the call.
+//!
+//! The input of the call can not be read again on chain after the dispatch
+//! of ink!, so the forwarder can not append the sender to the input like the
+//! ERC2771 of solidity. Instead the forwarder calls the `set_forwarded_sender`
+//! message of the recipient with the signer before forwarding the call, and
+//! clears it after the call.
+//!
+//! To use it, expose the `set_forwarded_sender` message and override the
+//! `metis_lang::Context` of the contract:
+//!
+//! ```ignore
+//! impl metis_lang::Context<Contract> for Contract {
+//!     fn _msg_sender(&self) -> AccountId {
+//!         erc2771_context::Impl::_msg_sender(self)
+//!     }
+//! }
+//!
+//! impl Contract {
+//!     #[ink(message)]
+//!     pub fn set_forwarded_sender(&mut self, sender: Option<AccountId>) {
+//!         erc2771_context::Impl::set_forwarded_sender(self, sender)
+//!     }
+//! }
+//! ```
+
+pub use metis_lang::{
+    Env,
+    EnvAccess,
+    Storage,
+};
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// The selector of the `set_forwarded_sender` message of the recipient,
+/// which is `selector_id!(set_forwarded_sender)`.
+pub const SET_FORWARDED_SENDER_SELECTOR: [u8; 4] = [0x46, 0xb4, 0xb9, 0xa9];
+
+/// The Data of erc2771 context component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E>
+where
+    E: Env,
+{
+    /// The trusted forwarder
+    pub trusted_forwarder: Lazy<E::AccountId>,
+    /// The sender of the call forwarded by the trusted forwarder
+    pub forwarded_sender: Lazy<Option<E::AccountId>>,
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            trusted_forwarder: Lazy::default(),
+            forwarded_sender: Lazy::new(None),
+        }
+    }
+}
+
+/// The `Impl` define erc2771 context component impl funcs
+pub trait Impl<E>: Storage<E, Data<E>> + EnvAccess<E>
+where
+    E: Env,
+{
+    /// Initialize the component with the `trusted_forwarder`.
+    fn init(&mut self, trusted_forwarder: E::AccountId) {
+        Lazy::set(&mut self.get_mut().trusted_forwarder, trusted_forwarder);
+        Lazy::set(&mut self.get_mut().forwarded_sender, None);
+    }
+
+    /// Returns whether the `forwarder` is the trusted forwarder.
+    fn is_trusted_forwarder(&self, forwarder: &E::AccountId) -> bool {
+        *self.get().trusted_forwarder == *forwarder
+    }
+
+    /// Set the sender of the calls forwarded by the trusted forwarder, the
+    /// forwarder should clear it by `None` after the call.
+    ///
+    /// Requirements:
+    ///
+    /// - the caller must be the trusted forwarder.
+    fn set_forwarded_sender(&mut self, sender: Option<E::AccountId>) {
+        assert!(
+            self.is_trusted_forwarder(&Self::caller()),
+            "ERC2771Context: caller is not the trusted forwarder"
+        );
+
+        Lazy::set(&mut self.get_mut().forwarded_sender, sender);
+    }
+
+    /// The sender of the message, the account set by the forwarder if the
+    /// caller is the trusted forwarder, else the caller.
+    fn _msg_sender(&self) -> E::AccountId {
+        let caller = Self::caller();
+        if !self.is_trusted_forwarder(&caller) {
+            return caller
+        }
+
+        (*self.get().forwarded_sender).clone().unwrap_or(caller)
+    }
+}
+
+impl<E: Env, T: Storage<E, Data<E>> + EnvAccess<E>> Impl<E> for T {}
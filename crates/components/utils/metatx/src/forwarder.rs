@@ -0,0 +1,193 @@
+//! A minimal forwarder, which verifies the signature and the nonce of a
+//! request, then calls the target with the signer as the forwarded sender,
+//! see `erc2771_context` for the target.
+//!
+//! The request is signed as the typed data of `metis_lang::crypto`, in the
+//! domain of the forwarder, the signer can be an account or a contract.
+
+use crate::{
+    erc2771_context::SET_FORWARDED_SENDER_SELECTOR,
+    ForwardRequest,
+    Signature,
+};
+use ink_prelude::vec::Vec;
+use metis_lang::crypto::{
+    self,
+    Domain,
+};
+pub use metis_lang::{
+    Env,
+    EnvAccess,
+    Storage,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    collections::HashMap as StorageHashMap,
+    traits::SpreadLayout,
+};
+
+/// The name of the signing domain of the forwarder.
+pub const DOMAIN_NAME: &[u8] = b"MinimalForwarder";
+
+/// The version of the signing domain of the forwarder.
+pub const DOMAIN_VERSION: &[u8] = b"0.0.1";
+
+/// The Data of forwarder component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data<E>
+where
+    E: Env,
+{
+    /// The next nonce of the signers
+    pub nonces: StorageHashMap<E::AccountId, u64>,
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for Data<E>
+where
+    E: Env,
+{
+    fn default() -> Self {
+        Self {
+            nonces: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<E> Data<E>
+where
+    E: Env,
+{
+    /// Get the next nonce of `from`
+    pub fn nonce(&self, from: &E::AccountId) -> u64 {
+        self.nonces.get(from).copied().unwrap_or(0)
+    }
+
+    /// Increase the nonce of `from`
+    pub fn increase_nonce(&mut self, from: &E::AccountId) {
+        let nonce = self.nonce(from);
+        self.nonces.insert(from.clone(), nonce + 1);
+    }
+}
+
+/// The forwarder Error types.
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    /// The call to the target failed, the nonce is used still.
+    CallFailed,
+}
+
+/// The forwarder Result type.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// The `Impl` define forwarder component impl funcs
+pub trait Impl<E>: Storage<E, Data<E>> + EnvAccess<E>
+where
+    E: Env,
+{
+    /// Returns the next nonce of `from`.
+    fn get_nonce(&self, from: E::AccountId) -> u64 {
+        self.get().nonce(&from)
+    }
+
+    /// Returns whether the `req` is signed by `req.from` with the
+    /// `signature`, and the nonce of the `req` is the next nonce.
+    fn verify(
+        &self,
+        req: ForwardRequest<E::AccountId, E::Balance>,
+        signature: Signature,
+    ) -> bool {
+        self.get().nonce(&req.from) == req.nonce
+            && crypto::is_valid_signature_now::<E>(
+                &req.from,
+                &self._hash_request(&req),
+                &signature,
+            )
+    }
+
+    /// Verify the `req` by the `signature`, then call the target with the
+    /// `req.from` as the forwarded sender.
+    ///
+    /// The forwarded sender of the target is set before the call, and
+    /// cleared after the call even if the call failed.
+    ///
+    /// The nonce is used even if the call failed, so the failed request can
+    /// not be replayed.
+    ///
+    /// Requirements:
+    ///
+    /// - the signature must match the request.
+    fn execute(
+        &mut self,
+        req: ForwardRequest<E::AccountId, E::Balance>,
+        signature: Signature,
+    ) -> Result<()> {
+        assert!(
+            self.verify(req.clone(), signature),
+            "MinimalForwarder: signature does not match request"
+        );
+        self.get_mut().increase_nonce(&req.from);
+
+        self._set_forwarded_sender(&req.to, Some(req.from.clone()))?;
+
+        let res = metis_lang::call::invoke_with_gas_limit::<E>(
+            req.to.clone(),
+            req.gas,
+            req.selector,
+            &req.data,
+            req.value,
+        );
+
+        self._set_forwarded_sender(&req.to, None)?;
+
+        res.map_err(|_| Error::CallFailed)
+    }
+
+    /// Returns the separator of the signing domain of the forwarder.
+    fn domain_separator(&self) -> [u8; 32] {
+        Domain {
+            name: Vec::from(DOMAIN_NAME),
+            version: Vec::from(DOMAIN_VERSION),
+            verifying_contract: Self::account_id(),
+            salt: None,
+        }
+        .separator()
+    }
+
+    /// Returns the hash of the message signed for the `req`.
+    fn _hash_request(&self, req: &ForwardRequest<E::AccountId, E::Balance>) -> [u8; 32] {
+        crypto::hash_typed_data(&self.domain_separator(), req)
+    }
+
+    /// Set the forwarded sender of the target `to` to `sender`.
+    fn _set_forwarded_sender(
+        &self,
+        to: &E::AccountId,
+        sender: Option<E::AccountId>,
+    ) -> Result<()> {
+        metis_lang::call::invoke::<E>(
+            to.clone(),
+            SET_FORWARDED_SENDER_SELECTOR,
+            &sender.encode(),
+            E::Balance::from(0_u8),
+        )
+        .map_err(|_| Error::CallFailed)
+    }
+}
+
+impl<E: Env, T: Storage<E, Data<E>> + EnvAccess<E>> Impl<E> for T {}
@@ -0,0 +1,24 @@
+//! Meta-transactions support, an account signs a request off-chain, and a
+//! relayer submits it to the `forwarder`, which calls the target contract
+//! with the signer as the forwarded sender.
+//!
+//! The target contract trusts the forwarder by the `erc2771_context`, which
+//! overrides the `metis_lang::Context` of the contract, so the components
+//! will use the signer as the sender of the message.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod erc2771_context;
+pub mod forwarder;
+mod types;
+
+pub use metis_lang::{
+    Env,
+    EnvAccess,
+    Storage,
+};
+
+pub use types::{
+    ForwardRequest,
+    Signature,
+};
@@ -0,0 +1,39 @@
+use ink_prelude::vec::Vec;
+use metis_lang::crypto::TypedData;
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[cfg(feature = "std")]
+use scale_info::TypeInfo;
+
+/// The signature of a request, the ECDSA signature of an account, or the
+/// signature accepted by the `is_valid_signature` of a contract.
+pub type Signature = Vec<u8>;
+
+/// A request signed by `from`, to call the message with `selector` of `to`.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo))]
+pub struct ForwardRequest<AccountId, Balance> {
+    /// The signer of the request.
+    pub from: AccountId,
+    /// The account called by the request.
+    pub to: AccountId,
+    /// The native balance transferred by the forwarder.
+    pub value: Balance,
+    /// The gas limit of the call, `0` means all the remaining gas.
+    pub gas: u64,
+    /// The nonce of `from`, each nonce can be used only once.
+    pub nonce: u64,
+    /// The selector of the called message.
+    pub selector: [u8; 4],
+    /// The encoded args of the called message.
+    pub data: Vec<u8>,
+}
+
+impl<AccountId: Encode, Balance: Encode> TypedData
+    for ForwardRequest<AccountId, Balance>
+{
+    const TYPE: &'static [u8] = b"ForwardRequest(from,to,value,gas,nonce,selector,data)";
+}
@@ -0,0 +1,58 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod contract {
+    use metis_lang::import;
+    use metis_metatx::forwarder;
+    pub use metis_metatx::{
+        forwarder::{
+            Error,
+            Result,
+        },
+        ForwardRequest,
+        Signature,
+    };
+
+    #[ink(storage)]
+    #[import(forwarder)]
+    pub struct Forwarder {
+        forwarder: forwarder::Data<Forwarder>,
+    }
+
+    impl Forwarder {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                forwarder: forwarder::Data::new(),
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_nonce(&self, from: AccountId) -> u64 {
+            forwarder::Impl::get_nonce(self, from)
+        }
+
+        #[ink(message)]
+        pub fn domain_separator(&self) -> [u8; 32] {
+            forwarder::Impl::domain_separator(self)
+        }
+
+        #[ink(message)]
+        pub fn verify(
+            &self,
+            req: ForwardRequest<AccountId, Balance>,
+            signature: Signature,
+        ) -> bool {
+            forwarder::Impl::verify(self, req, signature)
+        }
+
+        #[ink(message, payable)]
+        pub fn execute(
+            &mut self,
+            req: ForwardRequest<AccountId, Balance>,
+            signature: Signature,
+        ) -> Result<()> {
+            forwarder::Impl::execute(self, req, signature)
+        }
+    }
+}
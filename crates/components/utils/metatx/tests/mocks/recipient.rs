@@ -0,0 +1,76 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod contract {
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_metatx::erc2771_context;
+    use metis_ownable as ownable;
+
+    #[ink(storage)]
+    #[import(erc2771_context, ownable)]
+    pub struct Recipient {
+        erc2771_context: erc2771_context::Data<Recipient>,
+        ownable: ownable::Data<Recipient>,
+    }
+
+    /// Event emitted when Owner AccountId Transferred
+    #[ink(event)]
+    #[metis(ownable)]
+    pub struct OwnershipTransferred {
+        /// previous owner account id
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
+        /// new owner account id
+        #[ink(topic)]
+        new_owner: Option<AccountId>,
+    }
+
+    impl metis_lang::Context<Recipient> for Recipient {
+        fn _msg_sender(&self) -> AccountId {
+            erc2771_context::Impl::_msg_sender(self)
+        }
+    }
+
+    impl Recipient {
+        #[ink(constructor)]
+        pub fn new(trusted_forwarder: AccountId) -> Self {
+            let mut instance = Self {
+                erc2771_context: erc2771_context::Data::new(),
+                ownable: ownable::Data::new(),
+            };
+
+            erc2771_context::Impl::init(&mut instance, trusted_forwarder);
+            ownable::Impl::init(&mut instance);
+
+            instance
+        }
+
+        #[ink(message)]
+        pub fn is_trusted_forwarder(&self, forwarder: AccountId) -> bool {
+            erc2771_context::Impl::is_trusted_forwarder(self, &forwarder)
+        }
+
+        #[ink(message)]
+        pub fn set_forwarded_sender(&mut self, sender: Option<AccountId>) {
+            erc2771_context::Impl::set_forwarded_sender(self, sender)
+        }
+
+        #[ink(message)]
+        pub fn msg_sender(&self) -> AccountId {
+            metis_lang::Context::_msg_sender(self)
+        }
+
+        #[ink(message)]
+        pub fn owner(&self) -> Option<AccountId> {
+            ownable::Impl::owner(self).clone()
+        }
+
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) {
+            ownable::Impl::transfer_ownership(self, &new_owner)
+        }
+    }
+}
@@ -0,0 +1,135 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod recipient;
+}
+
+mod erc2771_context_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use metis_metatx::erc2771_context::SET_FORWARDED_SENDER_SELECTOR;
+    use metis_test::*;
+    use mocks::recipient::contract::*;
+
+    #[ink::test]
+    fn new_works() {
+        let accounts = default_accounts();
+        let recipient = call_as(accounts.alice, || Recipient::new(accounts.django));
+
+        assert!(recipient.is_trusted_forwarder(accounts.django));
+        assert!(!recipient.is_trusted_forwarder(accounts.alice));
+        assert_eq!(recipient.owner(), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn set_forwarded_sender_selector_works() {
+        assert_eq!(
+            SET_FORWARDED_SENDER_SELECTOR,
+            metis_lang::selector_id!(set_forwarded_sender)
+        );
+    }
+
+    #[ink::test]
+    fn msg_sender_from_account_works() {
+        let accounts = default_accounts();
+        let recipient = Recipient::new(accounts.django);
+
+        assert_eq!(
+            call_as(accounts.bob, || recipient.msg_sender()),
+            accounts.bob
+        );
+    }
+
+    #[ink::test]
+    fn msg_sender_from_trusted_forwarder_works() {
+        let accounts = default_accounts();
+        let mut recipient = Recipient::new(accounts.django);
+
+        call_as(accounts.django, || {
+            recipient.set_forwarded_sender(Some(accounts.eve))
+        });
+
+        assert_eq!(
+            call_as(accounts.django, || recipient.msg_sender()),
+            accounts.eve
+        );
+
+        // the forwarded sender is only used for the calls by the forwarder
+        assert_eq!(
+            call_as(accounts.bob, || recipient.msg_sender()),
+            accounts.bob
+        );
+    }
+
+    #[ink::test]
+    fn msg_sender_from_trusted_forwarder_without_sender_is_caller() {
+        let accounts = default_accounts();
+        let mut recipient = Recipient::new(accounts.django);
+
+        assert_eq!(
+            call_as(accounts.django, || recipient.msg_sender()),
+            accounts.django
+        );
+
+        call_as(accounts.django, || {
+            recipient.set_forwarded_sender(Some(accounts.eve))
+        });
+        call_as(accounts.django, || recipient.set_forwarded_sender(None));
+
+        assert_eq!(
+            call_as(accounts.django, || recipient.msg_sender()),
+            accounts.django
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "ERC2771Context: caller is not the trusted forwarder")]
+    fn set_forwarded_sender_by_untrusted_forwarder_should_panic() {
+        let accounts = default_accounts();
+        let mut recipient = Recipient::new(accounts.django);
+
+        call_as(accounts.frank, || {
+            recipient.set_forwarded_sender(Some(accounts.eve))
+        });
+    }
+
+    #[ink::test]
+    fn transfer_ownership_by_forwarder_works() {
+        let accounts = default_accounts();
+        let mut recipient = call_as(accounts.alice, || Recipient::new(accounts.django));
+
+        call_as(accounts.django, || {
+            recipient.set_forwarded_sender(Some(accounts.alice));
+            recipient.transfer_ownership(accounts.bob);
+            recipient.set_forwarded_sender(None);
+        });
+
+        assert_eq!(recipient.owner(), Some(accounts.bob));
+    }
+
+    #[ink::test]
+    #[should_panic]
+    fn transfer_ownership_by_forwarder_for_not_owner_should_panic() {
+        let accounts = default_accounts();
+        let mut recipient = call_as(accounts.alice, || Recipient::new(accounts.django));
+
+        // the forwarded sender is not the owner
+        call_as(accounts.django, || {
+            recipient.set_forwarded_sender(Some(accounts.eve));
+            recipient.transfer_ownership(accounts.eve);
+        });
+    }
+
+    #[ink::test]
+    #[should_panic]
+    fn transfer_ownership_by_forwarder_without_sender_should_panic() {
+        let accounts = default_accounts();
+        let mut recipient = call_as(accounts.alice, || Recipient::new(accounts.django));
+
+        // the forwarder itself is not the owner
+        call_as(accounts.django, || {
+            recipient.transfer_ownership(accounts.bob)
+        });
+    }
+}
@@ -0,0 +1,433 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod forwarder;
+}
+
+mod forwarder_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use ink_prelude::vec::Vec;
+    use metis_lang::{
+        crypto::{
+            self,
+            Domain,
+            IS_VALID_SIGNATURE_SELECTOR,
+        },
+        mock,
+    };
+    use metis_metatx::{
+        erc2771_context::SET_FORWARDED_SENDER_SELECTOR,
+        forwarder::{
+            DOMAIN_NAME,
+            DOMAIN_VERSION,
+        },
+    };
+    use metis_test::*;
+    use mocks::forwarder::contract::*;
+    use scale::Encode;
+    use secp256k1::{
+        Message,
+        PublicKey,
+        Secp256k1,
+        SecretKey,
+    };
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    const SELECTOR: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+
+    /// The secret key of the signer, and the account of it.
+    fn signer(seed: u8) -> (SecretKey, AccountId) {
+        let secret_key = SecretKey::from_slice(&[seed; 32]).expect("invalid secret key");
+        let public_key = PublicKey::from_secret_key(&Secp256k1::new(), &secret_key);
+
+        let account = crypto::ecdsa_to_account_id::<Forwarder>(&public_key.serialize());
+        (secret_key, account)
+    }
+
+    /// The hash signed for the `req`, calculated without the forwarder.
+    fn hash_request(req: &ForwardRequest<AccountId, Balance>) -> [u8; 32] {
+        let domain = Domain {
+            name: Vec::from(DOMAIN_NAME),
+            version: Vec::from(DOMAIN_VERSION),
+            verifying_contract: contract_id(),
+            salt: None,
+        };
+
+        crypto::hash_typed_data(&domain.separator(), req)
+    }
+
+    fn sign(
+        secret_key: &SecretKey,
+        req: &ForwardRequest<AccountId, Balance>,
+    ) -> Signature {
+        let message = Message::from_slice(&hash_request(req)).expect("invalid message");
+        let (recovery_id, compact) = Secp256k1::new()
+            .sign_recoverable(&message, secret_key)
+            .serialize_compact();
+
+        let mut signature = compact.to_vec();
+        signature.push(recovery_id.to_i32() as u8);
+        signature
+    }
+
+    /// Register a contract signer at `account`, which accepts the signature
+    /// `accepted` only.
+    fn mock_contract_signer(account: AccountId, accepted: Signature) {
+        mock::register(account, move |call| {
+            assert_eq!(call.selector, IS_VALID_SIGNATURE_SELECTOR);
+            let (_, signature) = call.decode_input::<([u8; 32], Vec<u8>)>()?;
+            if signature == accepted {
+                Ok(IS_VALID_SIGNATURE_SELECTOR.encode())
+            } else {
+                Ok([0_u8; 4].encode())
+            }
+        });
+    }
+
+    fn request(
+        from: AccountId,
+        to: AccountId,
+        nonce: u64,
+    ) -> ForwardRequest<AccountId, Balance> {
+        ForwardRequest {
+            from,
+            to,
+            value: 0,
+            gas: 0,
+            nonce,
+            selector: SELECTOR,
+            data: (1_u32, true).encode(),
+        }
+    }
+
+    /// A call forwarded to the target, with the forwarded sender set by the
+    /// forwarder when the target is called.
+    type ForwardedCall = (mock::MockCallInfo, Option<AccountId>);
+
+    /// Register a target at `account` like the `erc2771_context`, which keeps
+    /// the forwarded sender, and records the forwarded calls.
+    fn mock_target(account: AccountId) -> Rc<RefCell<Vec<ForwardedCall>>> {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+
+        let target_calls = calls.clone();
+        let mut forwarded_sender = None;
+        mock::register(account, move |call| {
+            if call.selector == SET_FORWARDED_SENDER_SELECTOR {
+                assert_eq!(call.caller, contract_id());
+                forwarded_sender = call.decode_input::<Option<AccountId>>()?;
+            } else {
+                target_calls
+                    .borrow_mut()
+                    .push((call.clone(), forwarded_sender));
+            }
+            Ok(Vec::new())
+        });
+
+        calls
+    }
+
+    /// Register a target at `account` which records the selectors of all the
+    /// calls, and reverts the calls with `SELECTOR`.
+    fn mock_reverted_target(account: AccountId) -> Rc<RefCell<Vec<[u8; 4]>>> {
+        let selectors = Rc::new(RefCell::new(Vec::new()));
+
+        let target_selectors = selectors.clone();
+        mock::register(account, move |call| {
+            target_selectors.borrow_mut().push(call.selector);
+            if call.selector == SELECTOR {
+                Err(ink_env::Error::CalleeReverted)
+            } else {
+                Ok(Vec::new())
+            }
+        });
+
+        selectors
+    }
+
+    #[ink::test]
+    fn verify_works() {
+        let accounts = default_accounts();
+        let forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&secret_key, &req);
+
+        assert!(forwarder.verify(req, signature.clone()));
+        assert_eq!(forwarder.get_nonce(from), 0);
+    }
+
+    #[ink::test]
+    fn verify_with_wrong_nonce_fails() {
+        let accounts = default_accounts();
+        let forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+
+        let req = request(from, accounts.django, 1);
+        let signature = sign(&secret_key, &req);
+
+        assert!(!forwarder.verify(req, signature.clone()));
+    }
+
+    #[ink::test]
+    fn verify_by_other_signer_fails() {
+        let accounts = default_accounts();
+        let forwarder = Forwarder::new();
+        let (_, from) = signer(0x11);
+        let (other_key, _) = signer(0x22);
+
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&other_key, &req);
+
+        assert!(!forwarder.verify(req, signature.clone()));
+    }
+
+    #[ink::test]
+    fn verify_with_tampered_request_fails() {
+        let accounts = default_accounts();
+        let forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&secret_key, &req);
+
+        let mut tampered = req.clone();
+        tampered.data = (2_u32, true).encode();
+        assert!(!forwarder.verify(tampered, signature.clone()));
+
+        let mut tampered = req;
+        tampered.to = accounts.eve;
+        assert!(!forwarder.verify(tampered, signature.clone()));
+    }
+
+    #[ink::test]
+    fn execute_works() {
+        let accounts = default_accounts();
+        let mut forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+        let calls = mock_target(accounts.django);
+
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&secret_key, &req);
+
+        // anyone can relay the request
+        let res = call_as(accounts.bob, || {
+            forwarder.execute(req.clone(), signature.clone())
+        });
+        assert_eq!(res, Ok(()));
+        assert_eq!(forwarder.get_nonce(from), 1);
+
+        let calls = calls.borrow();
+        assert_eq!(calls.len(), 1);
+
+        // the input is forwarded as it is, with the signer as the sender
+        let (call, sender) = &calls[0];
+        assert_eq!(call.callee, accounts.django);
+        assert_eq!(call.caller, contract_id());
+        assert_eq!(call.selector, SELECTOR);
+        assert_eq!(call.input, req.data);
+        assert_eq!(*sender, Some(from));
+    }
+
+    #[ink::test]
+    fn execute_clears_forwarded_sender() {
+        let accounts = default_accounts();
+        let mut forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+        let calls = mock_target(accounts.django);
+
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&secret_key, &req);
+        assert_eq!(forwarder.execute(req, signature), Ok(()));
+
+        // a call by the forwarder which is not forwarded has no sender
+        assert_eq!(
+            metis_lang::call::invoke::<Forwarder>(
+                accounts.django,
+                SELECTOR,
+                &Vec::new(),
+                0
+            ),
+            Ok(())
+        );
+
+        let calls = calls.borrow();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].1, Some(from));
+        assert_eq!(calls[1].1, None);
+    }
+
+    #[ink::test]
+    fn execute_next_nonce_works() {
+        let accounts = default_accounts();
+        let mut forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+        let calls = mock_target(accounts.django);
+
+        for nonce in 0..3 {
+            let req = request(from, accounts.django, nonce);
+            let signature = sign(&secret_key, &req);
+            assert_eq!(forwarder.execute(req, signature), Ok(()));
+        }
+
+        assert_eq!(forwarder.get_nonce(from), 3);
+        assert_eq!(calls.borrow().len(), 3);
+        assert!(calls
+            .borrow()
+            .iter()
+            .all(|(_, sender)| *sender == Some(from)));
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "MinimalForwarder: signature does not match request")]
+    fn execute_replay_should_panic() {
+        let accounts = default_accounts();
+        let mut forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+        mock_target(accounts.django);
+
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&secret_key, &req);
+
+        assert_eq!(forwarder.execute(req.clone(), signature.clone()), Ok(()));
+        let _ = forwarder.execute(req, signature);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "MinimalForwarder: signature does not match request")]
+    fn execute_by_other_signer_should_panic() {
+        let accounts = default_accounts();
+        let mut forwarder = Forwarder::new();
+        let (_, from) = signer(0x11);
+        let (other_key, _) = signer(0x22);
+        mock_target(accounts.django);
+
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&other_key, &req);
+
+        let _ = forwarder.execute(req, signature);
+    }
+
+    #[ink::test]
+    fn execute_failed_call_uses_nonce() {
+        let accounts = default_accounts();
+        let mut forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+        mock::register_revert(accounts.django);
+
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&secret_key, &req);
+
+        assert_eq!(
+            forwarder.execute(req.clone(), signature.clone()),
+            Err(Error::CallFailed)
+        );
+        assert_eq!(forwarder.get_nonce(from), 1);
+        assert!(!forwarder.verify(req, signature.clone()));
+    }
+
+    #[ink::test]
+    fn execute_failed_call_clears_forwarded_sender() {
+        let accounts = default_accounts();
+        let mut forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+        let selectors = mock_reverted_target(accounts.django);
+
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&secret_key, &req);
+
+        assert_eq!(forwarder.execute(req, signature), Err(Error::CallFailed));
+        assert_eq!(
+            *selectors.borrow(),
+            vec![
+                SET_FORWARDED_SENDER_SELECTOR,
+                SELECTOR,
+                SET_FORWARDED_SENDER_SELECTOR
+            ]
+        );
+    }
+
+    #[ink::test]
+    fn execute_to_account_without_code_fails() {
+        let accounts = default_accounts();
+        let mut forwarder = Forwarder::new();
+        let (secret_key, from) = signer(0x11);
+
+        // the target can not accept the forwarded sender
+        let req = request(from, accounts.django, 0);
+        let signature = sign(&secret_key, &req);
+
+        assert_eq!(forwarder.execute(req, signature), Err(Error::CallFailed));
+        assert_eq!(forwarder.get_nonce(from), 1);
+    }
+
+    #[ink::test]
+    fn is_valid_signature_selector_works() {
+        assert_eq!(
+            IS_VALID_SIGNATURE_SELECTOR,
+            metis_lang::selector_id!(is_valid_signature)
+        );
+    }
+
+    #[ink::test]
+    fn domain_separator_works() {
+        let forwarder = Forwarder::new();
+        let domain = Domain {
+            name: Vec::from(DOMAIN_NAME),
+            version: Vec::from(DOMAIN_VERSION),
+            verifying_contract: contract_id(),
+            salt: None,
+        };
+
+        assert_eq!(forwarder.domain_separator(), domain.separator());
+
+        let other_domain = Domain {
+            verifying_contract: default_accounts().django,
+            ..domain
+        };
+        assert_ne!(forwarder.domain_separator(), other_domain.separator());
+    }
+
+    #[ink::test]
+    fn verify_by_contract_signer_works() {
+        let accounts = default_accounts();
+        let forwarder = Forwarder::new();
+        let signature = vec![0x01, 0x02, 0x03];
+        mock_contract_signer(accounts.charlie, signature.clone());
+
+        let req = request(accounts.charlie, accounts.django, 0);
+        assert!(forwarder.verify(req.clone(), signature));
+        assert!(!forwarder.verify(req, vec![0x04]));
+    }
+
+    #[ink::test]
+    fn execute_by_contract_signer_works() {
+        let accounts = default_accounts();
+        let mut forwarder = Forwarder::new();
+        let signature = vec![0x01, 0x02, 0x03];
+        mock_contract_signer(accounts.charlie, signature.clone());
+        let calls = mock_target(accounts.django);
+
+        let req = request(accounts.charlie, accounts.django, 0);
+        assert_eq!(forwarder.execute(req, signature), Ok(()));
+
+        assert_eq!(forwarder.get_nonce(accounts.charlie), 1);
+        assert_eq!(calls.borrow().len(), 1);
+    }
+
+    #[ink::test]
+    fn verify_by_account_without_code_fails() {
+        let accounts = default_accounts();
+        let forwarder = Forwarder::new();
+
+        // the signature is not an ECDSA signature, and the signer is not a
+        // contract
+        let req = request(accounts.charlie, accounts.django, 0);
+        assert!(!forwarder.verify(req, vec![0x01, 0x02, 0x03]));
+    }
+}
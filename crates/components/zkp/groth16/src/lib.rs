@@ -0,0 +1,143 @@
+//! Groth16 verifier, verifies the zk-SNARK proofs by the verifying keys
+//! registered once under an id.
+//!
+//! The pairing checks are done by the curve ops of the chain extension (see
+//! the `CurveExtension` of the `patra_env`), the verifying keys, the proofs
+//! and the public inputs are the points and the scalars serialized by the
+//! arkworks. The contract should implement the `ChainExtension` to call the
+//! curve ops:
+//!
+//! ```ignore
+//! impl metis_groth16::ChainExtension for Contract {}
+//! ```
+//!
+//! The registration is not restricted by the component, the contract should
+//! check the caller before calling `_register_verifying_key`, such as by the
+//! `ownable` component.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "mock")]
+pub mod mock;
+mod module;
+mod types;
+pub mod verifier;
+
+use ink_lang::ChainExtensionInstance;
+use ink_prelude::vec::Vec;
+pub use metis_lang::{
+    Env,
+    EnvAccess,
+    Storage,
+};
+
+pub use module::Data;
+pub use patra_env::{
+    CurveError,
+    CurveExtension,
+};
+pub use types::{
+    Curve,
+    Error,
+    Result,
+    VerifyingKey,
+    VerifyingKeyId,
+};
+
+/// The chain extension of the curve ops, gives the verifier the
+/// `CurveExtension` of the chain.
+pub trait ChainExtension {
+    /// Returns the instance to call the curve ops.
+    fn extension() -> <CurveExtension as ChainExtensionInstance>::Instance {
+        <CurveExtension as ChainExtensionInstance>::instantiate()
+    }
+}
+
+/// The `EventEmit` impl the event emit api for groth16 component.
+pub trait EventEmit<E: Env>: EnvAccess<E> {
+    /// Emitted when the verifying key `vk_id` on the `curve` is registered.
+    fn emit_event_verifying_key_registered(
+        &mut self,
+        vk_id: VerifyingKeyId,
+        curve: Curve,
+    );
+
+    /// Emitted when the verifying key `vk_id` is removed.
+    fn emit_event_verifying_key_removed(&mut self, vk_id: VerifyingKeyId);
+}
+
+/// The `Impl` define groth16 component impl funcs
+pub trait Impl<E>: EventEmit<E> + Storage<E, Data> + ChainExtension
+where
+    E: Env,
+{
+    /// Returns the verifying key `vk_id`.
+    fn verifying_key(&self, vk_id: VerifyingKeyId) -> Option<VerifyingKey> {
+        Storage::<E, Data>::get(self).get(&vk_id).cloned()
+    }
+
+    /// Verify the `proof` of the `public_inputs` by the verifying key
+    /// `vk_id`, returns whether the proof is valid.
+    ///
+    /// Return error if the key is not registered or the number of the
+    /// `public_inputs` does not match the key.
+    fn verify(
+        &self,
+        vk_id: VerifyingKeyId,
+        proof: Vec<u8>,
+        public_inputs: Vec<Vec<u8>>,
+    ) -> Result<bool> {
+        let vk = Storage::<E, Data>::get(self)
+            .get(&vk_id)
+            .ok_or(Error::VerifyingKeyNotFound)?;
+
+        self._verify_proof(vk, proof, public_inputs)
+    }
+
+    /// Register the verifying key `vk` under `vk_id`.
+    ///
+    /// Return error if a key has been registered under `vk_id`.
+    fn _register_verifying_key(
+        &mut self,
+        vk_id: VerifyingKeyId,
+        vk: VerifyingKey,
+    ) -> Result<()> {
+        if !verifier::is_valid_verifying_key(&vk) {
+            return Err(Error::InvalidVerifyingKey)
+        }
+
+        if Storage::<E, Data>::get(self).contains(&vk_id) {
+            return Err(Error::VerifyingKeyExists)
+        }
+
+        let curve = vk.curve;
+        Storage::<E, Data>::get_mut(self).insert(vk_id, vk);
+        self.emit_event_verifying_key_registered(vk_id, curve);
+
+        Ok(())
+    }
+
+    /// Remove the verifying key `vk_id`, so the id can be registered again.
+    ///
+    /// Return error if the key is not registered.
+    fn _remove_verifying_key(&mut self, vk_id: VerifyingKeyId) -> Result<()> {
+        Storage::<E, Data>::get_mut(self)
+            .remove(&vk_id)
+            .ok_or(Error::VerifyingKeyNotFound)?;
+        self.emit_event_verifying_key_removed(vk_id);
+
+        Ok(())
+    }
+
+    /// Do the pairing check of the `proof` by the `vk`.
+    fn _verify_proof(
+        &self,
+        vk: &VerifyingKey,
+        proof: Vec<u8>,
+        public_inputs: Vec<Vec<u8>>,
+    ) -> Result<bool> {
+        verifier::verify::<Self>(vk, &proof, &public_inputs)
+    }
+}
+
+impl<E: Env, T: EventEmit<E> + Storage<E, Data> + ChainExtension> Impl<E> for T {}
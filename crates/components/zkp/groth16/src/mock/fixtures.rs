@@ -0,0 +1,344 @@
+//! Groth16 fixtures of all the supported curves, the vectors are static hex
+//! of the points serialized (uncompressed) by the arkworks, so the tests do not depend on
+//! the setup of the arkworks and each run uses the same vectors.
+//!
+//! The circuit proves the knowledge of `a` and `b` with `a * b = c`, `c` is
+//! the only public input.
+//!
+//! `generate` is only the tool to regenerate the vectors, the ignored test
+//! prints them in the layout of this file:
+//!
+//! ```sh
+//! cargo test -p metis_groth16 --test test_groth16 -- --ignored regenerate_fixtures --nocapture
+//! ```
+
+use super::serialize;
+use crate::{
+    Curve,
+    VerifyingKey,
+};
+use ark_ec::PairingEngine;
+use ark_ff::Field;
+use ark_groth16::{
+    create_random_proof,
+    generate_random_parameters,
+};
+use ark_relations::{
+    lc,
+    r1cs::{
+        ConstraintSynthesizer,
+        ConstraintSystemRef,
+        SynthesisError,
+    },
+};
+use ark_std::{
+    rand::{
+        rngs::StdRng,
+        SeedableRng,
+    },
+    UniformRand,
+};
+
+/// The seed of the regenerated fixtures.
+const SEED: u64 = 0x6d65746973;
+
+/// A valid proof of the verifying key.
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    pub vk: VerifyingKey,
+    pub proof: Vec<u8>,
+    pub public_inputs: Vec<Vec<u8>>,
+}
+
+/// Returns the fixture of the `curve`.
+pub fn fixture(curve: Curve) -> Fixture {
+    hex_fixture(curve).decode(curve)
+}
+
+/// Generate a new fixture of the `curve` by the arkworks, only used to
+/// regenerate the static vectors.
+pub fn generate(curve: Curve) -> Fixture {
+    match curve {
+        Curve::Bls12_377 => generate_by::<ark_bls12_377::Bls12_377>(curve),
+        Curve::Bls12_381 => generate_by::<ark_bls12_381::Bls12_381>(curve),
+        Curve::Bn254 => generate_by::<ark_bn254::Bn254>(curve),
+        Curve::Bw6_761 => generate_by::<ark_bw6_761::BW6_761>(curve),
+    }
+}
+
+/// Returns the source of the static vectors of the `fixture`, in the layout
+/// of this file.
+pub fn fixture_source(curve: Curve, fixture: &Fixture) -> String {
+    let (g1, g2) = (curve.g1_size(), curve.g2_size());
+    let vk = &fixture.vk.vk;
+    let proof = &fixture.proof;
+
+    let mut source = format!("const {}: HexFixture = HexFixture {{\n", const_name(curve));
+    let mut field = |name: &str, items: Vec<&[u8]>| {
+        source.push_str(&format!("    {}: &[\n", name));
+        for item in items {
+            source.push_str(&format!("        \"{}\",\n", encode_hex(item)));
+        }
+        source.push_str("    ],\n");
+    };
+
+    field(
+        "vk",
+        vec![
+            &vk[..g1],
+            &vk[g1..g1 + g2],
+            &vk[g1 + g2..g1 + 2 * g2],
+            &vk[g1 + 2 * g2..],
+        ],
+    );
+    field(
+        "gamma_abc",
+        fixture
+            .vk
+            .gamma_abc
+            .iter()
+            .map(|point| &point[..])
+            .collect(),
+    );
+    field(
+        "proof",
+        vec![&proof[..g1], &proof[g1..g1 + g2], &proof[g1 + g2..]],
+    );
+    field(
+        "public_inputs",
+        fixture
+            .public_inputs
+            .iter()
+            .map(|input| &input[..])
+            .collect(),
+    );
+
+    source.push_str("};\n");
+    source
+}
+
+/// The static vectors of a fixture, the hex of the serialized points and
+/// scalars.
+struct HexFixture {
+    /// The `alpha_g1`, `beta_g2`, `gamma_g2` and `delta_g2` of the key
+    vk: &'static [&'static str],
+    /// The `gamma_abc_g1` of the key
+    gamma_abc: &'static [&'static str],
+    /// The `a`, `b` and `c` of the proof
+    proof: &'static [&'static str],
+    /// The public inputs
+    public_inputs: &'static [&'static str],
+}
+
+impl HexFixture {
+    fn decode(&self, curve: Curve) -> Fixture {
+        let concat =
+            |items: &[&str]| items.iter().flat_map(|item| decode_hex(item)).collect();
+        let each = |items: &[&str]| items.iter().map(|item| decode_hex(item)).collect();
+
+        Fixture {
+            vk: VerifyingKey {
+                curve,
+                vk: concat(self.vk),
+                gamma_abc: each(self.gamma_abc),
+            },
+            proof: concat(self.proof),
+            public_inputs: each(self.public_inputs),
+        }
+    }
+}
+
+fn hex_fixture(curve: Curve) -> &'static HexFixture {
+    match curve {
+        Curve::Bls12_377 => &BLS12_377,
+        Curve::Bls12_381 => &BLS12_381,
+        Curve::Bn254 => &BN254,
+        Curve::Bw6_761 => &BW6_761,
+    }
+}
+
+fn const_name(curve: Curve) -> &'static str {
+    match curve {
+        Curve::Bls12_377 => "BLS12_377",
+        Curve::Bls12_381 => "BLS12_381",
+        Curve::Bn254 => "BN254",
+        Curve::Bw6_761 => "BW6_761",
+    }
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    assert!(hex.len() % 2 == 0, "Groth16: odd hex length");
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("Groth16: invalid hex"))
+        .collect()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// The circuit of `a * b = c`.
+#[derive(Clone, Copy)]
+struct MulCircuit<F: Field> {
+    a: Option<F>,
+    b: Option<F>,
+}
+
+impl<F: Field> ConstraintSynthesizer<F> for MulCircuit<F> {
+    fn generate_constraints(
+        self,
+        cs: ConstraintSystemRef<F>,
+    ) -> Result<(), SynthesisError> {
+        let a =
+            cs.new_witness_variable(|| self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b =
+            cs.new_witness_variable(|| self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        let c = cs.new_input_variable(|| {
+            let a = self.a.ok_or(SynthesisError::AssignmentMissing)?;
+            let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+            Ok(a * b)
+        })?;
+
+        cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + c)
+    }
+}
+
+fn generate_by<E: PairingEngine>(curve: Curve) -> Fixture {
+    let rng = &mut StdRng::seed_from_u64(SEED);
+
+    let params = generate_random_parameters::<E, _, _>(
+        MulCircuit::<E::Fr> { a: None, b: None },
+        rng,
+    )
+    .expect("Groth16: setup should work");
+
+    let a = E::Fr::rand(rng);
+    let b = E::Fr::rand(rng);
+    let proof = create_random_proof(
+        MulCircuit {
+            a: Some(a),
+            b: Some(b),
+        },
+        &params,
+        rng,
+    )
+    .expect("Groth16: prove should work");
+
+    let vk = &params.vk;
+    let vk_bytes = [
+        serialize(&vk.alpha_g1),
+        serialize(&vk.beta_g2),
+        serialize(&vk.gamma_g2),
+        serialize(&vk.delta_g2),
+    ]
+    .iter()
+    .flat_map(|bytes| bytes.clone().expect("Groth16: serialize should work"))
+    .collect();
+    let proof_bytes = [
+        serialize(&proof.a),
+        serialize(&proof.b),
+        serialize(&proof.c),
+    ]
+    .iter()
+    .flat_map(|bytes| bytes.clone().expect("Groth16: serialize should work"))
+    .collect();
+
+    Fixture {
+        vk: VerifyingKey {
+            curve,
+            vk: vk_bytes,
+            gamma_abc: vk
+                .gamma_abc_g1
+                .iter()
+                .map(|point| serialize(point).expect("Groth16: serialize should work"))
+                .collect(),
+        },
+        proof: proof_bytes,
+        public_inputs: vec![serialize(&(a * b)).expect("Groth16: serialize should work")],
+    }
+}
+
+const BLS12_377: HexFixture = HexFixture {
+    vk: &[
+        "9135a071871e8cb07a79b3e73c16465e68d261e5babb42dda9709072e6af7bfa49750f2e9b4bd37247d576fb84bf69002a8a1c3008f1b8ba70f85f010d83119dd0f2b212552871632d688e49283d14d32e1f5c9ebe4242eb1137420db6c76a01",
+        "2794fbc4fb1e752dd06a93f89886848724ddd7d466886a6175466fac6d1a80d2293a5bba74a11f2fba81b1077f1728007f5788733df97c784675ae775d66cae080ece325de418dfa96d0a6e51f75d04bb76c053247117504867e611047b39300ad1fc78b3e89647af2b524365fd9bcaa8127d67cca01af75c945f1d80ca8fda8c62c3ee2842bfec99cfdac5ce74e56000d2ac70657760f72b1fcc31dc23f9fe8190c39c698ad774922abdf9c4e80127c4156e0d3f18357140749502130600700",
+        "5560aa6f6dcaacb1aa85d71fd44242d745908de2cb42fddcf1e1a12b97fb8f2f32d075b9d575634c89efba6c83db5800ab7cf512e21c3402a87da992ef09c07db2073d660e339f0742793d1f3bf504e874032c269181a286865bf5285aa40c011a49441fcf0c8c4bd774e4d2269649470d8d5ffddc4e62afd9bdd5dcad9f1caec1d3d94f9f25c335e07bd99654371e01aa76246daf292d416d277e79e60897862ead6c891aafc770bb0c70a52f91a60918d9c788f0d2674000dc21cb7db17001",
+        "6b54e28d4160bbae134d7de48adbafc23b742afc3be86b31aacd07ca09559db4fa582c65d48762cfc64eba25f70ddb00a1489a8fe25f04db088c5aa4a3aafde0cdfc52e163d775f4989300451bf73c159ce8dbeff68eba891cdc1b1d69633101d67e26423276254c3d1c11035f9754b0112d3324244a1063e421ae3b9371b24160442fb9602e40572c9a293239440000f7a2f7af9df9ef9740ab28ceb0ae30d26358dcf71136293d9707ef614ac3e0cb14bff3d7a19533b6dea958ad817ef900",
+    ],
+    gamma_abc: &[
+        "474e26d73150eefb2c55a7968d9570479f958ae30c71d73aa7da1f5182e3671a45ba6959957128db5fb6f989997c3e00ba13563ef917a4360ebb9f0ea350141a529dd7669a83f2fa2206040c4667d7d4b9e068a223c66e90938ff060425ec200",
+        "e39a6f2b13da80440cd526f2f2c663b55d938503e53ba256d789c33437037b680a9c66dfe46b463355f4111bb9ce930176a48f75ad0a18183dd1bdbf70e83e889eedcf866bf51487829871d31d7574b5a8a75c883502bdee094789c22e7c4901",
+    ],
+    proof: &[
+        "9b99728742340669e4f10075d78e8d48c53c286ddceeb8ed65a1210a24fd1c07cb051c280a6dddbd446709463b022600dbdef621d23a5a2714b7215baa31d17d62c82eac125825c63e4e3d3fc8276710e6463ba7539b267a4827ee07b0694e00",
+        "2123783503997375bcd48e8078020e4e3e108845583695fee8050cc9ab1b6a55138747add7bd78cc10ebea4fe0a2e800cd2d475d2d424789e4ae48bc1011f6d816e82792ea6d21a25f8cf47c371d0310908df31ee8fca7ba70e5c95ffcd3c8009749dc7b55b502dfa639b342be736b958481133a4b26b2fe8b01ef762094d21d801132fb33664d573488c2ef2ced2201b8453476de6ca5abc8991e8bbe556c98a89e2107245fb20becadcb0fa85f332c522ee6d05860b9777a8b2de41654e800",
+        "808a24ae98fe9a58176a0e28d8473ee0198e6346d262890f9bccc0cad1e41bd681e62a01b330a5fb18c80e54098d890067b30f6fda20ef7092d86a8dd532476075ebdde6259dfd3f41898bd0db97fff8ec92d3e4ecb062a710c8463fa1463400",
+    ],
+    public_inputs: &[
+        "e921acb23ab3a035f1efa97c82d65fef856a8e6a60cbad11c1ebd61980969c0c",
+    ],
+};
+
+const BLS12_381: HexFixture = HexFixture {
+    vk: &[
+        "d4fa14efcf32f7a041f474d3b0598717698e6691c53b78f38ce1054403610734d91203cd045c2bfda616beae7fe72f0c19dc9ad9e21f4f50326eb6bf0a28eabf5b643e192727622165bc5a4ebcce494478054ac3a631ddb23a9f2a3062242e0a",
+        "10f0f4e92236c5cbe6830ecb151b686ff22a07feb3979e75d24a26ec1b7808af0591ae0adb7b7516fd51d47500871319309457c5933dbb451a904ebdc3c62d087575da866bec1a157895de5f02b230d6ac2012e390151345f06f178d1b4c1e0a6eeadecfb253dc29bdb6d57b70adc5af074611d7f5199ac91b95c9e50bc7b7bc9454065ab5ba68094244e098a6e8d30acd704672591947fbd76206ef80a5e9470b27b9b7fbbd78b1b10e3d6b1f438ed583f34a99ebf61d3e7dbac9f832fd5f0c",
+        "505bf608876bbedd1e74e157a49a2780779f4395ea62b84b2eef71a06131aa313aadfcdc9baa9a37fb136ab9a3f36007a86a9e7e440b56f440613bdffa11e7683ca359a7339b7aefd2e228053fad2ebdcd7a90d98cbc0e013c327dd0dca97a1784e83d2753e0aefe2ed36123cfbef9223158fd6dd25f125275e975164081fa51ccafae0f0c1899e59bc62331578edd11a2d27c17bf815af2ff9fc14968033c2fd2bf3dd29981ee3cef43d77b7c4e8542d2fafc3b7fd9bdb7c577465a1852010e",
+        "407d2486651114765ac0b9e240fcd45b14df82277497ce7fdf48b49bf3145977debcfc7b6b88f258943124cf419c7603d460678fa7963b1e6cd3adcf7736a25b8f26c247834641dda6da8e77c0584334058d2492d2a13f7c1b06ea19fa944504dee752018b0714a538f2b4cb949467f3a165fbad3dd030ca4738807655ba5efbcc6a98cede72dbaa4e2212fccc2361031bf44fe43845de7f61037c625e3051a815e01adfd541dd831f849dfa594d880d4fda6961de09725a12443888393ff112",
+    ],
+    gamma_abc: &[
+        "d87534546ff472b2b24d81259afcd09035e93a47b2fd3abb35f5d1ecb19c21de5d32e971746c55ff26795da827f48a00a4fdda45a20897498d320669538f87e2d6f123f8e257dc770a8efd91a32a6f4b74d7479f836744839f8bfe01b9ac5714",
+        "7ac5ebf46e4d58ee62f4f50796d52201dd3d4dfe8d8235c845d9cd1a95feb4e0203439cd3da9acfbe7b80635b4270f00df99613185bc182d2d267937c25cad79f6097c20254ec5d96cf94e5622d97dbaf969af7fc89085f8692131c3489ca700",
+    ],
+    proof: &[
+        "36fe23e492f3f3318915a5cb0bdc7fe274c0cb7d753bf351375b365343447cc92c7fe3da774a8a3a9e3c94fc66d68e05e5b3f89e6a5a72db9d7ba75fc803e852535487fd8df42a6608d403667164aebcaa402d2469ccbafe5a56289bb250dc14",
+        "7db03f18dd1a5d823a8b5570329a3c9762e6e713eaea24111c0862a57984acebd42a022845696e59adfe954a55e3b5094d0dc820fea7f77b5275d42b8e838a786efc62cc345079145608ef3e52be93c1e1be50697fa1483b54784f906a7c8211d6dd9a064885cbe4dfcaeab14583c039ba9da84b621228f070ef8ce9c18093b04fe0d82e99b058e5b7c0bf0671858c149154d9504b179971413eeede4da517a4ef9b56baed852911fe9a020b20e5277b0f132977c4f27217583c1fab4e3c1114",
+        "c0d76ec8679216c7de15b3a8e60bb98821905d505f93ce22aec3a10d797e6ae355c239deae4f686b0f1e3cb092facc1357b8022a626fcafd47897f93bd9c0ba073909a49fd2e90c1b00a8255696bc139d67b5d8af558a951bb9001ebb11c850c",
+    ],
+    public_inputs: &[
+        "074a925702c55c733b2c5c5f07c50d30291a562052c454f255b9167c9e0e7054",
+    ],
+};
+
+const BN254: HexFixture = HexFixture {
+    vk: &[
+        "1d640c4faee6060b16609021f1587cd2019c0dfed76d52c9f310c483efeaf40b521db40edb240008a9ac6979806dc86792fc0f588a7a14e3912b19fdb9591c02",
+        "c1866edb06a8232713d9c1bc0bbe147481abe8ca6e9811fca238b1a36c182c24c94767c5e40ef4e9e956e3e71b39a5b61ff6076729ad638de48a752972f3e10d1b86717c299a8c35eed1dc02ca6243379c1e831b90aced81a13f706254238116545264419787cefdb98e09bd1fa972a23e0764de300fe1e7b723a53d1364da01",
+        "9bfb8ac55c02adb7abc19ce23bb3de987c28e2f2b94be0cb3331b37db8b5ae20189cc645c9eb4d8f5eb26bfd3f3e2bd1409348db7cbda23e3610830ce4b04a251189f366d3fe8cfac9beb912c4b86dfb02713ecfa2d2938a71eb3c6394df1921001f64a936e5756bd407a317458a201b70d36dcc6eabdeeab5a9da609a15be0f",
+        "35d0d1381d6c8d549d72adea78520cf2fa4c6124284306e65bbc155ea9874a0bdb7276b77585ab3bcd36b647eca67e81a895eca1bc735affd4389ff9e86275135b4077d2fe860827033d8226b7c1888ed66812f87c9d9c2d949d3e3d38191c1d8a313b9b49585ba54c5357d9b5a14d7b9e625d1b479d642aa24ff9fa22f2af02",
+    ],
+    gamma_abc: &[
+        "a3b5be3bf3df62f4dccced0262812004b66dc41949c6ff6f5d17cee3ac99011198f2753b0e01f827818ce31c32d87f65b135ce97ae6ca122357df744d2fa5c2b",
+        "da8cdcacdb9a5699c2cc5b945346b6cc85a020d081c158bbbdf0b2f5366a1923dee58811a2192b62b0b50461ab3b037fbe42a7c424cd0d47b1ec7795d960d42e",
+    ],
+    proof: &[
+        "b70f54aed24a7958caf2bd81b77d02a01a467720b4f023b3e519afe745eb550ba2bb65199201ce6747d78aaa4467581a32464e750819879de466afd63d7ada21",
+        "28b4df114f6deb8ddce6c09c14fe8f72cee9cc5177b8ff5570c70416bc6ccf282ad7f95608c1ce062b322a49f0fcfe245873c88a1e9c366671e5b431204e2f1b94446963d3a0630e1c948f0aaa2e32a31a8e50f93c8f4aa431490327e3600f13142b71e7bc24650ebf0735632fe2d4cf6875a597c7da9289095282ff9cdb8806",
+        "62281b05fd5cb9293f466046360fa500db52d8bf634b00b48715676a8288dc0697c6d4b34813aa8602d08e85bf98d799d42461ad812659bafa3bc9506ad5fd2d",
+    ],
+    public_inputs: &[
+        "62cf768e17a5d24581499a13e417dca514b3aa841e303af24e9445819e0c8607",
+    ],
+};
+
+const BW6_761: HexFixture = HexFixture {
+    vk: &[
+        "6876ecb3e0de57987e1b4f9042dbe2616b46df219772ff99008a706b065337ff6b7ecb0d3fab7230c00090d156501eb9204a6ffc25e9894a55730ba01349b92831bb31c6e19f82510a7f7123757b60ed54b41aea472d1bf21da1952fcd369f00bb462ef79dabc3a56bf9bcf962ddcda67c5d3ad52cbb3683c0cdb24d6dd05845a8687f9f8c0563e2f813db479c06949287bb92f5de6dcfb630b3c5a9538367d32a695b8bb195f25fbad7d52de8bbe8f23363aee586edd566947521accad91200",
+        "bec65ba08f1ade9b56380608acc77277d6615109527879cf97a3b16daff4f2b750dfc7c4b4f5b5ce4c877306717decde5c1d737f7e4c2921a514ed6fdef551e5229a72f1e8ad5b3c3fd5eb0bbe9173b8b8ee15274938ec92708a5813ee6118014b83477281409604984b8ef04b0168885f5df13b379095914d8fc96d9f6a262e204c03444304b2706ad852a63a3faccb5827e844b2568ff8214e0b65cffa3e0e5d13d20b53d83f6158c83dc25fa3ef3c8074063532ee87961b47b344d8b1d400",
+        "75eed8fc938679adbca44e2be0cdc13d6d8fb01f338e15a17ad4d0cc2829879ab00776ba14dc0cde5891a0be12c9ea143c9cc504e9ad4d2a9b4bbaa86befd196729115fb15cb6d8d9b2db36db44bf49baf78cd5a4603cb71c2c208de30dfb600412c089a3aef0cd04e510463cbe9e638b8c1ee37b7c50d84337f7b1e9a8ac92ca909c55034d34bd6940dd51d0053e521e336f30e7be06a097dd6af8bd863023a6a1bffa6c71747b7688af21e9c8f258aa4c6b53d34288bcb1bade0eb88bd6d00",
+        "e50cfd94331294e28b86b2817403674471adaf251e2fe0b25595e4bc4858940486b3b0b388e6c8c7afc1ac35d60488999141ff64e1fe01449c86c28aaa300a34b0db8f8d23b18a5a703c76789414880607e04168c3fb45c88dc08327993e85007c75dbc28514d883ee46e17df05faa03b800e2d8b74ebcc819f2afc2bd8fc3904d8cc7b320152f565e689f157c0d3c8bb9502d60c634e8d6c67df2d3d3a243fc0fc9d9aa9d0bb5bdb64fbad9a944932d86d8f30ee75472a0dce0e36db28a9300",
+    ],
+    gamma_abc: &[
+        "7befeac869268819772a2583d4ea3249f5546efed55ff78800f196e96a9c4b18c8437e283d56953eda2a75923b3150346d568f98739c40f23b49b01b3eda31fa6f491bf0c3d85e449558eaf38efed742fdd693b9a80484ca1775a9595cd62b00f6dfe6d886b2dc2f9283bb1afec77a2a2d8b86011f8cfe6e365d11af18e1aee3df63828c00160de1a2edb1ae508b83b2c443cde3bcb800dd0d88b2cf46e7fec229e13d9101201522575b084f456ee37ca191d213289b7346f71a92ba7ad9ad00",
+        "d8d00d199e6d7128fc3c6a91c279a8c8f1f3effa77c560744d481a81d47f20e6cdd0ed61d24dab3216299461e39682a05d07eb03524b16ae30ff94b73a90cea30b7ef66067dfd420d1ca8174702ba6569045a40b9dc01a4f6a75db83a5ae5c00cfb967d453ae42bc04715e93539130c39312b27a44ec81c78b652462106809959dc61c1610bde9220027305955f1d99884bbb6e3732ab6d5bc50fa9d92346383a1cc54ce2b7f7cc992a37fd60588fecca7eb3dc724849c01155e038c3173a600",
+    ],
+    proof: &[
+        "5fb7e4f00aab76f4619d8fe7bf729a62323f2a5468b1c639c1289fa28b63c72204f6a3faf5117564008c86aeedcb58004804cc2dd5b6f9816f211f114e7474d051401fb5faf8a750ac1995d8688140a92112c8289f25c1dd71df6d0750247a005dbc1c4fc446d3fbbbae12065fdb996d7962ec21e2f3fff93723433f58be0785409d0533cc50082a7da082845d5cce320019f0c7f77952272e605793308fae539e532b2119e6115664923ef371bdca21e5d3c6e30c2033d23823622b5ebdf800",
+        "173a96f07c26fef3516467343dd8f11e9e88cded7b8853182786b68cc6e7fa3abb6e487c821e94f4b755b1ca57794c58f09ad485278f62bea1c68b7be5b11c16a94052c6f593a25c97cb2d149180c7e739e8e4065f305c1feb44998ae9d287003856bb1e1201c6f36fca15e76f2629e8a1fe12075f49b0ca85417bff8f553eb1a08b8625737c0a93f858f2a9bf5bb5fe655252b2135a526c0a6b467603aeacce7b35a43e74baf04ae6f7a27b32031be657a41106695e8a8753777d11da235d00",
+        "4ca5cc896569b80e4c7e6a2fdae66225b847f443bc672c776f58ad2d4cc1ceb8b5aa181dd9b1c94d6e926b426be8623cf7098a2c19d21e8bfa87366a9f7552cbd5af9ca4a2a0a7487059b616b0d0c53a10c7344616857616d3b5268d4a992f00753dfc4da5ba301dde5b814630824312c8e27c55fbfa6f72687d7cb2cf63273c2ecd6c7847ed716b30f76dde00172d5a74636bcc2fd755a8fd17b4a7b286c9d50dfeac716b8a393ba5ca85c93d84ca362cbe7ef3c067e7ac3634f7ee07ab8b00",
+    ],
+    public_inputs: &[
+        "5eb9d45db3f4c9034dc59fcff1e6a7978b97bd90630cc6b6bb4e747950cc3b92ca0b2275d4509f0845cd5aa7c32b8001",
+    ],
+};
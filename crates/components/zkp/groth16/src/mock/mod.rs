@@ -0,0 +1,206 @@
+//! Off-chain fallback of the curve chain extension for the tests.
+//!
+//! The pairing checks of the groth16 verifier are done by the curve functions
+//! of the chain, which the ink! off-chain environment can not call. With the
+//! `mock` feature, `register_curve_extension` registers the add, mul and
+//! pairing functions of all the supported curves and the debug function, computed by the arkworks,
+//! so the proofs can be verified in `cargo test`:
+//!
+//! ```ignore
+//! #[ink::test]
+//! fn verify_works() {
+//!     metis_groth16::mock::register_curve_extension();
+//!
+//!     let fixture = metis_groth16::mock::fixture(Curve::Bn254);
+//!     ...
+//! }
+//! ```
+//!
+//! The functions are the same as the `CurveExtension` of the `patra_env`,
+//! the func id is the id of the curve plus the op, the input and the output
+//! are SCALE encoded as the args and the return value.
+//!
+//! NOTE: The extensions are registered in the off-chain environment, so the
+//! `register_curve_extension` should be called in each `#[ink::test]`.
+
+mod fixtures;
+
+pub use fixtures::{
+    fixture,
+    fixture_source,
+    generate as generate_fixture,
+    Fixture,
+};
+
+use crate::Curve;
+use ark_ec::{
+    AffineCurve,
+    PairingEngine,
+    ProjectiveCurve,
+};
+use ark_ff::{
+    One,
+    PrimeField,
+};
+use ark_serialize::{
+    CanonicalDeserialize,
+    CanonicalSerialize,
+};
+use ink_env::test;
+use patra_env::{
+    CurveError,
+    Scalar,
+    G1,
+    G2,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+/// The curves supported by the groth16 verifier.
+pub const CURVES: [Curve; 4] = [
+    Curve::Bls12_377,
+    Curve::Bls12_381,
+    Curve::Bn254,
+    Curve::Bw6_761,
+];
+
+/// The op of the curve functions, added to the curve id as the func id.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Op {
+    Add = 0x00,
+    Mul = 0x01,
+    Pairing = 0x02,
+}
+
+/// The func id of the debug function, which returns the input.
+pub const DEBUG_FUNC_ID: u32 = 0x2a;
+
+/// A curve function computed by the arkworks, from the SCALE encoded args
+/// to the SCALE encoded return value.
+type CurveFn = fn(&[u8]) -> Result<Vec<u8>, CurveError>;
+
+/// The off-chain curve function registered by the func id.
+struct CurveExtension {
+    func_id: u32,
+    func: CurveFn,
+}
+
+impl test::ChainExtension for CurveExtension {
+    fn func_id(&self) -> u32 {
+        self.func_id
+    }
+
+    fn call(&mut self, input: &[u8], output: &mut Vec<u8>) -> u32 {
+        match (self.func)(input) {
+            Ok(res) => {
+                output.extend_from_slice(&res);
+                0
+            }
+            Err(err) => err.status_code(),
+        }
+    }
+}
+
+/// Returns the func id of the `op` on the `curve`.
+pub fn func_id(curve: Curve, op: Op) -> u32 {
+    curve.id() + op as u32
+}
+
+/// Register the off-chain curve functions of all the supported curves and
+/// the debug function.
+pub fn register_curve_extension() {
+    test::register_chain_extension(CurveExtension {
+        func_id: DEBUG_FUNC_ID,
+        func: debug,
+    });
+
+    for curve in CURVES.iter() {
+        let funcs: [(Op, CurveFn); 3] = match curve {
+            Curve::Bls12_377 => ops::<ark_bls12_377::Bls12_377>(),
+            Curve::Bls12_381 => ops::<ark_bls12_381::Bls12_381>(),
+            Curve::Bn254 => ops::<ark_bn254::Bn254>(),
+            Curve::Bw6_761 => ops::<ark_bw6_761::BW6_761>(),
+        };
+
+        for (op, func) in funcs.iter() {
+            test::register_chain_extension(CurveExtension {
+                func_id: func_id(*curve, *op),
+                func: *func,
+            });
+        }
+    }
+}
+
+fn ops<E: PairingEngine>() -> [(Op, CurveFn); 3] {
+    [
+        (Op::Add, add::<E>),
+        (Op::Mul, mul::<E>),
+        (Op::Pairing, pairing::<E>),
+    ]
+}
+
+/// Returns the input.
+fn debug(input: &[u8]) -> Result<Vec<u8>, CurveError> {
+    let input = decode::<Vec<u8>>(input)?;
+    Ok(input.encode())
+}
+
+/// Returns the sum of two G1 points.
+fn add<E: PairingEngine>(input: &[u8]) -> Result<Vec<u8>, CurveError> {
+    let (a, b) = decode::<(G1, G1)>(input)?;
+    let a = deserialize::<E::G1Affine>(&a.0)?;
+    let b = deserialize::<E::G1Affine>(&b.0)?;
+
+    let sum = (a.into_projective() + b.into_projective()).into_affine();
+    Ok(G1(serialize(&sum).ok_or(CurveError::InvalidOutput)?).encode())
+}
+
+/// Returns the product of a G1 point and a scalar.
+fn mul<E: PairingEngine>(input: &[u8]) -> Result<Vec<u8>, CurveError> {
+    let (point, scalar) = decode::<(G1, Scalar)>(input)?;
+    let point = deserialize::<E::G1Affine>(&point.0)?;
+    let scalar = deserialize::<E::Fr>(&scalar.0)?;
+
+    let product = point.mul(scalar.into_repr()).into_affine();
+    Ok(G1(serialize(&product).ok_or(CurveError::InvalidOutput)?).encode())
+}
+
+/// Returns whether the product of the pairings of the (G1, G2) pairs is one.
+fn pairing<E: PairingEngine>(input: &[u8]) -> Result<Vec<u8>, CurveError> {
+    let pairs = decode::<Vec<(G1, G2)>>(input)?;
+
+    let mut prepared = Vec::new();
+    for (g1, g2) in pairs.iter() {
+        let g1 = deserialize::<E::G1Affine>(&g1.0)?;
+        let g2 = deserialize::<E::G2Affine>(&g2.0)?;
+        prepared.push((g1.into(), g2.into()));
+    }
+
+    let product = E::product_of_pairings(prepared.iter());
+    Ok(product.is_one().encode())
+}
+
+/// Decode the SCALE encoded args of the curve function.
+fn decode<T: Decode>(input: &[u8]) -> Result<T, CurveError> {
+    T::decode(&mut &input[..]).map_err(|_| CurveError::InvalidInput)
+}
+
+/// Deserialize the uncompressed point or the scalar by the arkworks.
+fn deserialize<T: CanonicalDeserialize>(mut bytes: &[u8]) -> Result<T, CurveError> {
+    let value =
+        T::deserialize_uncompressed(&mut bytes).map_err(|_| CurveError::InvalidInput)?;
+    if !bytes.is_empty() {
+        return Err(CurveError::InvalidInput)
+    }
+
+    Ok(value)
+}
+
+/// Serialize the point or the scalar by the arkworks, uncompressed.
+pub(crate) fn serialize<T: CanonicalSerialize>(value: &T) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    value.serialize_uncompressed(&mut bytes).ok()?;
+    Some(bytes)
+}
@@ -0,0 +1,52 @@
+use crate::types::{
+    VerifyingKey,
+    VerifyingKeyId,
+};
+
+#[cfg(not(feature = "ink-as-dependency"))]
+use ::ink_storage::{
+    collections::HashMap as StorageHashMap,
+    traits::SpreadLayout,
+};
+
+/// The Data of groth16 component
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Data {
+    /// Mapping from the id to the verifying key
+    verifying_keys: StorageHashMap<VerifyingKeyId, VerifyingKey>,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self {
+            verifying_keys: StorageHashMap::new(),
+        }
+    }
+}
+
+impl Data {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the verifying key of `id`.
+    pub fn get(&self, id: &VerifyingKeyId) -> Option<&VerifyingKey> {
+        self.verifying_keys.get(id)
+    }
+
+    /// Returns whether the verifying key of `id` has been registered.
+    pub fn contains(&self, id: &VerifyingKeyId) -> bool {
+        self.verifying_keys.contains_key(id)
+    }
+
+    /// Sets the verifying key of `id`.
+    pub fn insert(&mut self, id: VerifyingKeyId, vk: VerifyingKey) {
+        self.verifying_keys.insert(id, vk);
+    }
+
+    /// Removes the verifying key of `id`.
+    pub fn remove(&mut self, id: &VerifyingKeyId) -> Option<VerifyingKey> {
+        self.verifying_keys.take(id)
+    }
+}
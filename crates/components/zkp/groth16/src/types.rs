@@ -0,0 +1,96 @@
+use ink_prelude::vec::Vec;
+use scale::{
+    Decode,
+    Encode,
+};
+
+use ink_storage::traits::{
+    PackedLayout,
+    SpreadLayout,
+};
+
+#[cfg(feature = "std")]
+use ink_storage::traits::StorageLayout;
+
+#[cfg(feature = "std")]
+use scale_info::TypeInfo;
+
+/// The id of the registered verifying keys.
+pub type VerifyingKeyId = u32;
+
+/// The curves supported by the groth16 verifier.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo, StorageLayout))]
+#[derive(SpreadLayout, PackedLayout)]
+pub enum Curve {
+    Bls12_377,
+    Bls12_381,
+    Bn254,
+    Bw6_761,
+}
+
+impl Curve {
+    /// The id of the curve, the func ids of the curve ops are from it.
+    pub fn id(&self) -> u32 {
+        match self {
+            Curve::Bls12_377 => 0x00,
+            Curve::Bls12_381 => 0x10,
+            Curve::Bn254 => 0x20,
+            Curve::Bw6_761 => 0x30,
+        }
+    }
+
+    /// The size of a serialized (uncompressed) G1 point.
+    pub fn g1_size(&self) -> usize {
+        match self {
+            Curve::Bls12_377 | Curve::Bls12_381 => 96,
+            Curve::Bn254 => 64,
+            Curve::Bw6_761 => 192,
+        }
+    }
+
+    /// The size of a serialized (uncompressed) G2 point.
+    pub fn g2_size(&self) -> usize {
+        match self {
+            Curve::Bls12_377 | Curve::Bls12_381 => 192,
+            Curve::Bn254 => 128,
+            Curve::Bw6_761 => 192,
+        }
+    }
+}
+
+/// A groth16 verifying key, the points are serialized by the arkworks
+/// (uncompressed).
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo, StorageLayout))]
+#[derive(SpreadLayout, PackedLayout)]
+pub struct VerifyingKey {
+    /// The curve of the key.
+    pub curve: Curve,
+    /// The `alpha_g1`, `beta_g2`, `gamma_g2` and `delta_g2` of the key.
+    pub vk: Vec<u8>,
+    /// The `gamma_abc_g1` of the key, one more than the public inputs.
+    pub gamma_abc: Vec<Vec<u8>>,
+}
+
+/// The Errors from groth16 verifier
+#[derive(Encode, Decode, Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "std", derive(TypeInfo))]
+pub enum Error {
+    /// No verifying key registered by the id.
+    VerifyingKeyNotFound,
+    /// A verifying key has been registered by the id.
+    VerifyingKeyExists,
+    /// The verifying key has no `gamma_abc` or the points are not in the
+    /// sizes of the curve.
+    InvalidVerifyingKey,
+    /// The proof is not in the size of the curve.
+    InvalidProof,
+    /// The number of the public inputs does not match the verifying key.
+    InvalidPublicInputsLength,
+    /// The curve ops failed, such as the points can not be deserialized.
+    CurveFailed,
+}
+
+/// The Result of groth16 verifier
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,182 @@
+//! Groth16 verification by the curve ops of the chain extension, the points
+//! are serialized (uncompressed) by the arkworks, see the `CurveExtension` of
+//! the `patra_env`.
+//!
+//! The proof `(a, b, c)` is valid if
+//! `e(a, b) * e(-alpha, beta) * e(-acc, gamma) * e(-c, delta) == 1`, where
+//! `acc = gamma_abc[0] + sum(public_inputs[i] * gamma_abc[i + 1])`.
+
+use crate::{
+    ChainExtension,
+    Curve,
+    Error,
+    Result,
+    VerifyingKey,
+};
+use ink_prelude::{
+    vec,
+    vec::Vec,
+};
+use patra_env::{
+    Scalar,
+    G1,
+    G2,
+};
+
+/// The flag of the y of the uncompressed point, set if it is the point at
+/// infinity.
+const POINT_AT_INFINITY: u8 = 1 << 6;
+
+/// The moduli of the base fields of the curves, in little endian.
+const BLS12_377_MODULUS: [u8; 48] = [
+    0x01, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x08, 0x85, 0x00, 0x00, 0x00, 0x30, 0x44, 0x5d,
+    0x0b, 0x17, 0x00, 0x48, 0x09, 0xba, 0x2f, 0x62, 0xf3, 0x1e, 0x8f, 0x13, 0xf5, 0x00,
+    0xf3, 0xd9, 0x22, 0x1a, 0x3b, 0x49, 0xa1, 0x6c, 0xc0, 0x05, 0x3b, 0xc6, 0xea, 0x10,
+    0xc5, 0x17, 0x46, 0x3a, 0xae, 0x01,
+];
+const BLS12_381_MODULUS: [u8; 48] = [
+    0xab, 0xaa, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xb9, 0xff, 0xff, 0x53, 0xb1, 0xfe, 0xff,
+    0xab, 0x1e, 0x24, 0xf6, 0xb0, 0xf6, 0xa0, 0xd2, 0x30, 0x67, 0xbf, 0x12, 0x85, 0xf3,
+    0x84, 0x4b, 0x77, 0x64, 0xd7, 0xac, 0x4b, 0x43, 0xb6, 0xa7, 0x1b, 0x4b, 0x9a, 0xe6,
+    0x7f, 0x39, 0xea, 0x11, 0x01, 0x1a,
+];
+const BN254_MODULUS: [u8; 32] = [
+    0x47, 0xfd, 0x7c, 0xd8, 0x16, 0x8c, 0x20, 0x3c, 0x8d, 0xca, 0x71, 0x68, 0x91, 0x6a,
+    0x81, 0x97, 0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8, 0x29, 0xa0, 0x31, 0xe1,
+    0x72, 0x4e, 0x64, 0x30,
+];
+const BW6_761_MODULUS: [u8; 96] = [
+    0x8b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9d, 0xf4, 0x82, 0x00, 0x00, 0x70, 0x68, 0x3e,
+    0x91, 0xe6, 0x37, 0xa4, 0xf0, 0xea, 0xae, 0xf8, 0x0c, 0x16, 0xf8, 0xa8, 0x67, 0x56,
+    0xc2, 0x16, 0xa1, 0x98, 0x2e, 0xff, 0xeb, 0x73, 0xdc, 0xd3, 0xdc, 0x71, 0x90, 0xfd,
+    0xf9, 0x12, 0xed, 0xc8, 0x89, 0x86, 0x04, 0x23, 0xb4, 0x25, 0xff, 0xba, 0xce, 0x03,
+    0x19, 0xe9, 0x84, 0xe5, 0x38, 0xa6, 0x7b, 0x70, 0x41, 0xbe, 0x87, 0x80, 0xef, 0x75,
+    0x82, 0x52, 0x88, 0x46, 0xd1, 0x81, 0x6a, 0x18, 0x26, 0xb9, 0x3e, 0xff, 0xfa, 0x04,
+    0x40, 0xc9, 0x87, 0xd1, 0x0a, 0xce, 0x83, 0xfb, 0x24, 0xe8, 0x22, 0x01,
+];
+
+/// Returns the modulus of the base field of the `curve`, in little endian.
+fn modulus(curve: Curve) -> &'static [u8] {
+    match curve {
+        Curve::Bls12_377 => &BLS12_377_MODULUS,
+        Curve::Bls12_381 => &BLS12_381_MODULUS,
+        Curve::Bn254 => &BN254_MODULUS,
+        Curve::Bw6_761 => &BW6_761_MODULUS,
+    }
+}
+
+/// Returns whether the points of the `vk` are in the sizes of its curve.
+pub fn is_valid_verifying_key(vk: &VerifyingKey) -> bool {
+    let curve = vk.curve;
+    !vk.gamma_abc.is_empty()
+        && vk.vk.len() == curve.g1_size() + 3 * curve.g2_size()
+        && vk
+            .gamma_abc
+            .iter()
+            .all(|point| point.len() == curve.g1_size())
+}
+
+/// Verify the `proof` of the `public_inputs` by the `vk`.
+pub fn verify<C: ChainExtension + ?Sized>(
+    vk: &VerifyingKey,
+    proof: &[u8],
+    public_inputs: &[Vec<u8>],
+) -> Result<bool> {
+    let curve = vk.curve;
+    let (g1, g2) = (curve.g1_size(), curve.g2_size());
+
+    if !is_valid_verifying_key(vk) {
+        return Err(Error::InvalidVerifyingKey)
+    }
+    if public_inputs.len() + 1 != vk.gamma_abc.len() {
+        return Err(Error::InvalidPublicInputsLength)
+    }
+    if proof.len() != 2 * g1 + g2 {
+        return Err(Error::InvalidProof)
+    }
+
+    let (alpha, rest) = vk.vk.split_at(g1);
+    let (beta, rest) = rest.split_at(g2);
+    let (gamma, delta) = rest.split_at(g2);
+
+    let (a, rest) = proof.split_at(g1);
+    let (b, c) = rest.split_at(g2);
+
+    let mut acc = G1(vk.gamma_abc[0].clone());
+    for (input, point) in public_inputs.iter().zip(vk.gamma_abc[1..].iter()) {
+        let product = mul::<C>(curve, G1(point.clone()), Scalar(input.clone()))?;
+        acc = add::<C>(curve, acc, product)?;
+    }
+
+    pairing::<C>(
+        curve,
+        vec![
+            (G1(a.to_vec()), G2(b.to_vec())),
+            (neg_g1(curve, alpha), G2(beta.to_vec())),
+            (neg_g1(curve, &acc.0), G2(gamma.to_vec())),
+            (neg_g1(curve, c), G2(delta.to_vec())),
+        ],
+    )
+}
+
+/// Returns the negation of an uncompressed G1 point `(x, y)`, which is
+/// `(x, p - y)`, the point at infinity is the negation of itself.
+fn neg_g1(curve: Curve, point: &[u8]) -> G1 {
+    let mut res = point.to_vec();
+    let size = point.len() / 2;
+    let y = &mut res[size..];
+
+    let is_infinity = y.last().map_or(true, |last| last & POINT_AT_INFINITY != 0);
+    if is_infinity || y.iter().all(|byte| *byte == 0) {
+        return G1(res)
+    }
+
+    let mut borrow = 0_u16;
+    for (byte, m) in y.iter_mut().zip(modulus(curve).iter()) {
+        let diff = (*m as u16).wrapping_sub(*byte as u16).wrapping_sub(borrow);
+        *byte = diff as u8;
+        borrow = (diff >> 8) & 1;
+    }
+
+    G1(res)
+}
+
+fn add<C: ChainExtension + ?Sized>(curve: Curve, a: G1, b: G1) -> Result<G1> {
+    let ext = C::extension();
+    match curve {
+        Curve::Bls12_377 => ext.bls12_377_add(a, b),
+        Curve::Bls12_381 => ext.bls12_381_add(a, b),
+        Curve::Bn254 => ext.alt_bn128_add(a, b),
+        Curve::Bw6_761 => ext.bw6_761_add(a, b),
+    }
+    .map_err(|_| Error::CurveFailed)
+}
+
+fn mul<C: ChainExtension + ?Sized>(
+    curve: Curve,
+    point: G1,
+    scalar: Scalar,
+) -> Result<G1> {
+    let ext = C::extension();
+    match curve {
+        Curve::Bls12_377 => ext.bls12_377_mul(point, scalar),
+        Curve::Bls12_381 => ext.bls12_381_mul(point, scalar),
+        Curve::Bn254 => ext.alt_bn128_mul(point, scalar),
+        Curve::Bw6_761 => ext.bw6_761_mul(point, scalar),
+    }
+    .map_err(|_| Error::CurveFailed)
+}
+
+fn pairing<C: ChainExtension + ?Sized>(
+    curve: Curve,
+    pairs: Vec<(G1, G2)>,
+) -> Result<bool> {
+    let ext = C::extension();
+    match curve {
+        Curve::Bls12_377 => ext.bls12_377_pairing(pairs),
+        Curve::Bls12_381 => ext.bls12_381_pairing(pairs),
+        Curve::Bn254 => ext.alt_bn128_pairing(pairs),
+        Curve::Bw6_761 => ext.bw6_761_pairing(pairs),
+    }
+    .map_err(|_| Error::CurveFailed)
+}
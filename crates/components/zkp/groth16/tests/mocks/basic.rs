@@ -0,0 +1,96 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[metis_lang::contract]
+pub mod contract {
+    use ink_prelude::vec::Vec;
+    use metis_groth16 as groth16;
+    pub use metis_groth16::{
+        Curve,
+        Error,
+        Result,
+        VerifyingKey,
+        VerifyingKeyId,
+    };
+    use metis_lang::{
+        import,
+        metis,
+    };
+    use metis_ownable as ownable;
+
+    #[ink(storage)]
+    #[import(groth16, ownable)]
+    pub struct Groth16 {
+        groth16: groth16::Data,
+        ownable: ownable::Data<Groth16>,
+    }
+
+    #[ink(event)]
+    #[metis(groth16)]
+    pub struct VerifyingKeyRegistered {
+        #[ink(topic)]
+        pub vk_id: VerifyingKeyId,
+        pub curve: Curve,
+    }
+
+    #[ink(event)]
+    #[metis(groth16)]
+    pub struct VerifyingKeyRemoved {
+        #[ink(topic)]
+        pub vk_id: VerifyingKeyId,
+    }
+
+    #[ink(event)]
+    #[metis(ownable)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        new_owner: Option<AccountId>,
+    }
+
+    impl groth16::ChainExtension for Groth16 {}
+
+    impl Groth16 {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self {
+                groth16: groth16::Data::new(),
+                ownable: ownable::Data::default(),
+            };
+
+            ownable::Impl::init(&mut instance);
+            instance
+        }
+
+        #[ink(message)]
+        pub fn verifying_key(&self, vk_id: VerifyingKeyId) -> Option<VerifyingKey> {
+            groth16::Impl::verifying_key(self, vk_id)
+        }
+
+        #[ink(message)]
+        pub fn verify(
+            &self,
+            vk_id: VerifyingKeyId,
+            proof: Vec<u8>,
+            public_inputs: Vec<Vec<u8>>,
+        ) -> Result<bool> {
+            groth16::Impl::verify(self, vk_id, proof, public_inputs)
+        }
+
+        #[ink(message)]
+        pub fn register_verifying_key(
+            &mut self,
+            vk_id: VerifyingKeyId,
+            vk: VerifyingKey,
+        ) -> Result<()> {
+            ownable::Impl::ensure_caller_is_owner(self);
+            groth16::Impl::_register_verifying_key(self, vk_id, vk)
+        }
+
+        #[ink(message)]
+        pub fn remove_verifying_key(&mut self, vk_id: VerifyingKeyId) -> Result<()> {
+            ownable::Impl::ensure_caller_is_owner(self);
+            groth16::Impl::_remove_verifying_key(self, vk_id)
+        }
+    }
+}
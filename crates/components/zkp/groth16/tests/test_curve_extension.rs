@@ -0,0 +1,255 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod curve_extension_tests {
+    use ink_lang::{
+        self as ink,
+        ChainExtensionInstance,
+    };
+    use metis_groth16::{
+        mock,
+        CurveError,
+        CurveExtension,
+    };
+    use patra_env::{
+        Scalar,
+        G1,
+        G2,
+    };
+
+    /// The known answers of the curve ops, the points are serialized
+    /// (uncompressed) by the arkworks.
+    struct Vectors {
+        /// A point `g` of the G1
+        g1: &'static str,
+        /// `2 * g`
+        g1_double: &'static str,
+        /// `3 * g`
+        g1_triple: &'static str,
+        /// `-g`
+        g1_neg: &'static str,
+        /// A point `h` of the G2
+        g2: &'static str,
+        /// The scalar `3`
+        scalar: &'static str,
+    }
+
+    const BLS12_377: Vectors = Vectors {
+        g1: "61ad5227d07497da8bd2dca6dfd45c386c4ab04c821c1bd0cfa7d72c44d142812f3962dfa4708bd537afd33c453c6001aa53c8ab1e208e2451e0ab2331884ec0ad49f5ed70f4a94f569f3bd602f809d739c3a203faffd4b7d16a56af44d6e600",
+        g1_double: "e77a3bc5f51cbf039be5a7dfeb15956650870cbff0097889736a30fd6f74620dc71ad602dd0632cb204959763064e300f5ee0bbe51fd258afb675c753abedf9336994e3093eab0c287baf1cb69f257d5e32ac3111684d4f76cc1815032270901",
+        g1_triple: "cd7d541e547d28022b00f4185379e9a8535497458c3ec85370ad19091a0a971b22d576e9ab9aae8a5a8714b4f48410013d23448fa20465cfba029e8b424c44b46c56f2f21d8f5025ad61e9c2097e24cc9f2b4589f6cf5c0ec6890f822933ae01",
+        g1_neg: "61ad5227d07497da8bd2dca6dfd45c386c4ab04c821c1bd0cfa7d72c44d142812f3962dfa4708bd537afd33c453c600157ac3754e19f7a60af1f540c13d5bc5652fe13ccbe6d49cf3874b92af0e118430186fe68c605660e19a66e680164c700",
+        g2: "5d662967392cde97a43b725943ee75870c7799afdc9c0fd6b3eee6c26a8b0f78219a99d64c9231364783621f40c10601c3bb0fa42b8d14c6ea442bea1285f30eb1cfac8446da7b345036657d4cdb0e5af5bb45e675ff55818278aa0f22aa4a01ff8c06eed0461ec37e49a66bc2e423b98e66f4eabcec6116205aaa777b26d96109c994b84c7d4042947dfc7c33743d0128a947ee89db186e6a0d36908f04ea40b45d5b5070f85b1a6827c4df114ce528a023e246f24304434eeae7f04d5a0500",
+        scalar: "0300000000000000000000000000000000000000000000000000000000000000",
+    };
+
+    const BLS12_381: Vectors = Vectors {
+        g1: "be42d32097a6128739a9cfc9b9031d12e4f273cdc6fa8e5d279234ac9d1291106708e4b6322217c8202378cf9083bd0b1d0db5104029637d050a66e4f6ea73636894276d3f620f2bb17912c04336ddbdcd0d958b1259cf1810f06efbc174040f",
+        g1_double: "8a352168b5437329cdda7f5e31d651a666acb2f1486d4fcc4742eeca2b919f4f1274d3ede5756ff8bcee95999a0d8716b97382e88b7843ec92dcb197a67eee25e1c68d8c42814c3c5187ccaa616667bfe69899c8d3ccf43c50f9edcb46a87116",
+        g1_triple: "e21c38485776844158e46c37b553fa45de3599fa95016e5a5b7df52016ab5e0f1937c4a7889ef8faee32c2b1d0e8b8181a019b847f9a59b19f9b3a43ef5a8fb12f9b3bd714ab3c6f2e77ccb448a5ff070abbc06f76716fcafb46e434720ba80e",
+        g1_neg: "be42d32097a6128739a9cfc9b9031d12e4f273cdc6fa8e5d279234ac9d1291106708e4b6322217c8202378cf9083bd0b8e9d4aefbfd69b3cfaf5edcc071538bbbb6189896170213c0e99723341159aa6099fb6b7a34e4c328af6103e289dfc0a",
+        g2: "a7c71a39818e07b7acd0c54b1d72bc5c0b4b828d7630cc5707e985a657ac0557630ba477fe99126d7e046a84ef3dee0c4884a7f2c28169a903d58cc2c6f77d6982acb88ecc7697eba21e1e4fe3f63f255334fee8e30d69d2b0e035948bb4dd18aa08b67ea4498b66c63f663ae43b9e93309449c0a6bbc3448e69003df3fb069eda4cacfce8024bc16c7c14e414d65512ddef2d8356635d0808e159c6b007327a29098194f890e843deeba26ed562934d6eff6ee7133d9abdcfd3f5f197052f10",
+        scalar: "0300000000000000000000000000000000000000000000000000000000000000",
+    };
+
+    const BN254: Vectors = Vectors {
+        g1: "7a044e06fe480df85e7abefa893bb8b6183a85844bba788f8ea132c5c3e742143ba062e04b49b3c4cb2438159fded200332cb95befa5f5f6a87a149c0acc7820",
+        g1_double: "8093f99d34534c28b6af996ba5be1c9144efe9c1c9304c9d4c29ea8498005b0fadbf7d81121edac7a8a6f140e78ff772db1a585757177f59cba3a9666673cc15",
+        g1_triple: "46813e0af5e493551b1c2b2093a6f7ab8d606c439507b31e31858177b541fa026b04fc7d7cbace7bc0a23af753708638d630ddc727d22d3d51be29a405e02414",
+        g1_neg: "7a044e06fe480df85e7abefa893bb8b6183a85844bba788f8ea132c5c3e742140c5d1af8ca426d77c1a53953f28bae962a2cc825c79f5ac180251d456882eb0f",
+        g2: "917bbcaa198ea1783b6455985a4f52f85fdfeb5646e71412a2b160c73a530325c0a4fde1867aed6e417d32ab33a2aa5e3f11ca95c2e43ffcd24fe8bfa8902a1424d06400beac634f6182129bee45124451fa93ee91e10421d43bcee604a5f7076d598db84eb9e6ab1fa888c3ee1e533124c58534273414bb0115afbdecdc1010",
+        scalar: "0300000000000000000000000000000000000000000000000000000000000000",
+    };
+
+    const BW6_761: Vectors = Vectors {
+        g1: "4eb250fa6f55991e0e2092f8d499131183b0ee0afd4c7943552e5330cc963891c5fa78ecf3f88df9b6da90b844c9a24b9759e1bbea9dc12a2a73291d3f5978bca37a1bfa99d1c1de2700e4688e18723dabc12feb45c9eac4d190bceb3157030129c3be447c0bf83b4ba56d09da39989d1a36d555c052b1197bbc49e20b3c6b21c16eee7dfa17131c0a71d925d61d6f8b9c3843bbeb4fc225c66db88f370b09cd57e78d3f0c3dfa7e3c5116fd427affec5cd011de79a891b5fdb18f4b0d0d0400",
+        g1_double: "826c2bc4d8d5b7e1313dafe596a85516371bfeaabe827d24deb72fe52b9046843a2df8c007ecec4f17304e38eff490dfde5e62bdf92eb1ffae3b337c0098c64a0e56098de5a3ea6002112278b8bf02ed514adb7725f7d89c905637b1add402017b8b69ee1e2df08d32a667e570659194818ecbf5469ffa56f9a2935e3bb8db0544c088cae9d57f2a5d86a7af6abc9c99da447ee05e2681682e18d1237d1aa68d829cf36358d5a815c617461ecf163216f7a9cf58e83ae02a8fbea7b1715e6600",
+        g1_triple: "a9c100e89342c5f6a5e5bd2a05042f0ed7e3dd8dbd5b424c69f0b4f44fb5a798376facf82f33276b15aa664e88e4240ed9b9519cdb69a87b6ef708a8178921891528a758fd6620d67a6232d6e01d35a4efd65746553f1287df0c5a41df4221009f410c347410a75a7da86028174ad8546696d4bd0766f6d79bf7f6f802077cf641f16da4b38fcba9c8c3b0d86471f5db29ffc09b6ddae7444d4554433b6567931572c18e86f2b2a0e81f7bb9a8363e28b1f0fdecb909492be014e698baa9d300",
+        g1_neg: "4eb250fa6f55991e0e2092f8d499131183b0ee0afd4c7943552e5330cc963891c5fa78ecf3f88df9b6da90b844c9a24b9759e1bbea9dc12a2a73291d3f5978bca37a1bfa99d1c1de2700e4688e18723dabc12feb45c9eac4d190bceb31570301623d41bb83f4a4b8375b92668e04f9481d6e1b95eea55bfc7cec1d74b6da35776d90fdf5e1bbc955868c20ed16ab1afb67ea706a136b0cde527bcc55019b72a3e9d6f940e33888d34bf5ba84279e26cce12ee926c620f61b0d1cf4af17db1e01",
+        g2: "0ba7fafa0a1916430018d1714a3e83396cc5efd01a43a264fadf3bde48f3d383f2025fcbaecd3f90847ebbbbe07e32a12e03baece651f6930d70a151bb422d56b22ee170b99aa3b6811654276992a7d2fa8f901624729d2d1328e77b6f840d01e2f8ab4b6f7eaf162ae582d8fc2a90d8a6b61f8b8b31fe6355ef6b3fc430c36dbd0958c2c73070df308bface3e9f7dba3c3bef4d08e3673ddb115ee56106fdb8ef36626e9cda28db93b554b8cfa2beb267cc52b6d0d0a18424bb294cf11de900",
+        scalar: "030000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+    };
+
+    /// The instance to call the curve ops, each call consumes an instance.
+    fn ext() -> <CurveExtension as ChainExtensionInstance>::Instance {
+        <CurveExtension as ChainExtensionInstance>::instantiate()
+    }
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("invalid hex"))
+            .collect()
+    }
+
+    fn g1(hex: &str) -> G1 {
+        G1(decode_hex(hex))
+    }
+
+    fn g2(hex: &str) -> G2 {
+        G2(decode_hex(hex))
+    }
+
+    fn scalar(hex: &str) -> Scalar {
+        Scalar(decode_hex(hex))
+    }
+
+    #[ink::test]
+    fn bls12_377_should_work() {
+        mock::register_curve_extension();
+        let v = &BLS12_377;
+
+        assert_eq!(
+            ext().bls12_377_add(g1(v.g1), g1(v.g1_double)),
+            Ok(g1(v.g1_triple))
+        );
+        assert_eq!(
+            ext().bls12_377_mul(g1(v.g1), scalar(v.scalar)),
+            Ok(g1(v.g1_triple))
+        );
+        assert_eq!(
+            ext().bls12_377_pairing(vec![(g1(v.g1), g2(v.g2)), (g1(v.g1_neg), g2(v.g2))]),
+            Ok(true)
+        );
+        assert_eq!(
+            ext().bls12_377_pairing(vec![(g1(v.g1), g2(v.g2)), (g1(v.g1), g2(v.g2))]),
+            Ok(false)
+        );
+
+        // the point has the bytes not deserialized
+        let mut point = g1(v.g1);
+        point.0.push(0);
+        assert_eq!(
+            ext().bls12_377_add(point, g1(v.g1_double)),
+            Err(CurveError::InvalidInput)
+        );
+        // the point is not on the curve
+        let mut point = g1(v.g1);
+        point.0[0] ^= 0x01;
+        assert_eq!(
+            ext().bls12_377_mul(point, scalar(v.scalar)),
+            Err(CurveError::InvalidInput)
+        );
+    }
+
+    #[ink::test]
+    fn bls12_381_should_work() {
+        mock::register_curve_extension();
+        let v = &BLS12_381;
+
+        assert_eq!(
+            ext().bls12_381_add(g1(v.g1), g1(v.g1_double)),
+            Ok(g1(v.g1_triple))
+        );
+        assert_eq!(
+            ext().bls12_381_mul(g1(v.g1), scalar(v.scalar)),
+            Ok(g1(v.g1_triple))
+        );
+        assert_eq!(
+            ext().bls12_381_pairing(vec![(g1(v.g1), g2(v.g2)), (g1(v.g1_neg), g2(v.g2))]),
+            Ok(true)
+        );
+        assert_eq!(
+            ext().bls12_381_pairing(vec![(g1(v.g1), g2(v.g2)), (g1(v.g1), g2(v.g2))]),
+            Ok(false)
+        );
+
+        // the point has the bytes not deserialized
+        let mut point = g1(v.g1);
+        point.0.push(0);
+        assert_eq!(
+            ext().bls12_381_add(point, g1(v.g1_double)),
+            Err(CurveError::InvalidInput)
+        );
+        // the point is not on the curve
+        let mut point = g1(v.g1);
+        point.0[0] ^= 0x01;
+        assert_eq!(
+            ext().bls12_381_mul(point, scalar(v.scalar)),
+            Err(CurveError::InvalidInput)
+        );
+    }
+
+    #[ink::test]
+    fn alt_bn128_should_work() {
+        mock::register_curve_extension();
+        let v = &BN254;
+
+        assert_eq!(
+            ext().alt_bn128_add(g1(v.g1), g1(v.g1_double)),
+            Ok(g1(v.g1_triple))
+        );
+        assert_eq!(
+            ext().alt_bn128_mul(g1(v.g1), scalar(v.scalar)),
+            Ok(g1(v.g1_triple))
+        );
+        assert_eq!(
+            ext().alt_bn128_pairing(vec![(g1(v.g1), g2(v.g2)), (g1(v.g1_neg), g2(v.g2))]),
+            Ok(true)
+        );
+        assert_eq!(
+            ext().alt_bn128_pairing(vec![(g1(v.g1), g2(v.g2)), (g1(v.g1), g2(v.g2))]),
+            Ok(false)
+        );
+
+        // the point has the bytes not deserialized
+        let mut point = g1(v.g1);
+        point.0.push(0);
+        assert_eq!(
+            ext().alt_bn128_add(point, g1(v.g1_double)),
+            Err(CurveError::InvalidInput)
+        );
+        // the point is not on the curve
+        let mut point = g1(v.g1);
+        point.0[0] ^= 0x01;
+        assert_eq!(
+            ext().alt_bn128_mul(point, scalar(v.scalar)),
+            Err(CurveError::InvalidInput)
+        );
+    }
+
+    #[ink::test]
+    fn bw6_761_should_work() {
+        mock::register_curve_extension();
+        let v = &BW6_761;
+
+        assert_eq!(
+            ext().bw6_761_add(g1(v.g1), g1(v.g1_double)),
+            Ok(g1(v.g1_triple))
+        );
+        assert_eq!(
+            ext().bw6_761_mul(g1(v.g1), scalar(v.scalar)),
+            Ok(g1(v.g1_triple))
+        );
+        assert_eq!(
+            ext().bw6_761_pairing(vec![(g1(v.g1), g2(v.g2)), (g1(v.g1_neg), g2(v.g2))]),
+            Ok(true)
+        );
+        assert_eq!(
+            ext().bw6_761_pairing(vec![(g1(v.g1), g2(v.g2)), (g1(v.g1), g2(v.g2))]),
+            Ok(false)
+        );
+
+        // the point has the bytes not deserialized
+        let mut point = g1(v.g1);
+        point.0.push(0);
+        assert_eq!(
+            ext().bw6_761_add(point, g1(v.g1_double)),
+            Err(CurveError::InvalidInput)
+        );
+        // the point is not on the curve
+        let mut point = g1(v.g1);
+        point.0[0] ^= 0x01;
+        assert_eq!(
+            ext().bw6_761_mul(point, scalar(v.scalar)),
+            Err(CurveError::InvalidInput)
+        );
+    }
+
+    #[ink::test]
+    fn debug_should_return_input() {
+        mock::register_curve_extension();
+
+        assert_eq!(ext().debug(vec![1, 2, 3]), Ok(vec![1, 2, 3]));
+        assert_eq!(ext().debug(Vec::new()), Ok(Vec::new()));
+    }
+}
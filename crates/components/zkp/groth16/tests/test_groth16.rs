@@ -0,0 +1,218 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mocks {
+    pub mod basic;
+}
+
+mod groth16_tests {
+    /// Imports all the definitions from the outer scope so we can use them here.
+    use super::*;
+    use ink_lang as ink;
+    use metis_groth16::mock;
+    use metis_test::*;
+    use mocks::basic::contract::*;
+
+    /// A verifying key for two public inputs, the points are not checked
+    /// until the pairing.
+    fn vk(curve: Curve) -> VerifyingKey {
+        let (g1, g2) = (curve.g1_size(), curve.g2_size());
+        VerifyingKey {
+            curve,
+            vk: vec![0x01; g1 + 3 * g2],
+            gamma_abc: vec![vec![0x02; g1], vec![0x03; g1], vec![0x04; g1]],
+        }
+    }
+
+    #[ink::test]
+    fn curve_id_should_match_zkmega() {
+        assert_eq!(Curve::Bls12_377.id(), 0x00);
+        assert_eq!(Curve::Bls12_381.id(), 0x10);
+        assert_eq!(Curve::Bn254.id(), 0x20);
+        assert_eq!(Curve::Bw6_761.id(), 0x30);
+    }
+
+    #[ink::test]
+    fn register_verifying_key_should_work() {
+        let mut groth16 = Groth16::new();
+
+        assert_eq!(groth16.verifying_key(1), None);
+        assert_eq!(groth16.register_verifying_key(1, vk(Curve::Bn254)), Ok(()));
+        assert_eq!(groth16.verifying_key(1), Some(vk(Curve::Bn254)));
+
+        let VerifyingKeyRegistered { vk_id, curve } = expect_event();
+        assert_eq!(vk_id, 1);
+        assert_eq!(curve, Curve::Bn254);
+    }
+
+    #[ink::test]
+    fn register_verifying_key_twice_should_fail() {
+        let mut groth16 = Groth16::new();
+
+        assert_eq!(groth16.register_verifying_key(1, vk(Curve::Bn254)), Ok(()));
+        assert_eq!(
+            groth16.register_verifying_key(1, vk(Curve::Bls12_381)),
+            Err(Error::VerifyingKeyExists)
+        );
+        assert_eq!(groth16.verifying_key(1), Some(vk(Curve::Bn254)));
+    }
+
+    #[ink::test]
+    fn register_verifying_key_without_gamma_abc_should_fail() {
+        let mut groth16 = Groth16::new();
+
+        let mut key = vk(Curve::Bn254);
+        key.gamma_abc.clear();
+
+        assert_eq!(
+            groth16.register_verifying_key(1, key),
+            Err(Error::InvalidVerifyingKey)
+        );
+    }
+
+    #[ink::test]
+    fn register_verifying_key_with_wrong_size_should_fail() {
+        let mut groth16 = Groth16::new();
+
+        let mut key = vk(Curve::Bn254);
+        key.vk.pop();
+        assert_eq!(
+            groth16.register_verifying_key(1, key),
+            Err(Error::InvalidVerifyingKey)
+        );
+
+        let mut key = vk(Curve::Bn254);
+        key.gamma_abc[1].push(0x00);
+        assert_eq!(
+            groth16.register_verifying_key(1, key),
+            Err(Error::InvalidVerifyingKey)
+        );
+    }
+
+    #[ink::test]
+    #[should_panic]
+    fn register_verifying_key_by_not_owner_should_panic() {
+        let accounts = default_accounts();
+        let mut groth16 = Groth16::new();
+
+        call_as(accounts.bob, || {
+            let _ = groth16.register_verifying_key(1, vk(Curve::Bn254));
+        });
+    }
+
+    #[ink::test]
+    fn remove_verifying_key_should_work() {
+        let mut groth16 = Groth16::new();
+
+        assert_eq!(groth16.register_verifying_key(1, vk(Curve::Bn254)), Ok(()));
+        assert_eq!(groth16.remove_verifying_key(1), Ok(()));
+        assert_eq!(groth16.verifying_key(1), None);
+
+        let VerifyingKeyRemoved { vk_id } = expect_event();
+        assert_eq!(vk_id, 1);
+
+        assert_eq!(
+            groth16.remove_verifying_key(1),
+            Err(Error::VerifyingKeyNotFound)
+        );
+        assert_eq!(
+            groth16.register_verifying_key(1, vk(Curve::Bls12_377)),
+            Ok(())
+        );
+    }
+
+    #[ink::test]
+    fn verify_by_unknown_key_should_fail() {
+        let groth16 = Groth16::new();
+
+        assert_eq!(
+            groth16.verify(1, vec![0x00; 8], vec![vec![0x00; 8], vec![0x00; 8]]),
+            Err(Error::VerifyingKeyNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn verify_with_wrong_inputs_len_should_fail() {
+        let mut groth16 = Groth16::new();
+
+        assert_eq!(groth16.register_verifying_key(1, vk(Curve::Bn254)), Ok(()));
+        assert_eq!(
+            groth16.verify(1, vec![0x00; 8], vec![vec![0x00; 8]]),
+            Err(Error::InvalidPublicInputsLength)
+        );
+        assert_eq!(
+            groth16.verify(
+                1,
+                vec![0x00; 8],
+                vec![vec![0x00; 8], vec![0x00; 8], vec![0x00; 8]]
+            ),
+            Err(Error::InvalidPublicInputsLength)
+        );
+    }
+
+    #[ink::test]
+    fn verify_with_wrong_proof_size_should_fail() {
+        let mut groth16 = Groth16::new();
+
+        assert_eq!(groth16.register_verifying_key(1, vk(Curve::Bn254)), Ok(()));
+        assert_eq!(
+            groth16.verify(1, vec![0x00; 8], vec![vec![0x00; 32], vec![0x00; 32]]),
+            Err(Error::InvalidProof)
+        );
+    }
+
+    /// Register the `fixture` and check the proof by the off-chain curve
+    /// functions.
+    fn verify_fixture(fixture: mock::Fixture) {
+        mock::register_curve_extension();
+
+        let mut groth16 = Groth16::new();
+        assert_eq!(
+            groth16.register_verifying_key(1, fixture.vk.clone()),
+            Ok(())
+        );
+
+        assert_eq!(
+            groth16.verify(1, fixture.proof.clone(), fixture.public_inputs.clone()),
+            Ok(true)
+        );
+
+        // the proof is not valid for other public inputs, the scalars are
+        // little endian so flipping the lowest bit keeps it in the field
+        let mut wrong_inputs = fixture.public_inputs.clone();
+        wrong_inputs[0][0] ^= 0x01;
+        assert_eq!(groth16.verify(1, fixture.proof, wrong_inputs), Ok(false));
+    }
+
+    #[ink::test]
+    fn verify_bls12_377_should_work() {
+        verify_fixture(mock::fixture(Curve::Bls12_377));
+    }
+
+    #[ink::test]
+    fn verify_bls12_381_should_work() {
+        verify_fixture(mock::fixture(Curve::Bls12_381));
+    }
+
+    #[ink::test]
+    fn verify_bn254_should_work() {
+        verify_fixture(mock::fixture(Curve::Bn254));
+    }
+
+    #[ink::test]
+    fn verify_bw6_761_should_work() {
+        verify_fixture(mock::fixture(Curve::Bw6_761));
+    }
+
+    /// Regenerate the static vectors of `metis_groth16::mock::fixture`, and
+    /// print them in the layout of the fixtures file.
+    #[ink::test]
+    #[ignore]
+    fn regenerate_fixtures() {
+        for curve in mock::CURVES.iter() {
+            let fixture = mock::generate_fixture(*curve);
+            verify_fixture(fixture.clone());
+
+            println!("{}", mock::fixture_source(*curve, &fixture));
+        }
+    }
+}
@@ -7,8 +7,17 @@ use syn::Result;
 pub fn generate_code(
     contract: &Contract,
     storage_ident: &syn::Ident,
+    items: &[syn::Item],
 ) -> Result<TokenStream2> {
     let no_cross_calling_cfg = gen_cross_calling_conflict_cfg(contract);
+    let context = if has_context_impl(items) {
+        quote! {}
+    } else {
+        quote! {
+            #no_cross_calling_cfg
+            impl metis_lang::Context<#storage_ident> for #storage_ident {}
+        }
+    };
     let env = quote! {
         #no_cross_calling_cfg
         use ::ink_lang::{EmitEvent, Env, StaticEnv};
@@ -36,17 +45,43 @@ pub fn generate_code(
             fn balance() -> <#storage_ident as metis_lang::Env>::Balance  {
                 Self::env().balance()
             }
+            fn minimum_balance() -> <#storage_ident as metis_lang::Env>::Balance  {
+                Self::env().minimum_balance()
+            }
             fn transfer(destination: <#storage_ident as metis_lang::Env>::AccountId, value: <#storage_ident as metis_lang::Env>::Balance) -> ink_env::Result<()>{
                 Self::env().transfer(destination, value)
             }
             fn block_timestamp() -> <#storage_ident as metis_lang::Env>::Timestamp{
                 Self::env().block_timestamp()
             }
+            fn block_number() -> <#storage_ident as metis_lang::Env>::BlockNumber{
+                Self::env().block_number()
+            }
             fn hash_bytes<H>(input: &[u8]) -> <H as ink_env::hash::HashOutput>::Type where H : ink_env::hash::CryptoHash{
                 Self::env().hash_bytes::<H>(input)
             }
         }
+
+        #context
     };
 
     Ok(env)
 }
+
+/// If the contract has implemented the `Context` itself, the default one
+/// should not be generated.
+fn has_context_impl(items: &[syn::Item]) -> bool {
+    items.iter().any(|item| {
+        match item {
+            syn::Item::Impl(item_impl) => {
+                item_impl
+                    .trait_
+                    .as_ref()
+                    .and_then(|(_, path, _)| path.segments.last())
+                    .map(|segment| segment.ident == "Context")
+                    .unwrap_or(false)
+            }
+            _ => false,
+        }
+    })
+}
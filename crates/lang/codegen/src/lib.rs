@@ -39,7 +39,7 @@ pub fn generate_code(attr: TokenStream2, input: TokenStream2) -> Result<TokenStr
         }
     };
 
-    let envs = env::generate_code(&contract_ink, &storage_ident)?;
+    let envs = env::generate_code(&contract_ink, &storage_ident, &items)?;
     let imports = import::generate_code(&contract_ink)?;
     let events = event::generate_code(&contract_ink, &storage_ident)?;
 
@@ -1,6 +1,21 @@
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::parse::Result;
+use convert_case::{
+    Case,
+    Casing,
+};
+use proc_macro2::{
+    Ident,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::{
+    parse::Result,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Token,
+};
 
 pub fn generate_code(_attr: TokenStream2, input: TokenStream2) -> Result<TokenStream2> {
     let typ = syn::parse2::<syn::ItemStruct>(input.clone())?;
@@ -33,3 +48,402 @@ pub fn generate_code(_attr: TokenStream2, input: TokenStream2) -> Result<TokenSt
         };
     })
 }
+
+/// Generate the cross-contract stub by the trait which define the messages
+/// of the component, the trait will be expanded to a ink! contract with
+/// `unimplemented!()` bodies, and the stub storage will impl `FromAccountId`.
+///
+/// The methods without receiver will be the constructors, others will be the
+/// messages, the args in `#[ink(...)]` such as `payable` and `selector` will
+/// be passed to the ink! attributes.
+///
+/// With the `mock` feature of the stub crate, the stub will be a mock which
+/// dispatches the calls by `metis_lang::mock`, and the trait with the messages
+/// will be exported for the in-process contract instances.
+pub fn generate_contract_code(
+    attr: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2> {
+    let item_trait = syn::parse2::<syn::ItemTrait>(input)?;
+    let trait_ident = &item_trait.ident;
+    let vis = &item_trait.vis;
+
+    let storage_ident = if attr.is_empty() {
+        format_ident!("{}Stub", trait_ident)
+    } else {
+        syn::parse2::<Ident>(attr)?
+    };
+    let mod_ident = format_ident!(
+        "__metis_stub_{}",
+        trait_ident
+            .to_string()
+            .from_case(Case::Camel)
+            .to_case(Case::Snake)
+    );
+
+    let mut fns = Vec::new();
+    for item in item_trait.items.iter() {
+        match item {
+            syn::TraitItem::Method(method) => fns.push(generate_stub_fn(method)?),
+            _ => {
+                return Err(syn::Error::new(
+                    item.span(),
+                    "the stub trait only support methods",
+                ))
+            }
+        }
+    }
+
+    let mock = generate_mock_code(&item_trait, &storage_ident)?;
+
+    Ok(quote! {
+        #[cfg(not(feature = "mock"))]
+        #[::ink_lang::contract]
+        mod #mod_ident {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[ink(storage)]
+            #[::metis_lang::stub]
+            pub struct #storage_ident {}
+
+            impl #storage_ident {
+                #( #fns )*
+            }
+        }
+
+        #[cfg(not(feature = "mock"))]
+        #vis use #mod_ident::#storage_ident;
+
+        #[cfg(feature = "mock")]
+        mod #mod_ident {
+            #mock
+        }
+
+        #[cfg(feature = "mock")]
+        #vis use #mod_ident::{
+            #storage_ident,
+            #trait_ident,
+        };
+    })
+}
+
+/// Generate the mock stub for the off-chain tests, each message builds a
+/// `MockCall` to the registered handler of the callee.
+fn generate_mock_code(
+    item_trait: &syn::ItemTrait,
+    storage_ident: &Ident,
+) -> Result<TokenStream2> {
+    let trait_ident = &item_trait.ident;
+
+    let mut trait_fns = Vec::new();
+    let mut stub_fns = Vec::new();
+    let mut dispatches = Vec::new();
+    let mut specs = Vec::new();
+    for item in item_trait.items.iter() {
+        let method = match item {
+            syn::TraitItem::Method(method) if method.sig.receiver().is_some() => method,
+            _ => continue,
+        };
+
+        let attrs = method
+            .attrs
+            .iter()
+            .filter(|attr| !attr.path.is_ident("ink"))
+            .collect::<Vec<_>>();
+        let sig = &method.sig;
+        let ident = &sig.ident;
+        let selector = message_selector(method)?;
+
+        let mut arg_idents = Vec::new();
+        let mut arg_tys = Vec::new();
+        for input in sig.inputs.iter() {
+            if let syn::FnArg::Typed(pat_type) = input {
+                match pat_type.pat.as_ref() {
+                    syn::Pat::Ident(pat_ident) => arg_idents.push(&pat_ident.ident),
+                    pat => {
+                        return Err(syn::Error::new(
+                            pat.span(),
+                            "the stub trait only support ident args",
+                        ))
+                    }
+                }
+                arg_tys.push(&pat_type.ty);
+            }
+        }
+
+        let output = match &sig.output {
+            syn::ReturnType::Default => quote! { () },
+            syn::ReturnType::Type(_, ty) => quote! { #ty },
+        };
+
+        let name = ident.to_string();
+        let mutates = matches!(
+            sig.receiver(),
+            Some(syn::FnArg::Receiver(syn::Receiver {
+                mutability: Some(_),
+                ..
+            }))
+        );
+        let payable = message_payable(method)?;
+        let arg_specs = arg_idents.iter().zip(arg_tys.iter()).map(|(ident, ty)| {
+            let name = ident.to_string();
+            let segs = display_name(ty);
+            quote! { (#name, &[ #( #segs ),* ]) }
+        });
+        let return_type = match &sig.output {
+            syn::ReturnType::Default => quote! { None },
+            syn::ReturnType::Type(_, ty) => {
+                let segs = display_name(ty);
+                quote! { Some(&[ #( #segs ),* ]) }
+            }
+        };
+        specs.push(quote! {
+            ::metis_lang::mock::MessageSpec {
+                name: #name,
+                selector: #selector,
+                mutates: #mutates,
+                payable: #payable,
+                args: &[ #( #arg_specs ),* ],
+                return_type: #return_type,
+            }
+        });
+
+        trait_fns.push(quote! {
+            #( #attrs )*
+            #sig;
+        });
+
+        stub_fns.push(quote! {
+            #( #attrs )*
+            pub fn #ident(
+                &self,
+                #( #arg_idents: #arg_tys ),*
+            ) -> ::metis_lang::mock::MockCall<#output> {
+                ::metis_lang::mock::MockCall::new(
+                    self.account_id,
+                    #selector,
+                    ::scale::Encode::encode(&( #( #arg_idents, )* )),
+                )
+            }
+        });
+
+        dispatches.push(quote! {
+            #selector => {
+                let ( #( #arg_idents, )* ): ( #( #arg_tys, )* ) = call.decode_input()?;
+                let output = #trait_ident::#ident(&mut *instance, #( #arg_idents ),*);
+                Ok(::scale::Encode::encode(&output))
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[allow(dead_code)]
+        type AccountId = <::ink_env::DefaultEnvironment as ::ink_env::Environment>::AccountId;
+        #[allow(dead_code)]
+        type Balance = <::ink_env::DefaultEnvironment as ::ink_env::Environment>::Balance;
+        #[allow(dead_code)]
+        type Hash = <::ink_env::DefaultEnvironment as ::ink_env::Environment>::Hash;
+        #[allow(dead_code)]
+        type Timestamp = <::ink_env::DefaultEnvironment as ::ink_env::Environment>::Timestamp;
+        #[allow(dead_code)]
+        type BlockNumber = <::ink_env::DefaultEnvironment as ::ink_env::Environment>::BlockNumber;
+
+        /// The messages of the mocked contract, impl by the in-process
+        /// contract instances.
+        pub trait #trait_ident {
+            #( #trait_fns )*
+        }
+
+        pub struct #storage_ident {
+            account_id: AccountId,
+        }
+
+        impl metis_lang::Env for #storage_ident {
+            type AccountId = AccountId;
+            type Balance = Balance;
+            type Hash = Hash;
+            type Timestamp = Timestamp;
+            type BlockNumber = BlockNumber;
+        }
+
+        impl<E> metis_lang::FromAccountId<E> for #storage_ident
+        where
+            E: metis_lang::Env,
+        {
+            /// Creates the mock stub to the contract at the account ID.
+            fn from_account_id(account_id: E::AccountId) -> Self {
+                Self {
+                    account_id: account_id.into(),
+                }
+            }
+        }
+
+        impl<'a> ::ink_lang::ForwardCall for &'a #storage_ident {
+            type Forwarder = &'a #storage_ident;
+
+            fn call(self) -> Self::Forwarder {
+                self
+            }
+        }
+
+        impl<'a> ::ink_lang::ForwardCallMut for &'a mut #storage_ident {
+            type Forwarder = &'a mut #storage_ident;
+
+            fn call_mut(self) -> Self::Forwarder {
+                self
+            }
+        }
+
+        impl #storage_ident {
+            /// The specs of the messages, to check the stub against the
+            /// metadata of the real contract by `metis_lang::mock::check_messages`.
+            pub const MESSAGES: &'static [::metis_lang::mock::MessageSpec] = &[
+                #( #specs ),*
+            ];
+
+            #( #stub_fns )*
+
+            /// Register the in-process `instance` as the contract at `account_id`.
+            pub fn mock<T>(
+                account_id: AccountId,
+                instance: ::std::rc::Rc<::std::cell::RefCell<T>>,
+            ) where
+                T: #trait_ident + 'static,
+            {
+                ::metis_lang::mock::register(account_id, move |call| {
+                    let mut instance = instance.borrow_mut();
+                    match call.selector {
+                        #( #dispatches )*
+                        _ => Err(::metis_lang::mock::Error::CalleeTrapped),
+                    }
+                })
+            }
+        }
+    })
+}
+
+/// The selector of the message, by `#[ink(selector = "0x...")]` or the
+/// default one of ink!, which is the first 4 bytes of the message name hash.
+fn message_selector(method: &syn::TraitItemMethod) -> Result<TokenStream2> {
+    for attr in method.attrs.iter().filter(|attr| attr.path.is_ident("ink")) {
+        let args = attr.parse_args_with(
+            Punctuated::<syn::NestedMeta, Token![,]>::parse_terminated,
+        )?;
+        for arg in args.iter() {
+            if let syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) = arg {
+                if !name_value.path.is_ident("selector") {
+                    continue
+                }
+
+                let value = match &name_value.lit {
+                    syn::Lit::Str(lit) => {
+                        let value = lit.value();
+                        u32::from_str_radix(value.trim_start_matches("0x"), 16).ok()
+                    }
+                    syn::Lit::Int(lit) => lit.base10_parse::<u32>().ok(),
+                    _ => None,
+                };
+
+                let bytes = value
+                    .ok_or_else(|| {
+                        syn::Error::new(name_value.lit.span(), "invalid selector")
+                    })?
+                    .to_be_bytes();
+                return Ok(quote! { [ #( #bytes ),* ] })
+            }
+        }
+    }
+
+    let hash = blake2b_256(method.sig.ident.to_string().as_bytes());
+    let bytes = &hash[0..4];
+    Ok(quote! { [ #( #bytes ),* ] })
+}
+
+/// Whether the message is `#[ink(payable)]`.
+fn message_payable(method: &syn::TraitItemMethod) -> Result<bool> {
+    for attr in method.attrs.iter().filter(|attr| attr.path.is_ident("ink")) {
+        let args = attr.parse_args_with(
+            Punctuated::<syn::NestedMeta, Token![,]>::parse_terminated,
+        )?;
+        if args.iter().any(|arg| {
+            matches!(arg, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("payable"))
+        }) {
+            return Ok(true)
+        }
+    }
+
+    Ok(false)
+}
+
+/// The display name of the type in the metadata, which is the path segments
+/// without the generic args, ink! gives no display name for other types.
+fn display_name(ty: &syn::Type) -> Vec<String> {
+    match ty {
+        syn::Type::Path(type_path) => {
+            type_path
+                .path
+                .segments
+                .iter()
+                .map(|seg| seg.ident.to_string())
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn blake2b_256(input: &[u8]) -> [u8; 32] {
+    use ::blake2::digest::{
+        Update as _,
+        VariableOutput as _,
+    };
+
+    let mut output = [0_u8; 32];
+    let mut blake2 = blake2::VarBlake2b::new_keyed(&[], 32);
+    blake2.update(input);
+    blake2.finalize_variable(|result| output.copy_from_slice(result));
+    output
+}
+
+fn generate_stub_fn(method: &syn::TraitItemMethod) -> Result<TokenStream2> {
+    let mut ink_args = Vec::new();
+    let mut attrs = Vec::new();
+    for attr in method.attrs.iter() {
+        if attr.path.is_ident("ink") {
+            let args = attr.parse_args_with(
+                Punctuated::<syn::NestedMeta, Token![,]>::parse_terminated,
+            )?;
+            ink_args.extend(args.into_iter());
+        } else {
+            attrs.push(attr);
+        }
+    }
+
+    let mut sig = method.sig.clone();
+    let kind = if sig.receiver().is_some() {
+        quote! { message }
+    } else {
+        quote! { constructor }
+    };
+
+    // mark the args as unused for the `unimplemented!()` body
+    for input in sig.inputs.iter_mut() {
+        if let syn::FnArg::Typed(pat_type) = input {
+            if let syn::Pat::Ident(pat_ident) = pat_type.pat.as_mut() {
+                pat_ident.ident = format_ident!("_{}", pat_ident.ident);
+                pat_ident.mutability = None;
+            }
+        }
+    }
+
+    Ok(quote! {
+        #( #attrs )*
+        #[ink(#kind #(, #ink_args )*)]
+        pub #sig {
+            unimplemented!()
+        }
+    })
+}
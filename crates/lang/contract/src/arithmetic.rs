@@ -33,7 +33,9 @@ use core::{
 use num_traits::{
     checked_pow,
     Bounded,
+    CheckedAdd,
     CheckedMul,
+    CheckedSub,
     One,
     Unsigned,
     Zero,
@@ -61,7 +63,9 @@ pub trait BaseArithmetic:
     + MulAssign<Self>
     + Div<Self, Output = Self>
     + DivAssign<Self>
+    + CheckedAdd
     + CheckedMul
+    + CheckedSub
     + Saturating
     + TryFrom<u16>
     + TryFrom<u32>
@@ -85,8 +89,6 @@ pub trait BaseArithmetic:
 // UniqueSaturatedFrom<u128> +
 // Shl<u32, Output = Self> +
 // Shr<u32, Output = Self> +
-// CheckedAdd +
-// CheckedSub +
 // CheckedDiv +
 // CheckedShl +
 // CheckedShr +
@@ -110,7 +112,9 @@ impl<T> BaseArithmetic for T where
         + MulAssign<Self>
         + Div<Self, Output = Self>
         + DivAssign<Self>
+        + CheckedAdd
         + CheckedMul
+        + CheckedSub
         + Saturating
         + TryFrom<u16>
         + TryFrom<u32>
@@ -0,0 +1,132 @@
+//! Generic cross-contract calls by the raw selector and encoded input, for
+//! the components which call arbitrary messages, such as the multisig.
+//!
+//! With the `mock` feature the calls are dispatched by `crate::mock`, so
+//! they can be checked in the off-chain tests.
+
+use crate::Env;
+use ink_prelude::vec::Vec;
+
+/// The encoded args of a message, which is encoded as the raw bytes without
+/// the length prefix.
+pub struct RawInput<'a>(pub &'a [u8]);
+
+impl<'a> scale::Encode for RawInput<'a> {
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0);
+    }
+}
+
+/// Call the message with `selector` of `callee`, `input` is the encoded args
+/// of the message, and `value` native balance will be transferred. The
+/// return value of the message is ignored.
+pub fn invoke<E: Env>(
+    callee: E::AccountId,
+    selector: [u8; 4],
+    input: &Vec<u8>,
+    value: E::Balance,
+) -> ink_env::Result<()> {
+    invoke_with_gas_limit::<E>(callee, 0, selector, input, value)
+}
+
+/// Same as `invoke`, with at most `gas_limit` gas for the call, `0` means
+/// all the remaining gas.
+#[cfg(not(feature = "mock"))]
+pub fn invoke_with_gas_limit<E: Env>(
+    callee: E::AccountId,
+    gas_limit: u64,
+    selector: [u8; 4],
+    input: &Vec<u8>,
+    value: E::Balance,
+) -> ink_env::Result<()> {
+    use ink_env::{
+        call::{
+            build_call,
+            ExecutionInput,
+            Selector,
+        },
+        DefaultEnvironment,
+    };
+
+    build_call::<DefaultEnvironment>()
+        .callee(callee.into())
+        .gas_limit(gas_limit)
+        .transferred_value(value.into())
+        .exec_input(
+            ExecutionInput::new(Selector::new(selector)).push_arg(RawInput(&input[..])),
+        )
+        .returns::<()>()
+        .fire()
+}
+
+/// Same as `invoke`, with at most `gas_limit` gas for the call, the gas is
+/// not metered by the mock calls.
+#[cfg(feature = "mock")]
+pub fn invoke_with_gas_limit<E: Env>(
+    callee: E::AccountId,
+    _gas_limit: u64,
+    selector: [u8; 4],
+    input: &Vec<u8>,
+    value: E::Balance,
+) -> ink_env::Result<()> {
+    crate::mock::dispatch(callee.into(), selector, input.clone(), value.into())
+        .map(|_| ())
+}
+
+/// Returns whether there is a contract at `account`.
+#[cfg(not(feature = "mock"))]
+pub fn is_contract<E: Env>(account: &E::AccountId) -> bool {
+    ink_env::is_contract::<ink_env::DefaultEnvironment>(&account.clone().into())
+}
+
+/// Returns whether there is a contract at `account`, which is a contract
+/// registered by `crate::mock`.
+#[cfg(feature = "mock")]
+pub fn is_contract<E: Env>(account: &E::AccountId) -> bool {
+    crate::mock::is_registered(&account.clone().into())
+}
+
+/// Call the message with `selector` of `callee` like `invoke`, without any
+/// native balance transferred, and decode the return value as `R`.
+#[cfg(not(feature = "mock"))]
+pub fn invoke_with_output<E: Env, R: scale::Decode>(
+    callee: E::AccountId,
+    selector: [u8; 4],
+    input: &Vec<u8>,
+) -> ink_env::Result<R> {
+    use ink_env::{
+        call::{
+            build_call,
+            utils::ReturnType,
+            ExecutionInput,
+            Selector,
+        },
+        DefaultEnvironment,
+    };
+
+    build_call::<DefaultEnvironment>()
+        .callee(callee.into())
+        .gas_limit(0)
+        .transferred_value(0)
+        .exec_input(
+            ExecutionInput::new(Selector::new(selector)).push_arg(RawInput(&input[..])),
+        )
+        .returns::<ReturnType<R>>()
+        .fire()
+}
+
+/// Call the message with `selector` of `callee` like `invoke`, without any
+/// native balance transferred, and decode the return value as `R`.
+#[cfg(feature = "mock")]
+pub fn invoke_with_output<E: Env, R: scale::Decode>(
+    callee: E::AccountId,
+    selector: [u8; 4],
+    input: &Vec<u8>,
+) -> ink_env::Result<R> {
+    let output = crate::mock::dispatch(callee.into(), selector, input.clone(), 0)?;
+    <R as scale::Decode>::decode(&mut &output[..]).map_err(ink_env::Error::Decode)
+}
@@ -0,0 +1,27 @@
+//! The context of the current call, the components should get the sender of
+//! the message by `Context::_msg_sender` instead of `EnvAccess::caller`, so
+//! the contract can override it, such as to support the meta-transactions.
+//!
+//! The `#[metis_lang::contract]` implements the `Context` for the contract
+//! by default, to override it, just implement the `Context` in the contract:
+//!
+//! ```ignore
+//! impl metis_lang::Context<Contract> for Contract {
+//!     fn _msg_sender(&self) -> AccountId {
+//!         erc2771_context::Impl::_msg_sender(self)
+//!     }
+//! }
+//! ```
+
+use crate::{
+    Env,
+    EnvAccess,
+};
+
+/// The `Context` provides the information of the current call.
+pub trait Context<E: Env>: EnvAccess<E> {
+    /// The sender of the current message, by default it is the caller.
+    fn _msg_sender(&self) -> E::AccountId {
+        Self::caller()
+    }
+}
@@ -0,0 +1,166 @@
+//! Signature verification utilities.
+//!
+//! - ECDSA: recover the compressed public key of a signature by
+//!   `ink_env::ecdsa_recover`, the account of the key is the blake2x256
+//!   hash of it, same as the ECDSA accounts of substrate.
+//! - Typed data: the hash of a typed struct signed for a domain, like the
+//!   EIP-712, but the data is encoded by SCALE and hashed by blake2x256.
+//! - Signature checker: check a signature of an account or a contract, the
+//!   contract signers should impl the `is_valid_signature` message, like the
+//!   ERC1271.
+
+use crate::Env;
+use ink_env::hash::Blake2x256;
+use ink_prelude::vec::Vec;
+use scale::{
+    Decode,
+    Encode,
+};
+
+/// The ECDSA signature, the last byte is the recovery id.
+pub type EcdsaSignature = [u8; 65];
+
+/// The compressed ECDSA public key.
+pub type EcdsaPublicKey = [u8; 33];
+
+/// The selector of the message `is_valid_signature(hash: [u8; 32],
+/// signature: Vec<u8>) -> [u8; 4]` of the contract signers, same as the
+/// `selector_id!(is_valid_signature)`. It is also the magic value returned
+/// by the message when the signature is valid.
+pub const IS_VALID_SIGNATURE_SELECTOR: [u8; 4] = [0x46, 0xad, 0x12, 0xfa];
+
+/// The type name of the `Domain`.
+pub const DOMAIN_TYPE: &[u8] = b"Domain(name,version,verifying_contract,salt)";
+
+/// Returns the blake2x256 hash of `input`.
+pub fn blake2x256(input: &[u8]) -> [u8; 32] {
+    let mut output = [0_u8; 32];
+    ink_env::hash_bytes::<Blake2x256>(input, &mut output);
+    output
+}
+
+/// Returns the public key which signed the `message_hash` with the
+/// `signature`, `None` if the signature is invalid.
+pub fn ecdsa_recover(
+    signature: &EcdsaSignature,
+    message_hash: &[u8; 32],
+) -> Option<EcdsaPublicKey> {
+    let mut public_key = [0_u8; 33];
+    ink_env::ecdsa_recover(signature, message_hash, &mut public_key).ok()?;
+    Some(public_key)
+}
+
+/// Returns the account of the compressed ECDSA `public_key`.
+pub fn ecdsa_to_account_id<E: Env>(public_key: &EcdsaPublicKey) -> E::AccountId {
+    let account = blake2x256(&public_key[..]);
+    E::AccountId::decode(&mut &account[..])
+        .expect("Crypto: account id should be decoded from 32 bytes")
+}
+
+/// Returns the account which signed the `message_hash` with the ECDSA
+/// `signature`, `None` if the signature is invalid.
+pub fn recover<E: Env>(
+    signature: &EcdsaSignature,
+    message_hash: &[u8; 32],
+) -> Option<E::AccountId> {
+    ecdsa_recover(signature, message_hash).map(|key| ecdsa_to_account_id::<E>(&key))
+}
+
+/// The domain of the typed data, to avoid the signatures to be replayed in
+/// other contracts or other versions.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Domain<AccountId> {
+    /// The name of the signing domain, such as the name of the contract.
+    pub name: Vec<u8>,
+    /// The current major version of the signing domain.
+    pub version: Vec<u8>,
+    /// The contract which verifies the signature.
+    pub verifying_contract: AccountId,
+    /// An optional salt to disambiguate the domain.
+    pub salt: Option<[u8; 32]>,
+}
+
+impl<AccountId: Encode> Domain<AccountId> {
+    /// Returns the domain separator.
+    pub fn separator(&self) -> [u8; 32] {
+        blake2x256(&(type_hash(DOMAIN_TYPE), self).encode())
+    }
+}
+
+/// The struct which can be signed as typed data.
+///
+/// ```ignore
+/// impl TypedData for Permit {
+///     const TYPE: &'static [u8] = b"Permit(owner,spender,value,nonce,deadline)";
+/// }
+/// ```
+pub trait TypedData: Encode {
+    /// The type name of the struct with the names of the fields.
+    const TYPE: &'static [u8];
+
+    /// Returns the hash of the struct.
+    fn struct_hash(&self) -> [u8; 32] {
+        blake2x256(&(type_hash(Self::TYPE), self).encode())
+    }
+}
+
+/// Returns the hash of the type name.
+pub fn type_hash(type_name: &[u8]) -> [u8; 32] {
+    blake2x256(type_name)
+}
+
+/// Returns the hash to sign for the `struct_hash` in the domain of
+/// `domain_separator`.
+pub fn typed_data_hash(domain_separator: &[u8; 32], struct_hash: &[u8; 32]) -> [u8; 32] {
+    let mut input = [0_u8; 66];
+    input[..2].copy_from_slice(b"\x19\x01");
+    input[2..34].copy_from_slice(domain_separator);
+    input[34..].copy_from_slice(struct_hash);
+    blake2x256(&input)
+}
+
+/// Returns the hash to sign for the typed `data` in the domain of
+/// `domain_separator`.
+pub fn hash_typed_data<T: TypedData>(domain_separator: &[u8; 32], data: &T) -> [u8; 32] {
+    typed_data_hash(domain_separator, &data.struct_hash())
+}
+
+/// Returns whether the `signature` of the `hash` is valid for the `signer`,
+/// which is an ECDSA signature of the account, or a signature accepted by
+/// the `is_valid_signature` of the contract. The contract is not called if
+/// there is no code at the `signer`.
+pub fn is_valid_signature_now<E: Env>(
+    signer: &E::AccountId,
+    hash: &[u8; 32],
+    signature: &[u8],
+) -> bool {
+    if signature.len() == 65 {
+        let mut ecdsa_signature = [0_u8; 65];
+        ecdsa_signature.copy_from_slice(signature);
+        if recover::<E>(&ecdsa_signature, hash).as_ref() == Some(signer) {
+            return true
+        }
+    }
+
+    crate::call::is_contract::<E>(signer)
+        && is_valid_contract_signature_now::<E>(signer, hash, signature)
+}
+
+/// Returns whether the `signature` of the `hash` is accepted by the
+/// `is_valid_signature` of the contract `signer`, `false` if the `signer` is
+/// not a contract or the call failed.
+pub fn is_valid_contract_signature_now<E: Env>(
+    signer: &E::AccountId,
+    hash: &[u8; 32],
+    signature: &[u8],
+) -> bool {
+    let input = (hash, signature).encode();
+    let res = crate::call::invoke_with_output::<E, [u8; 4]>(
+        signer.clone(),
+        IS_VALID_SIGNATURE_SELECTOR,
+        &input,
+    );
+
+    matches!(res, Ok(magic) if magic == IS_VALID_SIGNATURE_SELECTOR)
+}
@@ -0,0 +1,341 @@
+//! Topics and decoding of the contract events, for the off-chain indexers.
+//!
+//! An event of a contract is emitted as the encoded event enum of the
+//! contract, so the first byte of the data is the index of the event in the
+//! metadata, and the rest is the event struct. The topics are calculated as
+//! the ink! codegen does:
+//!
+//! - the signature topic, from `<Storage>::<Event>`, such as `Erc20::Transfer`,
+//!   encoded without the length prefix.
+//! - a topic for each `#[ink(topic)]` field, from the field path as the prefix,
+//!   such as `Erc20::Transfer::from`, and the encoded value of the field.
+//!
+//! The events of the components are emitted by the `emit_event_<name>`
+//! generated by `metis_lang_codegen::event`, which emit the `#[ink(event)]`
+//! structs of the contract, so the same helpers work for all of them:
+//!
+//! ```ignore
+//! let events = ContractEvents::from_metadata("Erc20", &metadata_json)?;
+//!
+//! // filter the transfers to `alice` by the topics.
+//! let topics = [
+//!     events.signature_topic("Transfer")?,
+//!     events.field_topic("Transfer", "to", &Some(alice))?,
+//! ];
+//!
+//! // decode the raw event data into the event type.
+//! let transfer = events.decode::<Transfer>(&data)?;
+//! ```
+
+use ink_env::{
+    hash::{
+        Blake2x256,
+        CryptoHash,
+        HashOutput,
+    },
+    Clear,
+    Hash,
+};
+use scale::{
+    Decode,
+    DecodeAll,
+    Encode,
+};
+use serde_json::Value;
+
+/// For calculating the event topic hash.
+pub struct PrefixedValue<'a, 'b, T> {
+    pub prefix: &'a [u8],
+    pub value: &'b T,
+}
+
+impl<X> Encode for PrefixedValue<'_, '_, X>
+where
+    X: Encode,
+{
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.prefix.size_hint() + self.value.size_hint()
+    }
+
+    #[inline]
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        self.prefix.encode_to(dest);
+        self.value.encode_to(dest);
+    }
+}
+
+/// Bytes encoded without the length prefix, same as the fixed size array,
+/// used for the event signature.
+struct RawBytes<'a>(&'a [u8]);
+
+impl Encode for RawBytes<'_> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0);
+    }
+}
+
+/// Calculate the topic hash of an encoded entity, the entity is used as it
+/// is if its encoding fits in the hash, else the blake2x256 of the encoding.
+pub fn encoded_into_hash<T>(entity: &T) -> Hash
+where
+    T: Encode,
+{
+    let mut result = Hash::clear();
+    let len_result = result.as_ref().len();
+    let encoded = entity.encode();
+    let len_encoded = encoded.len();
+    if len_encoded <= len_result {
+        result.as_mut()[..len_encoded].copy_from_slice(&encoded);
+        return result
+    }
+    let mut hash_output = <<Blake2x256 as HashOutput>::Type as Default>::default();
+    <Blake2x256 as CryptoHash>::hash(&encoded, &mut hash_output);
+    let copy_len = core::cmp::min(hash_output.len(), len_result);
+    result.as_mut()[0..copy_len].copy_from_slice(&hash_output[0..copy_len]);
+    result
+}
+
+/// The first topic of an event, `signature` is like `b"Erc20::Transfer"`.
+pub fn signature_topic(signature: &[u8]) -> Hash {
+    encoded_into_hash(&PrefixedValue {
+        prefix: b"",
+        value: &RawBytes(signature),
+    })
+}
+
+/// The topic of an event field, `prefix` is like `b"Erc20::Transfer::from"`.
+pub fn field_topic<T>(prefix: &[u8], value: &T) -> Hash
+where
+    T: Encode,
+{
+    encoded_into_hash(&PrefixedValue { prefix, value })
+}
+
+/// An event type to decode the raw event data into, which should have the
+/// same fields in order as the `#[ink(event)]` struct of the contract.
+pub trait Event: Decode {
+    /// The name of the event in the metadata, such as `Transfer`.
+    const NAME: &'static str;
+}
+
+/// The Errors from decoding the events
+#[derive(Debug)]
+pub enum Error {
+    /// The metadata can not be parsed.
+    Metadata(String),
+    /// No event by the name in the metadata.
+    UnknownEvent(String),
+    /// No topic field by the name in the event.
+    UnknownTopic(String),
+    /// The data or the topics are not of the expected event.
+    Mismatch(String),
+    /// The event data can not be decoded.
+    Decode(scale::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Metadata(msg) => write!(f, "invalid metadata: {}", msg),
+            Error::UnknownEvent(name) => write!(f, "unknown event: {}", name),
+            Error::UnknownTopic(name) => write!(f, "unknown topic: {}", name),
+            Error::Mismatch(name) => write!(f, "not a {} event", name),
+            Error::Decode(err) => write!(f, "decode failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<scale::Error> for Error {
+    fn from(err: scale::Error) -> Self {
+        Error::Decode(err)
+    }
+}
+
+/// The Result of decoding the events
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A field of an event in the metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventField {
+    pub name: String,
+    /// Whether the field is a `#[ink(topic)]`.
+    pub indexed: bool,
+}
+
+/// An event in the metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventSpec {
+    pub name: String,
+    /// The index of the event, which is the first byte of the event data.
+    pub index: u8,
+    pub fields: Vec<EventField>,
+}
+
+/// The events of a contract from its metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractEvents {
+    storage_ident: String,
+    events: Vec<EventSpec>,
+}
+
+impl ContractEvents {
+    /// Parse the events from the `metadata.json` of the contract,
+    /// `storage_ident` is the name of the storage struct of the contract,
+    /// such as `Erc20`, which is the prefix of the topics.
+    pub fn from_metadata(storage_ident: &str, metadata: &str) -> Result<Self> {
+        let metadata: Value = serde_json::from_str(metadata)
+            .map_err(|err| Error::Metadata(err.to_string()))?;
+
+        Self::from_metadata_value(storage_ident, &metadata)
+    }
+
+    /// Parse the events from the parsed `metadata.json` of the contract.
+    pub fn from_metadata_value(storage_ident: &str, metadata: &Value) -> Result<Self> {
+        let project = ["V3", "V2", "V1", "V0"]
+            .iter()
+            .find_map(|version| metadata.get(version))
+            .unwrap_or(metadata);
+        let events = project
+            .get("spec")
+            .ok_or_else(|| Error::Metadata("no spec".into()))?
+            .get("events")
+            .and_then(Value::as_array)
+            .ok_or_else(|| Error::Metadata("no events".into()))?;
+
+        let events = events
+            .iter()
+            .enumerate()
+            .map(|(index, event)| -> Result<EventSpec> {
+                let fields = event
+                    .get("args")
+                    .and_then(Value::as_array)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|arg| -> Result<EventField> {
+                        Ok(EventField {
+                            name: label(arg)?,
+                            indexed: arg
+                                .get("indexed")
+                                .and_then(Value::as_bool)
+                                .unwrap_or_default(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(EventSpec {
+                    name: label(event)?,
+                    index: index as u8,
+                    fields,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self::new(storage_ident, events))
+    }
+
+    pub fn new(storage_ident: &str, events: Vec<EventSpec>) -> Self {
+        Self {
+            storage_ident: storage_ident.into(),
+            events,
+        }
+    }
+
+    /// Returns all the events of the contract.
+    pub fn events(&self) -> &[EventSpec] {
+        &self.events
+    }
+
+    /// Returns the event by the name, such as `Transfer`.
+    pub fn event(&self, name: &str) -> Result<&EventSpec> {
+        self.events
+            .iter()
+            .find(|event| event.name == name)
+            .ok_or_else(|| Error::UnknownEvent(name.into()))
+    }
+
+    /// Returns the signature of the event, such as `Erc20::Transfer`.
+    pub fn signature(&self, name: &str) -> Result<String> {
+        let event = self.event(name)?;
+        Ok(format!("{}::{}", self.storage_ident, event.name))
+    }
+
+    /// Returns the signature topic of the event, the first topic of it.
+    pub fn signature_topic(&self, name: &str) -> Result<Hash> {
+        Ok(signature_topic(self.signature(name)?.as_bytes()))
+    }
+
+    /// Returns the topic of the `field` of the event with the `value`, the
+    /// field should be a `#[ink(topic)]`.
+    pub fn field_topic<T>(&self, name: &str, field: &str, value: &T) -> Result<Hash>
+    where
+        T: Encode,
+    {
+        let signature = self.signature(name)?;
+        let event = self.event(name)?;
+        if !event.fields.iter().any(|f| f.name == field && f.indexed) {
+            return Err(Error::UnknownTopic(format!("{}::{}", signature, field)))
+        }
+
+        Ok(field_topic(
+            format!("{}::{}", signature, field).as_bytes(),
+            value,
+        ))
+    }
+
+    /// Returns the event of the raw event data, by its index and by the
+    /// signature topic if the `topics` is not empty.
+    pub fn identify(&self, data: &[u8], topics: &[Hash]) -> Option<&EventSpec> {
+        let index = *data.first()?;
+        let event = self.events.iter().find(|event| event.index == index)?;
+
+        match topics.first() {
+            Some(topic) if *topic != self.signature_topic(&event.name).ok()? => None,
+            _ => Some(event),
+        }
+    }
+
+    /// Decode the raw event data as a `T`, the data should be of the event
+    /// `T::NAME` and be decoded exactly.
+    pub fn decode<T: Event>(&self, data: &[u8]) -> Result<T> {
+        let event = self.event(T::NAME)?;
+        match data.split_first() {
+            Some((index, data)) if *index == event.index => {
+                Ok(<T as DecodeAll>::decode_all(data)?)
+            }
+            _ => Err(Error::Mismatch(T::NAME.into())),
+        }
+    }
+
+    /// Decode the raw event data as a `T`, also checks the signature topic.
+    pub fn decode_with_topics<T: Event>(
+        &self,
+        data: &[u8],
+        topics: &[Hash],
+    ) -> Result<T> {
+        if topics.first() != Some(&self.signature_topic(T::NAME)?) {
+            return Err(Error::Mismatch(T::NAME.into()))
+        }
+
+        self.decode(data)
+    }
+}
+
+/// The name of an event or a field, which is the `label` since the V3.
+fn label(value: &Value) -> Result<String> {
+    value
+        .get("label")
+        .or_else(|| value.get("name"))
+        .and_then(Value::as_str)
+        .map(String::from)
+        .ok_or_else(|| Error::Metadata(format!("no name of {}", value)))
+}
@@ -1,8 +1,18 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-mod arithmetic;
+pub mod arithmetic;
+pub mod call;
+pub mod context;
+pub mod crypto;
+#[cfg(feature = "std")]
+pub mod events;
+pub mod merkle_proof;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod storage;
 mod traits;
 
+pub use context::Context;
 pub use traits::{
     AccountId,
     Balance,
@@ -46,8 +56,10 @@ pub trait EnvAccess<E: Env> {
     fn transferred_balance() -> E::Balance;
     fn account_id() -> E::AccountId;
     fn balance() -> E::Balance;
+    fn minimum_balance() -> E::Balance;
     fn transfer(destination: E::AccountId, value: E::Balance) -> ink_env::Result<()>;
     fn block_timestamp() -> E::Timestamp;
+    fn block_number() -> E::BlockNumber;
     fn hash_bytes<H>(input: &[u8]) -> <H as HashOutput>::Type
     where
         H: CryptoHash;
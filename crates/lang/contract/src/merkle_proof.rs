@@ -0,0 +1,131 @@
+//! Verification of the Merkle tree proofs.
+//!
+//! The pairs of the nodes are sorted before hashing, so the proofs do not
+//! need to contain the positions of the nodes. The leaves should be hashed
+//! from the data by a different way from the pairs, such as hashing the
+//! encoded data, to avoid the second preimage attacks.
+//!
+//! The hashes are calculated by `EnvAccess::hash_bytes`, with `H` such as
+//! `Blake2x256` or `Keccak256`:
+//!
+//! ```ignore
+//! merkle_proof::verify::<E, Self, Blake2x256>(&proof, root, leaf)
+//! ```
+
+use crate::{
+    CryptoHash,
+    Env,
+    EnvAccess,
+    HashOutput,
+};
+use ink_prelude::vec::Vec;
+
+/// The node of the Merkle tree.
+pub type Node = [u8; 32];
+
+/// Returns the hash of the sorted pair of `a` and `b`.
+pub fn hash_pair<E, A, H>(a: &Node, b: &Node) -> Node
+where
+    E: Env,
+    A: EnvAccess<E>,
+    H: CryptoHash + HashOutput<Type = Node>,
+{
+    let mut input = [0_u8; 64];
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    input[..32].copy_from_slice(first);
+    input[32..].copy_from_slice(second);
+
+    A::hash_bytes::<H>(&input)
+}
+
+/// Returns the root rebuilt from the `leaf` and the `proof`, which is the
+/// sibling nodes from the leaf to the root.
+pub fn process_proof<E, A, H>(proof: &[Node], leaf: Node) -> Node
+where
+    E: Env,
+    A: EnvAccess<E>,
+    H: CryptoHash + HashOutput<Type = Node>,
+{
+    proof
+        .iter()
+        .fold(leaf, |computed, node| hash_pair::<E, A, H>(&computed, node))
+}
+
+/// Returns whether the `leaf` is in the tree of the `root` by the `proof`.
+pub fn verify<E, A, H>(proof: &[Node], root: Node, leaf: Node) -> bool
+where
+    E: Env,
+    A: EnvAccess<E>,
+    H: CryptoHash + HashOutput<Type = Node>,
+{
+    process_proof::<E, A, H>(proof, leaf) == root
+}
+
+/// Returns the root rebuilt from multiple `leaves` by a multiproof, `None`
+/// if the multiproof is invalid.
+///
+/// The `proof_flags` tells the second node of each pair: `true` for the next
+/// leaf or hash computed, `false` for the next node of the `proof`. The
+/// leaves must be in the order of the multiproof generation.
+pub fn process_multi_proof<E, A, H>(
+    proof: &[Node],
+    proof_flags: &[bool],
+    leaves: &[Node],
+) -> Option<Node>
+where
+    E: Env,
+    A: EnvAccess<E>,
+    H: CryptoHash + HashOutput<Type = Node>,
+{
+    let total_hashes = proof_flags.len();
+    if leaves.len() + proof.len() != total_hashes + 1 {
+        return None
+    }
+
+    let mut hashes = Vec::with_capacity(total_hashes);
+    let (mut leaf_pos, mut hash_pos, mut proof_pos) = (0, 0, 0);
+
+    // take the next leaf, or the next hash computed if the leaves are used up
+    let mut next = |hashes: &Vec<Node>| {
+        if leaf_pos < leaves.len() {
+            leaf_pos += 1;
+            Some(leaves[leaf_pos - 1])
+        } else {
+            hash_pos += 1;
+            hashes.get(hash_pos - 1).copied()
+        }
+    };
+
+    for flag in proof_flags.iter() {
+        let a = next(&hashes)?;
+        let b = if *flag {
+            next(&hashes)?
+        } else {
+            proof_pos += 1;
+            *proof.get(proof_pos - 1)?
+        };
+        hashes.push(hash_pair::<E, A, H>(&a, &b));
+    }
+
+    match (hashes.last(), leaves.first()) {
+        (Some(root), _) => Some(*root),
+        (None, Some(leaf)) => Some(*leaf),
+        (None, None) => proof.first().copied(),
+    }
+}
+
+/// Returns whether the `leaves` are all in the tree of the `root` by the
+/// multiproof of `proof` and `proof_flags`.
+pub fn multi_proof_verify<E, A, H>(
+    proof: &[Node],
+    proof_flags: &[bool],
+    root: Node,
+    leaves: &[Node],
+) -> bool
+where
+    E: Env,
+    A: EnvAccess<E>,
+    H: CryptoHash + HashOutput<Type = Node>,
+{
+    process_multi_proof::<E, A, H>(proof, proof_flags, leaves) == Some(root)
+}
@@ -0,0 +1,380 @@
+//! Mock cross-contract calls for the off-chain tests.
+//!
+//! The ink! off-chain environment can not invoke other contracts, so with the
+//! `mock` feature the stubs generated by `#[metis_lang::stub_contract]` will
+//! dispatch the calls to the handlers registered by the callee account id:
+//!
+//! - If no handler is registered for the callee, the call returns
+//!   `Err(Error::NotCallable)`, same as calling an account without code.
+//! - A handler can be a closure by `register`, or an in-process contract
+//!   instance by the `mock` fn of the generated stub.
+//! - A handler returns `Err` to revert the call, the transferred value will
+//!   be returned to the caller.
+//!
+//! The handler is called in a new execution context, the caller is the
+//! contract which made the call, and the callee is the mocked account.
+//!
+//! NOTE: The registry is thread local, each `#[ink::test]` runs in its own
+//! thread, call `reset` if some tests share a thread.
+//!
+//! The messages of a stub are defined by hand, so the mock stub exports the
+//! `MESSAGES` specs, which can be checked against the metadata of the real
+//! contract by `check_messages`:
+//!
+//! ```ignore
+//! extern "Rust" {
+//!     fn __ink_generate_metadata() -> ink_metadata::MetadataVersioned;
+//! }
+//!
+//! let metadata = serde_json::to_value(unsafe { __ink_generate_metadata() })?;
+//! assert_eq!(check_messages(&metadata, Erc20Stub::MESSAGES), Ok(()));
+//! ```
+
+use core::marker::PhantomData;
+use ink_env::{
+    call::Selector,
+    test,
+    DefaultEnvironment,
+    Environment,
+};
+use serde_json::Value;
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    rc::Rc,
+};
+
+pub use ink_env::Error;
+
+type AccountId = <DefaultEnvironment as Environment>::AccountId;
+type Balance = <DefaultEnvironment as Environment>::Balance;
+
+/// The gas limit used by the pushed execution contexts.
+const GAS_LIMIT: Balance = 1000000;
+
+/// The result of a mocked call, the output is the encoded return value.
+pub type MockResult = Result<Vec<u8>, Error>;
+
+type Handler = Rc<RefCell<dyn FnMut(&MockCallInfo) -> MockResult>>;
+
+thread_local! {
+    static CONTRACTS: RefCell<BTreeMap<AccountId, Handler>> = RefCell::new(BTreeMap::new());
+}
+
+/// The info of a call to a mocked contract.
+#[derive(Debug, Clone)]
+pub struct MockCallInfo {
+    /// The contract which made the call.
+    pub caller: AccountId,
+    /// The mocked contract.
+    pub callee: AccountId,
+    /// The selector of the called message.
+    pub selector: [u8; 4],
+    /// The encoded args of the called message.
+    pub input: Vec<u8>,
+    /// The native balance transferred by the call.
+    pub transferred_value: Balance,
+}
+
+impl MockCallInfo {
+    /// Decode the input as `Args`, which is the tuple of the message args.
+    pub fn decode_input<Args>(&self) -> Result<Args, Error>
+    where
+        Args: scale::Decode,
+    {
+        <Args as scale::Decode>::decode(&mut &self.input[..]).map_err(Error::Decode)
+    }
+}
+
+/// Register the `handler` as the contract at `account`, replacing the
+/// handler registered before.
+pub fn register<F>(account: AccountId, handler: F)
+where
+    F: FnMut(&MockCallInfo) -> MockResult + 'static,
+{
+    let handler: Handler = Rc::new(RefCell::new(handler));
+    CONTRACTS.with(|contracts| contracts.borrow_mut().insert(account, handler));
+}
+
+/// Register a contract at `account` which reverts all the calls.
+pub fn register_revert(account: AccountId) {
+    register(account, |_| Err(Error::CalleeReverted));
+}
+
+/// Unregister the contract at `account`, the calls to it will return
+/// `Err(Error::NotCallable)`.
+pub fn unregister(account: &AccountId) {
+    CONTRACTS.with(|contracts| contracts.borrow_mut().remove(account));
+}
+
+/// Returns whether there is a contract registered at `account`.
+pub fn is_registered(account: &AccountId) -> bool {
+    CONTRACTS.with(|contracts| contracts.borrow().contains_key(account))
+}
+
+/// Unregister all the contracts.
+pub fn reset() {
+    CONTRACTS.with(|contracts| contracts.borrow_mut().clear());
+}
+
+/// Dispatch a call to the handler registered for `callee`.
+pub fn dispatch(
+    callee: AccountId,
+    selector: [u8; 4],
+    input: Vec<u8>,
+    transferred_value: Balance,
+) -> MockResult {
+    let handler = CONTRACTS
+        .with(|contracts| contracts.borrow().get(&callee).cloned())
+        .ok_or(Error::NotCallable)?;
+
+    let caller = ink_env::account_id::<DefaultEnvironment>().unwrap_or([0x0; 32].into());
+
+    _transfer(&caller, &callee, transferred_value)?;
+
+    test::push_execution_context::<DefaultEnvironment>(
+        caller,
+        callee,
+        GAS_LIMIT,
+        transferred_value,
+        test::CallData::new(Selector::new(selector)),
+    );
+
+    let info = MockCallInfo {
+        caller,
+        callee,
+        selector,
+        input,
+        transferred_value,
+    };
+    let res = (&mut *handler.borrow_mut())(&info);
+
+    test::pop_execution_context();
+
+    if res.is_err() {
+        // revert the transferred value
+        let _ = _transfer(&callee, &caller, transferred_value);
+    }
+
+    res
+}
+
+fn _transfer(from: &AccountId, to: &AccountId, value: Balance) -> Result<(), Error> {
+    if value == 0 {
+        return Ok(())
+    }
+
+    let from_balance = test::get_account_balance::<DefaultEnvironment>(*from)?;
+    if from_balance < value {
+        return Err(Error::TransferFailed)
+    }
+    let to_balance = test::get_account_balance::<DefaultEnvironment>(*to).unwrap_or(0);
+
+    test::set_account_balance::<DefaultEnvironment>(*from, from_balance - value)?;
+    test::set_account_balance::<DefaultEnvironment>(*to, to_balance + value)
+}
+
+/// A call to a mocked contract, built by the mock stub like the `CallBuilder`
+/// of ink!, returns `R` when fired.
+pub struct MockCall<R> {
+    callee: AccountId,
+    selector: [u8; 4],
+    input: Vec<u8>,
+    transferred_value: Balance,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<R> MockCall<R>
+where
+    R: scale::Decode,
+{
+    /// Creates a call to the message with `selector` of `callee`, `input` is
+    /// the encoded args of the message.
+    pub fn new(callee: AccountId, selector: [u8; 4], input: Vec<u8>) -> Self {
+        Self {
+            callee,
+            selector,
+            input,
+            transferred_value: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets the gas limit for the call, which is ignored by the mock.
+    pub fn gas_limit(self, _gas_limit: u64) -> Self {
+        self
+    }
+
+    /// Sets the value transferred by the call.
+    pub fn transferred_value(mut self, transferred_value: Balance) -> Self {
+        self.transferred_value = transferred_value;
+        self
+    }
+
+    /// Invokes the mocked contract and decodes the return value.
+    pub fn fire(self) -> Result<R, Error> {
+        let output = dispatch(
+            self.callee,
+            self.selector,
+            self.input,
+            self.transferred_value,
+        )?;
+
+        <R as scale::Decode>::decode(&mut &output[..]).map_err(Error::Decode)
+    }
+}
+
+/// The spec of a message of the stub, same as the message in the metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageSpec {
+    pub name: &'static str,
+    pub selector: [u8; 4],
+    /// Whether the message takes `&mut self`.
+    pub mutates: bool,
+    pub payable: bool,
+    /// The name and the display name of the type of the args.
+    pub args: &'static [(&'static str, &'static [&'static str])],
+    /// The display name of the return type, `None` for `()`.
+    pub return_type: Option<&'static [&'static str]>,
+}
+
+/// Check the `messages` of the stub against the metadata of the real contract,
+/// which is the serialized `MetadataVersioned` of ink!, returns the mismatches.
+///
+/// The metadata gives no display name for the types which are not paths,
+/// such as the `&TokenId` args of the contracts, which encode the same as
+/// the `TokenId` in the stub, so the empty display names are not checked.
+pub fn check_messages(metadata: &Value, messages: &[MessageSpec]) -> Result<(), String> {
+    let project = ["V3", "V2", "V1", "V0"]
+        .iter()
+        .find_map(|version| metadata.get(version))
+        .unwrap_or(metadata);
+    let contract_messages = project
+        .get("spec")
+        .and_then(|spec| spec.get("messages"))
+        .and_then(Value::as_array)
+        .ok_or_else(|| "no messages in the metadata".to_string())?;
+
+    let mut errors = Vec::new();
+    for message in messages.iter() {
+        let contract_message = match contract_messages
+            .iter()
+            .find(|m| spec_name(m).as_deref() == Some(message.name))
+        {
+            Some(m) => m,
+            None => {
+                errors.push(format!("`{}`: not found in the contract", message.name));
+                continue
+            }
+        };
+
+        let selector = contract_message
+            .get("selector")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let expected = format!(
+            "0x{}",
+            message
+                .selector
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        );
+        if selector.to_lowercase() != expected {
+            errors.push(format!(
+                "`{}`: selector {} in the stub, {} in the contract",
+                message.name, expected, selector
+            ));
+        }
+
+        for (key, value) in
+            [("mutates", message.mutates), ("payable", message.payable)].iter()
+        {
+            let contract_value = contract_message
+                .get(*key)
+                .and_then(Value::as_bool)
+                .unwrap_or_default();
+            if contract_value != *value {
+                errors.push(format!(
+                    "`{}`: {} is {} in the stub, {} in the contract",
+                    message.name, key, value, contract_value
+                ));
+            }
+        }
+
+        let args = contract_message
+            .get("args")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if args.len() != message.args.len() {
+            errors.push(format!(
+                "`{}`: {} args in the stub, {} in the contract",
+                message.name,
+                message.args.len(),
+                args.len()
+            ));
+        } else {
+            for ((arg_name, display_name), arg) in message.args.iter().zip(args.iter()) {
+                let contract_display_name = display_name_of(arg.get("type"));
+                if !display_name_matches(display_name, &contract_display_name) {
+                    errors.push(format!(
+                        "`{}`: arg `{}` is {:?} in the stub, {:?} in the contract",
+                        message.name, arg_name, display_name, contract_display_name
+                    ));
+                }
+            }
+        }
+
+        let contract_return_type = contract_message
+            .get("returnType")
+            .filter(|ty| !ty.is_null())
+            .map(|ty| display_name_of(Some(ty)));
+        let matches = match (message.return_type, &contract_return_type) {
+            (None, None) => true,
+            (Some(expected), Some(actual)) => display_name_matches(expected, actual),
+            _ => false,
+        };
+        if !matches {
+            errors.push(format!(
+                "`{}`: return type is {:?} in the stub, {:?} in the contract",
+                message.name, message.return_type, contract_return_type
+            ));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+/// The name of the message, which is a string or the path segments.
+fn spec_name(value: &Value) -> Option<String> {
+    match value.get("label").or_else(|| value.get("name"))? {
+        Value::String(name) => Some(name.clone()),
+        Value::Array(segs) => segs.last().and_then(Value::as_str).map(String::from),
+        _ => None,
+    }
+}
+
+fn display_name_of(type_spec: Option<&Value>) -> Vec<String> {
+    type_spec
+        .and_then(|ty| ty.get("displayName"))
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(Value::as_str)
+        .map(String::from)
+        .collect()
+}
+
+fn display_name_matches(expected: &[&str], actual: &[String]) -> bool {
+    actual.is_empty()
+        || expected
+            .iter()
+            .copied()
+            .eq(actual.iter().map(String::as_str))
+}
@@ -0,0 +1,76 @@
+use ink_storage::{
+    collections::HashMap as StorageHashMap,
+    traits::SpreadLayout,
+};
+
+/// The bits of a slot of the `BitMap`.
+const SLOT_BITS: u64 = 256;
+
+/// Bools packed into slots of 256 bits, indexed by `u64`, such as the
+/// claimed flags of the airdrop.
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct BitMap {
+    /// Mapping from the slot to the bits
+    slots: StorageHashMap<u64, [u8; 32]>,
+}
+
+impl Default for BitMap {
+    fn default() -> Self {
+        Self {
+            slots: StorageHashMap::new(),
+        }
+    }
+}
+
+impl BitMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the bit at `index`.
+    pub fn get(&self, index: u64) -> bool {
+        let (slot, byte, mask) = position(index);
+        self.slots
+            .get(&slot)
+            .map(|bits| bits[byte] & mask != 0)
+            .unwrap_or(false)
+    }
+
+    /// Sets the bit at `index` to `value`.
+    pub fn set_to(&mut self, index: u64, value: bool) {
+        if value {
+            self.set(index)
+        } else {
+            self.unset(index)
+        }
+    }
+
+    /// Sets the bit at `index`.
+    pub fn set(&mut self, index: u64) {
+        let (slot, byte, mask) = position(index);
+        let mut bits = self.slots.get(&slot).copied().unwrap_or_default();
+        bits[byte] |= mask;
+        self.slots.insert(slot, bits);
+    }
+
+    /// Unsets the bit at `index`.
+    pub fn unset(&mut self, index: u64) {
+        let (slot, byte, mask) = position(index);
+        if let Some(mut bits) = self.slots.get(&slot).copied() {
+            bits[byte] &= !mask;
+            if bits == [0_u8; 32] {
+                self.slots.take(&slot);
+            } else {
+                self.slots.insert(slot, bits);
+            }
+        }
+    }
+}
+
+/// Returns the slot, the byte in the slot and the mask in the byte of the
+/// bit at `index`.
+fn position(index: u64) -> (u64, usize, u8) {
+    let bit = index % SLOT_BITS;
+    (index / SLOT_BITS, (bit / 8) as usize, 1 << (bit % 8))
+}
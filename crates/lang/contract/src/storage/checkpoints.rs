@@ -0,0 +1,324 @@
+use super::{
+    StorageKey,
+    StorageValue,
+};
+use ink_storage::{
+    collections::{
+        HashMap as StorageHashMap,
+        Vec as StorageVec,
+    },
+    traits::{
+        PackedLayout,
+        SpreadLayout,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+/// A value since the `key`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, SpreadLayout, PackedLayout,
+)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ::ink_storage::traits::StorageLayout)
+)]
+pub struct Checkpoint<K, V> {
+    pub key: K,
+    pub value: V,
+}
+
+/// The history of a value by the increasing keys, such as the block numbers,
+/// the value at a key can be looked up by binary search.
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct Checkpoints<K, V>
+where
+    K: StorageValue + Ord + Copy,
+    V: StorageValue + Default + Clone,
+{
+    checkpoints: StorageVec<Checkpoint<K, V>>,
+}
+
+impl<K, V> Default for Checkpoints<K, V>
+where
+    K: StorageValue + Ord + Copy,
+    V: StorageValue + Default + Clone,
+{
+    fn default() -> Self {
+        Self {
+            checkpoints: StorageVec::new(),
+        }
+    }
+}
+
+impl<K, V> Checkpoints<K, V>
+where
+    K: StorageValue + Ord + Copy,
+    V: StorageValue + Default + Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push the `value` since the `key`, returns the previous value and the
+    /// new value. If the `key` is the key of the last checkpoint, the last
+    /// checkpoint is updated.
+    ///
+    /// Panic if the `key` is less than the key of the last checkpoint.
+    pub fn push(&mut self, key: K, value: V) -> (V, V) {
+        let len = self.checkpoints.len();
+        match self.checkpoints.last().cloned() {
+            Some(last) => {
+                assert!(last.key <= key, "Checkpoints: decreasing keys");
+                if last.key == key {
+                    self.checkpoints
+                        .set(
+                            len - 1,
+                            Checkpoint {
+                                key,
+                                value: value.clone(),
+                            },
+                        )
+                        .expect("Checkpoints: last checkpoint should exist");
+                } else {
+                    self.checkpoints.push(Checkpoint {
+                        key,
+                        value: value.clone(),
+                    });
+                }
+
+                (last.value, value)
+            }
+            None => {
+                self.checkpoints.push(Checkpoint {
+                    key,
+                    value: value.clone(),
+                });
+                (V::default(), value)
+            }
+        }
+    }
+
+    /// Returns the value in the last checkpoint, the default value if there
+    /// is no checkpoint.
+    pub fn latest(&self) -> V {
+        self.checkpoints
+            .last()
+            .map(|checkpoint| checkpoint.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the last checkpoint.
+    pub fn latest_checkpoint(&self) -> Option<&Checkpoint<K, V>> {
+        self.checkpoints.last()
+    }
+
+    /// Returns the number of the checkpoints.
+    pub fn len(&self) -> u32 {
+        self.checkpoints.len()
+    }
+
+    /// Returns whether there is no checkpoint.
+    pub fn is_empty(&self) -> bool {
+        self.checkpoints.is_empty()
+    }
+
+    /// Returns the checkpoint at `pos`.
+    pub fn at(&self, pos: u32) -> Option<&Checkpoint<K, V>> {
+        self.checkpoints.get(pos)
+    }
+
+    /// Returns the value in the first checkpoint with key greater than or
+    /// equal to the `key`, the default value if there is no such checkpoint.
+    pub fn lower_lookup(&self, key: K) -> V {
+        let pos = self.lower_bound(|checkpoint_key| checkpoint_key < key);
+        self.checkpoints
+            .get(pos)
+            .map(|checkpoint| checkpoint.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the value in the last checkpoint with key lower than or equal
+    /// to the `key`, the default value if there is no such checkpoint.
+    pub fn upper_lookup(&self, key: K) -> V {
+        let pos = self.lower_bound(|checkpoint_key| checkpoint_key <= key);
+        if pos == 0 {
+            return V::default()
+        }
+
+        self.checkpoints
+            .get(pos - 1)
+            .map(|checkpoint| checkpoint.value.clone())
+            .unwrap_or_default()
+    }
+
+    fn lower_bound<F>(&self, pred: F) -> u32
+    where
+        F: Fn(K) -> bool,
+    {
+        lower_bound(self.len(), |pos| self.at(pos), pred)
+    }
+}
+
+/// The checkpoints of each key, such as the votes of each account, the
+/// checkpoints of a key are the same as the `Checkpoints`.
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct CheckpointsMap<A, K, V>
+where
+    A: StorageKey,
+    K: StorageValue + Ord + Copy,
+    V: StorageValue + Default + Clone,
+{
+    /// Mapping from (account, index) to the checkpoint
+    checkpoints: StorageHashMap<(A, u32), Checkpoint<K, V>>,
+    /// Mapping from the account to the number of its checkpoints
+    lens: StorageHashMap<A, u32>,
+}
+
+impl<A, K, V> Default for CheckpointsMap<A, K, V>
+where
+    A: StorageKey,
+    K: StorageValue + Ord + Copy,
+    V: StorageValue + Default + Clone,
+{
+    fn default() -> Self {
+        Self {
+            checkpoints: StorageHashMap::new(),
+            lens: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<A, K, V> CheckpointsMap<A, K, V>
+where
+    A: StorageKey,
+    K: StorageValue + Ord + Copy,
+    V: StorageValue + Default + Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push the `value` since the `key` to the checkpoints of `account`,
+    /// returns the previous value and the new value. If the `key` is the key
+    /// of the last checkpoint, the last checkpoint is updated.
+    ///
+    /// Panic if the `key` is less than the key of the last checkpoint.
+    pub fn push(&mut self, account: &A, key: K, value: V) -> (V, V) {
+        let len = self.len(account);
+        let checkpoint = Checkpoint {
+            key,
+            value: value.clone(),
+        };
+
+        match self.latest_checkpoint(account).cloned() {
+            Some(last) => {
+                assert!(last.key <= key, "CheckpointsMap: decreasing keys");
+                if last.key == key {
+                    self.checkpoints
+                        .insert((account.clone(), len - 1), checkpoint);
+                } else {
+                    self.checkpoints.insert((account.clone(), len), checkpoint);
+                    self.lens.insert(account.clone(), len + 1);
+                }
+
+                (last.value, value)
+            }
+            None => {
+                self.checkpoints.insert((account.clone(), 0), checkpoint);
+                self.lens.insert(account.clone(), 1);
+                (V::default(), value)
+            }
+        }
+    }
+
+    /// Returns the value in the last checkpoint of `account`, the default
+    /// value if there is no checkpoint.
+    pub fn latest(&self, account: &A) -> V {
+        self.latest_checkpoint(account)
+            .map(|checkpoint| checkpoint.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the last checkpoint of `account`.
+    pub fn latest_checkpoint(&self, account: &A) -> Option<&Checkpoint<K, V>> {
+        match self.len(account) {
+            0 => None,
+            len => self.at(account, len - 1),
+        }
+    }
+
+    /// Returns the number of the checkpoints of `account`.
+    pub fn len(&self, account: &A) -> u32 {
+        self.lens.get(account).copied().unwrap_or(0)
+    }
+
+    /// Returns whether `account` has no checkpoint.
+    pub fn is_empty(&self, account: &A) -> bool {
+        self.len(account) == 0
+    }
+
+    /// Returns the checkpoint of `account` at `pos`.
+    pub fn at(&self, account: &A, pos: u32) -> Option<&Checkpoint<K, V>> {
+        self.checkpoints.get(&(account.clone(), pos))
+    }
+
+    /// Returns the value in the first checkpoint of `account` with key
+    /// greater than or equal to the `key`, the default value if there is no
+    /// such checkpoint.
+    pub fn lower_lookup(&self, account: &A, key: K) -> V {
+        let pos = self.lower_bound(account, |checkpoint_key| checkpoint_key < key);
+        self.at(account, pos)
+            .map(|checkpoint| checkpoint.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the value in the last checkpoint of `account` with key lower
+    /// than or equal to the `key`, the default value if there is no such
+    /// checkpoint.
+    pub fn upper_lookup(&self, account: &A, key: K) -> V {
+        let pos = self.lower_bound(account, |checkpoint_key| checkpoint_key <= key);
+        if pos == 0 {
+            return V::default()
+        }
+
+        self.at(account, pos - 1)
+            .map(|checkpoint| checkpoint.value.clone())
+            .unwrap_or_default()
+    }
+
+    fn lower_bound<F>(&self, account: &A, pred: F) -> u32
+    where
+        F: Fn(K) -> bool,
+    {
+        lower_bound(self.len(account), |pos| self.at(account, pos), pred)
+    }
+}
+
+/// Returns the position of the first checkpoint whose key does not satisfy
+/// `pred`, the keys satisfy `pred` must be the prefix of the `len`
+/// checkpoints.
+fn lower_bound<'a, K, V, G, F>(len: u32, get: G, pred: F) -> u32
+where
+    K: 'a + Copy,
+    V: 'a,
+    G: Fn(u32) -> Option<&'a Checkpoint<K, V>>,
+    F: Fn(K) -> bool,
+{
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let key = get(mid).expect("Checkpoints: mid should be in bounds").key;
+        if pred(key) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
@@ -0,0 +1,116 @@
+use super::StorageValue;
+use ink_storage::{
+    collections::HashMap as StorageHashMap,
+    lazy::Lazy,
+    traits::SpreadLayout,
+};
+
+/// A queue which can push and pop at both ends, the items are in the slots
+/// from `begin` (inclusive) to `end` (exclusive).
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct DoubleEndedQueue<T>
+where
+    T: StorageValue,
+{
+    begin: Lazy<i64>,
+    end: Lazy<i64>,
+    items: StorageHashMap<i64, T>,
+}
+
+impl<T> Default for DoubleEndedQueue<T>
+where
+    T: StorageValue,
+{
+    fn default() -> Self {
+        Self {
+            begin: Lazy::new(0),
+            end: Lazy::new(0),
+            items: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<T> DoubleEndedQueue<T>
+where
+    T: StorageValue,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push the `value` to the back of the queue.
+    pub fn push_back(&mut self, value: T) {
+        let end = *self.end;
+        self.items.insert(end, value);
+        Lazy::set(&mut self.end, end + 1);
+    }
+
+    /// Push the `value` to the front of the queue.
+    pub fn push_front(&mut self, value: T) {
+        let begin = *self.begin - 1;
+        self.items.insert(begin, value);
+        Lazy::set(&mut self.begin, begin);
+    }
+
+    /// Pop the value at the back of the queue, `None` if it is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None
+        }
+
+        let end = *self.end - 1;
+        Lazy::set(&mut self.end, end);
+        self.items.take(&end)
+    }
+
+    /// Pop the value at the front of the queue, `None` if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None
+        }
+
+        let begin = *self.begin;
+        Lazy::set(&mut self.begin, begin + 1);
+        self.items.take(&begin)
+    }
+
+    /// Returns the value at the front of the queue.
+    pub fn front(&self) -> Option<&T> {
+        self.at(0)
+    }
+
+    /// Returns the value at the back of the queue.
+    pub fn back(&self) -> Option<&T> {
+        match self.len() {
+            0 => None,
+            len => self.at(len - 1),
+        }
+    }
+
+    /// Returns the value at `index` from the front, `None` if out of bounds.
+    pub fn at(&self, index: u32) -> Option<&T> {
+        if index >= self.len() {
+            return None
+        }
+
+        self.items.get(&(*self.begin + index as i64))
+    }
+
+    /// Remove all the values.
+    pub fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+        Lazy::set(&mut self.begin, 0);
+        Lazy::set(&mut self.end, 0);
+    }
+
+    /// Returns the number of the values.
+    pub fn len(&self) -> u32 {
+        (*self.end - *self.begin) as u32
+    }
+
+    /// Returns whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        *self.end == *self.begin
+    }
+}
@@ -0,0 +1,85 @@
+use super::{
+    EnumerableSet,
+    StorageKey,
+    StorageValue,
+};
+use ink_storage::{
+    collections::HashMap as StorageHashMap,
+    traits::SpreadLayout,
+};
+
+/// A map which can be enumerated by the index.
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct EnumerableMap<K, V>
+where
+    K: StorageKey,
+    V: StorageValue,
+{
+    /// The keys of the map
+    keys: EnumerableSet<K>,
+    /// Mapping from the key to the value
+    values: StorageHashMap<K, V>,
+}
+
+impl<K, V> Default for EnumerableMap<K, V>
+where
+    K: StorageKey,
+    V: StorageValue,
+{
+    fn default() -> Self {
+        Self {
+            keys: EnumerableSet::new(),
+            values: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<K, V> EnumerableMap<K, V>
+where
+    K: StorageKey,
+    V: StorageValue,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `value` of the `key`, returns `true` if the `key` is new.
+    pub fn set(&mut self, key: K, value: V) -> bool {
+        self.values.insert(key.clone(), value);
+        self.keys.add(key)
+    }
+
+    /// Remove the `key` from the map, returns its value, `None` if the `key`
+    /// is not in the map.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.keys.remove(key);
+        self.values.take(key)
+    }
+
+    /// Returns whether the `key` is in the map.
+    pub fn contains(&self, key: &K) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Returns the number of the entries in the map.
+    pub fn len(&self) -> u32 {
+        self.keys.len()
+    }
+
+    /// Returns whether the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the value of the `key`.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    /// Returns the entry at `index`, `None` if out of bounds.
+    pub fn at(&self, index: u32) -> Option<(&K, &V)> {
+        let key = self.keys.at(index)?;
+        self.values.get(key).map(|value| (key, value))
+    }
+}
@@ -0,0 +1,214 @@
+use super::StorageKey;
+use ink_prelude::vec::Vec;
+use ink_storage::{
+    collections::{
+        HashMap as StorageHashMap,
+        Vec as StorageVec,
+    },
+    traits::SpreadLayout,
+};
+
+/// A set of values, which can be enumerated by the index.
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct EnumerableSet<T>
+where
+    T: StorageKey,
+{
+    /// The values of the set
+    values: StorageVec<T>,
+    /// Mapping from the value to its index in the `values`
+    indexes: StorageHashMap<T, u32>,
+}
+
+impl<T> Default for EnumerableSet<T>
+where
+    T: StorageKey,
+{
+    fn default() -> Self {
+        Self {
+            values: StorageVec::new(),
+            indexes: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<T> EnumerableSet<T>
+where
+    T: StorageKey,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the `value` to the set, returns `false` if it is already in it.
+    pub fn add(&mut self, value: T) -> bool {
+        if self.contains(&value) {
+            return false
+        }
+
+        self.indexes.insert(value.clone(), self.values.len());
+        self.values.push(value);
+        true
+    }
+
+    /// Remove the `value` from the set, returns `false` if it is not in it.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let index = match self.indexes.take(value) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let last_index = self.values.len() - 1;
+        if index != last_index {
+            let last = self
+                .values
+                .get(last_index)
+                .expect("EnumerableSet: last value should exist")
+                .clone();
+            self.indexes.insert(last.clone(), index);
+            self.values
+                .set(index, last)
+                .expect("EnumerableSet: index should be in bounds");
+        }
+        self.values.pop();
+
+        true
+    }
+
+    /// Returns whether the `value` is in the set.
+    pub fn contains(&self, value: &T) -> bool {
+        self.indexes.contains_key(value)
+    }
+
+    /// Returns the number of the values in the set.
+    pub fn len(&self) -> u32 {
+        self.values.len()
+    }
+
+    /// Returns whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the value at `index`, `None` if out of bounds.
+    pub fn at(&self, index: u32) -> Option<&T> {
+        self.values.get(index)
+    }
+
+    /// Returns all the values, it may be expensive for a large set.
+    pub fn values(&self) -> Vec<T> {
+        self.values.iter().cloned().collect()
+    }
+}
+
+/// A set of values for each key, which can be enumerated by the index, such
+/// as the members of each role.
+#[cfg_attr(feature = "std", derive(::ink_storage::traits::StorageLayout))]
+#[derive(Debug, SpreadLayout)]
+pub struct EnumerableSets<K, T>
+where
+    K: StorageKey,
+    T: StorageKey,
+{
+    /// Mapping from (key, index) to the value
+    values: StorageHashMap<(K, u32), T>,
+    /// Mapping from (key, value) to the index of the value
+    indexes: StorageHashMap<(K, T), u32>,
+    /// Mapping from the key to the number of the values
+    lens: StorageHashMap<K, u32>,
+}
+
+impl<K, T> Default for EnumerableSets<K, T>
+where
+    K: StorageKey,
+    T: StorageKey,
+{
+    fn default() -> Self {
+        Self {
+            values: StorageHashMap::new(),
+            indexes: StorageHashMap::new(),
+            lens: StorageHashMap::new(),
+        }
+    }
+}
+
+impl<K, T> EnumerableSets<K, T>
+where
+    K: StorageKey,
+    T: StorageKey,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the `value` to the set of `key`, returns `false` if it is already
+    /// in it.
+    pub fn add(&mut self, key: &K, value: T) -> bool {
+        if self.contains(key, &value) {
+            return false
+        }
+
+        let len = self.len(key);
+        self.values.insert((key.clone(), len), value.clone());
+        self.indexes.insert((key.clone(), value), len);
+        self.lens.insert(key.clone(), len + 1);
+        true
+    }
+
+    /// Remove the `value` from the set of `key`, returns `false` if it is
+    /// not in it.
+    pub fn remove(&mut self, key: &K, value: &T) -> bool {
+        let index = match self.indexes.take(&(key.clone(), value.clone())) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let last_index = self.len(key) - 1;
+        let last = self
+            .values
+            .take(&(key.clone(), last_index))
+            .expect("EnumerableSets: last value should exist");
+        if index != last_index {
+            self.indexes.insert((key.clone(), last.clone()), index);
+            self.values.insert((key.clone(), index), last);
+        }
+
+        if last_index == 0 {
+            self.lens.take(key);
+        } else {
+            self.lens.insert(key.clone(), last_index);
+        }
+
+        true
+    }
+
+    /// Returns whether the `value` is in the set of `key`.
+    pub fn contains(&self, key: &K, value: &T) -> bool {
+        self.indexes.contains_key(&(key.clone(), value.clone()))
+    }
+
+    /// Returns the number of the values in the set of `key`.
+    pub fn len(&self, key: &K) -> u32 {
+        self.lens.get(key).copied().unwrap_or(0)
+    }
+
+    /// Returns whether the set of `key` is empty.
+    pub fn is_empty(&self, key: &K) -> bool {
+        self.len(key) == 0
+    }
+
+    /// Returns the value at `index` of the set of `key`, `None` if out of
+    /// bounds.
+    pub fn at(&self, key: &K, index: u32) -> Option<&T> {
+        self.values.get(&(key.clone(), index))
+    }
+
+    /// Returns all the values of the set of `key`, it may be expensive for a
+    /// large set.
+    pub fn values(&self, key: &K) -> Vec<T> {
+        (0..self.len(key))
+            .filter_map(|index| self.at(key, index).cloned())
+            .collect()
+    }
+}
@@ -0,0 +1,91 @@
+//! Storage-efficient data structures for the components, all of them impl
+//! the `SpreadLayout`, so they can be used as the fields of the `Data` of
+//! the components:
+//!
+//! - `EnumerableSet`, `EnumerableSets`: sets which can be enumerated by the
+//!   index, `EnumerableSets` holds a set for each key, such as the members
+//!   of each role.
+//! - `EnumerableMap`: a map which can be enumerated by the index.
+//! - `BitMap`: bools packed into slots of 256 bits.
+//! - `Checkpoints`, `CheckpointsMap`: values of the increasing keys, such as
+//!   the block numbers, which can be looked up by binary search,
+//!   `CheckpointsMap` holds the checkpoints for each key, such as the votes
+//!   of each account.
+//! - `DoubleEndedQueue`: a queue which can push and pop at both ends.
+//!
+//! NOTE: the enumeration order may change when the items are removed, the
+//! last item is moved to the slot of the removed one (swap and pop).
+
+mod bitmap;
+mod checkpoints;
+mod double_ended_queue;
+mod enumerable_map;
+mod enumerable_set;
+
+pub use bitmap::BitMap;
+pub use checkpoints::{
+    Checkpoint,
+    Checkpoints,
+    CheckpointsMap,
+};
+pub use double_ended_queue::DoubleEndedQueue;
+pub use enumerable_map::EnumerableMap;
+pub use enumerable_set::{
+    EnumerableSet,
+    EnumerableSets,
+};
+
+use ink_storage::traits::PackedLayout;
+
+/// The items which can be stored as the keys of the storage collections.
+#[cfg(not(feature = "std"))]
+pub trait StorageKey: 'static + Ord + Clone + PackedLayout {}
+
+#[cfg(not(feature = "std"))]
+impl<T> StorageKey for T where T: 'static + Ord + Clone + PackedLayout {}
+
+/// The items which can be stored as the keys of the storage collections.
+#[cfg(feature = "std")]
+pub trait StorageKey:
+    'static
+    + Ord
+    + Clone
+    + PackedLayout
+    + ::scale_info::TypeInfo
+    + ::ink_storage::traits::StorageLayout
+{
+}
+
+#[cfg(feature = "std")]
+impl<T> StorageKey for T where
+    T: 'static
+        + Ord
+        + Clone
+        + PackedLayout
+        + ::scale_info::TypeInfo
+        + ::ink_storage::traits::StorageLayout
+{
+}
+
+/// The items which can be stored as the values of the storage collections.
+#[cfg(not(feature = "std"))]
+pub trait StorageValue: 'static + PackedLayout {}
+
+#[cfg(not(feature = "std"))]
+impl<T> StorageValue for T where T: 'static + PackedLayout {}
+
+/// The items which can be stored as the values of the storage collections.
+#[cfg(feature = "std")]
+pub trait StorageValue:
+    'static + PackedLayout + ::scale_info::TypeInfo + ::ink_storage::traits::StorageLayout
+{
+}
+
+#[cfg(feature = "std")]
+impl<T> StorageValue for T where
+    T: 'static
+        + PackedLayout
+        + ::scale_info::TypeInfo
+        + ::ink_storage::traits::StorageLayout
+{
+}
@@ -281,13 +281,14 @@ impl<T> Timestamp for T where
 }
 
 /// The type of block number.
-
 #[cfg(feature = "std")]
 pub trait BlockNumber:
     'static
     + scale::Codec
     + ::scale_info::TypeInfo
     + ::ink_storage::traits::StorageLayout
+    + SpreadLayout
+    + PackedLayout
     + Copy
     + Clone
     + PartialEq
@@ -302,6 +303,8 @@ impl<T> BlockNumber for T where
         + scale::Codec
         + ::scale_info::TypeInfo
         + ::ink_storage::traits::StorageLayout
+        + SpreadLayout
+        + PackedLayout
         + Copy
         + Clone
         + PartialEq
@@ -312,13 +315,29 @@ impl<T> BlockNumber for T where
 
 #[cfg(not(feature = "std"))]
 pub trait BlockNumber:
-    'static + scale::Codec + Copy + Clone + PartialEq + Eq + AtLeast32BitUnsigned
+    'static
+    + scale::Codec
+    + Copy
+    + SpreadLayout
+    + PackedLayout
+    + Clone
+    + PartialEq
+    + Eq
+    + AtLeast32BitUnsigned
 {
 }
 
 #[cfg(not(feature = "std"))]
 impl<T> BlockNumber for T where
-    T: 'static + scale::Codec + Copy + Clone + PartialEq + Eq + AtLeast32BitUnsigned
+    T: 'static
+        + scale::Codec
+        + Copy
+        + SpreadLayout
+        + PackedLayout
+        + Clone
+        + PartialEq
+        + Eq
+        + AtLeast32BitUnsigned
 {
 }
 
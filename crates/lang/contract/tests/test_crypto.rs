@@ -0,0 +1,202 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod crypto_tests {
+    use ink_env::{
+        DefaultEnvironment,
+        Environment,
+    };
+    use ink_lang as ink;
+    use metis_contract::{
+        call,
+        crypto::{
+            ecdsa_recover,
+            ecdsa_to_account_id,
+            is_valid_signature_now,
+            recover,
+            EcdsaPublicKey,
+            EcdsaSignature,
+            IS_VALID_SIGNATURE_SELECTOR,
+        },
+        mock,
+    };
+    use scale::Encode;
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+    };
+
+    type AccountId = <DefaultEnvironment as Environment>::AccountId;
+
+    /// The blake2x256 hash of `b"metis crypto test message"`.
+    const MESSAGE_HASH: [u8; 32] = [
+        0x70, 0x9b, 0xbc, 0xed, 0x98, 0x51, 0x20, 0x06, 0x72, 0x61, 0x46, 0x68, 0x31,
+        0x5a, 0x2d, 0x1a, 0x95, 0x59, 0xaf, 0xa8, 0x8f, 0xbf, 0x32, 0x3b, 0x15, 0x54,
+        0x0f, 0x66, 0xf4, 0x57, 0x82, 0xb5,
+    ];
+
+    /// The secp256k1 signature of `MESSAGE_HASH` by the key
+    /// `sha256(b"metis crypto test key")`, the last byte is the recovery id.
+    const SIGNATURE: EcdsaSignature = [
+        0xdf, 0x06, 0xdf, 0x7e, 0x52, 0xca, 0x9f, 0x91, 0x52, 0xe8, 0xd4, 0x29, 0xe8,
+        0xf8, 0x7f, 0xc6, 0x1b, 0x0d, 0x0f, 0xd9, 0xf9, 0x6e, 0x67, 0x41, 0x2c, 0x8f,
+        0x96, 0xea, 0x95, 0x4e, 0xc9, 0x5e, 0x0e, 0x34, 0x0c, 0x68, 0xc2, 0xbe, 0x4b,
+        0xdc, 0xcb, 0x7e, 0x07, 0x7c, 0x1d, 0xdd, 0xf3, 0x21, 0x47, 0xca, 0x46, 0x5a,
+        0xa9, 0xfb, 0xaf, 0x3f, 0x49, 0x79, 0xe0, 0x14, 0x35, 0xba, 0xb1, 0xe9, 0x00,
+    ];
+
+    /// The compressed public key of the signer.
+    const PUBLIC_KEY: EcdsaPublicKey = [
+        0x03, 0x05, 0x13, 0x97, 0x0b, 0x8b, 0x6f, 0xd0, 0xd2, 0xaf, 0x08, 0x9f, 0xa9,
+        0x70, 0x42, 0xb8, 0x3e, 0xb8, 0x12, 0xff, 0xcd, 0xeb, 0x2e, 0x9b, 0x40, 0x6d,
+        0x57, 0x34, 0x8e, 0x57, 0x81, 0xc7, 0x5c,
+    ];
+
+    /// The account of the signer, the blake2x256 hash of `PUBLIC_KEY`.
+    const SIGNER: [u8; 32] = [
+        0x63, 0x76, 0x14, 0x00, 0x79, 0x0e, 0xff, 0x63, 0x72, 0xa4, 0xe3, 0x5c, 0x8d,
+        0xf0, 0xe3, 0xbb, 0x14, 0xaa, 0x78, 0x25, 0x0b, 0x5a, 0x58, 0x7a, 0xd7, 0xf6,
+        0x9a, 0x4f, 0xef, 0x2e, 0x02, 0x03,
+    ];
+
+    /// Register a contract signer at `account` which accepts the `SIGNATURE`
+    /// of `MESSAGE_HASH`, returns the count of the calls.
+    fn mock_signer(account: AccountId) -> Rc<RefCell<u32>> {
+        let calls = Rc::new(RefCell::new(0));
+
+        let signer_calls = calls.clone();
+        mock::register(account, move |call| {
+            *signer_calls.borrow_mut() += 1;
+            assert_eq!(call.selector, IS_VALID_SIGNATURE_SELECTOR);
+
+            let (hash, signature) = call.decode_input::<([u8; 32], Vec<u8>)>()?;
+            if hash == MESSAGE_HASH && signature == SIGNATURE.to_vec() {
+                Ok(IS_VALID_SIGNATURE_SELECTOR.encode())
+            } else {
+                Ok([0x00; 4].encode())
+            }
+        });
+
+        calls
+    }
+
+    #[ink::test]
+    fn recover_known_vector_works() {
+        assert_eq!(ecdsa_recover(&SIGNATURE, &MESSAGE_HASH), Some(PUBLIC_KEY));
+        assert_eq!(
+            ecdsa_to_account_id::<DefaultEnvironment>(&PUBLIC_KEY),
+            AccountId::from(SIGNER)
+        );
+        assert_eq!(
+            recover::<DefaultEnvironment>(&SIGNATURE, &MESSAGE_HASH),
+            Some(AccountId::from(SIGNER))
+        );
+
+        assert!(is_valid_signature_now::<DefaultEnvironment>(
+            &AccountId::from(SIGNER),
+            &MESSAGE_HASH,
+            &SIGNATURE
+        ));
+    }
+
+    #[ink::test]
+    fn signature_of_other_message_is_invalid() {
+        let mut other_hash = MESSAGE_HASH;
+        other_hash[0] ^= 0x01;
+
+        assert_ne!(
+            recover::<DefaultEnvironment>(&SIGNATURE, &other_hash),
+            Some(AccountId::from(SIGNER))
+        );
+        assert!(!is_valid_signature_now::<DefaultEnvironment>(
+            &AccountId::from(SIGNER),
+            &other_hash,
+            &SIGNATURE
+        ));
+    }
+
+    #[ink::test]
+    fn malformed_signature_is_invalid() {
+        let signer = AccountId::from(SIGNER);
+
+        // r and s are zero
+        let mut zero = [0x00; 65];
+        zero[64] = SIGNATURE[64];
+        assert_eq!(ecdsa_recover(&zero, &MESSAGE_HASH), None);
+        assert!(!is_valid_signature_now::<DefaultEnvironment>(
+            &signer,
+            &MESSAGE_HASH,
+            &zero
+        ));
+
+        // invalid recovery id
+        let mut bad_recovery_id = SIGNATURE;
+        bad_recovery_id[64] = 0x04;
+        assert_eq!(ecdsa_recover(&bad_recovery_id, &MESSAGE_HASH), None);
+
+        // wrong length
+        assert!(!is_valid_signature_now::<DefaultEnvironment>(
+            &signer,
+            &MESSAGE_HASH,
+            &SIGNATURE[..64]
+        ));
+        assert!(!is_valid_signature_now::<DefaultEnvironment>(
+            &signer,
+            &MESSAGE_HASH,
+            &[]
+        ));
+    }
+
+    #[ink::test]
+    fn contract_signer_works() {
+        let contract = AccountId::from([0x0c; 32]);
+        let calls = mock_signer(contract);
+        assert!(call::is_contract::<DefaultEnvironment>(&contract));
+
+        assert!(is_valid_signature_now::<DefaultEnvironment>(
+            &contract,
+            &MESSAGE_HASH,
+            &SIGNATURE
+        ));
+        assert_eq!(*calls.borrow(), 1);
+
+        let mut other_hash = MESSAGE_HASH;
+        other_hash[0] ^= 0x01;
+        assert!(!is_valid_signature_now::<DefaultEnvironment>(
+            &contract,
+            &other_hash,
+            &SIGNATURE
+        ));
+        assert_eq!(*calls.borrow(), 2);
+    }
+
+    #[ink::test]
+    fn reverted_contract_signer_is_invalid() {
+        let contract = AccountId::from([0x0c; 32]);
+        mock::register_revert(contract);
+
+        assert!(!is_valid_signature_now::<DefaultEnvironment>(
+            &contract,
+            &MESSAGE_HASH,
+            &SIGNATURE
+        ));
+    }
+
+    #[ink::test]
+    fn signer_without_code_is_not_called() {
+        let contract = AccountId::from([0x0c; 32]);
+        let calls = mock_signer(contract);
+        mock::unregister(&contract);
+        assert!(!call::is_contract::<DefaultEnvironment>(&contract));
+
+        assert!(!is_valid_signature_now::<DefaultEnvironment>(
+            &contract,
+            &MESSAGE_HASH,
+            &SIGNATURE
+        ));
+        assert_eq!(
+            *calls.borrow(),
+            0,
+            "account without code should not be called"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod bitmap_tests {
+    use ink_lang as ink;
+    use metis_contract::storage::BitMap;
+
+    #[ink::test]
+    fn set_works() {
+        let mut bitmap = BitMap::new();
+        assert!(!bitmap.get(0));
+
+        bitmap.set(0);
+        bitmap.set(7);
+        bitmap.set(8);
+
+        assert!(bitmap.get(0));
+        assert!(bitmap.get(7));
+        assert!(bitmap.get(8));
+        assert!(!bitmap.get(1));
+        assert!(!bitmap.get(9));
+    }
+
+    #[ink::test]
+    fn set_at_slot_bounds_works() {
+        let mut bitmap = BitMap::new();
+
+        // 255 is the last bit of the first slot, 256 is the first bit of
+        // the second slot
+        bitmap.set(255);
+        assert!(bitmap.get(255));
+        assert!(!bitmap.get(256));
+        assert!(!bitmap.get(254));
+        assert!(!bitmap.get(0));
+
+        bitmap.set(256);
+        assert!(bitmap.get(256));
+        assert!(!bitmap.get(257));
+
+        bitmap.unset(255);
+        assert!(!bitmap.get(255));
+        assert!(bitmap.get(256));
+
+        bitmap.unset(256);
+        assert!(!bitmap.get(256));
+    }
+
+    #[ink::test]
+    fn set_large_index_works() {
+        let mut bitmap = BitMap::new();
+
+        bitmap.set(u64::MAX);
+        assert!(bitmap.get(u64::MAX));
+        assert!(!bitmap.get(u64::MAX - 1));
+    }
+
+    #[ink::test]
+    fn set_to_works() {
+        let mut bitmap = BitMap::new();
+
+        bitmap.set_to(3, true);
+        assert!(bitmap.get(3));
+
+        bitmap.set_to(3, false);
+        assert!(!bitmap.get(3));
+
+        // unset a bit never set is a no-op
+        bitmap.unset(4);
+        assert!(!bitmap.get(4));
+    }
+
+    #[ink::test]
+    fn unset_keeps_other_bits_in_slot() {
+        let mut bitmap = BitMap::new();
+        bitmap.set(1);
+        bitmap.set(2);
+
+        bitmap.unset(1);
+        assert!(!bitmap.get(1));
+        assert!(bitmap.get(2));
+    }
+}
@@ -0,0 +1,169 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod checkpoints_tests {
+    use ink_lang as ink;
+    use metis_contract::storage::{
+        Checkpoint,
+        Checkpoints,
+        CheckpointsMap,
+    };
+
+    /// Checkpoints with the values 10, 20, 30 since the keys 2, 4, 6.
+    fn checkpoints() -> Checkpoints<u32, u64> {
+        let mut checkpoints = Checkpoints::new();
+        checkpoints.push(2, 10);
+        checkpoints.push(4, 20);
+        checkpoints.push(6, 30);
+        checkpoints
+    }
+
+    #[ink::test]
+    fn push_works() {
+        let mut checkpoints = Checkpoints::<u32, u64>::new();
+        assert!(checkpoints.is_empty());
+        assert_eq!(checkpoints.latest(), 0);
+        assert_eq!(checkpoints.latest_checkpoint(), None);
+
+        assert_eq!(checkpoints.push(1, 10), (0, 10));
+        assert_eq!(checkpoints.push(3, 20), (10, 20));
+
+        assert_eq!(checkpoints.len(), 2);
+        assert_eq!(checkpoints.latest(), 20);
+        assert_eq!(checkpoints.at(0), Some(&Checkpoint { key: 1, value: 10 }));
+        assert_eq!(
+            checkpoints.latest_checkpoint(),
+            Some(&Checkpoint { key: 3, value: 20 })
+        );
+    }
+
+    #[ink::test]
+    fn push_same_key_updates_last() {
+        let mut checkpoints = checkpoints();
+
+        assert_eq!(checkpoints.push(6, 35), (30, 35));
+        assert_eq!(checkpoints.len(), 3);
+        assert_eq!(checkpoints.latest(), 35);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "Checkpoints: decreasing keys")]
+    fn push_decreasing_key_should_panic() {
+        let mut checkpoints = checkpoints();
+        checkpoints.push(5, 40);
+    }
+
+    #[ink::test]
+    fn upper_lookup_works() {
+        let checkpoints = checkpoints();
+
+        // before the first key
+        assert_eq!(checkpoints.upper_lookup(0), 0);
+        assert_eq!(checkpoints.upper_lookup(1), 0);
+
+        // at and between the keys
+        assert_eq!(checkpoints.upper_lookup(2), 10);
+        assert_eq!(checkpoints.upper_lookup(3), 10);
+        assert_eq!(checkpoints.upper_lookup(4), 20);
+        assert_eq!(checkpoints.upper_lookup(5), 20);
+
+        // at and after the last key
+        assert_eq!(checkpoints.upper_lookup(6), 30);
+        assert_eq!(checkpoints.upper_lookup(u32::MAX), 30);
+    }
+
+    #[ink::test]
+    fn lower_lookup_works() {
+        let checkpoints = checkpoints();
+
+        // before the first key
+        assert_eq!(checkpoints.lower_lookup(0), 10);
+        assert_eq!(checkpoints.lower_lookup(2), 10);
+
+        // between the keys
+        assert_eq!(checkpoints.lower_lookup(3), 20);
+        assert_eq!(checkpoints.lower_lookup(5), 30);
+
+        // at and after the last key
+        assert_eq!(checkpoints.lower_lookup(6), 30);
+        assert_eq!(checkpoints.lower_lookup(7), 0);
+        assert_eq!(checkpoints.lower_lookup(u32::MAX), 0);
+    }
+
+    #[ink::test]
+    fn lookup_empty_works() {
+        let checkpoints = Checkpoints::<u32, u64>::new();
+
+        assert_eq!(checkpoints.upper_lookup(0), 0);
+        assert_eq!(checkpoints.upper_lookup(u32::MAX), 0);
+        assert_eq!(checkpoints.lower_lookup(0), 0);
+        assert_eq!(checkpoints.lower_lookup(u32::MAX), 0);
+    }
+
+    #[ink::test]
+    fn lookup_single_works() {
+        let mut checkpoints = Checkpoints::<u32, u64>::new();
+        checkpoints.push(0, 10);
+
+        assert_eq!(checkpoints.upper_lookup(0), 10);
+        assert_eq!(checkpoints.upper_lookup(1), 10);
+        assert_eq!(checkpoints.lower_lookup(0), 10);
+        assert_eq!(checkpoints.lower_lookup(1), 0);
+    }
+
+    #[ink::test]
+    fn map_push_works() {
+        let mut map = CheckpointsMap::<u8, u32, u64>::new();
+        assert!(map.is_empty(&1));
+        assert_eq!(map.latest(&1), 0);
+        assert_eq!(map.latest_checkpoint(&1), None);
+
+        assert_eq!(map.push(&1, 1, 10), (0, 10));
+        assert_eq!(map.push(&1, 3, 20), (10, 20));
+        assert_eq!(map.push(&1, 3, 25), (20, 25));
+        assert_eq!(map.push(&2, 2, 5), (0, 5));
+
+        assert_eq!(map.len(&1), 2);
+        assert_eq!(map.latest(&1), 25);
+        assert_eq!(map.at(&1, 0), Some(&Checkpoint { key: 1, value: 10 }));
+        assert_eq!(
+            map.latest_checkpoint(&1),
+            Some(&Checkpoint { key: 3, value: 25 })
+        );
+
+        // the checkpoints of each key are independent
+        assert_eq!(map.len(&2), 1);
+        assert_eq!(map.latest(&2), 5);
+        assert!(map.is_empty(&3));
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "CheckpointsMap: decreasing keys")]
+    fn map_push_decreasing_key_should_panic() {
+        let mut map = CheckpointsMap::<u8, u32, u64>::new();
+        map.push(&1, 4, 10);
+        map.push(&1, 3, 20);
+    }
+
+    #[ink::test]
+    fn map_lookup_works() {
+        let mut map = CheckpointsMap::<u8, u32, u64>::new();
+        map.push(&1, 2, 10);
+        map.push(&1, 4, 20);
+        map.push(&1, 6, 30);
+        map.push(&2, 3, 100);
+
+        assert_eq!(map.upper_lookup(&1, 1), 0);
+        assert_eq!(map.upper_lookup(&1, 3), 10);
+        assert_eq!(map.upper_lookup(&1, 4), 20);
+        assert_eq!(map.upper_lookup(&1, u32::MAX), 30);
+
+        assert_eq!(map.lower_lookup(&1, 0), 10);
+        assert_eq!(map.lower_lookup(&1, 5), 30);
+        assert_eq!(map.lower_lookup(&1, 7), 0);
+
+        assert_eq!(map.upper_lookup(&2, 2), 0);
+        assert_eq!(map.upper_lookup(&2, 3), 100);
+        assert_eq!(map.upper_lookup(&3, u32::MAX), 0);
+        assert_eq!(map.lower_lookup(&3, 0), 0);
+    }
+}
@@ -0,0 +1,101 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod double_ended_queue_tests {
+    use ink_lang as ink;
+    use metis_contract::storage::DoubleEndedQueue;
+
+    fn items(queue: &DoubleEndedQueue<u32>) -> Vec<u32> {
+        (0..queue.len())
+            .map(|index| *queue.at(index).expect("item should exist"))
+            .collect()
+    }
+
+    #[ink::test]
+    fn new_is_empty() {
+        let mut queue = DoubleEndedQueue::<u32>::new();
+
+        assert!(queue.is_empty());
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.front(), None);
+        assert_eq!(queue.back(), None);
+        assert_eq!(queue.at(0), None);
+        assert_eq!(queue.pop_front(), None);
+        assert_eq!(queue.pop_back(), None);
+    }
+
+    #[ink::test]
+    fn push_back_works() {
+        let mut queue = DoubleEndedQueue::new();
+        queue.push_back(1);
+        queue.push_back(2);
+
+        assert_eq!(items(&queue), vec![1, 2]);
+        assert_eq!(queue.front(), Some(&1));
+        assert_eq!(queue.back(), Some(&2));
+    }
+
+    #[ink::test]
+    fn push_front_on_empty_works() {
+        let mut queue = DoubleEndedQueue::new();
+        queue.push_front(1);
+
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.front(), Some(&1));
+        assert_eq!(queue.back(), Some(&1));
+
+        queue.push_front(0);
+        queue.push_back(2);
+        assert_eq!(items(&queue), vec![0, 1, 2]);
+    }
+
+    #[ink::test]
+    fn pop_works() {
+        let mut queue = DoubleEndedQueue::new();
+        for item in 0..4 {
+            queue.push_back(item);
+        }
+
+        assert_eq!(queue.pop_front(), Some(0));
+        assert_eq!(queue.pop_back(), Some(3));
+        assert_eq!(items(&queue), vec![1, 2]);
+
+        assert_eq!(queue.pop_back(), Some(2));
+        assert_eq!(queue.pop_back(), Some(1));
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop_front(), None);
+    }
+
+    #[ink::test]
+    fn pop_front_after_push_front_works() {
+        let mut queue = DoubleEndedQueue::new();
+        queue.push_front(1);
+        queue.push_front(0);
+
+        assert_eq!(queue.pop_back(), Some(1));
+        assert_eq!(queue.pop_front(), Some(0));
+        assert!(queue.is_empty());
+    }
+
+    #[ink::test]
+    fn clear_works() {
+        let mut queue = DoubleEndedQueue::new();
+        queue.push_front(0);
+        queue.push_back(1);
+        queue.push_back(2);
+
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.front(), None);
+        assert_eq!(queue.at(0), None);
+
+        // the queue can be used again
+        queue.push_front(3);
+        queue.push_back(4);
+        assert_eq!(items(&queue), vec![3, 4]);
+
+        // clear an empty queue is a no-op
+        let mut empty = DoubleEndedQueue::<u32>::new();
+        empty.clear();
+        assert!(empty.is_empty());
+    }
+}
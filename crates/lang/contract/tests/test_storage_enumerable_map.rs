@@ -0,0 +1,68 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod enumerable_map_tests {
+    use ink_lang as ink;
+    use metis_contract::storage::EnumerableMap;
+
+    fn entries(map: &EnumerableMap<u32, u64>) -> Vec<(u32, u64)> {
+        (0..map.len())
+            .map(|index| {
+                let (key, value) = map.at(index).expect("entry should exist");
+                (*key, *value)
+            })
+            .collect()
+    }
+
+    fn map_of(keys: &[u32]) -> EnumerableMap<u32, u64> {
+        let mut map = EnumerableMap::new();
+        for key in keys {
+            assert!(map.set(*key, *key as u64 * 100));
+        }
+        map
+    }
+
+    #[ink::test]
+    fn set_works() {
+        let mut map = map_of(&[1, 2]);
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&1), Some(&100));
+        assert_eq!(map.get(&3), None);
+
+        // set an existing key updates the value in place
+        assert!(!map.set(1, 111));
+        assert_eq!(entries(&map), vec![(1, 111), (2, 200)]);
+    }
+
+    #[ink::test]
+    fn remove_last_works() {
+        let mut map = map_of(&[1, 2, 3]);
+
+        assert_eq!(map.remove(&3), Some(300));
+        assert_eq!(entries(&map), vec![(1, 100), (2, 200)]);
+        assert!(!map.contains(&3));
+        assert_eq!(map.get(&3), None);
+    }
+
+    #[ink::test]
+    fn remove_middle_works() {
+        let mut map = map_of(&[1, 2, 3, 4]);
+
+        // the last entry is moved to the slot of the removed one
+        assert_eq!(map.remove(&2), Some(200));
+        assert_eq!(entries(&map), vec![(1, 100), (4, 400), (3, 300)]);
+
+        assert_eq!(map.remove(&4), Some(400));
+        assert_eq!(entries(&map), vec![(1, 100), (3, 300)]);
+    }
+
+    #[ink::test]
+    fn remove_all_works() {
+        let mut map = map_of(&[1]);
+
+        assert_eq!(map.remove(&1), Some(100));
+        assert!(map.is_empty());
+        assert_eq!(map.at(0), None);
+        assert_eq!(map.remove(&1), None);
+    }
+}
@@ -0,0 +1,138 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod enumerable_set_tests {
+    use ink_lang as ink;
+    use metis_contract::storage::{
+        EnumerableSet,
+        EnumerableSets,
+    };
+
+    fn set_of(values: &[u32]) -> EnumerableSet<u32> {
+        let mut set = EnumerableSet::new();
+        for value in values {
+            assert!(set.add(*value));
+        }
+        set
+    }
+
+    #[ink::test]
+    fn add_works() {
+        let mut set = set_of(&[1, 2, 3]);
+
+        assert_eq!(set.len(), 3);
+        assert!(!set.is_empty());
+        assert!(set.contains(&2));
+        assert!(!set.contains(&4));
+        assert_eq!(set.values(), vec![1, 2, 3]);
+
+        // add again is a no-op
+        assert!(!set.add(2));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[ink::test]
+    fn remove_last_works() {
+        let mut set = set_of(&[1, 2, 3]);
+
+        assert!(set.remove(&3));
+        assert_eq!(set.values(), vec![1, 2]);
+        assert!(!set.contains(&3));
+        assert_eq!(set.at(2), None);
+
+        // the indexes of the left values are kept
+        assert!(set.remove(&1));
+        assert_eq!(set.values(), vec![2]);
+    }
+
+    #[ink::test]
+    fn remove_middle_works() {
+        let mut set = set_of(&[1, 2, 3, 4]);
+
+        // the last value is moved to the slot of the removed one
+        assert!(set.remove(&2));
+        assert_eq!(set.values(), vec![1, 4, 3]);
+        assert_eq!(set.at(1), Some(&4));
+
+        // the index of the moved value is updated
+        assert!(set.remove(&4));
+        assert_eq!(set.values(), vec![1, 3]);
+        assert!(set.contains(&3));
+        assert!(!set.contains(&4));
+    }
+
+    #[ink::test]
+    fn remove_all_works() {
+        let mut set = set_of(&[1]);
+
+        assert!(set.remove(&1));
+        assert!(set.is_empty());
+        assert_eq!(set.at(0), None);
+
+        // remove a value not in the set is a no-op
+        assert!(!set.remove(&1));
+
+        // the set can be used again
+        assert!(set.add(1));
+        assert_eq!(set.values(), vec![1]);
+    }
+
+    #[ink::test]
+    fn sets_add_works() {
+        let mut sets = EnumerableSets::<u32, u32>::new();
+
+        assert!(sets.add(&1, 10));
+        assert!(sets.add(&1, 11));
+        assert!(sets.add(&2, 10));
+        assert!(!sets.add(&1, 10));
+
+        assert_eq!(sets.values(&1), vec![10, 11]);
+        assert_eq!(sets.values(&2), vec![10]);
+        assert!(sets.is_empty(&3));
+        assert!(sets.contains(&2, &10));
+        assert!(!sets.contains(&2, &11));
+    }
+
+    #[ink::test]
+    fn sets_remove_last_works() {
+        let mut sets = EnumerableSets::<u32, u32>::new();
+        for value in 10..13 {
+            sets.add(&1, value);
+        }
+
+        assert!(sets.remove(&1, &12));
+        assert_eq!(sets.values(&1), vec![10, 11]);
+        assert_eq!(sets.at(&1, 2), None);
+    }
+
+    #[ink::test]
+    fn sets_remove_middle_works() {
+        let mut sets = EnumerableSets::<u32, u32>::new();
+        for value in 10..14 {
+            sets.add(&1, value);
+        }
+        sets.add(&2, 11);
+
+        assert!(sets.remove(&1, &11));
+        assert_eq!(sets.values(&1), vec![10, 13, 12]);
+        assert!(sets.contains(&1, &13));
+
+        // the other sets are not changed
+        assert_eq!(sets.values(&2), vec![11]);
+
+        assert!(sets.remove(&1, &13));
+        assert_eq!(sets.values(&1), vec![10, 12]);
+    }
+
+    #[ink::test]
+    fn sets_remove_all_works() {
+        let mut sets = EnumerableSets::<u32, u32>::new();
+        sets.add(&1, 10);
+
+        assert!(sets.remove(&1, &10));
+        assert!(sets.is_empty(&1));
+        assert!(!sets.remove(&1, &10));
+
+        assert!(sets.add(&1, 11));
+        assert_eq!(sets.values(&1), vec![11]);
+    }
+}
@@ -47,6 +47,21 @@ pub fn stub(attr: TokenStream, item: TokenStream) -> TokenStream {
     stub::generate(attr.into(), item.into()).into()
 }
 
+/// The macro to generate the cross-contract stub from the messages of component.
+/// Use like
+///    #[metis_lang::stub_contract(Erc20Stub)]
+///    pub trait Erc20 {
+///        fn balance_of(&self, account: AccountId) -> Balance;
+///        #[ink(payable)]
+///        fn deposit(&mut self, payee: AccountId);
+///    }
+/// This will generate a ink! contract with `Erc20Stub` storage which impl the
+/// `FromAccountId`, the methods without receiver will be the constructors.
+#[proc_macro_attribute]
+pub fn stub_contract(attr: TokenStream, item: TokenStream) -> TokenStream {
+    stub::generate_contract(attr.into(), item.into()).into()
+}
+
 /// The macro to generate hash by input.
 #[proc_macro]
 pub fn hash(input: TokenStream) -> TokenStream {
@@ -11,3 +11,17 @@ pub fn generate(attr: TokenStream2, input: TokenStream2) -> TokenStream2 {
 pub fn generate_or_err(attr: TokenStream2, input: TokenStream2) -> Result<TokenStream2> {
     metis_lang_codegen::stub::generate_code(attr, input)
 }
+
+pub fn generate_contract(attr: TokenStream2, input: TokenStream2) -> TokenStream2 {
+    match generate_contract_or_err(attr, input) {
+        Ok(tokens) => tokens,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+pub fn generate_contract_or_err(
+    attr: TokenStream2,
+    input: TokenStream2,
+) -> Result<TokenStream2> {
+    metis_lang_codegen::stub::generate_contract_code(attr, input)
+}
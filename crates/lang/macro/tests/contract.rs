@@ -4,6 +4,7 @@ fn tests() {
     t.pass("tests/contract/01-flipper-contract.rs");
     t.pass("tests/contract/02-flipper-owner-contract.rs");
     t.pass("tests/contract/03-stub.rs");
+    t.pass("tests/contract/04-stub-contract.rs");
     // t.pass("tests/02-create-builder.rs");
     // t.pass("tests/03-call-setters.rs");
     // t.pass("tests/04-call-build.rs");
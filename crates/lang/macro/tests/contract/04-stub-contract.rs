@@ -0,0 +1,31 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_prelude::vec::Vec;
+
+/// The Error for stub
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    NotAllowed,
+}
+
+#[metis_lang::stub_contract(ReceiverStub)]
+pub trait Receiver {
+    fn default() -> Self;
+
+    /// Do something
+    fn do_sth(
+        &mut self,
+        operator: AccountId,
+        from: AccountId,
+        data: Vec<u8>,
+    ) -> core::result::Result<[u8; 4], Error>;
+
+    #[ink(payable)]
+    fn deposit(&mut self, to: AccountId);
+
+    #[ink(selector = "0xCAFEBABE")]
+    fn get(&self) -> Balance;
+}
+
+fn main() {}
@@ -5,6 +5,7 @@ pub use metis_contract::{
     Balance,
     BlockNumber,
     ChainExtension,
+    Context,
     Env,
     EnvAccess,
     FromAccountId,
@@ -13,6 +14,21 @@ pub use metis_contract::{
     Timestamp,
 };
 
+pub use metis_contract::{
+    arithmetic,
+    call,
+    context,
+    crypto,
+    merkle_proof,
+    storage,
+};
+
+#[cfg(feature = "std")]
+pub use metis_contract::events;
+
+#[cfg(feature = "mock")]
+pub use metis_contract::mock;
+
 pub use metis_lang_macro::{
     contract,
     hash,
@@ -21,5 +37,6 @@ pub use metis_lang_macro::{
     reentrancy_guard,
     selector_id,
     stub,
+    stub_contract,
     supports,
 };
@@ -0,0 +1,525 @@
+//! The behaviour suite for contracts which compose the erc20 component.
+//!
+//! ```ignore
+//! impl metis_test::Erc20Behaviour for Erc20 { ... }
+//!
+//! #[ink::test]
+//! fn should_erc20_behavior_work() {
+//!     metis_test::erc20_behaves_like::<Erc20>(100000000000000000);
+//! }
+//! ```
+
+use crate::{
+    assert_topics,
+    default_accounts,
+    field_topic,
+    get_emitted_events,
+    next_call_by,
+    signature_topic,
+    AccountId,
+    Balance,
+};
+use core::fmt::Debug;
+use ink_env::test::EmittedEvent;
+
+/// The kinds of errors checked by the erc20 behaviour suite, each contract
+/// maps them into its own error type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Erc20ErrorKind {
+    /// Not enough balance to fulfill a request is available.
+    InsufficientBalance,
+    /// Not enough allowance to fulfill a request is available.
+    InsufficientAllowance,
+    /// The account is zero.
+    AccountIsZero,
+}
+
+/// Trait implemented by the contracts which should behave like an ERC-20.
+pub trait Erc20Behaviour: Sized {
+    /// The error type returned by the messages.
+    type Error: Debug + PartialEq;
+
+    /// The name of the contract storage, the prefix of the event topics, such
+    /// as `"Erc20"`.
+    const NAME: &'static str;
+
+    /// Create the contract, the `initial_supply` is minted to the caller.
+    fn new_erc20(
+        name: String,
+        symbol: String,
+        decimals: u8,
+        initial_supply: Balance,
+    ) -> Self;
+
+    /// Map the error `kind` into the error of the contract.
+    fn error(kind: Erc20ErrorKind) -> Self::Error;
+
+    /// Decode `event` as a `Transfer` event, return `(from, to, value)`.
+    fn decode_transfer_event(
+        event: &EmittedEvent,
+    ) -> Option<(Option<AccountId>, Option<AccountId>, Balance)>;
+
+    /// Decode `event` as an `Approval` event, return `(owner, spender, value)`.
+    fn decode_approval_event(
+        event: &EmittedEvent,
+    ) -> Option<(AccountId, AccountId, Balance)>;
+
+    /// Returns the name of the token.
+    fn name(&self) -> String;
+
+    /// Returns the symbol of the token.
+    fn symbol(&self) -> String;
+
+    /// Returns the number of decimals used to get its user representation.
+    fn decimals(&self) -> u8;
+
+    /// Returns the total token supply.
+    fn total_supply(&self) -> Balance;
+
+    /// Returns the account balance for the specified `owner`.
+    fn balance_of(&self, owner: AccountId) -> Balance;
+
+    /// Returns the remaining number of tokens that `spender` will be
+    /// allowed to spend on behalf of `owner`.
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance;
+
+    /// Moves `value` tokens from the caller's account to `to`.
+    fn transfer(&mut self, to: AccountId, value: Balance) -> Result<(), Self::Error>;
+
+    /// Sets `value` as the allowance of `spender` over the caller's tokens.
+    fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), Self::Error>;
+
+    /// Moves `value` tokens from `from` to `to` using the allowance mechanism.
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+    ) -> Result<(), Self::Error>;
+}
+
+/// Run the standard ERC-20 tests against the contract `C`, the contract will
+/// be created by alice with `init_amount` tokens for each case.
+pub fn erc20_behaves_like<C: Erc20Behaviour>(init_amount: Balance) {
+    let accounts = default_accounts();
+    let checker = Erc20BehaviourChecker::<C> {
+        init_amount,
+        owner: accounts.alice,
+        bob: accounts.bob,
+        zero_account: AccountId::from([0x00; 32]),
+        _contract: core::marker::PhantomData,
+    };
+
+    checker.init_state_should_work();
+    checker.should_behave_like_erc20_transfer();
+    checker.should_behave_like_erc20_transfer_from();
+    checker.should_behave_like_erc20_approve();
+}
+
+struct Erc20BehaviourChecker<C: Erc20Behaviour> {
+    init_amount: Balance,
+    owner: AccountId,
+    bob: AccountId,
+    zero_account: AccountId,
+    _contract: core::marker::PhantomData<C>,
+}
+
+impl<C: Erc20Behaviour> Erc20BehaviourChecker<C> {
+    fn new_contract(&self, initial_supply: Balance) -> C {
+        next_call_by(&self.owner);
+        C::new_erc20(
+            String::from("MockErc20Token"),
+            String::from("MET"),
+            18_u8,
+            initial_supply,
+        )
+    }
+
+    fn topic_prefix(event: &str, field: &str) -> Vec<u8> {
+        format!("{}::{}::{}", C::NAME, event, field).into_bytes()
+    }
+
+    /// Find the last `Transfer` event emitted after the `from` index.
+    fn last_transfer_event_since(from: usize) -> EmittedEvent {
+        get_emitted_events()
+            .into_iter()
+            .skip(from)
+            .filter(|event| C::decode_transfer_event(event).is_some())
+            .last()
+            .expect("encountered no Transfer event")
+    }
+
+    /// Find the last `Approval` event emitted after the `from` index.
+    fn last_approval_event_since(from: usize) -> EmittedEvent {
+        get_emitted_events()
+            .into_iter()
+            .skip(from)
+            .filter(|event| C::decode_approval_event(event).is_some())
+            .last()
+            .expect("encountered no Approval event")
+    }
+
+    fn assert_transfer_event(
+        event: &EmittedEvent,
+        expected_from: Option<AccountId>,
+        expected_to: Option<AccountId>,
+        expected_value: Balance,
+    ) {
+        let (from, to, value) = C::decode_transfer_event(event)
+            .expect("encountered unexpected event kind: expected a Transfer event");
+        assert_eq!(from, expected_from, "encountered invalid Transfer.from");
+        assert_eq!(to, expected_to, "encountered invalid Transfer.to");
+        assert_eq!(value, expected_value, "encountered invalid Transfer.value");
+
+        assert_topics(
+            event,
+            &[
+                signature_topic(format!("{}::Transfer", C::NAME).as_bytes()),
+                field_topic(&Self::topic_prefix("Transfer", "from"), &expected_from),
+                field_topic(&Self::topic_prefix("Transfer", "to"), &expected_to),
+            ],
+        );
+    }
+
+    fn assert_approval_event(
+        event: &EmittedEvent,
+        expected_owner: AccountId,
+        expected_spender: AccountId,
+        expected_value: Balance,
+    ) {
+        let (owner, spender, value) = C::decode_approval_event(event)
+            .expect("encountered unexpected event kind: expected a Approval event");
+        assert_eq!(owner, expected_owner, "encountered invalid Approval.owner");
+        assert_eq!(
+            spender, expected_spender,
+            "encountered invalid Approval.spender"
+        );
+        assert_eq!(value, expected_value, "encountered invalid Approval.value");
+
+        assert_topics(
+            event,
+            &[
+                signature_topic(format!("{}::Approval", C::NAME).as_bytes()),
+                field_topic(&Self::topic_prefix("Approval", "owner"), &expected_owner),
+                field_topic(
+                    &Self::topic_prefix("Approval", "spender"),
+                    &expected_spender,
+                ),
+            ],
+        );
+    }
+
+    /// Transfer by `from` and check the emitted `Transfer` event if ok.
+    fn transfer(
+        erc20: &mut C,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+    ) -> Result<(), C::Error> {
+        let events_len = get_emitted_events().len();
+
+        next_call_by(&from);
+        let res = erc20.transfer(to, value);
+
+        if res.is_ok() {
+            Self::assert_transfer_event(
+                &Self::last_transfer_event_since(events_len),
+                Some(from),
+                Some(to),
+                value,
+            );
+        }
+
+        res
+    }
+
+    /// Approve by `owner` and check the emitted `Approval` event if ok.
+    fn approve(
+        erc20: &mut C,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+    ) -> Result<(), C::Error> {
+        let events_len = get_emitted_events().len();
+
+        next_call_by(&owner);
+        let res = erc20.approve(spender, value);
+
+        if res.is_ok() {
+            Self::assert_approval_event(
+                &Self::last_approval_event_since(events_len),
+                owner,
+                spender,
+                value,
+            );
+        }
+
+        res
+    }
+
+    fn init_state_should_work(&self) {
+        let events_len = get_emitted_events().len();
+        let erc20 = self.new_contract(self.init_amount);
+
+        // for emit the init transfer
+        Self::assert_transfer_event(
+            &Self::last_transfer_event_since(events_len),
+            None,
+            Some(self.owner),
+            self.init_amount,
+        );
+
+        // for metadatas
+        assert_eq!(
+            String::from("MockErc20Token"),
+            erc20.name(),
+            "name should be default"
+        );
+        assert_eq!(
+            String::from("MET"),
+            erc20.symbol(),
+            "symbol should be default"
+        );
+        assert_eq!(18, erc20.decimals(), "default decimals should be 18");
+
+        // for init amount
+        assert_eq!(
+            self.init_amount,
+            erc20.total_supply(),
+            "total amount should be default"
+        );
+        assert_eq!(
+            self.init_amount,
+            erc20.balance_of(self.owner),
+            "default account balance_of should be default"
+        );
+        assert_eq!(
+            0,
+            erc20.balance_of(self.bob),
+            "others accounts balance should be 0"
+        );
+    }
+
+    fn should_behave_like_erc20_transfer(&self) {
+        let (from, to, balance) = (self.owner, self.bob, self.init_amount);
+        let mut erc20 = self.new_contract(balance);
+
+        // when the sender does not have enough balance
+        assert_eq!(
+            Self::transfer(&mut erc20, from, to, balance + 1),
+            Err(C::error(Erc20ErrorKind::InsufficientBalance)),
+            "when the sender does not have enough balance"
+        );
+
+        // when the sender transfers all balance
+        assert_eq!(
+            Self::transfer(&mut erc20, from, to, balance),
+            Ok(()),
+            "when the sender transfers all balance"
+        );
+        assert_eq!(erc20.balance_of(from), 0, "from amount should be 0");
+        assert_eq!(erc20.balance_of(to), balance, "to amount should be balance");
+
+        // when the sender transfers zero tokens
+        assert_eq!(
+            Self::transfer(&mut erc20, to, from, 0),
+            Ok(()),
+            "when the sender transfers zero tokens"
+        );
+        assert_eq!(
+            erc20.balance_of(from),
+            0,
+            "from amount should be 0 as no changed"
+        );
+        assert_eq!(
+            erc20.balance_of(to),
+            balance,
+            "to amount should be balance as no changed"
+        );
+    }
+
+    fn should_behave_like_erc20_transfer_from(&self) {
+        self._transfer_from_request_amount_should_ok();
+        self._transfer_from_approved_no_enough_should_err();
+        self._transfer_from_to_zero_account_should_err();
+        self._transfer_from_from_is_zero_account_should_err();
+    }
+
+    fn _transfer_from_request_amount_should_ok(&self) {
+        let (from, to, amount) = (self.owner, self.bob, self.init_amount);
+        let mut erc20 = self.new_contract(amount);
+
+        // when the spender has enough approved balance
+        assert_eq!(
+            Self::approve(&mut erc20, from, to, amount),
+            Ok(()),
+            "approve from spender to to should ok"
+        );
+        assert_eq!(erc20.allowance(from, to), amount, "the spender allowance");
+
+        // call transfer_from by to
+        let events_len = get_emitted_events().len();
+        next_call_by(&to);
+        assert_eq!(
+            erc20.transfer_from(from, to, amount),
+            Ok(()),
+            "transfers the requested amount should ok"
+        );
+
+        Self::assert_transfer_event(
+            &Self::last_transfer_event_since(events_len),
+            Some(from),
+            Some(to),
+            amount,
+        );
+        Self::assert_approval_event(
+            &Self::last_approval_event_since(events_len),
+            from,
+            to,
+            0,
+        );
+
+        assert_eq!(erc20.balance_of(from), 0, "from amount should be 0");
+        assert_eq!(erc20.balance_of(to), amount, "to amount should be amount");
+        assert_eq!(
+            erc20.allowance(from, to),
+            0,
+            "decreases the spender allowance should ok"
+        );
+    }
+
+    fn _transfer_from_approved_no_enough_should_err(&self) {
+        let (from, to, amount) = (self.owner, self.bob, self.init_amount);
+        let mut erc20 = self.new_contract(amount);
+
+        assert_eq!(
+            Self::approve(&mut erc20, from, to, amount - 1),
+            Ok(()),
+            "approve from spender to to should ok"
+        );
+
+        // the token owner has enough balance
+        next_call_by(&to);
+        assert_eq!(
+            erc20.transfer_from(from, to, amount),
+            Err(C::error(Erc20ErrorKind::InsufficientAllowance)),
+            "the token owner has enough balance should error"
+        );
+
+        // the token owner does not have enough balance
+        next_call_by(&from);
+        assert_eq!(
+            erc20.transfer_from(from, to, amount + 1),
+            Err(C::error(Erc20ErrorKind::InsufficientAllowance)),
+            "the token owner does not have enough balance should error"
+        );
+    }
+
+    fn _transfer_from_to_zero_account_should_err(&self) {
+        let (from, to, amount) = (self.owner, self.bob, self.init_amount);
+        let mut erc20 = self.new_contract(amount);
+
+        assert_eq!(
+            Self::approve(&mut erc20, from, to, amount),
+            Ok(()),
+            "approve from spender to to should ok"
+        );
+
+        next_call_by(&to);
+        assert_eq!(
+            erc20.transfer_from(from, self.zero_account, amount),
+            Err(C::error(Erc20ErrorKind::AccountIsZero)),
+            "transfer to the zero address should error"
+        );
+
+        assert_eq!(erc20.balance_of(from), amount, "from amount no changed");
+        assert_eq!(erc20.balance_of(to), 0, "to amount no changed");
+        assert_eq!(
+            erc20.allowance(from, to),
+            amount,
+            "the spender allowance no changed"
+        );
+    }
+
+    fn _transfer_from_from_is_zero_account_should_err(&self) {
+        let (from, to, amount) = (self.owner, self.bob, self.init_amount);
+        let mut erc20 = self.new_contract(amount);
+
+        assert_eq!(
+            Self::approve(&mut erc20, from, to, amount),
+            Ok(()),
+            "approve from spender to to should ok"
+        );
+
+        next_call_by(&to);
+        assert_eq!(
+            erc20.transfer_from(self.zero_account, to, amount),
+            Err(C::error(Erc20ErrorKind::InsufficientAllowance)),
+            "transfer from the zero address should error"
+        );
+
+        assert_eq!(erc20.balance_of(from), amount, "from amount no changed");
+        assert_eq!(erc20.balance_of(to), 0, "to amount no changed");
+        assert_eq!(
+            erc20.allowance(from, to),
+            amount,
+            "the spender allowance no changed"
+        );
+    }
+
+    fn should_behave_like_erc20_approve(&self) {
+        let (from, to, amount) = (self.owner, self.bob, self.init_amount);
+
+        // approve is not limited by the balance of the owner
+        for approved in [amount, amount + 1].iter().copied() {
+            // when there was no approved amount before
+            let mut erc20 = self.new_contract(amount);
+            assert_eq!(
+                Self::approve(&mut erc20, from, to, approved),
+                Ok(()),
+                "approve from spender to to should ok"
+            );
+            assert_eq!(
+                erc20.allowance(from, to),
+                approved,
+                "the spender allowance should ok"
+            );
+
+            // when the spender had an approved amount, replaces it
+            let mut erc20 = self.new_contract(amount);
+            assert_eq!(
+                Self::approve(&mut erc20, from, to, 1),
+                Ok(()),
+                "approve from spender to to with 1 should ok"
+            );
+            assert_eq!(
+                erc20.allowance(from, to),
+                1,
+                "the spender allowance should ok"
+            );
+            assert_eq!(
+                Self::approve(&mut erc20, from, to, approved),
+                Ok(()),
+                "approve from spender to to should replace"
+            );
+            assert_eq!(
+                erc20.allowance(from, to),
+                approved,
+                "the spender allowance should be replaced"
+            );
+        }
+
+        // when the spender is the zero address
+        let mut erc20 = self.new_contract(amount);
+        assert_eq!(
+            Self::approve(&mut erc20, from, self.zero_account, amount),
+            Err(C::error(Erc20ErrorKind::AccountIsZero)),
+            "approve from spender to zero with amount should error"
+        );
+        assert_eq!(
+            erc20.allowance(from, self.zero_account),
+            0,
+            "the spender allowance should be 0"
+        );
+    }
+}
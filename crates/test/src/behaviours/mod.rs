@@ -0,0 +1,7 @@
+//! Reusable behaviour suites for components.
+//!
+//! A suite is a trait which abstracts the messages of a component, with a
+//! `*_behaves_like` function that runs the standard tests of the component
+//! against any contract implementing the trait.
+
+pub mod erc20;
@@ -0,0 +1,110 @@
+//! Helpers to control the off-chain environment.
+
+use crate::{
+    AccountId,
+    Balance,
+    BlockNumber,
+    Timestamp,
+};
+use ink_env::{
+    call,
+    test,
+    DefaultEnvironment,
+};
+
+/// The gas limit used by the pushed execution contexts.
+const GAS_LIMIT: u64 = 1000000;
+
+/// Get the default accounts of the off-chain environment.
+pub fn default_accounts() -> test::DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+        .expect("Off-chain environment should have been initialized already")
+}
+
+/// Get the account id of the contract under test.
+pub fn contract_id() -> AccountId {
+    test::get_current_contract_account_id::<DefaultEnvironment>()
+        .expect("Cannot get contract id")
+}
+
+/// Push a new execution context, the next call will be from `caller` with
+/// `value` native balance transferred.
+pub fn set_caller_with_value(caller: AccountId, value: Balance) {
+    let callee = ink_env::account_id::<DefaultEnvironment>().unwrap_or([0x0; 32].into());
+    let mut data = test::CallData::new(call::Selector::new([0x00; 4])); // dummy
+
+    data.push_arg(&caller);
+
+    test::push_execution_context::<DefaultEnvironment>(
+        caller, callee, GAS_LIMIT, value, data,
+    );
+}
+
+/// Push a new execution context, the next call will be from `caller`.
+pub fn set_caller(caller: AccountId) {
+    set_caller_with_value(caller, 0);
+}
+
+/// Push a new execution context, the next call will be from `account` with
+/// a default endowment.
+pub fn next_call_by(account: &AccountId) {
+    set_caller_with_value(account.clone(), GAS_LIMIT as Balance);
+}
+
+/// Set the native balance of `account`.
+pub fn set_balance(account: AccountId, balance: Balance) {
+    test::set_account_balance::<DefaultEnvironment>(account, balance)
+        .expect("Cannot set account balance");
+}
+
+/// Get the native balance of `account`.
+pub fn get_balance(account: AccountId) -> Balance {
+    test::get_account_balance::<DefaultEnvironment>(account)
+        .expect("Cannot get account balance")
+}
+
+/// Get the timestamp of the current block.
+pub fn block_timestamp() -> Timestamp {
+    ink_env::block_timestamp::<DefaultEnvironment>().expect("Cannot get block timestamp")
+}
+
+/// Get the number of the current block.
+pub fn block_number() -> BlockNumber {
+    ink_env::block_number::<DefaultEnvironment>().expect("Cannot get block number")
+}
+
+/// Advance one block, the block timestamp will be increased.
+pub fn advance_block() {
+    test::advance_block::<DefaultEnvironment>().expect("Cannot advance block");
+}
+
+/// Advance blocks until the block timestamp passed the `timestamp`.
+pub fn advance_after(timestamp: Timestamp) {
+    while block_timestamp() <= timestamp {
+        advance_block();
+    }
+}
+
+/// Call `f` as `caller`, the execution context is popped after the call.
+pub fn call_as<R, F>(caller: AccountId, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    set_caller(caller);
+    let res = f();
+    test::pop_execution_context();
+    res
+}
+
+/// Run `f` in a new off-chain environment, same as the `#[ink::test]`, for
+/// the tests which can not use the attribute, such as the proptest cases.
+pub fn run_in_env<F>(f: F)
+where
+    F: FnOnce(),
+{
+    test::run_test::<DefaultEnvironment, _>(|_| {
+        f();
+        Ok(())
+    })
+    .expect("Cannot run test in off-chain environment");
+}
@@ -0,0 +1,120 @@
+//! Helpers to decode and check the events emitted in the off-chain environment.
+
+use crate::Hash;
+use ink_env::test::{
+    self,
+    EmittedEvent,
+};
+use scale::DecodeAll;
+
+/// The topics are calculated by `metis_lang::events`, same as the codegen.
+pub use metis_lang::events::{
+    encoded_into_hash,
+    field_topic,
+    signature_topic,
+    PrefixedValue,
+};
+
+/// Get all the emitted events.
+pub fn get_emitted_events() -> Vec<EmittedEvent> {
+    test::recorded_events().collect::<Vec<_>>()
+}
+
+/// Get the last emitted event.
+pub fn get_last_emitted_event() -> EmittedEvent {
+    get_emitted_events()
+        .pop()
+        .expect("encountered no emitted event")
+}
+
+/// Check event emitted current len is expected, return the emitted events.
+pub fn assert_emitted_event_len(expected: usize) -> Vec<EmittedEvent> {
+    let emitted_events = get_emitted_events();
+    assert_eq!(
+        expected,
+        emitted_events.len(),
+        "encountered invalid emitted event len"
+    );
+    emitted_events
+}
+
+/// Decode an emitted event as the event struct `T`.
+///
+/// The data of an emitted event is the encoded event enum of the contract,
+/// so the first byte is the index of the event, and the rest is the event
+/// struct. Return `None` if the rest can not be decoded as a `T` exactly.
+///
+/// NOTE: the variant index is not checked, events with the same layout can
+/// be decoded as each other, use `decode_event_with_signature` for them.
+pub fn decode_event<T>(event: &EmittedEvent) -> Option<T>
+where
+    T: scale::Decode,
+{
+    let (_, data) = event.data.split_first()?;
+    <T as DecodeAll>::decode_all(data).ok()
+}
+
+/// Decode an emitted event as the event struct `T`, only if its first topic
+/// is the topic of `signature`, such as `b"Erc20::Transfer"`.
+pub fn decode_event_with_signature<T>(event: &EmittedEvent, signature: &[u8]) -> Option<T>
+where
+    T: scale::Decode,
+{
+    let topic = event.topics.first()?.decode::<Hash>().ok()?;
+    if topic != signature_topic(signature) {
+        return None
+    }
+
+    decode_event(event)
+}
+
+/// Decode the emitted event at `index` as `T`, panic if it is not a `T`.
+pub fn expect_event_at<T>(index: usize) -> T
+where
+    T: scale::Decode,
+{
+    let emitted_events = get_emitted_events();
+    let event = emitted_events
+        .get(index)
+        .unwrap_or_else(|| panic!("encountered no emitted event at {}", index));
+
+    decode_event(event).unwrap_or_else(|| {
+        panic!(
+            "encountered unexpected event kind: expected a {}",
+            core::any::type_name::<T>()
+        )
+    })
+}
+
+/// Decode the last emitted event as `T`, panic if it is not a `T`.
+///
+/// ```ignore
+/// let Transfer { from, to, value } = expect_event::<Transfer>();
+/// ```
+pub fn expect_event<T>() -> T
+where
+    T: scale::Decode,
+{
+    let len = get_emitted_events().len();
+    assert!(len > 0, "encountered no emitted event");
+
+    expect_event_at(len - 1)
+}
+
+/// Assert the topics of `event` are the `expected`.
+pub fn assert_topics(event: &EmittedEvent, expected: &[Hash]) {
+    assert_eq!(
+        event.topics.len(),
+        expected.len(),
+        "encountered invalid topics len"
+    );
+
+    for (n, (actual_topic, expected_topic)) in
+        event.topics.iter().zip(expected).enumerate()
+    {
+        let topic = actual_topic
+            .decode::<Hash>()
+            .expect("encountered invalid topic encoding");
+        assert_eq!(topic, *expected_topic, "encountered invalid topic at {}", n);
+    }
+}
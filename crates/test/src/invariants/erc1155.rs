@@ -0,0 +1,508 @@
+//! The invariant harness for contracts which compose the erc1155 component.
+//!
+//! ```ignore
+//! impl metis_test::Erc1155Invariant for Erc1155 { ... }
+//!
+//! proptest! {
+//!     #[test]
+//!     fn erc1155_invariants_hold(ops in metis_test::erc1155_ops(100, 32)) {
+//!         metis_test::run_erc1155_invariants::<Erc1155>(&ops);
+//!     }
+//! }
+//! ```
+
+use super::{
+    account,
+    all_accounts,
+    caller_accounts,
+    caller_index,
+    target_index,
+};
+use crate::{
+    call_as,
+    get_emitted_events,
+    run_in_env,
+    AccountId,
+    Balance,
+};
+use core::fmt::Debug;
+use ink_env::test::EmittedEvent;
+use proptest::prelude::*;
+use std::collections::BTreeMap;
+
+/// The count of the token ids used by the harness.
+pub const TOKEN_TYPES_COUNT: u8 = 4;
+
+/// The operations on an erc1155 contract, the accounts are the index of
+/// `invariants::account`, the tokens are the seeds of `token_id`.
+#[derive(Debug, Clone)]
+pub enum Erc1155Op {
+    /// Mint `amount` of the token to `to`.
+    Mint { to: u8, token: u8, amount: Balance },
+    /// `caller` burns `amount` of the token from `from`.
+    Burn {
+        caller: u8,
+        from: u8,
+        token: u8,
+        amount: Balance,
+    },
+    /// `caller` transfers `amount` of the token from `from` to `to`.
+    Transfer {
+        caller: u8,
+        from: u8,
+        to: u8,
+        token: u8,
+        amount: Balance,
+    },
+    /// `caller` transfers the `(token, amount)` items from `from` to `to`.
+    BatchTransfer {
+        caller: u8,
+        from: u8,
+        to: u8,
+        items: Vec<(u8, Balance)>,
+    },
+    /// `caller` sets the `approved` of `operator` for all its tokens.
+    SetApprovalForAll {
+        caller: u8,
+        operator: u8,
+        approved: bool,
+    },
+}
+
+/// Strategy of an erc1155 operation, the amounts are in `0..=max_amount`.
+pub fn erc1155_op(max_amount: Balance) -> impl Strategy<Value = Erc1155Op> {
+    let token = 0..TOKEN_TYPES_COUNT;
+    let amount = 0..=max_amount;
+
+    prop_oneof![
+        3 => (target_index(), token.clone(), amount.clone())
+            .prop_map(|(to, token, amount)| Erc1155Op::Mint { to, token, amount }),
+        1 => (caller_index(), caller_index(), token.clone(), amount.clone()).prop_map(
+            |(caller, from, token, amount)| Erc1155Op::Burn {
+                caller,
+                from,
+                token,
+                amount,
+            }
+        ),
+        4 => (
+            caller_index(),
+            caller_index(),
+            target_index(),
+            token.clone(),
+            amount.clone()
+        )
+            .prop_map(|(caller, from, to, token, amount)| Erc1155Op::Transfer {
+                caller,
+                from,
+                to,
+                token,
+                amount,
+            }),
+        2 => (
+            caller_index(),
+            caller_index(),
+            target_index(),
+            prop::collection::vec((token, amount), 1..4)
+        )
+            .prop_map(|(caller, from, to, items)| Erc1155Op::BatchTransfer {
+                caller,
+                from,
+                to,
+                items,
+            }),
+        2 => (caller_index(), target_index(), any::<bool>()).prop_map(
+            |(caller, operator, approved)| Erc1155Op::SetApprovalForAll {
+                caller,
+                operator,
+                approved,
+            }
+        ),
+    ]
+}
+
+/// Strategy of a sequence of erc1155 operations, with at most `max_len` ops.
+pub fn erc1155_ops(
+    max_amount: Balance,
+    max_len: usize,
+) -> impl Strategy<Value = Vec<Erc1155Op>> {
+    prop::collection::vec(erc1155_op(max_amount), 1..max_len)
+}
+
+/// Trait implemented by the erc1155 contracts checked by the invariant
+/// harness, the optional messages return `None` if the contract has no such
+/// message.
+pub trait Erc1155Invariant: Sized {
+    /// The token id type of the contract.
+    type TokenId: Copy + Debug + Ord;
+
+    /// The error type returned by the messages.
+    type Error: Debug;
+
+    /// Create the contract.
+    fn new_erc1155() -> Self;
+
+    /// Get the token id by the `seed` in `0..TOKEN_TYPES_COUNT`.
+    fn token_id(seed: u8) -> Self::TokenId;
+
+    /// Decode `event` as a `TransferSingle` or a `TransferBatch` event,
+    /// return `(from, to, [(id, value)])`.
+    fn decode_transfer_event(
+        event: &EmittedEvent,
+    ) -> Option<(
+        Option<AccountId>,
+        Option<AccountId>,
+        Vec<(Self::TokenId, Balance)>,
+    )>;
+
+    /// Decode `event` as an `ApprovalForAll` event, return
+    /// `(owner, operator, approved)`.
+    fn decode_approval_for_all_event(
+        event: &EmittedEvent,
+    ) -> Option<(AccountId, AccountId, bool)>;
+
+    /// Returns the amount of tokens of token type `id` owned by `account`.
+    fn balance_of(&self, account: AccountId, id: Self::TokenId) -> Balance;
+
+    /// Returns true if `operator` is approved to transfer ``account``'s tokens.
+    fn is_approved_for_all(&self, account: AccountId, operator: AccountId) -> bool;
+
+    /// Creates `amount` tokens of token type `id`, and assigns them to `to`.
+    fn mint(
+        &mut self,
+        to: AccountId,
+        id: Self::TokenId,
+        amount: Balance,
+    ) -> Result<(), Self::Error>;
+
+    /// Destroys `amount` tokens of token type `id` from `from` by the caller.
+    fn burn(
+        &mut self,
+        _from: AccountId,
+        _id: Self::TokenId,
+        _amount: Balance,
+    ) -> Option<Result<(), Self::Error>> {
+        None
+    }
+
+    /// Transfers `amount` tokens of token type `id` from `from` to `to` by
+    /// the caller.
+    fn safe_transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        id: Self::TokenId,
+        amount: Balance,
+    ) -> Result<(), Self::Error>;
+
+    /// Batched version of `safe_transfer_from`.
+    fn safe_batch_transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        ids: Vec<Self::TokenId>,
+        amounts: Vec<Balance>,
+    ) -> Result<(), Self::Error>;
+
+    /// Grants or revokes permission to `operator` to transfer the caller's tokens.
+    fn set_approval_for_all(&mut self, operator: AccountId, approved: bool);
+}
+
+/// Run the erc1155 invariant harness in a new off-chain environment, the
+/// contract will be created by alice.
+pub fn run_erc1155_invariants<C: Erc1155Invariant>(ops: &[Erc1155Op]) {
+    run_in_env(|| check_erc1155_invariants::<C>(ops))
+}
+
+/// Run the erc1155 invariant harness in the current off-chain environment.
+pub fn check_erc1155_invariants<C: Erc1155Invariant>(ops: &[Erc1155Op]) {
+    let mut contract = call_as(account(1), C::new_erc1155);
+
+    let mut ledger = Erc1155Ledger::<C::TokenId>::default();
+    let mut events_count = ledger.replay::<C>(0);
+    ledger.check(&contract, "constructor");
+
+    for (step, op) in ops.iter().enumerate() {
+        let step = format!("step {} {:?}", step, op);
+
+        if would_trap(&contract, op) {
+            continue
+        }
+
+        // transfer to the zero account is the only failure without trap
+        let should_fail = match *op {
+            Erc1155Op::Mint { to, .. }
+            | Erc1155Op::Transfer { to, .. }
+            | Erc1155Op::BatchTransfer { to, .. } => account(to) == account(0),
+            _ => false,
+        };
+
+        match execute(&mut contract, op) {
+            Some(res) => {
+                assert_eq!(
+                    res.is_err(),
+                    should_fail,
+                    "{}: unexpected result {:?}",
+                    step,
+                    res
+                );
+                if should_fail {
+                    assert_eq!(
+                        get_emitted_events().len(),
+                        events_count,
+                        "{}: failed operation should not emit events",
+                        step
+                    );
+                }
+            }
+            None => continue,
+        }
+
+        events_count = ledger.replay::<C>(events_count);
+        ledger.check(&contract, &step);
+    }
+}
+
+/// Whether the operation would trap by the preconditions of the contract.
+fn would_trap<C: Erc1155Invariant>(contract: &C, op: &Erc1155Op) -> bool {
+    let is_allowed = |caller: u8, from: u8| {
+        account(caller) == account(from)
+            || contract.is_approved_for_all(account(from), account(caller))
+    };
+    let zero = account(0);
+
+    match op {
+        Erc1155Op::Mint { .. } => false,
+        Erc1155Op::Burn {
+            caller,
+            from,
+            token,
+            amount,
+        } => {
+            !is_allowed(*caller, *from)
+                || contract.balance_of(account(*from), C::token_id(*token)) < *amount
+        }
+        Erc1155Op::Transfer {
+            caller,
+            from,
+            to,
+            token,
+            amount,
+        } => {
+            !is_allowed(*caller, *from)
+                || (account(*to) != zero
+                    && contract.balance_of(account(*from), C::token_id(*token)) < *amount)
+        }
+        Erc1155Op::BatchTransfer {
+            caller,
+            from,
+            to,
+            items,
+        } => {
+            if !is_allowed(*caller, *from) {
+                return true
+            }
+            if account(*to) == zero {
+                return false
+            }
+
+            // the items are transferred one by one, the balance of `from`
+            // keeps the same if it transfers to itself.
+            let mut spent = BTreeMap::<u8, Balance>::new();
+            items.iter().any(|(token, amount)| {
+                let balance = contract.balance_of(account(*from), C::token_id(*token));
+                let total = spent.entry(*token).or_default();
+                if from != to {
+                    *total += amount;
+                } else {
+                    *total = *amount;
+                }
+                *total > balance
+            })
+        }
+        Erc1155Op::SetApprovalForAll {
+            caller, operator, ..
+        } => account(*caller) == account(*operator),
+    }
+}
+
+/// Execute the operation, `None` if the contract has no such message.
+fn execute<C: Erc1155Invariant>(
+    contract: &mut C,
+    op: &Erc1155Op,
+) -> Option<Result<(), C::Error>> {
+    match op {
+        Erc1155Op::Mint { to, token, amount } => {
+            Some(contract.mint(account(*to), C::token_id(*token), *amount))
+        }
+        Erc1155Op::Burn {
+            caller,
+            from,
+            token,
+            amount,
+        } => {
+            call_as(account(*caller), || {
+                contract.burn(account(*from), C::token_id(*token), *amount)
+            })
+        }
+        Erc1155Op::Transfer {
+            caller,
+            from,
+            to,
+            token,
+            amount,
+        } => {
+            Some(call_as(account(*caller), || {
+                contract.safe_transfer_from(
+                    account(*from),
+                    account(*to),
+                    C::token_id(*token),
+                    *amount,
+                )
+            }))
+        }
+        Erc1155Op::BatchTransfer {
+            caller,
+            from,
+            to,
+            items,
+        } => {
+            let ids = items.iter().map(|(token, _)| C::token_id(*token)).collect();
+            let amounts = items.iter().map(|(_, amount)| *amount).collect();
+
+            Some(call_as(account(*caller), || {
+                contract.safe_batch_transfer_from(
+                    account(*from),
+                    account(*to),
+                    ids,
+                    amounts,
+                )
+            }))
+        }
+        Erc1155Op::SetApprovalForAll {
+            caller,
+            operator,
+            approved,
+        } => {
+            call_as(account(*caller), || {
+                contract.set_approval_for_all(account(*operator), *approved)
+            });
+            Some(Ok(()))
+        }
+    }
+}
+
+/// The state replayed from the emitted events.
+#[derive(Debug)]
+struct Erc1155Ledger<T> {
+    supplies: BTreeMap<T, Balance>,
+    balances: BTreeMap<(AccountId, T), Balance>,
+    operators: BTreeMap<(AccountId, AccountId), bool>,
+}
+
+impl<T> Default for Erc1155Ledger<T> {
+    fn default() -> Self {
+        Self {
+            supplies: BTreeMap::new(),
+            balances: BTreeMap::new(),
+            operators: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Copy + Debug + Ord> Erc1155Ledger<T> {
+    /// Replay the events emitted from `from`, returns the count of events.
+    fn replay<C: Erc1155Invariant<TokenId = T>>(&mut self, from: usize) -> usize {
+        let events = get_emitted_events();
+
+        for event in events.iter().skip(from) {
+            if let Some((from, to, items)) = C::decode_transfer_event(event) {
+                for (id, value) in items.into_iter() {
+                    match from {
+                        Some(from) => {
+                            let balance = self.balances.entry((from, id)).or_default();
+                            *balance = balance.checked_sub(value).unwrap_or_else(|| {
+                                panic!(
+                                    "Transfer event moves more {:?} than the balance",
+                                    id
+                                )
+                            });
+                        }
+                        None => *self.supplies.entry(id).or_default() += value,
+                    }
+
+                    match to {
+                        Some(to) => *self.balances.entry((to, id)).or_default() += value,
+                        None => {
+                            let supply = self.supplies.entry(id).or_default();
+                            *supply = supply.checked_sub(value).unwrap_or_else(|| {
+                                panic!(
+                                    "Transfer event burns more {:?} than the supply",
+                                    id
+                                )
+                            });
+                        }
+                    }
+                }
+            } else if let Some((owner, operator, approved)) =
+                C::decode_approval_for_all_event(event)
+            {
+                self.operators.insert((owner, operator), approved);
+            }
+        }
+
+        events.len()
+    }
+
+    /// Check the state of the contract with the ledger and the invariants.
+    fn check<C: Erc1155Invariant<TokenId = T>>(&self, contract: &C, step: &str) {
+        // the zero account can not hold tokens, and its balance can not be
+        // queried, so only the callers are checked.
+        let accounts = caller_accounts();
+
+        for seed in 0..TOKEN_TYPES_COUNT {
+            let id = C::token_id(seed);
+
+            let mut sum: Balance = 0;
+            for account in accounts.iter() {
+                let balance = contract.balance_of(*account, id);
+                assert_eq!(
+                    balance,
+                    self.balances
+                        .get(&(*account, id))
+                        .cloned()
+                        .unwrap_or_default(),
+                    "{}: balance of {:?} for {:?} should be consistent with the events",
+                    step,
+                    account,
+                    id
+                );
+                sum += balance;
+            }
+
+            assert_eq!(
+                sum,
+                self.supplies.get(&id).cloned().unwrap_or_default(),
+                "{}: sum of balances of {:?} should be the minted minus the burned",
+                step,
+                id
+            );
+        }
+
+        for owner in accounts.iter() {
+            for operator in all_accounts().iter() {
+                assert_eq!(
+                    contract.is_approved_for_all(*owner, *operator),
+                    self.operators
+                        .get(&(*owner, *operator))
+                        .cloned()
+                        .unwrap_or(false),
+                    "{}: operator {:?} of {:?} should be consistent with the events",
+                    step,
+                    operator,
+                    owner
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,424 @@
+//! The invariant harness for contracts which compose the erc20 component.
+//!
+//! ```ignore
+//! impl metis_test::Erc20Invariant for Erc20 { ... }
+//!
+//! proptest! {
+//!     #[test]
+//!     fn erc20_invariants_hold(ops in metis_test::erc20_ops(2000, 32)) {
+//!         metis_test::run_erc20_invariants::<Erc20>(1000, &ops);
+//!     }
+//! }
+//! ```
+
+use super::{
+    account,
+    all_accounts,
+    caller_index,
+    target_index,
+};
+use crate::{
+    call_as,
+    get_emitted_events,
+    run_in_env,
+    AccountId,
+    Balance,
+    Erc20Behaviour,
+    Erc20ErrorKind,
+};
+use proptest::prelude::*;
+use std::collections::BTreeMap;
+
+/// The operations on an erc20 contract, the accounts are the index of
+/// `invariants::account`.
+#[derive(Debug, Clone)]
+pub enum Erc20Op {
+    /// `from` transfers `value` to `to`.
+    Transfer { from: u8, to: u8, value: Balance },
+    /// `owner` approves `spender` with `value`.
+    Approve {
+        owner: u8,
+        spender: u8,
+        value: Balance,
+    },
+    /// `spender` transfers `value` from `from` to `to`.
+    TransferFrom {
+        spender: u8,
+        from: u8,
+        to: u8,
+        value: Balance,
+    },
+    /// Mint `value` to `to`.
+    Mint { to: u8, value: Balance },
+    /// Burn `value` from `from`.
+    Burn { from: u8, value: Balance },
+    /// Pause the contract.
+    Pause,
+    /// Unpause the contract.
+    Unpause,
+}
+
+/// Strategy of an erc20 operation, the values are in `0..=max_value`.
+pub fn erc20_op(max_value: Balance) -> impl Strategy<Value = Erc20Op> {
+    let value = 0..=max_value;
+
+    prop_oneof![
+        4 => (caller_index(), target_index(), value.clone())
+            .prop_map(|(from, to, value)| Erc20Op::Transfer { from, to, value }),
+        3 => (caller_index(), target_index(), value.clone()).prop_map(
+            |(owner, spender, value)| Erc20Op::Approve {
+                owner,
+                spender,
+                value,
+            }
+        ),
+        3 => (caller_index(), target_index(), target_index(), value.clone()).prop_map(
+            |(spender, from, to, value)| Erc20Op::TransferFrom {
+                spender,
+                from,
+                to,
+                value,
+            }
+        ),
+        2 => (target_index(), value.clone())
+            .prop_map(|(to, value)| Erc20Op::Mint { to, value }),
+        2 => (target_index(), value).prop_map(|(from, value)| Erc20Op::Burn { from, value }),
+        1 => Just(Erc20Op::Pause),
+        1 => Just(Erc20Op::Unpause),
+    ]
+}
+
+/// Strategy of a sequence of erc20 operations, with at most `max_len` ops.
+pub fn erc20_ops(
+    max_value: Balance,
+    max_len: usize,
+) -> impl Strategy<Value = Vec<Erc20Op>> {
+    prop::collection::vec(erc20_op(max_value), 1..max_len)
+}
+
+/// Trait implemented by the erc20 contracts checked by the invariant harness,
+/// the optional messages return `None` if the contract has no such message.
+pub trait Erc20Invariant: Erc20Behaviour {
+    /// Returns the cap on the token's total supply.
+    fn cap(&self) -> Option<Balance> {
+        None
+    }
+
+    /// Creates `value` tokens and assigns them to `to`.
+    fn mint(
+        &mut self,
+        _to: AccountId,
+        _value: Balance,
+    ) -> Option<Result<(), Self::Error>> {
+        None
+    }
+
+    /// Destroys `value` tokens from `from`.
+    fn burn(
+        &mut self,
+        _from: AccountId,
+        _value: Balance,
+    ) -> Option<Result<(), Self::Error>> {
+        None
+    }
+
+    /// Returns whether the contract is paused.
+    fn paused(&self) -> Option<bool> {
+        None
+    }
+
+    /// Pause the contract.
+    fn pause(&mut self) -> Option<()> {
+        None
+    }
+
+    /// Unpause the contract.
+    fn unpause(&mut self) -> Option<()> {
+        None
+    }
+}
+
+/// Run the erc20 invariant harness in a new off-chain environment, the
+/// contract will be created by alice with `init_amount` tokens.
+pub fn run_erc20_invariants<C: Erc20Invariant>(init_amount: Balance, ops: &[Erc20Op]) {
+    run_in_env(|| check_erc20_invariants::<C>(init_amount, ops))
+}
+
+/// Run the erc20 invariant harness in the current off-chain environment.
+pub fn check_erc20_invariants<C: Erc20Invariant>(init_amount: Balance, ops: &[Erc20Op]) {
+    let mut contract = call_as(account(1), || {
+        C::new_erc20(
+            String::from("MetisTestToken"),
+            String::from("MET"),
+            18_u8,
+            init_amount,
+        )
+    });
+
+    let mut ledger = Erc20Ledger::default();
+    let mut events_count = ledger.replay::<C>(0);
+    ledger.check(&contract, "constructor");
+
+    for (step, op) in ops.iter().enumerate() {
+        let step = format!("step {} {:?}", step, op);
+
+        if would_trap(&contract, op) {
+            continue
+        }
+
+        let expected = expected_result(&contract, op);
+        match execute(&mut contract, op) {
+            Some(Ok(())) => {
+                assert!(
+                    expected.is_none(),
+                    "{}: should fail by {:?}",
+                    step,
+                    expected
+                );
+            }
+            Some(Err(err)) => {
+                let expected = expected
+                    .unwrap_or_else(|| panic!("{}: should succeed, got {:?}", step, err));
+                assert_eq!(err, C::error(expected), "{}: unexpected error", step);
+                assert_eq!(
+                    get_emitted_events().len(),
+                    events_count,
+                    "{}: failed operation should not emit events",
+                    step
+                );
+            }
+            None => continue,
+        }
+
+        events_count = ledger.replay::<C>(events_count);
+        ledger.check(&contract, &step);
+    }
+}
+
+/// Whether the operation would trap by the preconditions of the contract.
+fn would_trap<C: Erc20Invariant>(contract: &C, op: &Erc20Op) -> bool {
+    let paused = contract.paused().unwrap_or(false);
+    let zero = account(0);
+
+    match *op {
+        Erc20Op::Transfer { to, .. } => paused && account(to) != zero,
+        Erc20Op::Approve { .. } => false,
+        Erc20Op::TransferFrom {
+            spender,
+            from,
+            to,
+            value,
+        } => {
+            let allowed = contract.allowance(account(from), account(spender)) >= value;
+            let has_zero = account(from) == zero || account(to) == zero;
+            paused && allowed && !has_zero
+        }
+        Erc20Op::Mint { to, value } => {
+            let cap_exceeded = match contract.cap() {
+                Some(cap) => contract.total_supply() + value > cap,
+                None => false,
+            };
+            cap_exceeded || (paused && account(to) != zero)
+        }
+        Erc20Op::Burn { from, .. } => paused && account(from) != zero,
+        Erc20Op::Pause => paused,
+        Erc20Op::Unpause => !paused,
+    }
+}
+
+/// The expected error of the operation, `None` if it should succeed.
+fn expected_result<C: Erc20Invariant>(
+    contract: &C,
+    op: &Erc20Op,
+) -> Option<Erc20ErrorKind> {
+    let zero = account(0);
+
+    match *op {
+        Erc20Op::Transfer { from, to, value } => {
+            if account(to) == zero {
+                Some(Erc20ErrorKind::AccountIsZero)
+            } else if contract.balance_of(account(from)) < value {
+                Some(Erc20ErrorKind::InsufficientBalance)
+            } else {
+                None
+            }
+        }
+        Erc20Op::Approve { spender, .. } => {
+            if account(spender) == zero {
+                Some(Erc20ErrorKind::AccountIsZero)
+            } else {
+                None
+            }
+        }
+        Erc20Op::TransferFrom {
+            spender,
+            from,
+            to,
+            value,
+        } => {
+            if contract.allowance(account(from), account(spender)) < value {
+                Some(Erc20ErrorKind::InsufficientAllowance)
+            } else if account(from) == zero || account(to) == zero {
+                Some(Erc20ErrorKind::AccountIsZero)
+            } else if contract.balance_of(account(from)) < value {
+                Some(Erc20ErrorKind::InsufficientBalance)
+            } else {
+                None
+            }
+        }
+        Erc20Op::Mint { to, .. } => {
+            if account(to) == zero {
+                Some(Erc20ErrorKind::AccountIsZero)
+            } else {
+                None
+            }
+        }
+        Erc20Op::Burn { from, value } => {
+            if account(from) == zero {
+                Some(Erc20ErrorKind::AccountIsZero)
+            } else if contract.balance_of(account(from)) < value {
+                Some(Erc20ErrorKind::InsufficientBalance)
+            } else {
+                None
+            }
+        }
+        Erc20Op::Pause | Erc20Op::Unpause => None,
+    }
+}
+
+/// Execute the operation, `None` if the contract has no such message.
+fn execute<C: Erc20Invariant>(
+    contract: &mut C,
+    op: &Erc20Op,
+) -> Option<Result<(), C::Error>> {
+    match *op {
+        Erc20Op::Transfer { from, to, value } => {
+            Some(call_as(account(from), || {
+                contract.transfer(account(to), value)
+            }))
+        }
+        Erc20Op::Approve {
+            owner,
+            spender,
+            value,
+        } => {
+            Some(call_as(account(owner), || {
+                contract.approve(account(spender), value)
+            }))
+        }
+        Erc20Op::TransferFrom {
+            spender,
+            from,
+            to,
+            value,
+        } => {
+            Some(call_as(account(spender), || {
+                contract.transfer_from(account(from), account(to), value)
+            }))
+        }
+        Erc20Op::Mint { to, value } => contract.mint(account(to), value),
+        Erc20Op::Burn { from, value } => contract.burn(account(from), value),
+        Erc20Op::Pause => contract.pause().map(Ok),
+        Erc20Op::Unpause => contract.unpause().map(Ok),
+    }
+}
+
+/// The state replayed from the emitted events.
+#[derive(Debug, Default)]
+struct Erc20Ledger {
+    total_supply: Balance,
+    balances: BTreeMap<AccountId, Balance>,
+    allowances: BTreeMap<(AccountId, AccountId), Balance>,
+}
+
+impl Erc20Ledger {
+    /// Replay the events emitted from `from`, returns the count of events.
+    fn replay<C: Erc20Invariant>(&mut self, from: usize) -> usize {
+        let events = get_emitted_events();
+
+        for event in events.iter().skip(from) {
+            if let Some((from, to, value)) = C::decode_transfer_event(event) {
+                match from {
+                    Some(from) => {
+                        let balance = self.balances.entry(from).or_default();
+                        *balance = balance
+                            .checked_sub(value)
+                            .expect("Transfer event moves more than the balance");
+                    }
+                    None => self.total_supply += value,
+                }
+
+                match to {
+                    Some(to) => *self.balances.entry(to).or_default() += value,
+                    None => {
+                        self.total_supply = self
+                            .total_supply
+                            .checked_sub(value)
+                            .expect("Transfer event burns more than the total supply")
+                    }
+                }
+            } else if let Some((owner, spender, value)) = C::decode_approval_event(event)
+            {
+                self.allowances.insert((owner, spender), value);
+            }
+        }
+
+        events.len()
+    }
+
+    /// Check the state of the contract with the ledger and the invariants.
+    fn check<C: Erc20Invariant>(&self, contract: &C, step: &str) {
+        let accounts = all_accounts();
+
+        let total_supply = contract.total_supply();
+        assert_eq!(
+            total_supply, self.total_supply,
+            "{}: total supply should be consistent with the events",
+            step
+        );
+
+        let mut sum: Balance = 0;
+        for account in accounts.iter() {
+            let balance = contract.balance_of(*account);
+            assert_eq!(
+                balance,
+                self.balances.get(account).cloned().unwrap_or_default(),
+                "{}: balance of {:?} should be consistent with the events",
+                step,
+                account
+            );
+            sum += balance;
+        }
+        assert_eq!(
+            sum, total_supply,
+            "{}: sum of balances should be the total supply",
+            step
+        );
+
+        if let Some(cap) = contract.cap() {
+            assert!(
+                total_supply <= cap,
+                "{}: total supply {} exceeds the cap {}",
+                step,
+                total_supply,
+                cap
+            );
+        }
+
+        for owner in accounts.iter() {
+            for spender in accounts.iter() {
+                assert_eq!(
+                    contract.allowance(*owner, *spender),
+                    self.allowances
+                        .get(&(*owner, *spender))
+                        .cloned()
+                        .unwrap_or_default(),
+                    "{}: allowance of {:?} to {:?} should be consistent with the events",
+                    step,
+                    owner,
+                    spender
+                );
+            }
+        }
+    }
+}
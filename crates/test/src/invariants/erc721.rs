@@ -0,0 +1,496 @@
+//! The invariant harness for contracts which compose the erc721 component.
+//!
+//! ```ignore
+//! impl metis_test::Erc721Invariant for Erc721 { ... }
+//!
+//! proptest! {
+//!     #[test]
+//!     fn erc721_invariants_hold(ops in metis_test::erc721_ops(32)) {
+//!         metis_test::run_erc721_invariants::<Erc721>(&ops);
+//!     }
+//! }
+//! ```
+
+use super::{
+    account,
+    all_accounts,
+    caller_accounts,
+    caller_index,
+    target_index,
+};
+use crate::{
+    call_as,
+    get_emitted_events,
+    run_in_env,
+    AccountId,
+};
+use core::fmt::Debug;
+use ink_env::test::EmittedEvent;
+use proptest::prelude::*;
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+/// The count of the token ids used by the harness.
+pub const TOKENS_COUNT: u8 = 8;
+
+/// The operations on an erc721 contract, the accounts are the index of
+/// `invariants::account`, the tokens are the seeds of `token_id`.
+#[derive(Debug, Clone)]
+pub enum Erc721Op {
+    /// Mint the token to `to`.
+    Mint { to: u8, token: u8 },
+    /// Burn the token.
+    Burn { token: u8 },
+    /// `caller` transfers the token from `from` to `to`.
+    TransferFrom {
+        caller: u8,
+        from: u8,
+        to: u8,
+        token: u8,
+    },
+    /// `caller` approves `to` to operate the token.
+    Approve {
+        caller: u8,
+        to: Option<u8>,
+        token: u8,
+    },
+    /// `caller` sets the `approved` of `operator` for all its tokens.
+    SetApprovalForAll {
+        caller: u8,
+        operator: u8,
+        approved: bool,
+    },
+}
+
+/// Strategy of an erc721 operation.
+pub fn erc721_op() -> impl Strategy<Value = Erc721Op> {
+    let token = 0..TOKENS_COUNT;
+
+    prop_oneof![
+        3 => (target_index(), token.clone())
+            .prop_map(|(to, token)| Erc721Op::Mint { to, token }),
+        1 => token.clone().prop_map(|token| Erc721Op::Burn { token }),
+        4 => (caller_index(), target_index(), target_index(), token.clone()).prop_map(
+            |(caller, from, to, token)| Erc721Op::TransferFrom {
+                caller,
+                from,
+                to,
+                token,
+            }
+        ),
+        2 => (caller_index(), prop::option::of(target_index()), token).prop_map(
+            |(caller, to, token)| Erc721Op::Approve { caller, to, token }
+        ),
+        2 => (caller_index(), target_index(), any::<bool>()).prop_map(
+            |(caller, operator, approved)| Erc721Op::SetApprovalForAll {
+                caller,
+                operator,
+                approved,
+            }
+        ),
+    ]
+}
+
+/// Strategy of a sequence of erc721 operations, with at most `max_len` ops.
+pub fn erc721_ops(max_len: usize) -> impl Strategy<Value = Vec<Erc721Op>> {
+    prop::collection::vec(erc721_op(), 1..max_len)
+}
+
+/// Trait implemented by the erc721 contracts checked by the invariant harness,
+/// the optional messages return `None` if the contract has no such message.
+pub trait Erc721Invariant: Sized {
+    /// The token id type of the contract.
+    type TokenId: Copy + Debug + Ord;
+
+    /// The error type returned by the messages.
+    type Error: Debug;
+
+    /// Create the contract.
+    fn new_erc721() -> Self;
+
+    /// Get the token id by the `seed` in `0..TOKENS_COUNT`.
+    fn token_id(seed: u8) -> Self::TokenId;
+
+    /// Decode `event` as a `Transfer` event, return `(from, to, token_id)`.
+    fn decode_transfer_event(
+        event: &EmittedEvent,
+    ) -> Option<(Option<AccountId>, Option<AccountId>, Self::TokenId)>;
+
+    /// Decode `event` as an `Approval` event, return `(owner, spender, token_id)`.
+    fn decode_approval_event(
+        event: &EmittedEvent,
+    ) -> Option<(AccountId, Option<AccountId>, Self::TokenId)>;
+
+    /// Decode `event` as an `ApprovalForAll` event, return
+    /// `(owner, operator, approved)`.
+    fn decode_approval_for_all_event(
+        event: &EmittedEvent,
+    ) -> Option<(AccountId, AccountId, bool)>;
+
+    /// Returns the number of tokens in ``owner``'s account.
+    fn balance_of(&self, owner: AccountId) -> u64;
+
+    /// Returns the owner of the token, `None` if the token not exists.
+    fn owner_of(&self, token_id: Self::TokenId) -> Option<AccountId>;
+
+    /// Returns the account approved for the existing token.
+    fn get_approved(&self, token_id: Self::TokenId) -> Option<AccountId>;
+
+    /// Returns if the `operator` is allowed to manage all of the assets of `owner`.
+    fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool;
+
+    /// Mints the token and transfers it to `to`.
+    fn mint(&mut self, to: AccountId, token_id: Self::TokenId)
+        -> Result<(), Self::Error>;
+
+    /// Destroys the token.
+    fn burn(&mut self, _token_id: Self::TokenId) -> Option<Result<(), Self::Error>> {
+        None
+    }
+
+    /// Transfers the token from `from` to `to` by the caller.
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        token_id: Self::TokenId,
+    ) -> Result<(), Self::Error>;
+
+    /// Gives permission to `to` to transfer the token by the caller.
+    fn approve(&mut self, to: Option<AccountId>, token_id: Self::TokenId);
+
+    /// Approve or remove `operator` as an operator for the caller.
+    fn set_approval_for_all(&mut self, operator: AccountId, approved: bool);
+
+    /// Returns the total amount of tokens, for the enumerable contracts.
+    fn total_supply(&self) -> Option<u32> {
+        None
+    }
+
+    /// Returns the token at `index` of all the tokens, for the enumerable
+    /// contracts.
+    fn token_by_index(&self, _index: u32) -> Option<Self::TokenId> {
+        None
+    }
+
+    /// Returns the token at `index` of the tokens owned by `owner`, for the
+    /// enumerable contracts.
+    fn token_of_owner_by_index(
+        &self,
+        _owner: AccountId,
+        _index: u64,
+    ) -> Option<Self::TokenId> {
+        None
+    }
+}
+
+/// Run the erc721 invariant harness in a new off-chain environment, the
+/// contract will be created by alice.
+pub fn run_erc721_invariants<C: Erc721Invariant>(ops: &[Erc721Op]) {
+    run_in_env(|| check_erc721_invariants::<C>(ops))
+}
+
+/// Run the erc721 invariant harness in the current off-chain environment.
+pub fn check_erc721_invariants<C: Erc721Invariant>(ops: &[Erc721Op]) {
+    let mut contract = call_as(account(1), C::new_erc721);
+
+    let mut ledger = Erc721Ledger::<C::TokenId>::default();
+    let mut events_count = ledger.replay::<C>(0);
+    ledger.check(&contract, "constructor");
+
+    for (step, op) in ops.iter().enumerate() {
+        let step = format!("step {} {:?}", step, op);
+
+        if would_trap(&contract, op) {
+            continue
+        }
+
+        match execute(&mut contract, op) {
+            Some(Ok(())) => (),
+            Some(Err(err)) => panic!("{}: should succeed, got {:?}", step, err),
+            None => continue,
+        }
+
+        events_count = ledger.replay::<C>(events_count);
+        ledger.check(&contract, &step);
+    }
+}
+
+/// Whether the operation would trap by the preconditions of the contract.
+fn would_trap<C: Erc721Invariant>(contract: &C, op: &Erc721Op) -> bool {
+    let zero = account(0);
+
+    match *op {
+        Erc721Op::Mint { to, token } => {
+            account(to) == zero || contract.owner_of(C::token_id(token)).is_some()
+        }
+        Erc721Op::Burn { token } => contract.owner_of(C::token_id(token)).is_none(),
+        Erc721Op::TransferFrom {
+            caller,
+            from,
+            to,
+            token,
+        } => {
+            let token_id = C::token_id(token);
+            match contract.owner_of(token_id) {
+                Some(owner) => {
+                    let caller = account(caller);
+                    let allowed = caller == owner
+                        || contract.get_approved(token_id) == Some(caller)
+                        || contract.is_approved_for_all(owner, caller);
+
+                    !allowed || owner != account(from) || account(to) == zero
+                }
+                None => true,
+            }
+        }
+        Erc721Op::Approve { caller, to, token } => {
+            match contract.owner_of(C::token_id(token)) {
+                Some(owner) => {
+                    let caller = account(caller);
+                    let allowed =
+                        caller == owner || contract.is_approved_for_all(owner, caller);
+
+                    !allowed || to.map(account) == Some(owner)
+                }
+                None => true,
+            }
+        }
+        Erc721Op::SetApprovalForAll {
+            caller, operator, ..
+        } => account(caller) == account(operator),
+    }
+}
+
+/// Execute the operation, `None` if the contract has no such message.
+fn execute<C: Erc721Invariant>(
+    contract: &mut C,
+    op: &Erc721Op,
+) -> Option<Result<(), C::Error>> {
+    match *op {
+        Erc721Op::Mint { to, token } => {
+            Some(contract.mint(account(to), C::token_id(token)))
+        }
+        Erc721Op::Burn { token } => contract.burn(C::token_id(token)),
+        Erc721Op::TransferFrom {
+            caller,
+            from,
+            to,
+            token,
+        } => {
+            Some(call_as(account(caller), || {
+                contract.transfer_from(account(from), account(to), C::token_id(token))
+            }))
+        }
+        Erc721Op::Approve { caller, to, token } => {
+            call_as(account(caller), || {
+                contract.approve(to.map(account), C::token_id(token))
+            });
+            Some(Ok(()))
+        }
+        Erc721Op::SetApprovalForAll {
+            caller,
+            operator,
+            approved,
+        } => {
+            call_as(account(caller), || {
+                contract.set_approval_for_all(account(operator), approved)
+            });
+            Some(Ok(()))
+        }
+    }
+}
+
+/// The state replayed from the emitted events.
+#[derive(Debug)]
+struct Erc721Ledger<T> {
+    owners: BTreeMap<T, AccountId>,
+    approvals: BTreeMap<T, AccountId>,
+    operators: BTreeMap<(AccountId, AccountId), bool>,
+}
+
+impl<T> Default for Erc721Ledger<T> {
+    fn default() -> Self {
+        Self {
+            owners: BTreeMap::new(),
+            approvals: BTreeMap::new(),
+            operators: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Copy + Debug + Ord> Erc721Ledger<T> {
+    /// Replay the events emitted from `from`, returns the count of events.
+    fn replay<C: Erc721Invariant<TokenId = T>>(&mut self, from: usize) -> usize {
+        let events = get_emitted_events();
+
+        for event in events.iter().skip(from) {
+            if let Some((from, to, token_id)) = C::decode_transfer_event(event) {
+                assert_eq!(
+                    self.owners.get(&token_id).cloned(),
+                    from,
+                    "Transfer event of {:?} should be from the owner",
+                    token_id
+                );
+                assert!(
+                    !self.approvals.contains_key(&token_id),
+                    "approval of {:?} should be cleared before transfer",
+                    token_id
+                );
+
+                match to {
+                    Some(to) => self.owners.insert(token_id, to),
+                    None => self.owners.remove(&token_id),
+                };
+            } else if let Some((owner, spender, token_id)) =
+                C::decode_approval_event(event)
+            {
+                assert_eq!(
+                    self.owners.get(&token_id),
+                    Some(&owner),
+                    "Approval event of {:?} should be from the owner",
+                    token_id
+                );
+
+                match spender {
+                    Some(spender) => self.approvals.insert(token_id, spender),
+                    None => self.approvals.remove(&token_id),
+                };
+            } else if let Some((owner, operator, approved)) =
+                C::decode_approval_for_all_event(event)
+            {
+                self.operators.insert((owner, operator), approved);
+            }
+        }
+
+        events.len()
+    }
+
+    /// Check the state of the contract with the ledger and the invariants.
+    fn check<C: Erc721Invariant<TokenId = T>>(&self, contract: &C, step: &str) {
+        for seed in 0..TOKENS_COUNT {
+            let token_id = C::token_id(seed);
+            let owner = contract.owner_of(token_id);
+            assert_eq!(
+                owner,
+                self.owners.get(&token_id).cloned(),
+                "{}: owner of {:?} should be consistent with the events",
+                step,
+                token_id
+            );
+
+            if owner.is_some() {
+                assert_eq!(
+                    contract.get_approved(token_id),
+                    self.approvals.get(&token_id).cloned(),
+                    "{}: approval of {:?} should be consistent with the events",
+                    step,
+                    token_id
+                );
+            }
+        }
+
+        let mut sum = 0_u64;
+        for account in all_accounts().iter() {
+            let balance = contract.balance_of(*account);
+            let owned = self.owners.values().filter(|o| *o == account).count() as u64;
+            assert_eq!(
+                balance, owned,
+                "{}: balance of {:?} should be the count of its tokens",
+                step, account
+            );
+            sum += balance;
+        }
+        assert_eq!(
+            sum,
+            self.owners.len() as u64,
+            "{}: sum of balances should be the count of tokens",
+            step
+        );
+
+        for owner in caller_accounts().iter() {
+            for operator in all_accounts().iter() {
+                assert_eq!(
+                    contract.is_approved_for_all(*owner, *operator),
+                    self.operators
+                        .get(&(*owner, *operator))
+                        .cloned()
+                        .unwrap_or(false),
+                    "{}: operator {:?} of {:?} should be consistent with the events",
+                    step,
+                    operator,
+                    owner
+                );
+            }
+        }
+
+        if let Some(total_supply) = contract.total_supply() {
+            self.check_enumerable(contract, total_supply, step);
+        }
+    }
+
+    /// Check the indexes of the enumerable contract.
+    fn check_enumerable<C: Erc721Invariant<TokenId = T>>(
+        &self,
+        contract: &C,
+        total_supply: u32,
+        step: &str,
+    ) {
+        assert_eq!(
+            total_supply as usize,
+            self.owners.len(),
+            "{}: total supply should be the count of tokens",
+            step
+        );
+
+        let all_tokens = (0..total_supply)
+            .map(|index| {
+                contract.token_by_index(index).unwrap_or_else(|| {
+                    panic!("{}: no token at index {} of all tokens", step, index)
+                })
+            })
+            .collect::<Vec<_>>();
+        let all_tokens_set = all_tokens.iter().cloned().collect::<BTreeSet<_>>();
+        assert_eq!(
+            all_tokens.len(),
+            all_tokens_set.len(),
+            "{}: all tokens index should not have duplicates",
+            step
+        );
+        assert_eq!(
+            all_tokens_set,
+            self.owners.keys().cloned().collect::<BTreeSet<_>>(),
+            "{}: all tokens index should be the existing tokens",
+            step
+        );
+
+        for account in caller_accounts().iter() {
+            let owned_tokens = (0..contract.balance_of(*account))
+                .map(|index| {
+                    contract
+                        .token_of_owner_by_index(*account, index)
+                        .unwrap_or_else(|| {
+                            panic!(
+                                "{}: no token at index {} of {:?}",
+                                step, index, account
+                            )
+                        })
+                })
+                .collect::<BTreeSet<_>>();
+            let expected = self
+                .owners
+                .iter()
+                .filter(|(_, owner)| *owner == account)
+                .map(|(token_id, _)| *token_id)
+                .collect::<BTreeSet<_>>();
+
+            assert_eq!(
+                owned_tokens, expected,
+                "{}: owner index of {:?} should be its tokens",
+                step, account
+            );
+        }
+    }
+}
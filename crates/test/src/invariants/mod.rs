@@ -0,0 +1,66 @@
+//! Property based invariant harnesses for the token components.
+//!
+//! A harness runs a random sequence of operations generated by `proptest`
+//! against a mock contract, and checks the invariants of the component after
+//! each step:
+//!
+//! - The state of the contract is consistent with the emitted events, which
+//!   are replayed into a ledger from the constructor.
+//! - The supply invariants, such as the sum of the balances is the total
+//!   supply, and the cap is never exceeded.
+//!
+//! The operations which would trap (panic) in the contract are skipped: the
+//! off-chain environment can not roll back the storage of a trapped message,
+//! so the harness checks the preconditions by the queries of the contract
+//! before each operation.
+//!
+//! The operations refer to the accounts by index, see `account`.
+
+pub mod erc1155;
+pub mod erc20;
+pub mod erc721;
+
+use crate::{
+    default_accounts,
+    AccountId,
+};
+use proptest::prelude::*;
+
+/// The count of the accounts used by the harnesses, index `0` is the zero
+/// account, the others are the default accounts from alice to frank.
+pub const ACCOUNTS_COUNT: u8 = 7;
+
+/// Get the account by `index`, see `ACCOUNTS_COUNT`.
+pub fn account(index: u8) -> AccountId {
+    let accounts = default_accounts();
+    match index % ACCOUNTS_COUNT {
+        0 => AccountId::from([0x00; 32]),
+        1 => accounts.alice,
+        2 => accounts.bob,
+        3 => accounts.charlie,
+        4 => accounts.django,
+        5 => accounts.eve,
+        _ => accounts.frank,
+    }
+}
+
+/// All the accounts used by the harnesses, include the zero account.
+pub fn all_accounts() -> Vec<AccountId> {
+    (0..ACCOUNTS_COUNT).map(account).collect()
+}
+
+/// The accounts which can be the caller, exclude the zero account.
+pub fn caller_accounts() -> Vec<AccountId> {
+    (1..ACCOUNTS_COUNT).map(account).collect()
+}
+
+/// Strategy of the index of an account which can be the caller.
+pub fn caller_index() -> impl Strategy<Value = u8> + Clone {
+    1..ACCOUNTS_COUNT
+}
+
+/// Strategy of the index of an account which can be the target, include the
+/// zero account.
+pub fn target_index() -> impl Strategy<Value = u8> + Clone {
+    0..ACCOUNTS_COUNT
+}
@@ -0,0 +1,69 @@
+//! Off-chain test helpers for metis components.
+//!
+//! This crate collects the helpers every component test needs, so that the
+//! mocks of components can share them instead of copying `tests/utils`:
+//!
+//! - `env`: set the caller, the transferred value, balances and advance the
+//!   block timestamp in the off-chain environment.
+//! - `event`: typed decoding of the emitted events and topic assertions.
+//! - `behaviours`: reusable conformance suites, any contract which composes a
+//!   component can run the standard tests of the component by impl the
+//!   behaviour trait, such as `erc20_behaves_like`.
+//! - `invariants`: property based harnesses, which run random sequences of
+//!   operations against the token contracts and check the invariants after
+//!   each step, such as `run_erc20_invariants`.
+//! - `merkle`: build the Merkle trees and the proofs, for the components
+//!   which verify the proofs by `metis_lang::merkle_proof`.
+//!
+//! NOTE: All the helpers work with `ink_env::DefaultEnvironment`, which is the
+//! environment of the off-chain test engine.
+
+pub mod behaviours;
+pub mod env;
+pub mod event;
+pub mod invariants;
+pub mod merkle;
+
+pub use behaviours::erc20::{
+    erc20_behaves_like,
+    Erc20Behaviour,
+    Erc20ErrorKind,
+};
+pub use env::*;
+pub use event::*;
+pub use invariants::{
+    erc1155::{
+        erc1155_ops,
+        run_erc1155_invariants,
+        Erc1155Invariant,
+        Erc1155Op,
+    },
+    erc20::{
+        erc20_ops,
+        run_erc20_invariants,
+        Erc20Invariant,
+        Erc20Op,
+    },
+    erc721::{
+        erc721_ops,
+        run_erc721_invariants,
+        Erc721Invariant,
+        Erc721Op,
+    },
+};
+pub use merkle::MerkleTree;
+
+/// The account id type of the off-chain test environment.
+pub type AccountId = <ink_env::DefaultEnvironment as ink_env::Environment>::AccountId;
+
+/// The balance type of the off-chain test environment.
+pub type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+
+/// The hash type of the off-chain test environment.
+pub type Hash = <ink_env::DefaultEnvironment as ink_env::Environment>::Hash;
+
+/// The block number type of the off-chain test environment.
+pub type BlockNumber = <ink_env::DefaultEnvironment as ink_env::Environment>::BlockNumber;
+
+/// The timestamp type of the off-chain test environment.
+pub type Timestamp = <ink_env::DefaultEnvironment as ink_env::Environment>::Timestamp;
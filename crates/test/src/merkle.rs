@@ -0,0 +1,136 @@
+//! Build the Merkle trees and the proofs for the tests, the pairs are sorted
+//! before hashing, same as `metis_lang::merkle_proof`.
+//!
+//! The tree is stored as a complete binary tree in an array, the root is at
+//! `0` and the leaves are at the end in reverse order.
+
+use ink_env::hash::{
+    CryptoHash,
+    HashOutput,
+};
+use std::{
+    collections::VecDeque,
+    marker::PhantomData,
+};
+
+/// The node of the Merkle tree.
+pub type Node = [u8; 32];
+
+/// A Merkle tree hashed by `H`, such as `Blake2x256` or `Keccak256`.
+pub struct MerkleTree<H> {
+    tree: Vec<Node>,
+    leaves_len: usize,
+    _hasher: PhantomData<H>,
+}
+
+impl<H> MerkleTree<H>
+where
+    H: CryptoHash + HashOutput<Type = Node>,
+{
+    /// Build the tree from the hashed `leaves`.
+    pub fn new(leaves: &[Node]) -> Self {
+        assert!(!leaves.is_empty(), "expected at least one leaf");
+
+        let mut tree = vec![Node::default(); 2 * leaves.len() - 1];
+        let len = tree.len();
+        for (i, leaf) in leaves.iter().enumerate() {
+            tree[len - 1 - i] = *leaf;
+        }
+        for i in (0..len - leaves.len()).rev() {
+            tree[i] = hash_pair::<H>(&tree[2 * i + 1], &tree[2 * i + 2]);
+        }
+
+        Self {
+            tree,
+            leaves_len: leaves.len(),
+            _hasher: PhantomData,
+        }
+    }
+
+    /// The root of the tree.
+    pub fn root(&self) -> Node {
+        self.tree[0]
+    }
+
+    /// The proof of the leaf at `index`, from the leaf to the root.
+    pub fn proof(&self, index: usize) -> Vec<Node> {
+        let mut pos = self.tree_index(index);
+        let mut proof = Vec::new();
+        while pos > 0 {
+            proof.push(self.tree[sibling(pos)]);
+            pos = parent(pos);
+        }
+
+        proof
+    }
+
+    /// The multiproof of the leaves at `indices`, returns the proof, the
+    /// proof flags and the leaves in the order of the multiproof.
+    pub fn multi_proof(&self, indices: &[usize]) -> (Vec<Node>, Vec<bool>, Vec<Node>) {
+        let mut positions = indices
+            .iter()
+            .map(|index| self.tree_index(*index))
+            .collect::<Vec<_>>();
+        positions.sort_unstable_by(|a, b| b.cmp(a));
+        positions.dedup();
+        assert_eq!(positions.len(), indices.len(), "expected unique indices");
+
+        let mut stack = positions.iter().copied().collect::<VecDeque<_>>();
+        let mut proof = Vec::new();
+        let mut proof_flags = Vec::new();
+        while let Some(pos) = stack.front().copied().filter(|pos| *pos > 0) {
+            stack.pop_front();
+            let sibling = sibling(pos);
+            if stack.front() == Some(&sibling) {
+                proof_flags.push(true);
+                stack.pop_front();
+            } else {
+                proof_flags.push(false);
+                proof.push(self.tree[sibling]);
+            }
+            stack.push_back(parent(pos));
+        }
+        if indices.is_empty() {
+            proof.push(self.tree[0]);
+        }
+
+        let leaves = positions.iter().map(|pos| self.tree[*pos]).collect();
+        (proof, proof_flags, leaves)
+    }
+
+    fn tree_index(&self, index: usize) -> usize {
+        assert!(index < self.leaves_len, "leaf index out of bounds");
+        self.tree.len() - 1 - index
+    }
+}
+
+/// Returns the hash of the sorted pair of `a` and `b`.
+pub fn hash_pair<H>(a: &Node, b: &Node) -> Node
+where
+    H: CryptoHash + HashOutput<Type = Node>,
+{
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    hash::<H>(&[&first[..], &second[..]].concat())
+}
+
+/// Returns the hash of `input` by `H`.
+pub fn hash<H>(input: &[u8]) -> Node
+where
+    H: CryptoHash + HashOutput<Type = Node>,
+{
+    let mut output = Node::default();
+    <H as CryptoHash>::hash(input, &mut output);
+    output
+}
+
+fn sibling(pos: usize) -> usize {
+    if pos % 2 == 1 {
+        pos + 1
+    } else {
+        pos - 1
+    }
+}
+
+fn parent(pos: usize) -> usize {
+    (pos - 1) / 2
+}
@@ -0,0 +1,37 @@
+#!/usr/bin/env bash
+# Check the features of the wasm builds of the workspace and the contracts
+# excluded from it, the `mock` feature of the stubs is only for the off-chain
+# tests, it is enabled by the dev-dependencies and must not leak into the
+# contracts.
+set -eu
+
+cd "$(dirname "$0")/.."
+
+TARGET=wasm32-unknown-unknown
+
+# Print the features of the wasm build, the args select the packages.
+features() {
+    cargo tree "$@" \
+        --target "$TARGET" \
+        --no-default-features \
+        --edges normal,build,features \
+        --prefix none
+}
+
+check() {
+    local name=$1
+    shift
+
+    if features "$@" | grep -E 'feature "mock"' | sort -u; then
+        echo "error: the \`mock\` feature is enabled for the $TARGET build of $name" >&2
+        exit 1
+    fi
+}
+
+check "the workspace" --workspace
+
+for manifest in $(find contracts/impls contracts/stubs -name Cargo.toml | sort); do
+    check "$manifest" --manifest-path "$manifest"
+done
+
+echo "ok: no \`mock\` feature for the $TARGET build"